@@ -437,8 +437,8 @@ impl KashCli {
                                         let balance_strings = BalanceStrings::from((&balance,&network_type, None));
                                         let id = id.short();
 
-                                        let mature_utxo_count = balance.as_ref().map(|balance|balance.mature_utxo_count.separated_string()).unwrap_or("N/A".to_string());
-                                        let pending_utxo_count = balance.as_ref().map(|balance|balance.pending_utxo_count).unwrap_or(0);
+                                        let mature_utxo_count = balance.as_ref().map(|balance|balance.mature_utxo_count().separated_string()).unwrap_or("N/A".to_string());
+                                        let pending_utxo_count = balance.as_ref().map(|balance|balance.pending_utxo_count()).unwrap_or(0);
 
                                         let pending_utxo_info = if pending_utxo_count > 0 {
                                             format!("({} pending)", pending_utxo_count)
@@ -788,11 +788,11 @@ impl Cli for KashCli {
                 prompt.push(style(account.name_with_id()).blue().to_string());
 
                 if let Ok(balance) = account.balance_as_strings(None) {
-                    if let Some(pending) = balance.pending {
-                        prompt.push(format!("{} ({})", balance.mature, pending));
-                    } else {
-                        prompt.push(balance.mature);
-                    }
+                    let assets = balance.assets.into_iter().map(|asset| match asset.pending {
+                        Some(pending) => format!("{} ({pending})", asset.mature),
+                        None => asset.mature,
+                    });
+                    prompt.push(assets.collect::<Vec<_>>().join(", "));
                 } else {
                     prompt.push("N/A".to_string());
                 }
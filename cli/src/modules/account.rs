@@ -84,6 +84,10 @@ impl Account {
                                 "account import mnemonic multisig [additional keys]",
                                 "Import mnemonic and additional keys for a multisig account",
                             ),
+                            (
+                                "account import watch-only [xpub keys]",
+                                "Import a watch-only account from one (bip32) or multiple (multisig) extended public keys",
+                            ),
                         ],
                         None,
                     )?;
@@ -175,9 +179,12 @@ impl Account {
 
                         return Ok(());
                     }
+                    "watch-only" => {
+                        crate::wizards::import::import_watch_only(&ctx, &argv).await?;
+                    }
                     _ => {
                         tprintln!(ctx, "unknown account import type: '{import_kind}'");
-                        tprintln!(ctx, "supported import types are: 'mnemonic' or 'legacy-data'\r\n");
+                        tprintln!(ctx, "supported import types are: 'mnemonic', 'legacy-data' or 'watch-only'\r\n");
                         return Ok(());
                     }
                 }
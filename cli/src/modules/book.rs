@@ -0,0 +1,151 @@
+use crate::imports::*;
+use kash_consensus_core::asset_type::AssetType;
+
+#[derive(Default, Handler)]
+#[help("Manage address book contacts")]
+pub struct Book;
+
+impl Book {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KashCli>()?;
+        let wallet = ctx.wallet();
+
+        if !wallet.is_open() {
+            return Err(Error::WalletIsNotOpen);
+        }
+
+        let op = if argv.is_empty() { "list".to_string() } else { argv.first().unwrap().to_lowercase() };
+        match op.as_str() {
+            "list" => {
+                let search = argv.get(1).cloned();
+                let entries = wallet.address_book_enumerate(search).await?;
+                if entries.is_empty() {
+                    tprintln!(ctx, "No contacts found");
+                }
+                for entry in entries.iter() {
+                    self.display_entry(&ctx, entry);
+                }
+            }
+            "add" => {
+                if argv.len() < 3 {
+                    return self.display_help(ctx).await;
+                }
+
+                let address = Address::try_from(argv[2].as_str())?;
+                let preferred_asset = argv.get(3).map(|asset| parse_asset_type(asset)).transpose()?;
+                let title = argv.iter().skip(4).cloned().collect::<Vec<_>>().join(" ");
+                let entry = AddressBookEntry::try_new(&argv[1], address)?.with_title(&title).with_preferred_asset(preferred_asset);
+
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                wallet.address_book_create_call(AddressBookCreateRequest { wallet_secret, entry: entry.clone() }).await?;
+                tprintln!(ctx, "Contact {} added", style(&entry.alias).cyan());
+            }
+            "rename" => {
+                if argv.len() < 3 {
+                    return self.display_help(ctx).await;
+                }
+
+                let mut entry = self.get_entry(&ctx, &argv[1]).await?;
+                entry.alias = argv[2].trim().to_string();
+                self.update(&ctx, &argv[1], entry).await?;
+            }
+            "asset" => {
+                if argv.len() < 2 {
+                    return self.display_help(ctx).await;
+                }
+
+                let preferred_asset = argv.get(2).map(|asset| parse_asset_type(asset)).transpose()?;
+                let entry = self.get_entry(&ctx, &argv[1]).await?.with_preferred_asset(preferred_asset);
+                self.update(&ctx, &argv[1], entry).await?;
+            }
+            "title" => {
+                if argv.len() < 2 {
+                    return self.display_help(ctx).await;
+                }
+
+                let title = argv.iter().skip(2).cloned().collect::<Vec<_>>().join(" ");
+                let entry = self.get_entry(&ctx, &argv[1]).await?.with_title(&title);
+                self.update(&ctx, &argv[1], entry).await?;
+            }
+            "note" => {
+                if argv.len() < 2 {
+                    return self.display_help(ctx).await;
+                }
+
+                let notes = argv.iter().skip(2).cloned().collect::<Vec<_>>().join(" ");
+                let notes = (!notes.is_empty()).then_some(notes);
+                let entry = self.get_entry(&ctx, &argv[1]).await?.with_notes(notes);
+                self.update(&ctx, &argv[1], entry).await?;
+            }
+            "remove" => {
+                if argv.len() < 2 {
+                    return self.display_help(ctx).await;
+                }
+
+                let aliases = argv.iter().skip(1).cloned().collect::<Vec<_>>();
+                let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+                wallet.address_book_remove_call(AddressBookRemoveRequest { wallet_secret, aliases: aliases.clone() }).await?;
+                tprintln!(ctx, "Removed {}", aliases.join(", "));
+            }
+            v => {
+                tprintln!(ctx, "unknown command: '{v}'\r\n");
+                return self.display_help(ctx).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_entry(&self, ctx: &Arc<KashCli>, alias: &str) -> Result<AddressBookEntry> {
+        let entry = ctx.store().as_address_book_store()?.load_single(alias).await?;
+        entry.map(|entry| (*entry).clone()).ok_or_else(|| Error::Custom(format!("contact '{alias}' not found")))
+    }
+
+    async fn update(&self, ctx: &Arc<KashCli>, alias: &str, entry: AddressBookEntry) -> Result<()> {
+        let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+        let request = AddressBookUpdateRequest { wallet_secret, alias: alias.to_string(), entry: entry.clone() };
+        ctx.wallet().address_book_update_call(request).await?;
+        tprintln!(ctx, "Contact {} updated", style(&entry.alias).cyan());
+        Ok(())
+    }
+
+    fn display_entry(&self, ctx: &Arc<KashCli>, entry: &AddressBookEntry) {
+        let preferred_asset = entry.preferred_asset.map(|asset| format!(" [{asset}]")).unwrap_or_default();
+        tprintln!(ctx, "{} - {} ({}){preferred_asset}", style(&entry.alias).cyan(), style(&entry.address).blue(), entry.network_type);
+        if !entry.title.is_empty() {
+            tprintln!(ctx, "    {}", entry.title);
+        }
+        if let Some(notes) = &entry.notes {
+            tprintln!(ctx, "    {}", style(notes).dim());
+        }
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KashCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("list [<search>]", "List contacts, optionally filtered by alias, title or address"),
+                (
+                    "add <alias> <address> [<asset> [<title>]]",
+                    "Add a contact with an optional preferred asset (KSH/KUSD/KRV) and title",
+                ),
+                ("rename <alias> <new alias>", "Change the contact alias"),
+                ("asset <alias> [<asset>]", "Set or clear the asset preferred by the contact"),
+                ("title <alias> [<title>]", "Set or clear the contact title"),
+                ("note <alias> [<notes>]", "Set or clear the contact notes"),
+                ("remove <alias> [<alias> ...]", "Remove contacts from the address book"),
+            ],
+            None,
+        )?;
+
+        tprintln!(ctx, "Contact aliases can be used in place of an address with the 'send' command");
+
+        Ok(())
+    }
+}
+
+fn parse_asset_type(asset: &str) -> Result<AssetType> {
+    match asset.to_uppercase().as_str() {
+        asset @ ("KSH" | "KUSD" | "KRV") => Ok(AssetType::from(asset)),
+        _ => Err(Error::Custom(format!("invalid asset type '{asset}', expected KSH, KUSD or KRV"))),
+    }
+}
@@ -5,9 +5,18 @@ use crate::imports::*;
 pub struct Broadcast;
 
 impl Broadcast {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, _argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<KashCli>()?;
-        ctx.wallet().broadcast().await?;
+
+        let Some(source) = argv.first() else {
+            tprintln!(ctx, "usage: broadcast <file|hex>");
+            return Ok(());
+        };
+
+        let (pskt, _) = load_pskt(source).await?;
+        let id = ctx.wallet().broadcast(&pskt).await?;
+        tprintln!(ctx, "Broadcast transaction {id}");
+
         Ok(())
     }
 }
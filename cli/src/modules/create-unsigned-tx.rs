@@ -1,13 +1,43 @@
 use crate::imports::*;
+use kash_consensus_core::asset_type::AssetType;
+use workflow_store::fs;
 
 #[derive(Default, Handler)]
+#[help("Create a partially signed transaction to be signed by the account cosigners")]
 pub struct CreateUnsignedTx;
 
 impl CreateUnsignedTx {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, _argv: Vec<String>, _cmd: &str) -> Result<()> {
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
         let ctx = ctx.clone().downcast_arc::<KashCli>()?;
-        let _account = ctx.wallet().account()?;
-        // TODO account.create_unsigned_transaction().await?;
+        let account = ctx.wallet().account()?.as_derivation_capable()?;
+
+        if argv.len() < 3 {
+            tprintln!(
+                ctx,
+                "usage: create-unsigned-tx <asset type(KSH/KUSD/KRV)> <address|contact> <amount> [<priority fee> [<file>]]"
+            );
+            return Ok(());
+        }
+
+        let asset_type = AssetType::from(argv.first().unwrap().as_str());
+        let (address, _) = resolve_address(&ctx.wallet(), argv.get(1).unwrap()).await?;
+        let amount_sompi = try_parse_required_nonzero_kash_as_sompi_u64(argv.get(2))?;
+        let priority_fee_sompi = try_parse_optional_kash_as_sompi_i64(argv.get(3))?.unwrap_or(0);
+
+        let outputs = PaymentOutputs::from((address, amount_sompi, asset_type));
+        let abortable = Abortable::default();
+
+        let pskt =
+            account.create_unsigned_transaction(asset_type, outputs.into(), priority_fee_sompi.into(), None, &abortable).await?;
+
+        tprintln!(ctx, "Created transaction {} spending {} inputs", pskt.id(), pskt.inputs.len());
+        if let Some(file) = argv.get(4) {
+            store_pskt(&pskt, &fs::resolve_path(file)?).await?;
+            tprintln!(ctx, "Partially signed transaction saved to '{file}'");
+        } else {
+            tprintln!(ctx, "{}", pskt.to_hex()?);
+        }
+
         Ok(())
     }
 }
@@ -9,6 +9,12 @@ impl Details {
         let ctx = ctx.clone().downcast_arc::<KashCli>()?;
         let account = ctx.select_account().await?.as_derivation_capable()?;
 
+        let balance = account.balance_as_strings(None)?;
+        tprintln!(ctx, "Balances:");
+        balance.assets.iter().for_each(|asset| {
+            tprintln!(ctx.term(), "{:>4}{}", "", asset);
+        });
+
         let derivation = account.derivation();
 
         let manager = derivation.receive_address_manager();
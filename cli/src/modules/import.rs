@@ -43,7 +43,9 @@ impl Import {
                     return Err("KDX/kashnet keydata file not found".into());
                 }
             }
-            // todo "read-only" => {}
+            "watch-only" => {
+                crate::wizards::import::import_watch_only(&ctx, &argv[1..]).await?;
+            }
             // "core" => {}
             v => {
                 tprintln!(ctx, "unknown command: '{v}'\r\n");
@@ -62,6 +64,7 @@ impl Import {
                     "Import a 24 or 12 word mnemonic (types: 'bip32' (default), 'legacy', 'multisig'), ",
                 ),
                 ("legacy", "Import a legacy (local KDX) wallet"),
+                ("watch-only [<xpub keys>]", "Import a watch-only account from extended public keys"),
                 // ("purge", "Purge an account from the wallet"),
             ],
             None,
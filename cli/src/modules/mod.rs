@@ -2,6 +2,7 @@ use crate::imports::*;
 
 pub mod account;
 pub mod address;
+pub mod book;
 pub mod broadcast;
 pub mod close;
 pub mod connect;
@@ -46,18 +47,45 @@ pub mod wallet;
 // applications that support metrics
 pub mod metrics;
 
-// TODO
-// broadcast
-// create-unsigned-tx
-// sign
-
 pub fn register_handlers(cli: &Arc<KashCli>) -> Result<()> {
     register_handlers!(
         cli,
         cli.handlers(),
         [
-            account, address, close, connect, details, disconnect, estimate, exit, export, guide, help, history, rpc, list, miner,
-            message, monitor, mute, network, node, open, ping, reload, select, send, server, settings, sweep, track, transfer,
+            account,
+            address,
+            book,
+            broadcast,
+            close,
+            connect,
+            create_unsigned_tx,
+            details,
+            disconnect,
+            estimate,
+            exit,
+            export,
+            guide,
+            help,
+            history,
+            rpc,
+            list,
+            miner,
+            message,
+            monitor,
+            mute,
+            network,
+            node,
+            open,
+            ping,
+            reload,
+            select,
+            send,
+            server,
+            settings,
+            sign,
+            sweep,
+            track,
+            transfer,
             wallet,
             // halt,
             // theme,  start, stop
@@ -116,8 +116,8 @@ impl Monitor {
                 let id = id.short();
 
                 let mature_utxo_count =
-                    balance.as_ref().map(|balance| balance.mature_utxo_count.separated_string()).unwrap_or("N/A".to_string());
-                let pending_utxo_count = balance.as_ref().map(|balance| balance.pending_utxo_count).unwrap_or(0);
+                    balance.as_ref().map(|balance| balance.mature_utxo_count().separated_string()).unwrap_or("N/A".to_string());
+                let pending_utxo_count = balance.as_ref().map(|balance| balance.pending_utxo_count()).unwrap_or(0);
 
                 let pending_utxo_info =
                     if pending_utxo_count > 0 { format!("({pending_utxo_count} pending)") } else { "".to_string() };
@@ -1,6 +1,12 @@
 use crate::imports::*;
 use convert_case::{Case, Casing};
-use kash_rpc_core::{api::ops::RpcApiOps, *};
+use kash_rpc_core::{
+    api::{
+        ops::RpcApiOps,
+        rpc::{MAX_SAFE_HEADERS_LIMIT, MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT, MAX_SAFE_UTXO_SET_LIMIT},
+    },
+    *,
+};
 
 #[derive(Default, Handler)]
 #[help("Execute RPC commands against the connected Kash node")]
@@ -126,24 +132,39 @@ impl Rpc {
                 let result = rpc.get_block_dag_info_call(GetBlockDagInfoRequest {}).await?;
                 self.println(&ctx, result);
             }
-            // RpcApiOps::ResolveFinalityConflict => {
-            //     let result = rpc.resolve_finality_conflict_call(ResolveFinalityConflictRequest {  }).await?;
-            //     self.println(&ctx, result);
-            // }
+            RpcApiOps::ResolveFinalityConflict => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing finality block hash argument"));
+                }
+                let finality_block_hash = RpcHash::from_hex(argv.remove(0).as_str())?;
+                let result = rpc.resolve_finality_conflict_call(ResolveFinalityConflictRequest { finality_block_hash }).await?;
+                self.println(&ctx, result);
+            }
             RpcApiOps::Shutdown => {
                 let result = rpc.shutdown_call(ShutdownRequest {}).await?;
                 self.println(&ctx, result);
             }
-            // RpcApiOps::GetHeaders => {
-            //     let result = rpc.get_headers_call(GetHeadersRequest {  }).await?;
-            //     self.println(&ctx, result);
-            // }
+            RpcApiOps::GetHeaders => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Missing start hash argument"));
+                }
+                let start_hash = RpcHash::from_hex(argv.remove(0).as_str())?;
+                let limit = argv
+                    .first()
+                    .map(|s| s.parse::<u64>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse limit to u64"))?
+                    .unwrap_or(MAX_SAFE_HEADERS_LIMIT);
+                let is_ascending = !argv.iter().any(|arg| arg == "desc");
+                let result = rpc.get_headers_call(GetHeadersRequest { start_hash, limit, is_ascending }).await?;
+                self.println(&ctx, result);
+            }
             RpcApiOps::GetUtxosByAddresses => {
                 if argv.is_empty() {
                     return Err(Error::custom("Please specify at least one address"));
                 }
                 let addresses = argv.iter().map(|s| Address::try_from(s.as_str())).collect::<std::result::Result<Vec<_>, _>>()?;
-                let result = rpc.get_utxos_by_addresses_call(GetUtxosByAddressesRequest { addresses }).await?;
+                let result = rpc.get_utxos_by_addresses_call(GetUtxosByAddressesRequest::new(addresses, None, 0)).await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetBalanceByAddress => {
@@ -153,7 +174,14 @@ impl Rpc {
                 let addresses = argv.iter().map(|s| Address::try_from(s.as_str())).collect::<std::result::Result<Vec<_>, _>>()?;
                 for address in addresses {
                     let result = rpc.get_balance_by_address_call(GetBalanceByAddressRequest { address }).await?;
-                    self.println(&ctx, sompi_to_kash(result.balance));
+                    match result.asset_balance {
+                        Some(balance) => self.println(
+                            &ctx,
+                            [("KSH", balance.ksh), ("KUSD", balance.kusd), ("KRV", balance.krv)]
+                                .map(|(asset, amount)| (asset, sompi_to_kash(amount))),
+                        ),
+                        None => self.println(&ctx, sompi_to_kash(result.balance)),
+                    }
                 }
             }
             RpcApiOps::GetBalancesByAddresses => {
@@ -209,7 +237,14 @@ impl Rpc {
                 self.println(&ctx, result);
             }
             RpcApiOps::GetCoinSupply => {
-                let result = rpc.get_coin_supply_call(GetCoinSupplyRequest {}).await?;
+                let audit = argv.iter().any(|arg| arg == "audit");
+                let daa_score = argv
+                    .iter()
+                    .find(|arg| *arg != "audit")
+                    .map(|arg| arg.parse::<u64>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse daa_score to u64"))?;
+                let result = rpc.get_coin_supply_call(GetCoinSupplyRequest { daa_score, audit }).await?;
                 self.println(&ctx, result);
             }
             RpcApiOps::GetDaaScoreTimestampEstimate => {
@@ -229,6 +264,67 @@ impl Rpc {
                     }
                 }
             }
+            RpcApiOps::GetPrice => {
+                let history_length = argv
+                    .first()
+                    .map(|s| s.parse::<u32>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse history length to u32"))?
+                    .unwrap_or_default();
+                let result = rpc.get_price_call(GetPriceRequest { history_length }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetTransaction => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Please specify a transaction id"));
+                }
+                let transaction_id = RpcTransactionId::from_hex(argv.remove(0).as_str())?;
+                let result = rpc.get_transaction_call(GetTransactionRequest { transaction_id }).await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetTransactionsByAddress => {
+                if argv.is_empty() {
+                    return Err(Error::custom("Please specify an address"));
+                }
+                let address = Address::try_from(argv.remove(0).as_str())?;
+                let start_blue_score = argv
+                    .first()
+                    .map(|s| s.parse::<u64>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse start blue score to u64"))?
+                    .unwrap_or_default();
+                let limit = argv
+                    .get(1)
+                    .map(|s| s.parse::<u32>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse limit to u32"))?
+                    .unwrap_or(MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT);
+                let start_transaction_id = argv.get(2).map(|s| RpcTransactionId::from_hex(s)).transpose()?;
+                let result = rpc
+                    .get_transactions_by_address_call(GetTransactionsByAddressRequest {
+                        address,
+                        start_blue_score,
+                        start_transaction_id,
+                        limit,
+                    })
+                    .await?;
+                self.println(&ctx, result);
+            }
+            RpcApiOps::GetUtxoSet => {
+                let source = match argv.first().map(|s| s.to_lowercase()).as_deref() {
+                    None | Some("virtual") => RpcUtxoSetSource::Virtual,
+                    Some("pruningpoint") => RpcUtxoSetSource::PruningPoint,
+                    Some(_) => return Err(Error::custom("Usage: rpc getutxoset [virtual|pruningpoint] [limit]")),
+                };
+                let limit = argv
+                    .get(1)
+                    .map(|s| s.parse::<u32>())
+                    .transpose()
+                    .map_err(|_| Error::custom("Could not parse limit to u32"))?
+                    .unwrap_or(MAX_SAFE_UTXO_SET_LIMIT);
+                let result = rpc.get_utxo_set_call(GetUtxoSetRequest::new(source, None, None, limit, None, None, None)).await?;
+                self.println(&ctx, result);
+            }
             _ => {
                 tprintln!(ctx, "rpc method exists but is not supported by the cli: '{op_str}'\r\n");
                 return Ok(());
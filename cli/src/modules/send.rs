@@ -13,16 +13,23 @@ impl Send {
 
         // Checking minimum argument length
         if argv.len() < 3 {
-            tprintln!(ctx, "usage: send <asset type(KSH/KUSD/KRV)> <address> <amount> <priority fee>");
+            tprintln!(ctx, "usage: send <asset type(KSH/KUSD/KRV)> <address|contact> <amount> <priority fee>");
             return Ok(());
         }
 
         // Parsing asset type, address, and amounts
         let asset_type = AssetType::from(argv.first().unwrap().as_str());
-        let address = Address::try_from(argv.get(1).unwrap().as_str())?;
+        let (address, contact) = resolve_address(&ctx.wallet(), argv.get(1).unwrap()).await?;
         let amount_sompi = try_parse_required_nonzero_kash_as_sompi_u64(argv.get(2))?;
         let priority_fee_sompi = try_parse_optional_kash_as_sompi_i64(argv.get(3))?.unwrap_or(0);
 
+        if let Some(contact) = contact {
+            tprintln!(ctx, "Sending to {} ({})", style(&contact.alias).cyan(), style(&contact.address).blue());
+            if let Some(preferred_asset) = contact.preferred_asset.filter(|preferred_asset| *preferred_asset != asset_type) {
+                tprintln!(ctx, "{}", style(format!("Warning: '{}' prefers to receive {preferred_asset}", contact.alias)).yellow());
+            }
+        }
+
         let outputs = PaymentOutputs::from((address.clone(), amount_sompi, asset_type));
         let abortable = Abortable::default();
         let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
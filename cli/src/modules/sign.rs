@@ -1,13 +1,104 @@
 use crate::imports::*;
+use kash_wallet_core::error::Error as WalletError;
+use std::path::PathBuf;
 
 #[derive(Default, Handler)]
-#[help("Sign the given partially signed transaction")]
+#[help("Sign or combine partially signed transactions")]
 pub struct Sign;
 
 impl Sign {
-    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, _argv: Vec<String>, _cmd: &str) -> Result<()> {
-        let _ctx = ctx.clone().downcast_arc::<KashCli>()?;
-        // TODO - ctx.wallet().account()?.sign().await?;
+    async fn main(self: Arc<Self>, ctx: &Arc<dyn Context>, argv: Vec<String>, _cmd: &str) -> Result<()> {
+        let ctx = ctx.clone().downcast_arc::<KashCli>()?;
+
+        match argv.first().map(String::as_str) {
+            None => self.display_help(ctx).await,
+            Some("combine") => {
+                if argv.len() < 3 {
+                    return self.display_help(ctx).await;
+                }
+
+                let (mut pskt, path) = load_pskt(&argv[1]).await?;
+                let mut count = 0;
+                for source in argv.iter().skip(2) {
+                    let (other, _) = load_pskt(source).await?;
+                    count += pskt.combine(&other)?;
+                }
+                tprintln!(ctx, "Combined {count} signatures");
+
+                self.store(ctx, &pskt, path).await
+            }
+            Some(source) => {
+                let account = ctx.wallet().account()?;
+                let (mut pskt, path) = load_pskt(source).await?;
+
+                // Nothing is signed blindly: the signer reviews what the transaction does before unlocking any key
+                let network_id = ctx.wallet().network_id()?;
+                if pskt.network_id != network_id {
+                    return Err(WalletError::PsktNetworkMismatch {
+                        expected: network_id.to_string(),
+                        found: pskt.network_id.to_string(),
+                    }
+                    .into());
+                }
+                pskt.validate()?;
+                self.display_summary(&ctx, &pskt)?;
+                let approve = ctx.term().ask(false, "Sign this transaction (type 'y' to approve)?: ").await?;
+                if !approve.trim().eq_ignore_ascii_case("y") {
+                    return Err(Error::UserAbort);
+                }
+
+                let (wallet_secret, payment_secret) = ctx.ask_wallet_secret(Some(&account)).await?;
+
+                let count = account.as_derivation_capable()?.sign_pskt(&mut pskt, wallet_secret, payment_secret).await?;
+                tprintln!(ctx, "Added {count} signatures");
+
+                self.store(ctx, &pskt, path).await
+            }
+        }
+    }
+
+    fn display_summary(&self, ctx: &Arc<KashCli>, pskt: &Pskt) -> Result<()> {
+        let network_type = pskt.network_id.network_type();
+        let suffix = asset_suffix(pskt.asset_type(), &network_type);
+        let amount = |sompi: u64| format!("{} {suffix}", sompi_to_kash_string(sompi));
+
+        tprintln!(ctx, "Transaction {} on {}", pskt.id(), pskt.network_id);
+        tprintln!(ctx, "Spending {} from {} inputs", amount(pskt.input_amount()), pskt.inputs.len());
+        for (address, value) in pskt.output_destinations() {
+            let address = address.map_or_else(|| "non-standard script".to_string(), |address| address.to_string());
+            tprintln!(ctx, "  {} to {address}", amount(value));
+        }
+        tprintln!(ctx, "Fees: {}", amount(pskt.fees()));
+        for (index, input) in pskt.inputs.iter().enumerate() {
+            tprintln!(ctx, "Input {index}: {} of {} signatures", input.signature_count(), input.minimum_signatures);
+        }
+
+        Ok(())
+    }
+
+    async fn store(self: Arc<Self>, ctx: Arc<KashCli>, pskt: &Pskt, path: Option<PathBuf>) -> Result<()> {
+        if pskt.is_fully_signed() {
+            tprintln!(ctx, "Transaction {} is fully signed and can be broadcast", pskt.id());
+        }
+
+        if let Some(path) = path {
+            store_pskt(pskt, &path).await?;
+            tprintln!(ctx, "Partially signed transaction saved to '{}'", path.display());
+        } else {
+            tprintln!(ctx, "{}", pskt.to_hex()?);
+        }
+
+        Ok(())
+    }
+
+    async fn display_help(self: Arc<Self>, ctx: Arc<KashCli>) -> Result<()> {
+        ctx.term().help(
+            &[
+                ("<file|hex>", "Sign the partially signed transaction with the keys of the selected account"),
+                ("combine <file|hex> <file|hex> ...", "Merge the signatures collected by several signers into the first transaction"),
+            ],
+            None,
+        )?;
 
         Ok(())
     }
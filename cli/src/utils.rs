@@ -1,7 +1,14 @@
 use crate::error::Error;
 use crate::result::Result;
+use kash_addresses::Address;
 use kash_consensus_core::constants::SOMPI_PER_KASH;
+use kash_wallet_core::storage::AddressBookEntry;
+use kash_wallet_core::tx::Pskt;
+use kash_wallet_core::wallet::Wallet;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use workflow_store::fs;
 
 pub fn try_parse_required_nonzero_kash_as_sompi_u64<S: ToString + Display>(kash_amount: Option<S>) -> Result<u64> {
     if let Some(kash_amount) = kash_amount {
@@ -58,3 +65,33 @@ pub fn try_parse_optional_kash_as_sompi_i64<S: ToString + Display>(kash_amount:
         Ok(None)
     }
 }
+
+/// Loads a partially signed transaction from the file at `source` or, if no such file exists, from
+/// its hex representation, returning the path of the file it was loaded from
+pub async fn load_pskt(source: &str) -> Result<(Pskt, Option<PathBuf>)> {
+    let path = fs::resolve_path(source)?;
+    if fs::exists(&path).await? {
+        let hex = fs::read_to_string(&path).await?;
+        Ok((Pskt::try_from_hex(&hex)?, Some(path)))
+    } else {
+        Ok((Pskt::try_from_hex(source)?, None))
+    }
+}
+
+/// Writes the hex representation of `pskt` to the file at `path`
+pub async fn store_pskt(pskt: &Pskt, path: &Path) -> Result<()> {
+    fs::write_string(path, &pskt.to_hex()?).await?;
+    Ok(())
+}
+
+/// Parses `destination` as a Kash address or, if it is not a valid address, resolves it as
+/// an address book contact alias, returning the matching contact entry
+pub async fn resolve_address(wallet: &Arc<Wallet>, destination: &str) -> Result<(Address, Option<Arc<AddressBookEntry>>)> {
+    match Address::try_from(destination) {
+        Ok(address) => Ok((address, None)),
+        Err(err) => match wallet.find_address_book_entry(destination).await? {
+            Some(entry) => Ok((entry.address.clone(), Some(entry))),
+            None => Err(err.into()),
+        },
+    }
+}
@@ -130,3 +130,51 @@ pub(crate) async fn import_with_mnemonic(ctx: &Arc<KashCli>, account_kind: Accou
     wallet.select(Some(&account)).await?;
     Ok(())
 }
+
+pub(crate) async fn import_watch_only(ctx: &Arc<KashCli>, xpub_keys: &[String]) -> Result<()> {
+    let term = ctx.term();
+    let wallet = ctx.wallet();
+
+    let mut xpub_keys = xpub_keys.to_vec();
+    if xpub_keys.is_empty() {
+        loop {
+            let xpub_key = term.ask(false, "Enter extended public key: (empty to stop)").await?;
+            if xpub_key.trim().is_empty() {
+                break;
+            }
+            xpub_keys.push(xpub_key.trim().to_owned());
+        }
+    }
+    if xpub_keys.is_empty() {
+        return Err(Error::UserAbort);
+    }
+
+    let (minimum_signatures, cosigner_index) = if xpub_keys.len() > 1 {
+        let minimum_signatures: u16 = term.ask(false, "Enter the minimum number of signatures required: ").await?.trim().parse()?;
+        // cosigners derive their addresses under their own path, the index of their key in the sorted key list
+        let cosigner_index = term
+            .ask(false, "Enter the index of the tracked cosigner key among the alphabetically sorted keys (press <enter> for 0): ")
+            .await?;
+        let cosigner_index: u8 = if cosigner_index.trim().is_empty() { 0 } else { cosigner_index.trim().parse()? };
+        (minimum_signatures, Some(cosigner_index))
+    } else {
+        (1, None)
+    };
+
+    let ecdsa = matches!(
+        term.ask(false, "Are the keys used with ECDSA signatures (type 'y' to approve)?: ").await?.trim(),
+        "y" | "Y" | "YES" | "yes"
+    );
+
+    let name = term.ask(false, "Please enter account name (optional, press <enter> to skip): ").await?.trim().to_string();
+    let name = name.is_not_empty().then_some(name);
+
+    let (wallet_secret, _) = ctx.ask_wallet_secret(None).await?;
+
+    let account_create_args = AccountCreateArgs::new_watch_only(xpub_keys, name, cosigner_index, minimum_signatures, ecdsa);
+    let account = wallet.create_account(&wallet_secret, account_create_args, true).await?;
+
+    tprintln!(ctx, "\nwatch-only account imported: {}\n", account.get_list_string()?);
+    wallet.select(Some(&account)).await?;
+    Ok(())
+}
@@ -11,6 +11,7 @@ use kash_consensus_core::{
     daa_score_timestamp::DaaScoreTimestamp,
     errors::consensus::ConsensusResult,
     header::Header,
+    price::BlockPrice,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
     trusted::{ExternalGhostdagData, TrustedBlock},
     tx::{MutableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
@@ -255,6 +256,15 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(move |c| c.get_virtual_utxos(from_outpoint, chunk_size, skip_first)).await
     }
 
+    pub async fn async_get_virtual_utxos_with_commitment(
+        &self,
+        from_outpoint: Option<TransactionOutpoint>,
+        chunk_size: usize,
+        skip_first: bool,
+    ) -> (Vec<(TransactionOutpoint, UtxoEntry)>, u64, Hash) {
+        self.clone().spawn_blocking(move |c| c.get_virtual_utxos_with_commitment(from_outpoint, chunk_size, skip_first)).await
+    }
+
     pub async fn async_get_tips(&self) -> Vec<Hash> {
         self.clone().spawn_blocking(|c| c.get_tips()).await
     }
@@ -283,6 +293,14 @@ impl ConsensusSessionOwned {
         self.clone().spawn_blocking(|c| c.get_chain_block_samples()).await
     }
 
+    pub async fn async_get_virtual_reference_price(&self) -> Option<u64> {
+        self.clone().spawn_blocking(|c| c.get_virtual_reference_price()).await
+    }
+
+    pub async fn async_get_reference_price_history(&self, max_chain_blocks: usize) -> Vec<BlockPrice> {
+        self.clone().spawn_blocking(move |c| c.get_reference_price_history(max_chain_blocks)).await
+    }
+
     /// Returns the antipast of block `hash` from the POV of `context`, i.e. `antipast(hash) ∩ past(context)`.
     /// Since this might be an expensive operation for deep blocks, we allow the caller to specify a limit
     /// `max_traversal_allowed` on the maximum amount of blocks to traverse for obtaining the answer
@@ -414,6 +432,10 @@ impl ConsensusSessionOwned {
     pub async fn async_finality_point(&self) -> Hash {
         self.clone().spawn_blocking(move |c| c.finality_point()).await
     }
+
+    pub async fn async_resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        self.clone().spawn_blocking(move |c| c.resolve_finality_conflict(finality_block_hash)).await
+    }
 }
 
 pub type ConsensusProxy = ConsensusSessionOwned;
@@ -17,9 +17,11 @@ use crate::{
         tx::TxResult,
     },
     header::Header,
+    price::BlockPrice,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
     trusted::{ExternalGhostdagData, TrustedBlock},
     tx::{MutableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
+    utxo::utxo_diff::UtxoDiff,
     BlockHashSet, BlueWorkType, ChainPath,
 };
 use kash_hashes::Hash;
@@ -145,6 +147,17 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the reference KSH/USD price of the virtual block, i.e., the median price reported by its blue mergeset
+    fn get_virtual_reference_price(&self) -> Option<u64> {
+        unimplemented!()
+    }
+
+    /// Returns the reference prices of the `max_chain_blocks` most recent selected chain blocks, ordered from the sink
+    /// backwards. Chain blocks with no reported price are skipped
+    fn get_reference_price_history(&self, max_chain_blocks: usize) -> Vec<BlockPrice> {
+        unimplemented!()
+    }
+
     fn get_virtual_parents(&self) -> BlockHashSet {
         unimplemented!()
     }
@@ -162,6 +175,17 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns a chunk of the virtual UTXO set along with the DAA score and the UTXO commitment of the virtual
+    /// state it was read from
+    fn get_virtual_utxos_with_commitment(
+        &self,
+        from_outpoint: Option<TransactionOutpoint>,
+        chunk_size: usize,
+        skip_first: bool,
+    ) -> (Vec<(TransactionOutpoint, UtxoEntry)>, u64, Hash) {
+        unimplemented!()
+    }
+
     fn get_tips(&self) -> Vec<Hash> {
         unimplemented!()
     }
@@ -170,7 +194,7 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
-    fn modify_coinbase_payload(&self, payload: Vec<u8>, miner_data: &MinerData) -> CoinbaseResult<Vec<u8>> {
+    fn modify_coinbase_payload(&self, payload: Vec<u8>, miner_data: &MinerData, daa_score: u64) -> CoinbaseResult<Vec<u8>> {
         unimplemented!()
     }
 
@@ -277,6 +301,12 @@ pub trait ConsensusApi: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the UTXO diff of the mergeset of a block belonging to the selected parent chain, i.e., the UTXOs
+    /// created and spent by the transactions it accepted. Diffs are kept only for chain blocks above the pruning point.
+    fn get_block_utxo_diff(&self, hash: Hash) -> ConsensusResult<Arc<UtxoDiff>> {
+        unimplemented!()
+    }
+
     fn is_chain_block(&self, hash: Hash) -> ConsensusResult<bool> {
         unimplemented!()
     }
@@ -329,6 +359,12 @@ pub trait ConsensusApi: Send + Sync {
     fn finality_point(&self) -> Hash {
         unimplemented!()
     }
+
+    /// Resolves a finality conflict by using `finality_block_hash` as the virtual finality point
+    /// and re-resolving virtual accordingly
+    fn resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        unimplemented!()
+    }
 }
 
 pub type DynConsensus = Arc<dyn ConsensusApi>;
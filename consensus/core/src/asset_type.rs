@@ -5,7 +5,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 /// Enum representing different types of assets in the Kash blockchain.
 /// This allows for the representation of multiple currencies such as KSH, KUSD, and KRV.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[wasm_bindgen(js_name = assetType)]
 pub enum AssetType {
     KSH = 0,
@@ -13,6 +13,11 @@ pub enum AssetType {
     KRV = 2,
 }
 
+impl AssetType {
+    /// All asset types, in the order of their numeric values
+    pub const ALL: [AssetType; 3] = [AssetType::KSH, AssetType::KUSD, AssetType::KRV];
+}
+
 impl From<u32> for AssetType {
     fn from(value: u32) -> Self {
         match value {
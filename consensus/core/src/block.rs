@@ -102,7 +102,8 @@ pub enum TemplateBuildMode {
 pub struct BlockTemplate {
     pub block: MutableBlock,
     pub miner_data: MinerData,
-    pub coinbase_has_red_reward: bool,
+    /// The number of trailing coinbase outputs rewarding the miner for red blocks (one per rewarded asset)
+    pub coinbase_red_reward_outputs: usize,
     pub selected_parent_timestamp: u64,
     pub selected_parent_daa_score: u64,
     pub selected_parent_hash: Hash,
@@ -112,12 +113,19 @@ impl BlockTemplate {
     pub fn new(
         block: MutableBlock,
         miner_data: MinerData,
-        coinbase_has_red_reward: bool,
+        coinbase_red_reward_outputs: usize,
         selected_parent_timestamp: u64,
         selected_parent_daa_score: u64,
         selected_parent_hash: Hash,
     ) -> Self {
-        Self { block, miner_data, coinbase_has_red_reward, selected_parent_timestamp, selected_parent_daa_score, selected_parent_hash }
+        Self {
+            block,
+            miner_data,
+            coinbase_red_reward_outputs,
+            selected_parent_timestamp,
+            selected_parent_daa_score,
+            selected_parent_hash,
+        }
     }
 
     pub fn to_virtual_state_approx_id(&self) -> VirtualStateApproxId {
@@ -1,15 +1,26 @@
-use crate::tx::{ScriptPublicKey, Transaction};
+use crate::{
+    asset_type::AssetType,
+    price::PriceReport,
+    tx::{ScriptPublicKey, Transaction},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct MinerData<T: AsRef<[u8]> = Vec<u8>> {
     pub script_public_key: ScriptPublicKey,
     pub extra_data: T,
+    /// Signed KSH/USD price report attached by the miner. It is committed to by the coinbase
+    /// payload only from the price oracle activation on
+    pub price_report: Option<PriceReport>,
 }
 
 impl<T: AsRef<[u8]>> MinerData<T> {
     pub fn new(script_public_key: ScriptPublicKey, extra_data: T) -> Self {
-        Self { script_public_key, extra_data }
+        Self { script_public_key, extra_data, price_report: None }
+    }
+
+    pub fn with_price_report(self, price_report: Option<PriceReport>) -> Self {
+        Self { price_report, ..self }
     }
 }
 
@@ -20,21 +31,69 @@ pub struct CoinbaseData<T: AsRef<[u8]> = Vec<u8>> {
     pub miner_data: MinerData<T>,
 }
 
+/// Transaction fees collected by a block, per asset type. Fees are denominated in the source asset of the paying transaction action
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq)]
+pub struct AssetFees {
+    pub ksh: u64,
+    pub kusd: u64,
+    pub krv: u64,
+}
+
+impl AssetFees {
+    pub fn get(&self, asset_type: AssetType) -> u64 {
+        match asset_type {
+            AssetType::KSH => self.ksh,
+            AssetType::KUSD => self.kusd,
+            AssetType::KRV => self.krv,
+        }
+    }
+
+    pub fn add(&mut self, asset_type: AssetType, fee: u64) {
+        match asset_type {
+            AssetType::KSH => self.ksh += fee,
+            AssetType::KUSD => self.kusd += fee,
+            AssetType::KRV => self.krv += fee,
+        }
+    }
+
+    pub fn sub(&mut self, asset_type: AssetType, fee: u64) {
+        match asset_type {
+            AssetType::KSH => self.ksh -= fee,
+            AssetType::KUSD => self.kusd -= fee,
+            AssetType::KRV => self.krv -= fee,
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct BlockRewardData {
     pub subsidy: u64,
-    pub total_fees: u64,
+    pub total_fees: AssetFees,
     pub script_public_key: ScriptPublicKey,
 }
 
 impl BlockRewardData {
-    pub fn new(subsidy: u64, total_fees: u64, script_public_key: ScriptPublicKey) -> Self {
+    pub fn new(subsidy: u64, total_fees: AssetFees, script_public_key: ScriptPublicKey) -> Self {
         Self { subsidy, total_fees, script_public_key }
     }
+
+    /// Returns the reward of the block in `asset_type`. The subsidy is always paid in KSH
+    pub fn reward(&self, asset_type: AssetType) -> u64 {
+        match asset_type {
+            AssetType::KSH => self.subsidy + self.total_fees.ksh,
+            _ => self.total_fees.get(asset_type),
+        }
+    }
+
+    /// Returns the reward of the block prior to the asset commitment activation, where coinbase outputs do not commit
+    /// to an asset type, so the subsidy and the fees of all assets are rewarded as a single KSH amount
+    pub fn legacy_reward(&self) -> u64 {
+        self.subsidy + self.total_fees.ksh + self.total_fees.kusd + self.total_fees.krv
+    }
 }
 
 /// Holds a coinbase transaction along with meta-data obtained during creation
 pub struct CoinbaseTransactionTemplate {
     pub tx: Transaction,
-    pub has_red_reward: bool, // Does the last output contain reward for red blocks
+    pub red_reward_outputs: usize, // The number of trailing outputs containing reward for red blocks (one per rewarded asset)
 }
@@ -132,6 +132,10 @@ impl<const BPS: u64> Bps<BPS> {
         BPS * LEGACY_COINBASE_MATURITY
     }
 
+    pub const fn krv_unbonding_maturity() -> u64 {
+        BPS * LEGACY_KRV_UNBONDING_MATURITY
+    }
+
     /// DAA score after which the pre-deflationary period switches to the deflationary period.
     ///
     /// This number is calculated as follows:
@@ -102,6 +102,13 @@ pub mod consensus {
 
     /// **Legacy** value of the coinbase maturity parameter for 1 BPS networks
     pub const LEGACY_COINBASE_MATURITY: u64 = 100;
+
+    //
+    // ~~~~~~~~~~~~~~~~~~~ Staking ~~~~~~~~~~~~~~~~~~~
+    //
+
+    /// **Legacy** value of the KRV unbonding maturity parameter for 1 BPS networks (one day worth of DAA score)
+    pub const LEGACY_KRV_UNBONDING_MATURITY: u64 = 86_400;
 }
 
 pub mod perf {
@@ -42,6 +42,9 @@ pub struct Config {
     /// Enable the UTXO index
     pub utxoindex: bool,
 
+    /// Enable the transaction index
+    pub txindex: bool,
+
     /// Enable RPC commands which affect the state of the node
     pub unsafe_rpc: bool,
 
@@ -83,6 +86,7 @@ impl Config {
             is_archival: false,
             enable_sanity_checks: false,
             utxoindex: false,
+            txindex: false,
             unsafe_rpc: false,
             enable_unsynced_mining: false,
             enable_mainnet_mining: false,
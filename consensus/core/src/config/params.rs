@@ -4,7 +4,7 @@ pub use super::{
     genesis::{GenesisBlock, DEVNET_GENESIS, GENESIS, SIMNET_GENESIS, TESTNET11_GENESIS, TESTNET_GENESIS},
 };
 use crate::{
-    constants::STORAGE_MASS_PARAMETER,
+    constants::{STORAGE_MASS_PARAMETER, TX_VERSION, TX_VERSION_ASSET_COMMITMENT},
     network::{NetworkId, NetworkType},
     BlockLevel, KType,
 };
@@ -83,11 +83,47 @@ pub struct Params {
     /// DAA score from which storage mass calculation and transaction mass field are activated as a consensus rule
     pub storage_mass_activation_daa_score: u64,
 
+    /// DAA score from which transactions are required to be of version
+    /// [`TX_VERSION_ASSET_COMMITMENT`](crate::constants::TX_VERSION_ASSET_COMMITMENT), i.e., to have their
+    /// ID, hash and signature hash commit to the transaction action and to the output asset types. From this score on,
+    /// inputs and outputs must also match the asset types of the transaction action and fees are accounted per asset.
+    ///
+    /// From this score on, the MuHash UTXO commitment also commits to the asset type of every UTXO entry, including
+    /// entries created before activation. The UTXO set is rehashed once, by the first chain block past activation
+    pub asset_commitment_activation_daa_score: u64,
+
+    /// DAA score from which coinbase payloads commit to a signed KSH/USD price report, and from which converting
+    /// transaction actions (KUSD minting, KSH staking and redeeming) are validated against the median reported price.
+    /// Converting actions are rejected from the asset commitment activation until this score. Activating requires
+    /// at least one price oracle public key
+    pub price_oracle_activation_daa_score: u64,
+
+    /// X-only public keys of the oracles trusted to sign KSH/USD price reports. None are built into the public networks yet;
+    /// devnet and simnet nodes can configure them (along with the activation) via `--price-oracle-key`
+    pub price_oracle_public_keys: &'static [[u8; 32]],
+
+    /// Maximal age, in milliseconds, of a price report relative to the timestamp of the block reporting it
+    pub max_price_report_age: u64,
+
+    /// Maximal deviation, in basis points, of the exchange rate applied by a converting action from the reference price
+    pub conversion_rate_tolerance_bps: u64,
+
     /// DAA score after which the pre-deflationary period switches to the deflationary period
     pub deflationary_phase_daa_score: u64,
 
     pub pre_deflationary_phase_base_subsidy: u64,
     pub coinbase_maturity: u64,
+
+    /// The number of DAA score units a KRV UTXO has to wait from its creation before it can be spent by a
+    /// `RedeemKSH` action. Note that KRV transfers create new UTXOs, hence they restart the bonding period
+    pub krv_unbonding_maturity: u64,
+
+    /// DAA score from which the KRV unbonding maturity is enforced as a consensus rule
+    pub krv_unbonding_activation_daa_score: u64,
+
+    /// DAA score from which the transaction introspection opcodes are enabled in input scripts
+    pub introspection_activation_daa_score: u64,
+
     pub skip_proof_of_work: bool,
     pub max_block_level: BlockLevel,
     pub pruning_proof_m: u64,
@@ -260,6 +296,24 @@ impl Params {
     pub fn finality_duration(&self) -> u64 {
         self.target_time_per_block * self.finality_depth
     }
+
+    /// Returns whether UTXO entries commit to their asset type in the UTXO commitment of a block at the given DAA score
+    #[inline]
+    #[must_use]
+    pub fn is_asset_commitment_active(&self, daa_score: u64) -> bool {
+        daa_score >= self.asset_commitment_activation_daa_score
+    }
+
+    /// Returns the transaction version required for transactions validated at the given DAA score
+    #[inline]
+    #[must_use]
+    pub fn transaction_version(&self, daa_score: u64) -> u16 {
+        if daa_score < self.asset_commitment_activation_daa_score {
+            TX_VERSION
+        } else {
+            TX_VERSION_ASSET_COMMITMENT
+        }
+    }
 }
 
 impl From<NetworkType> for Params {
@@ -349,6 +403,11 @@ pub const MAINNET_PARAMS: Params = Params {
 
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
+    asset_commitment_activation_daa_score: u64::MAX,
+    price_oracle_activation_daa_score: u64::MAX,
+    price_oracle_public_keys: &[],
+    max_price_report_age: 600_000,
+    conversion_rate_tolerance_bps: 100,
 
     // deflationary_phase_daa_score is the DAA score after which the pre-deflationary period
     // switches to the deflationary period. This number is calculated as follows:
@@ -359,6 +418,9 @@ pub const MAINNET_PARAMS: Params = Params {
     deflationary_phase_daa_score: 15778800 - 259200,
     pre_deflationary_phase_base_subsidy: 50000000000,
     coinbase_maturity: 100,
+    krv_unbonding_maturity: LEGACY_KRV_UNBONDING_MATURITY,
+    krv_unbonding_activation_daa_score: u64::MAX,
+    introspection_activation_daa_score: u64::MAX,
     skip_proof_of_work: false,
     max_block_level: 225,
     pruning_proof_m: 1000,
@@ -408,6 +470,11 @@ pub const TESTNET_PARAMS: Params = Params {
 
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
+    asset_commitment_activation_daa_score: u64::MAX,
+    price_oracle_activation_daa_score: u64::MAX,
+    price_oracle_public_keys: &[],
+    max_price_report_age: 600_000,
+    conversion_rate_tolerance_bps: 100,
 
     // deflationary_phase_daa_score is the DAA score after which the pre-deflationary period
     // switches to the deflationary period. This number is calculated as follows:
@@ -418,6 +485,9 @@ pub const TESTNET_PARAMS: Params = Params {
     deflationary_phase_daa_score: 15778800 - 259200,
     pre_deflationary_phase_base_subsidy: 50000000000,
     coinbase_maturity: 100,
+    krv_unbonding_maturity: LEGACY_KRV_UNBONDING_MATURITY,
+    krv_unbonding_activation_daa_score: u64::MAX,
+    introspection_activation_daa_score: u64::MAX,
     skip_proof_of_work: false,
     max_block_level: 250,
     pruning_proof_m: 1000,
@@ -458,6 +528,9 @@ pub const TESTNET11_PARAMS: Params = Params {
     deflationary_phase_daa_score: Testnet11Bps::deflationary_phase_daa_score(),
     pre_deflationary_phase_base_subsidy: Testnet11Bps::pre_deflationary_phase_base_subsidy(),
     coinbase_maturity: Testnet11Bps::coinbase_maturity(),
+    krv_unbonding_maturity: Testnet11Bps::krv_unbonding_maturity(),
+    krv_unbonding_activation_daa_score: u64::MAX,
+    introspection_activation_daa_score: u64::MAX,
 
     coinbase_payload_script_public_key_max_len: 150,
     max_coinbase_payload_len: 204,
@@ -474,6 +547,11 @@ pub const TESTNET11_PARAMS: Params = Params {
 
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: 0,
+    asset_commitment_activation_daa_score: u64::MAX,
+    price_oracle_activation_daa_score: u64::MAX,
+    price_oracle_public_keys: &[],
+    max_price_report_age: 600_000,
+    conversion_rate_tolerance_bps: 100,
 
     skip_proof_of_work: false,
     max_block_level: 250,
@@ -510,6 +588,9 @@ pub const SIMNET_PARAMS: Params = Params {
     deflationary_phase_daa_score: Testnet11Bps::deflationary_phase_daa_score(),
     pre_deflationary_phase_base_subsidy: Testnet11Bps::pre_deflationary_phase_base_subsidy(),
     coinbase_maturity: Testnet11Bps::coinbase_maturity(),
+    krv_unbonding_maturity: Testnet11Bps::krv_unbonding_maturity(),
+    krv_unbonding_activation_daa_score: u64::MAX,
+    introspection_activation_daa_score: u64::MAX,
 
     coinbase_payload_script_public_key_max_len: 150,
     max_coinbase_payload_len: 204,
@@ -526,6 +607,11 @@ pub const SIMNET_PARAMS: Params = Params {
 
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: 0,
+    asset_commitment_activation_daa_score: u64::MAX,
+    price_oracle_activation_daa_score: u64::MAX,
+    price_oracle_public_keys: &[],
+    max_price_report_age: 600_000,
+    conversion_rate_tolerance_bps: 100,
 
     skip_proof_of_work: true, // For simnet only, PoW can be simulated by default
     max_block_level: 250,
@@ -572,6 +658,11 @@ pub const DEVNET_PARAMS: Params = Params {
 
     storage_mass_parameter: STORAGE_MASS_PARAMETER,
     storage_mass_activation_daa_score: u64::MAX,
+    asset_commitment_activation_daa_score: u64::MAX,
+    price_oracle_activation_daa_score: u64::MAX,
+    price_oracle_public_keys: &[],
+    max_price_report_age: 600_000,
+    conversion_rate_tolerance_bps: 100,
 
     // deflationary_phase_daa_score is the DAA score after which the pre-deflationary period
     // switches to the deflationary period. This number is calculated as follows:
@@ -582,6 +673,9 @@ pub const DEVNET_PARAMS: Params = Params {
     deflationary_phase_daa_score: 15778800 - 259200,
     pre_deflationary_phase_base_subsidy: 50000000000,
     coinbase_maturity: 100,
+    krv_unbonding_maturity: LEGACY_KRV_UNBONDING_MATURITY,
+    krv_unbonding_activation_daa_score: u64::MAX,
+    introspection_activation_daa_score: u64::MAX,
     skip_proof_of_work: false,
     max_block_level: 250,
     pruning_proof_m: 1000,
@@ -1,9 +1,14 @@
 /// BLOCK_VERSION represents the current block version
 pub const BLOCK_VERSION: u16 = 1;
 
-/// TX_VERSION is the current latest supported transaction version.
+/// TX_VERSION is the legacy transaction version, whose ID, hash and signature hash do not commit
+/// to the transaction action and to the output asset types.
 pub const TX_VERSION: u16 = 0;
 
+/// TX_VERSION_ASSET_COMMITMENT is the transaction version whose ID, hash and signature hash commit to the
+/// transaction action and to the output asset types. It is the latest supported transaction version.
+pub const TX_VERSION_ASSET_COMMITMENT: u16 = 1;
+
 pub const LOCK_TIME_THRESHOLD: u64 = 500_000_000_000;
 
 /// MAX_SCRIPT_PUBLIC_KEY_VERSION is the current latest supported public key script version.
@@ -13,6 +13,23 @@ pub enum CoinbaseError {
 
     #[error("coinbase payload length is {0} bytes but it needs to be at least {1} bytes long in order to accommodate the script public key")]
     PayloadCantContainScriptPublicKey(usize, usize),
+
+    #[error(
+        "coinbase payload length is {0} bytes but it needs to be at least {1} bytes long in order to accommodate the price report"
+    )]
+    PayloadCantContainPriceReport(usize, usize),
+
+    #[error("coinbase payload reports a zero KSH/USD price")]
+    PayloadZeroPrice,
+
+    #[error("coinbase payload is expected to contain a KSH/USD price report from DAA score {0} on")]
+    PayloadMissingPriceReport(u64),
+
+    #[error("coinbase payload price report is not validly signed by price oracle {0}")]
+    InvalidPriceReportSignature(u8),
+
+    #[error("coinbase payload price report timestamp {0} is not within the allowed age window ending at the block timestamp {1}")]
+    PriceReportTimestampOutOfRange(u64, u64),
 }
 
 pub type CoinbaseResult<T> = std::result::Result<T, CoinbaseError>;
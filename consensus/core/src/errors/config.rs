@@ -14,6 +14,12 @@ pub enum ConfigError {
     #[error("Configuration: --ram-scale cannot be set above 10.0")]
     RamScaleTooHigh,
 
+    #[error("Cannot configure price oracles on any network except devnet and simnet")]
+    PriceOracleOnNonDevnet,
+
+    #[error("--price-oracle-activation-daa-score requires at least one --price-oracle-key")]
+    MissingPriceOracleKeys,
+
     #[cfg(feature = "devnet-prealloc")]
     #[error("Cannot preallocate UTXOs on any network except devnet")]
     PreallocUtxosOnNonDevnet,
@@ -1,5 +1,6 @@
+use crate::asset_type::AssetType;
 use crate::constants::MAX_SOMPI;
-use crate::tx::TransactionOutpoint;
+use crate::tx::{TransactionAction, TransactionOutpoint};
 use kash_txscript_errors::TxScriptError;
 use thiserror::Error;
 
@@ -20,6 +21,9 @@ pub enum TxRuleError {
     #[error("transaction version {0} is unknown")]
     UnknownTxVersion(u16),
 
+    #[error("transaction version {0} is not allowed at DAA score {1} where version {2} is expected")]
+    UnexpectedTxVersion(u16, u64, u16),
+
     #[error("transaction has {0} inputs where the max allowed is {1}")]
     TooManyInputs(usize, usize),
 
@@ -50,6 +54,12 @@ pub enum TxRuleError {
     )]
     ImmatureCoinbaseSpend(usize, TransactionOutpoint, u64, u64, u64),
 
+    #[error(
+        "transaction input #{0} tried to redeem KRV outpoint {1} with daa score of {2} 
+    while the merging block daa score is {3} and the KRV unbonding maturity period of {4} hasn't passed yet"
+    )]
+    ImmatureKrvRedeem(usize, TransactionOutpoint, u64, u64, u64),
+
     #[error("transaction total inputs spending amount overflowed u64")]
     InputAmountOverflow,
 
@@ -71,6 +81,23 @@ pub enum TxRuleError {
     #[error("transaction tries to spend {0} while its total inputs amount is {1}")]
     SpendTooHigh(u64, u64),
 
+    #[error("transaction input #{0} spends a {1} UTXO while the {2:?} action expects {3} inputs")]
+    WrongInputAssetType(usize, AssetType, TransactionAction, AssetType),
+
+    #[error("transaction output #{0} is of asset type {1} which is neither the source nor the destination asset of the {2:?} action")]
+    WrongOutputAssetType(usize, AssetType, TransactionAction),
+
+    #[error("no reference KSH/USD price is available for validating the {0:?} action")]
+    MissingConversionPrice(TransactionAction),
+
+    #[error("the {0:?} action is a conversion, which is only valid from the price oracle activation at DAA score {1} on")]
+    ConversionBeforePriceOracleActivation(TransactionAction, u64),
+
+    #[error(
+        "the {0:?} action outputs {1} units of its destination asset while {2} are expected at the reference price (tolerance: {3})"
+    )]
+    ConversionRateOutOfBand(TransactionAction, u64, u64, u64),
+
     #[error("one of the transaction sequence locks conditions was not met")]
     SequenceLockConditionsAreNotMet,
 
@@ -5,7 +5,7 @@ use crate::{
     tx::{ScriptPublicKey, Transaction, TransactionOutpoint, TransactionOutput, VerifiableTransaction},
 };
 
-use super::{sighash_type::SigHashType, HasherExtensions};
+use super::{sighash_type::SigHashType, tx::commits_to_assets, HasherExtensions};
 
 /// Holds all fields used in the calculation of a transaction's sig_hash which are
 /// the same for all transaction inputs.
@@ -104,7 +104,7 @@ fn outputs_hash(tx: &Transaction, hash_type: SigHashType, reused_values: &mut Si
         }
 
         let mut hasher = TransactionSigningHash::new();
-        hash_output(&mut hasher, &tx.outputs[input_index]);
+        hash_output(&mut hasher, &tx.outputs[input_index], commits_to_assets(tx));
         return hasher.finalize();
     }
 
@@ -113,8 +113,9 @@ fn outputs_hash(tx: &Transaction, hash_type: SigHashType, reused_values: &mut Si
         outputs_hash
     } else {
         let mut hasher = TransactionSigningHash::new();
+        let commit_asset_type = commits_to_assets(tx);
         for output in tx.outputs.iter() {
-            hash_output(&mut hasher, output);
+            hash_output(&mut hasher, output, commit_asset_type);
         }
         let outputs_hash = hasher.finalize();
         reused_values.outputs_hash = Some(outputs_hash);
@@ -127,9 +128,12 @@ fn hash_outpoint(hasher: &mut impl Hasher, outpoint: TransactionOutpoint) {
     hasher.write_u32(outpoint.index);
 }
 
-fn hash_output(hasher: &mut impl Hasher, output: &TransactionOutput) {
+fn hash_output(hasher: &mut impl Hasher, output: &TransactionOutput, commit_asset_type: bool) {
     hasher.write_u64(output.value);
     hash_script_public_key(hasher, &output.script_public_key);
+    if commit_asset_type {
+        hasher.write_u32(output.asset_type.into());
+    }
 }
 
 fn hash_script_public_key(hasher: &mut impl Hasher, script_public_key: &ScriptPublicKey) {
@@ -161,8 +165,12 @@ pub fn calc_schnorr_signature_hash(
         .write_u64(tx.lock_time)
         .update(&tx.subnetwork_id)
         .write_u64(tx.gas)
-        .update(payload_hash(tx))
-        .write_u8(hash_type.to_u8());
+        .update(payload_hash(tx));
+    if commits_to_assets(tx) {
+        // Commit to the action and to the asset type of the spent UTXO as well, so that neither can be altered post-signing
+        hasher.write_u32(tx.action.into()).write_u32(input.1.asset_type.into());
+    }
+    hasher.write_u8(hash_type.to_u8());
     hasher.finalize()
 }
 
@@ -186,7 +194,8 @@ mod tests {
     use smallvec::SmallVec;
 
     use crate::{
-        asset_type::AssetType::KSH,
+        asset_type::AssetType::{KRV, KSH, KUSD},
+        constants::TX_VERSION_ASSET_COMMITMENT,
         hashing::sighash_type::{SIG_HASH_ALL, SIG_HASH_ANY_ONE_CAN_PAY, SIG_HASH_NONE, SIG_HASH_SINGLE},
         subnets::SubnetworkId,
         tx::{PopulatedTransaction, Transaction, TransactionId, TransactionInput, UtxoEntry},
@@ -305,6 +314,10 @@ mod tests {
             ],
         );
 
+        let mut asset_commitment_tx = native_tx.clone();
+        asset_commitment_tx.version = TX_VERSION_ASSET_COMMITMENT;
+        let asset_commitment_populated_tx = PopulatedTransaction::new(&asset_commitment_tx, native_populated_tx.entries.clone());
+
         enum ModifyAction {
             NoAction,
             Output(usize),
@@ -315,6 +328,9 @@ mod tests {
             Payload,
             Gas,
             SubnetworkId,
+            TxAction,
+            OutputAssetType(usize),
+            SpentAssetType(usize),
         }
 
         struct TestVector<'a> {
@@ -559,6 +575,88 @@ mod tests {
                 action: ModifyAction::SubnetworkId,
                 expected_hash: "a5d1230ede0dfcfd522e04123a7bcd721462fed1d3a87352031a4f6e3c4389b6", // should change the hash
             },
+            // Legacy transaction version does not commit to asset types
+            TestVector {
+                name: "native-all-0-modify-tx-action",
+                populated_tx: &native_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::TxAction,
+                expected_hash: "03b7ac6927b2b67100734c3cc313ff8c2e8b3ce3e746d46dd660b706a916b1f5", // shouldn't change the hash
+            },
+            TestVector {
+                name: "native-all-0-modify-output-asset-type-1",
+                populated_tx: &native_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::OutputAssetType(1),
+                expected_hash: "03b7ac6927b2b67100734c3cc313ff8c2e8b3ce3e746d46dd660b706a916b1f5", // shouldn't change the hash
+            },
+            TestVector {
+                name: "native-all-0-modify-spent-asset-type-0",
+                populated_tx: &native_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::SpentAssetType(0),
+                expected_hash: "03b7ac6927b2b67100734c3cc313ff8c2e8b3ce3e746d46dd660b706a916b1f5", // shouldn't change the hash
+            },
+            // Asset commitment transaction version
+            TestVector {
+                name: "asset-commitment-all-0",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::NoAction,
+                expected_hash: "9d67252f46a06f2413323a727c642f38baffd6118e8545f4b759a58b172f719a", // should change the hash
+            },
+            TestVector {
+                name: "asset-commitment-all-0-modify-tx-action",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::TxAction,
+                expected_hash: "de27acabca12e82b6cc8bc38eba3daac156400267df99815b7e4477d4411d3e1", // should change the hash
+            },
+            TestVector {
+                name: "asset-commitment-all-0-modify-output-asset-type-1",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::OutputAssetType(1),
+                expected_hash: "7359a50c1ac3dedeed232be73ad6a682a373288b5a7b938ab5b72f8b2b03ab7a", // should change the hash
+            },
+            TestVector {
+                name: "asset-commitment-none-0",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_NONE,
+                input_index: 0,
+                action: ModifyAction::NoAction,
+                expected_hash: "7025a69e53c76358d9f3a661790490f8d0be216f99f2fcfa727483bfc707d75a", // should change the hash
+            },
+            TestVector {
+                name: "asset-commitment-none-0-modify-output-asset-type-1",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_NONE,
+                input_index: 0,
+                action: ModifyAction::OutputAssetType(1),
+                expected_hash: "7025a69e53c76358d9f3a661790490f8d0be216f99f2fcfa727483bfc707d75a", // shouldn't change the hash
+            },
+            TestVector {
+                name: "asset-commitment-all-0-modify-spent-asset-type-0",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::SpentAssetType(0),
+                expected_hash: "4fe046d870f91b6c9ab59f6f87c218f6eb782881bbe92bfefcee894768a75942", // should change the hash
+            },
+            TestVector {
+                name: "asset-commitment-all-0-modify-spent-asset-type-1",
+                populated_tx: &asset_commitment_populated_tx,
+                hash_type: SIG_HASH_ALL,
+                input_index: 0,
+                action: ModifyAction::SpentAssetType(1),
+                expected_hash: "9d67252f46a06f2413323a727c642f38baffd6118e8545f4b759a58b172f719a", // shouldn't change the hash
+            },
         ];
 
         for test in tests {
@@ -588,6 +686,13 @@ mod tests {
                 ModifyAction::SubnetworkId => {
                     tx.subnetwork_id = SubnetworkId::from_bytes([6, 6, 6, 4, 2, 0, 1, 3, 3, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
                 }
+                ModifyAction::TxAction => tx.action = TransactionAction::MintKUSD,
+                ModifyAction::OutputAssetType(i) => {
+                    tx.outputs[i].asset_type = KUSD;
+                }
+                ModifyAction::SpentAssetType(i) => {
+                    entries[i].asset_type = KRV;
+                }
             }
             let populated_tx = PopulatedTransaction::new(&tx, entries);
             let mut reused_values = SigHashReusedValues::new();
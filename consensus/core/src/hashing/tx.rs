@@ -1,5 +1,8 @@
 use super::HasherExtensions;
-use crate::tx::{Transaction, TransactionId, TransactionInput, TransactionOutpoint, TransactionOutput};
+use crate::{
+    constants::TX_VERSION_ASSET_COMMITMENT,
+    tx::{Transaction, TransactionId, TransactionInput, TransactionOutpoint, TransactionOutput},
+};
 use kash_hashes::{Hash, Hasher};
 
 /// A bitmask defining which transaction fields we
@@ -27,8 +30,15 @@ pub(crate) fn id(tx: &Transaction) -> TransactionId {
     hasher.finalize()
 }
 
+/// Returns whether the transaction version requires hashing to commit to the tx action and the output asset types
+#[inline(always)]
+pub(crate) fn commits_to_assets(tx: &Transaction) -> bool {
+    tx.version >= TX_VERSION_ASSET_COMMITMENT
+}
+
 /// Write the transaction into the provided hasher according to the encoding flags
 fn write_transaction<T: Hasher>(hasher: &mut T, tx: &Transaction, encoding_flags: TxEncodingFlags, include_mass_field: bool) {
+    let commit_assets = commits_to_assets(tx);
     hasher.update(tx.version.to_le_bytes()).write_len(tx.inputs.len());
     for input in tx.inputs.iter() {
         // Write the tx input
@@ -38,7 +48,11 @@ fn write_transaction<T: Hasher>(hasher: &mut T, tx: &Transaction, encoding_flags
     hasher.write_len(tx.outputs.len());
     for output in tx.outputs.iter() {
         // Write the tx output
-        write_output(hasher, output);
+        write_output(hasher, output, commit_assets);
+    }
+
+    if commit_assets {
+        hasher.write_u32(tx.action.into());
     }
 
     hasher.update(tx.lock_time.to_le_bytes()).update(&tx.subnetwork_id).update(tx.gas.to_le_bytes()).write_var_bytes(&tx.payload);
@@ -71,11 +85,14 @@ fn write_outpoint<T: Hasher>(hasher: &mut T, outpoint: &TransactionOutpoint) {
 }
 
 #[inline(always)]
-fn write_output<T: Hasher>(hasher: &mut T, output: &TransactionOutput) {
+fn write_output<T: Hasher>(hasher: &mut T, output: &TransactionOutput, commit_asset_type: bool) {
     hasher
         .update(output.value.to_le_bytes())
         .update(output.script_public_key.version().to_le_bytes())
         .write_var_bytes(output.script_public_key.script());
+    if commit_asset_type {
+        hasher.write_u32(output.asset_type.into());
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +100,7 @@ mod tests {
     use super::*;
     use crate::{
         asset_type::AssetType,
+        constants::TX_VERSION,
         subnets::{self, SubnetworkId},
         tx::{scriptvec, ScriptPublicKey},
     };
@@ -129,8 +147,8 @@ mod tests {
                 0,
                 Vec::new(),
             ),
-            expected_id: "dafa415216d26130a899422203559c809d3efe72e20d48505fb2f08787bc4f49",
-            expected_hash: "e4045023768d98839c976918f80c9419c6a93003724eda97f7c61a5b68de851b",
+            expected_id: "a756e8786ccda9ea0aaf07aa0e0874e745af60e47748c04db0fc13725a9310af",
+            expected_hash: "9f11e1c5a821a0bcd56e794514ac11fc5846f3301b3608c87093268346116e17",
         });
 
         let outputs = vec![TransactionOutput::new(1564, ScriptPublicKey::new(7, scriptvec![1, 2, 3, 4, 5]), AssetType::KSH)];
@@ -147,8 +165,8 @@ mod tests {
                 0,
                 Vec::new(),
             ),
-            expected_id: "d1cd9dc1f26955832ccd12c27afaef4b71443aa7e7487804baf340952ca927e5",
-            expected_hash: "e5523c70f6b986cad9f6959e63f080e6ac5f93bc2a9e0e01a89ca9bf6908f51c",
+            expected_id: "bb273aa072825c27be1acd7de9674e9b4f7bd8311b2d2b13ac1bacdb504e249d",
+            expected_hash: "63c1041ff45fc3f2df8fb1f93e55870618efba9cebeeb21462a146bfc9cd5a8b",
         });
 
         // Test #4
@@ -163,8 +181,8 @@ mod tests {
                 3,
                 Vec::new(),
             ),
-            expected_id: "59b3d6dc6cdc660c389c3fdb5704c48c598d279cdf1bab54182db586a4c95dd5",
-            expected_hash: "b70f2f14c2f161a29b77b9a78997887a8e727bb57effca38cd246cb270b19cd5",
+            expected_id: "7c6fa0326beaed9a28ef5d8f1bf131b1338d96522da732c8a23b12cb38077272",
+            expected_hash: "cf78649a63ebfc73adda7a95575eb065db9342152126014ee4e57ac3d84fdf31",
         });
 
         let inputs = vec![TransactionInput::new(
@@ -186,8 +204,8 @@ mod tests {
                 3,
                 Vec::new(),
             ),
-            expected_id: "9d106623860567915b19cea33af486286a31b4bfc68627c6d4d377287afb40ad",
-            expected_hash: "cd575e69fbf5f97fbfd4afb414feb56f8463b3948d6ac30f0ecdd9622672fab9",
+            expected_id: "4366c419c79ee56e898abf4addda4086d262ed8fbbaaa45f2367ed8b8e401aa7",
+            expected_hash: "07a9b454737cd3a6979a5c845bd3ad7fcad2e8157b06fbc5d4eb512a137cc652",
         });
 
         // Test #6
@@ -202,8 +220,8 @@ mod tests {
                 3,
                 Vec::new(),
             ),
-            expected_id: "3fad809b11bd5a4af027aa4ac3fbde97e40624fd40965ba3ee1ee1b57521ad10",
-            expected_hash: "b4eb5f0cab5060bf336af5dcfdeb2198cc088b693b35c87309bd3dda04f1cfb9",
+            expected_id: "8312209989f0687dfd98168ecde58b66880493b1471702c08dc69a0d7438c8ec",
+            expected_hash: "cc7dfece5621977381a4d82bb9cf81b0b4f82425b2a291dcbabc73420ce308a1",
         });
 
         // Test #7
@@ -218,8 +236,58 @@ mod tests {
                 3,
                 Vec::new(),
             ),
-            expected_id: "c542a204ab9416df910b01540b0c51b85e6d4e1724e081e224ea199a9e54e1b3",
-            expected_hash: "31da267d5c34f0740c77b8c9ebde0845a01179ec68074578227b804bac306361",
+            expected_id: "478331e57076423b09fc7b35c4f393ce86bc845cf02e23f27b994462f8930cde",
+            expected_hash: "dab8ba10d7c034f1a69f5c2ac9d7cfdef81beb0f14eb29f13fef1a2628335bc7",
+        });
+
+        let kusd_outputs = vec![TransactionOutput::new(1564, ScriptPublicKey::new(7, scriptvec![1, 2, 3, 4, 5]), AssetType::KUSD)];
+
+        // Test #8
+        tests.push(Test {
+            tx: Transaction::new(
+                TX_VERSION_ASSET_COMMITMENT,
+                inputs.clone(),
+                outputs.clone(),
+                TransactionAction::TransferKSH,
+                54,
+                SubnetworkId::from_byte(0),
+                3,
+                Vec::new(),
+            ),
+            expected_id: "c7d2867a65425eef24c5310322391f52987868ee062dbd60d801338d892a22c4",
+            expected_hash: "5e771a0c05d19b7b54cf28b1778320d9c04233226cb0fe44a95957ccfdc06889",
+        });
+
+        // Test #9
+        tests.push(Test {
+            tx: Transaction::new(
+                TX_VERSION_ASSET_COMMITMENT,
+                inputs.clone(),
+                kusd_outputs.clone(),
+                TransactionAction::TransferKSH,
+                54,
+                SubnetworkId::from_byte(0),
+                3,
+                Vec::new(),
+            ),
+            expected_id: "15d4a119b0a8fed5aa4196e772d06e14e8d1550a2fe2ccf88e4085b79c257a1d",
+            expected_hash: "a11665055165867b392f42237c033bc6b421f5a75c2b2f2ceb284e22fa7d06ad",
+        });
+
+        // Test #10
+        tests.push(Test {
+            tx: Transaction::new(
+                TX_VERSION_ASSET_COMMITMENT,
+                inputs.clone(),
+                kusd_outputs.clone(),
+                TransactionAction::MintKUSD,
+                54,
+                SubnetworkId::from_byte(0),
+                3,
+                Vec::new(),
+            ),
+            expected_id: "08a6a8b45c2d3cbdb0a22ef3623fc468bcad2292669eac563abdad1192ba5684",
+            expected_hash: "8b530bb913dda65efb1543693a9858672893f7ebabf175fdb3846cad8a213229",
         });
 
         for (i, test) in tests.iter().enumerate() {
@@ -232,6 +300,24 @@ mod tests {
             );
         }
 
+        // The legacy version does not commit to the action and asset types
+        let legacy = Transaction::new(
+            TX_VERSION,
+            inputs.clone(),
+            outputs.clone(),
+            TransactionAction::TransferKSH,
+            54,
+            SubnetworkId::from_byte(0),
+            3,
+            Vec::new(),
+        );
+        let mut legacy_mint = legacy.clone();
+        legacy_mint.outputs = kusd_outputs;
+        legacy_mint.action = TransactionAction::MintKUSD;
+        legacy_mint.finalize();
+        assert_eq!(legacy.id(), legacy_mint.id());
+        assert_eq!(hash(&legacy, false), hash(&legacy_mint, false));
+
         // Avoid compiler warnings on the last clone
         drop(inputs);
         drop(outputs);
@@ -25,6 +25,7 @@ pub mod mass;
 pub mod merkle;
 pub mod muhash;
 pub mod network;
+pub mod price;
 pub mod pruning;
 pub mod sign;
 pub mod subnets;
@@ -5,35 +5,53 @@ use crate::{
 use kash_hashes::HasherBase;
 use kash_muhash::MuHash;
 
+/// Extends [`MuHash`] with UTXO-set operations. From the asset commitment activation on, every UTXO entry commits
+/// to its asset type (see [`write_utxo`]), so the serialization depends on the DAA score of the point of view
+/// the multiset is computed for, and not on the entries themselves
 pub trait MuHashExtensions {
-    fn add_transaction(&mut self, tx: &impl VerifiableTransaction, block_daa_score: u64);
-    fn add_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry);
+    fn add_transaction(&mut self, tx: &impl VerifiableTransaction, block_daa_score: u64, asset_commitment_activation_daa_score: u64);
+    fn add_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry, commit_asset_type: bool);
+    fn remove_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry, commit_asset_type: bool);
+    /// Converts the multiset of a UTXO set computed before the asset commitment activation
+    /// into the multiset of the same UTXO set past activation
+    fn commit_utxo_asset_type(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry) {
+        self.remove_utxo(outpoint, entry, false);
+        self.add_utxo(outpoint, entry, true);
+    }
 }
 
 impl MuHashExtensions for MuHash {
-    fn add_transaction(&mut self, tx: &impl VerifiableTransaction, block_daa_score: u64) {
+    fn add_transaction(&mut self, tx: &impl VerifiableTransaction, block_daa_score: u64, asset_commitment_activation_daa_score: u64) {
+        let commit_asset_type = block_daa_score >= asset_commitment_activation_daa_score;
         let tx_id = tx.id();
         for (input, entry) in tx.populated_inputs() {
-            let mut writer = self.remove_element_builder();
-            write_utxo(&mut writer, entry, &input.previous_outpoint);
-            writer.finalize();
+            self.remove_utxo(&input.previous_outpoint, entry, commit_asset_type);
         }
         for (i, output) in tx.outputs().iter().enumerate() {
             let outpoint = TransactionOutpoint::new(tx_id, i as u32);
             let entry =
                 UtxoEntry::new(output.value, output.script_public_key.clone(), block_daa_score, tx.is_coinbase(), output.asset_type);
-            self.add_utxo(&outpoint, &entry);
+            self.add_utxo(&outpoint, &entry, commit_asset_type);
         }
     }
 
-    fn add_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry) {
+    fn add_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry, commit_asset_type: bool) {
         let mut writer = self.add_element_builder();
-        write_utxo(&mut writer, entry, outpoint);
+        write_utxo(&mut writer, entry, outpoint, commit_asset_type);
+        writer.finalize();
+    }
+
+    fn remove_utxo(&mut self, outpoint: &TransactionOutpoint, entry: &UtxoEntry, commit_asset_type: bool) {
+        let mut writer = self.remove_element_builder();
+        write_utxo(&mut writer, entry, outpoint, commit_asset_type);
         writer.finalize();
     }
 }
 
-fn write_utxo(writer: &mut impl HasherBase, entry: &UtxoEntry, outpoint: &TransactionOutpoint) {
+/// Serializes a UTXO into the multiset element, committing to its asset type if `commit_asset_type` is set.
+/// All the entries of a UTXO set are serialized alike, entries created before the activation included, so that
+/// the asset type of no entry can be altered without breaking the commitment
+fn write_utxo(writer: &mut impl HasherBase, entry: &UtxoEntry, outpoint: &TransactionOutpoint, commit_asset_type: bool) {
     writer
         // Outpoint
         .update(outpoint.transaction_id)
@@ -44,4 +62,44 @@ fn write_utxo(writer: &mut impl HasherBase, entry: &UtxoEntry, outpoint: &Transa
         .write_bool(entry.is_coinbase)
         .update(entry.script_public_key.version().to_le_bytes())
         .write_var_bytes(entry.script_public_key.script());
+    if commit_asset_type {
+        writer.write_u32(entry.asset_type.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        asset_type::AssetType::{self, KSH, KUSD},
+        tx::{scriptvec, ScriptPublicKey},
+    };
+    use kash_hashes::Hash;
+
+    #[test]
+    fn test_utxo_asset_type_commitment() {
+        let outpoint = TransactionOutpoint::new(Hash::from_u64_word(1), 0);
+        let entry = |block_daa_score, asset_type: AssetType| {
+            UtxoEntry::new(100, ScriptPublicKey::new(0, scriptvec![1, 2, 3]), block_daa_score, false, asset_type)
+        };
+        let commitment = |entry: &UtxoEntry, commit_asset_type| {
+            let mut multiset = MuHash::new();
+            multiset.add_utxo(&outpoint, entry, commit_asset_type);
+            multiset.finalize()
+        };
+
+        // Before activation, entries do not commit to their asset type
+        assert_eq!(commitment(&entry(9, KSH), false), commitment(&entry(9, KUSD), false));
+        // Past activation every entry does, including legacy ones
+        assert_ne!(commitment(&entry(9, KSH), true), commitment(&entry(9, KUSD), true));
+        assert_ne!(commitment(&entry(10, KSH), true), commitment(&entry(10, KUSD), true));
+        assert_ne!(commitment(&entry(10, KSH), true), commitment(&entry(10, KSH), false));
+
+        // Converting a legacy multiset commits the asset type of its entries
+        let legacy = entry(9, KUSD);
+        let mut multiset = MuHash::new();
+        multiset.add_utxo(&outpoint, &legacy, false);
+        multiset.commit_utxo_asset_type(&outpoint, &legacy);
+        assert_eq!(multiset.finalize(), commitment(&legacy, true));
+    }
 }
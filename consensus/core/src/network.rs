@@ -54,6 +54,15 @@ impl NetworkType {
         }
     }
 
+    pub fn default_http_rpc_port(&self) -> u16 {
+        match self {
+            NetworkType::Mainnet => 19110,
+            NetworkType::Testnet => 19210,
+            NetworkType::Simnet => 19510,
+            NetworkType::Devnet => 19610,
+        }
+    }
+
     pub fn iter() -> impl Iterator<Item = Self> {
         static NETWORK_TYPES: [NetworkType; 4] =
             [NetworkType::Mainnet, NetworkType::Testnet, NetworkType::Devnet, NetworkType::Simnet];
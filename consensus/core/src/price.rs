@@ -0,0 +1,111 @@
+use kash_hashes::{Hash, HasherBase, PriceReportSigningHash};
+use secp256k1::{schnorr::Signature, KeyPair, Message, XOnlyPublicKey};
+use serde::{Deserialize, Serialize};
+
+/// Length of the serialized signature of a [`PriceReport`]
+pub const PRICE_REPORT_SIGNATURE_LEN: usize = 64;
+
+/// A KSH/USD price report signed by one of the price oracles listed in the consensus params.
+/// Miners attach such a report to the payload of their coinbase transaction
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct PriceReport {
+    /// The reported price, expressed in KUSD sompi per one KSH
+    pub price: u64,
+    /// Unix time (in milliseconds) at which the report was signed
+    pub timestamp: u64,
+    /// Index of the signing oracle within the consensus list of price oracle public keys
+    pub oracle_index: u8,
+    /// Schnorr signature of the oracle over the report [signing hash](PriceReport::signing_hash)
+    pub signature: [u8; PRICE_REPORT_SIGNATURE_LEN],
+}
+
+impl PriceReport {
+    /// Creates a report signed with the given oracle key pair
+    pub fn new_signed(price: u64, timestamp: u64, oracle_index: u8, oracle_key_pair: &KeyPair) -> Self {
+        let hash = Self::calc_signing_hash(price, timestamp, oracle_index);
+        let msg = Message::from_slice(hash.as_bytes().as_slice()).unwrap();
+        let signature = *oracle_key_pair.sign_schnorr(msg).as_ref();
+        Self { price, timestamp, oracle_index, signature }
+    }
+
+    pub fn signing_hash(&self) -> Hash {
+        Self::calc_signing_hash(self.price, self.timestamp, self.oracle_index)
+    }
+
+    fn calc_signing_hash(price: u64, timestamp: u64, oracle_index: u8) -> Hash {
+        let mut hasher = PriceReportSigningHash::new();
+        hasher.update(price.to_le_bytes()).update(timestamp.to_le_bytes()).update([oracle_index]);
+        hasher.finalize()
+    }
+
+    /// Returns whether the report is signed by the oracle it claims to be signed by
+    pub fn verify(&self, oracle_public_keys: &[[u8; 32]]) -> bool {
+        let Some(public_key) = oracle_public_keys.get(self.oracle_index as usize) else {
+            return false;
+        };
+        let (Ok(public_key), Ok(signature), Ok(msg)) = (
+            XOnlyPublicKey::from_slice(public_key),
+            Signature::from_slice(&self.signature),
+            Message::from_slice(self.signing_hash().as_bytes().as_slice()),
+        ) else {
+            return false;
+        };
+        signature.verify(&msg, &public_key).is_ok()
+    }
+}
+
+/// The reference KSH/USD price of a chain block, i.e., the price against which the transactions
+/// accepted by the block are validated
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlockPrice {
+    pub hash: Hash,
+    pub daa_score: u64,
+    pub price: u64,
+}
+
+/// Returns the median of the reported prices, or `None` if there are none. For an even number of prices
+/// the lower of the two middle prices is returned, so the median is always one of the reported prices
+pub fn median_price(mut prices: Vec<u64>) -> Option<u64> {
+    if prices.is_empty() {
+        return None;
+    }
+    let mid = (prices.len() - 1) / 2;
+    Some(*prices.select_nth_unstable(mid).1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secp256k1::SECP256K1;
+
+    #[test]
+    fn test_price_report_signature() {
+        let oracles =
+            [KeyPair::from_seckey_slice(SECP256K1, &[1; 32]).unwrap(), KeyPair::from_seckey_slice(SECP256K1, &[2; 32]).unwrap()];
+        let oracle_public_keys = oracles.map(|key_pair| key_pair.x_only_public_key().0.serialize());
+
+        let report = PriceReport::new_signed(42_000_000, 1_000, 1, &oracles[1]);
+        assert!(report.verify(&oracle_public_keys));
+
+        // Tampering with any of the signed fields invalidates the report
+        assert!(!PriceReport { price: 43_000_000, ..report }.verify(&oracle_public_keys));
+        assert!(!PriceReport { timestamp: 1_001, ..report }.verify(&oracle_public_keys));
+        assert!(!PriceReport { oracle_index: 0, ..report }.verify(&oracle_public_keys));
+
+        // Reports of unknown oracles, or signed with a key other than the one of the claimed oracle, are invalid
+        assert!(!PriceReport::new_signed(42_000_000, 1_000, 2, &oracles[1]).verify(&oracle_public_keys));
+        assert!(!PriceReport::new_signed(42_000_000, 1_000, 0, &oracles[1]).verify(&oracle_public_keys));
+        assert!(!report.verify(&[]));
+    }
+
+    #[test]
+    fn test_median_price() {
+        assert_eq!(median_price(vec![]), None);
+        assert_eq!(median_price(vec![7]), Some(7));
+        assert_eq!(median_price(vec![9, 1]), Some(1));
+        assert_eq!(median_price(vec![5, 1, 9]), Some(5));
+        assert_eq!(median_price(vec![100, 2, 3, 1]), Some(2));
+        // A minority of outliers cannot move the median away from the honest prices
+        assert_eq!(median_price(vec![u64::MAX, 10, 11, 1, 10]), Some(10));
+    }
+}
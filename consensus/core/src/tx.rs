@@ -130,7 +130,7 @@ impl TransactionOutput {
 }
 
 /// Defines the kind of a transaction
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
 #[wasm_bindgen(js_name = TransactionAction)]
 #[derive(Default)]
@@ -151,6 +151,16 @@ pub enum TransactionAction {
 }
 
 impl TransactionAction {
+    /// All transaction actions, in the order of their numeric values
+    pub const ALL: [TransactionAction; 6] = [
+        TransactionAction::TransferKSH,
+        TransactionAction::TransferKUSD,
+        TransactionAction::TransferKRV,
+        TransactionAction::MintKUSD,
+        TransactionAction::StakeKSH,
+        TransactionAction::RedeemKSH,
+    ];
+
     /// Returns the types of assets involved in the transaction.
     /// For each transaction action, it provides the source (`FromAssetType`) and the destination (`ToAssetType`) asset types.
     pub fn asset_transfer_types(&self) -> (AssetType, AssetType) {
@@ -673,8 +683,8 @@ mod tests {
             4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
             36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65,
             66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95,
-            96, 97, 98, 99, 0, 0, 0, 0, 0, 0, 0, 0, 69, 146, 193, 64, 98, 49, 45, 0, 77, 32, 25, 122, 77, 15, 211, 252, 61, 210, 82,
-            177, 39, 153, 127, 33, 188, 172, 138, 38, 67, 75, 241, 176,
+            96, 97, 98, 99, 0, 0, 0, 0, 0, 0, 0, 0, 65, 177, 97, 82, 65, 150, 45, 204, 147, 138, 67, 228, 247, 62, 4, 239, 228, 182,
+            124, 163, 239, 67, 73, 218, 235, 134, 90, 0, 132, 230, 163, 72,
         ];
         assert_eq!(expected_bts, bts);
         assert_eq!(tx, bincode::deserialize(&bts).unwrap());
@@ -724,7 +734,7 @@ mod tests {
   "gas": 9,
   "payload": "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f60616263",
   "mass": 0,
-  "id": "4592c14062312d004d20197a4d0fd3fc3dd252b127997f21bcac8a26434bf1b0"
+  "id": "41b1615241962dcc938a43e4f73e04efe4b67ca3ef4349daeb865a0084e6a348"
 }"#;
         assert_eq!(expected_str, str);
         assert_eq!(tx, serde_json::from_str(&str).unwrap());
@@ -1,5 +1,10 @@
 use derive_more::Display;
-use kash_consensus_core::{acceptance_data::AcceptanceData, block::Block, utxo::utxo_diff::UtxoDiff};
+use kash_consensus_core::{
+    acceptance_data::AcceptanceData,
+    block::Block,
+    tx::{ScriptPublicKey, TransactionId},
+    utxo::utxo_diff::UtxoDiff,
+};
 use kash_hashes::Hash;
 use kash_notify::{
     events::EventType,
@@ -24,7 +29,7 @@ pub enum Notification {
     #[display(fmt = "FinalityConflict notification: violating block hash {}", "_0.violating_block_hash")]
     FinalityConflict(FinalityConflictNotification),
 
-    #[display(fmt = "FinalityConflict notification: violating block hash {}", "_0.finality_block_hash")]
+    #[display(fmt = "FinalityConflictResolved notification: finality block hash {}", "_0.finality_block_hash")]
     FinalityConflictResolved(FinalityConflictResolvedNotification),
 
     #[display(fmt = "UtxosChanged notification")]
@@ -41,6 +46,9 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "MempoolChanged notification: transaction {} {}", "_0.transaction_id", "_0.reason")]
+    MempoolChanged(MempoolChangedNotification),
 }
 }
 
@@ -137,11 +145,21 @@ pub struct UtxosChangedNotification {
     /// Accumulated UTXO diff between the last virtual state and the current virtual state
     pub accumulated_utxo_diff: Arc<UtxoDiff>,
     pub virtual_parents: Arc<Vec<Hash>>,
+    /// DAA score of the current virtual state
+    pub virtual_daa_score: u64,
+    /// DAA score of the fork point between the previous and the current selected chains, set only if chain blocks
+    /// were removed, i.e., if the virtual selected chain was reorged
+    pub reorg_daa_score: Option<u64>,
 }
 
 impl UtxosChangedNotification {
-    pub fn new(accumulated_utxo_diff: Arc<UtxoDiff>, virtual_parents: Arc<Vec<Hash>>) -> Self {
-        Self { accumulated_utxo_diff, virtual_parents }
+    pub fn new(
+        accumulated_utxo_diff: Arc<UtxoDiff>,
+        virtual_parents: Arc<Vec<Hash>>,
+        virtual_daa_score: u64,
+        reorg_daa_score: Option<u64>,
+    ) -> Self {
+        Self { accumulated_utxo_diff, virtual_parents, virtual_daa_score, reorg_daa_score }
     }
 }
 
@@ -172,3 +190,46 @@ pub struct PruningPointUtxoSetOverrideNotification {}
 
 #[derive(Debug, Clone)]
 pub struct NewBlockTemplateNotification {}
+
+/// Reason of a transaction entering or leaving the mempool
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum MempoolChangeReason {
+    /// The transaction was accepted into the mempool
+    #[display(fmt = "accepted")]
+    Accepted,
+    /// The transaction was accepted into the mempool after all its missing outpoints were resolved
+    #[display(fmt = "orphan resolved")]
+    OrphanResolved,
+    /// The transaction was removed from the mempool after having been included in a block
+    #[display(fmt = "included in block")]
+    IncludedInBlock,
+    /// The transaction was removed from the mempool because a block transaction spends some of its outpoints
+    #[display(fmt = "double spent in block")]
+    DoubleSpentInBlock,
+    /// The low priority transaction was removed from the mempool after having not been included in a block for too long
+    #[display(fmt = "expired")]
+    Expired,
+    /// The transaction was evicted from a full mempool, making room for a transaction paying a higher fee rate
+    #[display(fmt = "low fee")]
+    LowFee,
+    /// The transaction was removed from the mempool after having been found invalid
+    #[display(fmt = "invalidated")]
+    Invalidated,
+}
+
+/// Notification of a transaction entering or leaving the mempool
+///
+/// Orphan transactions are only notified once resolved.
+#[derive(Debug, Clone)]
+pub struct MempoolChangedNotification {
+    pub transaction_id: TransactionId,
+    pub reason: MempoolChangeReason,
+    /// Script public keys of the transaction outputs and of its populated inputs
+    pub script_public_keys: Arc<Vec<ScriptPublicKey>>,
+}
+
+impl MempoolChangedNotification {
+    pub fn new(transaction_id: TransactionId, reason: MempoolChangeReason, script_public_keys: Arc<Vec<ScriptPublicKey>>) -> Self {
+        Self { transaction_id, reason, script_public_keys }
+    }
+}
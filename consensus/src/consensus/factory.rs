@@ -59,7 +59,7 @@ pub struct MultiConsensusMetadata {
     version: u32,
 }
 
-const LATEST_DB_VERSION: u32 = 3;
+const LATEST_DB_VERSION: u32 = 5;
 impl Default for MultiConsensusMetadata {
     fn default() -> Self {
         Self {
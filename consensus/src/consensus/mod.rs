@@ -20,10 +20,12 @@ use crate::{
             headers::{CompactHeaderData, HeaderStoreReader},
             headers_selected_tip::HeadersSelectedTipStoreReader,
             past_pruning_points::PastPruningPointsStoreReader,
+            prices::PricesStoreReader,
             pruning::PruningStoreReader,
             relations::RelationsStoreReader,
             statuses::StatusesStoreReader,
             tips::TipsStoreReader,
+            utxo_diffs::UtxoDiffsStoreReader,
             utxo_set::{UtxoSetStore, UtxoSetStoreReader},
             virtual_state::VirtualStateStoreReader,
             DB,
@@ -57,9 +59,11 @@ use kash_consensus_core::{
     header::Header,
     muhash::MuHashExtensions,
     network::NetworkType,
+    price::BlockPrice,
     pruning::{PruningPointProof, PruningPointTrustedData, PruningPointsList},
     trusted::{ExternalGhostdagData, TrustedBlock},
     tx::{MutableTransaction, Transaction, TransactionOutpoint, UtxoEntry},
+    utxo::utxo_diff::UtxoDiff,
     BlockHashSet, BlueWorkType, ChainPath,
 };
 use kash_consensus_notify::root::ConsensusNotificationRoot;
@@ -590,6 +594,32 @@ impl ConsensusApi for Consensus {
         sample_headers
     }
 
+    fn get_virtual_reference_price(&self) -> Option<u64> {
+        self.virtual_stores.read().state.get().unwrap().reference_price
+    }
+
+    fn get_reference_price_history(&self, max_chain_blocks: usize) -> Vec<BlockPrice> {
+        if max_chain_blocks == 0 {
+            return vec![];
+        }
+
+        // We need consistency between the selected chain and the prices store reads
+        let _guard = self.pruning_lock.blocking_read();
+
+        let sc_read = self.storage.selected_chain_store.read();
+        let high_index = sc_read.get_tip().unwrap().0;
+        let low_index = high_index.saturating_sub(max_chain_blocks as u64 - 1);
+        (low_index..=high_index)
+            .rev()
+            // Chain blocks below the pruning point are pruned from the selected chain store
+            .map_while(|index| sc_read.get_by_index(index).ok())
+            .filter_map(|hash| {
+                let price = self.storage.prices_store.get(hash).ok()?;
+                Some(BlockPrice { hash, daa_score: self.storage.headers_store.get_daa_score(hash).unwrap(), price })
+            })
+            .collect()
+    }
+
     fn get_virtual_parents(&self) -> BlockHashSet {
         self.virtual_stores.read().state.get().unwrap().parents.iter().copied().collect()
     }
@@ -609,6 +639,19 @@ impl ConsensusApi for Consensus {
         iter.map(|item| item.unwrap()).collect()
     }
 
+    fn get_virtual_utxos_with_commitment(
+        &self,
+        from_outpoint: Option<TransactionOutpoint>,
+        chunk_size: usize,
+        skip_first: bool,
+    ) -> (Vec<(TransactionOutpoint, UtxoEntry)>, u64, Hash) {
+        // The UTXO set and the virtual state are read under the same lock, so they are consistent with each other
+        let virtual_stores = self.virtual_stores.read();
+        let state = virtual_stores.state.get().unwrap();
+        let iter = virtual_stores.utxo_set.seek_iterator(from_outpoint, chunk_size, skip_first);
+        (iter.map(|item| item.unwrap()).collect(), state.daa_score, state.multiset.clone().finalize())
+    }
+
     fn get_tips(&self) -> Vec<Hash> {
         self.body_tips_store.read().get().unwrap().read().iter().copied().collect_vec()
     }
@@ -641,8 +684,8 @@ impl ConsensusApi for Consensus {
         Ok(utxos)
     }
 
-    fn modify_coinbase_payload(&self, payload: Vec<u8>, miner_data: &MinerData) -> CoinbaseResult<Vec<u8>> {
-        self.services.coinbase_manager.modify_coinbase_payload(payload, miner_data)
+    fn modify_coinbase_payload(&self, payload: Vec<u8>, miner_data: &MinerData, daa_score: u64) -> CoinbaseResult<Vec<u8>> {
+        self.services.coinbase_manager.modify_coinbase_payload(payload, miner_data, daa_score)
     }
 
     fn validate_pruning_proof(&self, proof: &PruningPointProof) -> Result<(), PruningImportError> {
@@ -658,10 +701,13 @@ impl ConsensusApi for Consensus {
     }
 
     fn append_imported_pruning_point_utxos(&self, utxoset_chunk: &[(TransactionOutpoint, UtxoEntry)], current_multiset: &mut MuHash) {
+        // The imported UTXO set is the one of the pruning point, whose DAA score determines the serialization of the entries
+        let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
+        let commit_asset_type = self.config.is_asset_commitment_active(self.headers_store.get_daa_score(pruning_point).unwrap());
         let mut pruning_utxoset_write = self.pruning_utxoset_stores.write();
         pruning_utxoset_write.utxo_set.write_many(utxoset_chunk).unwrap();
         for (outpoint, entry) in utxoset_chunk {
-            current_multiset.add_utxo(outpoint, entry);
+            current_multiset.add_utxo(outpoint, entry, commit_asset_type);
         }
     }
 
@@ -823,6 +869,10 @@ impl ConsensusApi for Consensus {
             .collect::<ConsensusResult<Vec<_>>>()
     }
 
+    fn get_block_utxo_diff(&self, hash: Hash) -> ConsensusResult<Arc<UtxoDiff>> {
+        self.utxo_diffs_store.get(hash).unwrap_option().ok_or(ConsensusError::MissingData(hash))
+    }
+
     fn is_chain_block(&self, hash: Hash) -> ConsensusResult<bool> {
         self.is_chain_ancestor_of(hash, self.get_sink())
     }
@@ -904,4 +954,18 @@ impl ConsensusApi for Consensus {
         self.virtual_processor
             .virtual_finality_point(&self.virtual_stores.read().state.get().unwrap().ghostdag_data, self.pruning_point())
     }
+
+    fn resolve_finality_conflict(&self, finality_block_hash: Hash) -> ConsensusResult<()> {
+        {
+            let _guard = self.pruning_lock.blocking_read();
+            self.validate_block_exists(finality_block_hash)?;
+            if !self.statuses_store.read().get(finality_block_hash).unwrap().is_utxo_valid_or_pending() {
+                return Err(ConsensusError::InvalidBlock(finality_block_hash));
+            }
+            if !self.services.reachability_service.is_chain_ancestor_of(self.pruning_point(), finality_block_hash) {
+                return Err(ConsensusError::General("finality block does not have the pruning point on its selected chain"));
+            }
+        }
+        self.virtual_processor.resolve_finality_conflict(finality_block_hash)
+    }
 }
@@ -138,6 +138,10 @@ impl ConsensusServices {
             params.deflationary_phase_daa_score,
             params.pre_deflationary_phase_base_subsidy,
             params.target_time_per_block,
+            params.asset_commitment_activation_daa_score,
+            params.price_oracle_activation_daa_score,
+            params.price_oracle_public_keys,
+            params.max_price_report_age,
         );
 
         let mass_calculator = MassCalculator::new(
@@ -158,6 +162,12 @@ impl ConsensusServices {
             tx_script_cache_counters,
             mass_calculator.clone(),
             params.storage_mass_activation_daa_score,
+            params.asset_commitment_activation_daa_score,
+            params.price_oracle_activation_daa_score,
+            params.conversion_rate_tolerance_bps,
+            params.krv_unbonding_activation_daa_score,
+            params.krv_unbonding_maturity,
+            params.introspection_activation_daa_score,
         );
 
         let pruning_point_manager = PruningPointManager::new(
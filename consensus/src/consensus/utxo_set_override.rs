@@ -13,8 +13,9 @@ mod utxo_set_override_inner {
 
     pub fn set_genesis_utxo_commitment_from_config(config: &mut Config) {
         let mut genesis_multiset = MuHash::new();
+        let commit_asset_type = config.params.is_asset_commitment_active(config.params.genesis.daa_score);
         for (outpoint, entry) in config.initial_utxo_set.iter() {
-            genesis_multiset.add_utxo(outpoint, entry);
+            genesis_multiset.add_utxo(outpoint, entry, commit_asset_type);
         }
 
         config.params.genesis.utxo_commitment = genesis_multiset.finalize();
@@ -12,6 +12,7 @@ pub mod ghostdag;
 pub mod headers;
 pub mod headers_selected_tip;
 pub mod past_pruning_points;
+pub mod prices;
 pub mod pruning;
 pub mod pruning_utxoset;
 pub mod reachability;
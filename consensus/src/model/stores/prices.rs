@@ -0,0 +1,70 @@
+use kash_consensus_core::BlockHasher;
+use kash_database::prelude::CachePolicy;
+use kash_database::prelude::StoreError;
+use kash_database::prelude::DB;
+use kash_database::prelude::{BatchDbWriter, CachedDbAccess, DirectDbWriter};
+use kash_database::registry::DatabaseStorePrefixes;
+use kash_hashes::Hash;
+use rocksdb::WriteBatch;
+use std::sync::Arc;
+
+/// Reader API for the reference KSH/USD prices of UTXO-valid chain blocks, i.e., the median price reported by
+/// the blue mergeset of each block (in KUSD sompi per one KSH). Blocks with no reported price have no entry
+pub trait PricesStoreReader {
+    fn get(&self, hash: Hash) -> Result<u64, StoreError>;
+}
+
+pub trait PricesStore: PricesStoreReader {
+    // This is append only
+    fn insert(&self, hash: Hash, price: u64) -> Result<(), StoreError>;
+    fn delete(&self, hash: Hash) -> Result<(), StoreError>;
+}
+
+/// A DB + cache implementation of `PricesStore` trait, with concurrency support.
+#[derive(Clone)]
+pub struct DbPricesStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<Hash, u64, BlockHasher>,
+}
+
+impl DbPricesStore {
+    pub fn new(db: Arc<DB>, cache_policy: CachePolicy) -> Self {
+        Self { db: Arc::clone(&db), access: CachedDbAccess::new(db, cache_policy, DatabaseStorePrefixes::Prices.into()) }
+    }
+
+    pub fn clone_with_new_cache(&self, cache_policy: CachePolicy) -> Self {
+        Self::new(Arc::clone(&self.db), cache_policy)
+    }
+
+    pub fn insert_batch(&self, batch: &mut WriteBatch, hash: Hash, price: u64) -> Result<(), StoreError> {
+        if self.access.has(hash)? {
+            return Err(StoreError::HashAlreadyExists(hash));
+        }
+        self.access.write(BatchDbWriter::new(batch), hash, price)?;
+        Ok(())
+    }
+
+    pub fn delete_batch(&self, batch: &mut WriteBatch, hash: Hash) -> Result<(), StoreError> {
+        self.access.delete(BatchDbWriter::new(batch), hash)
+    }
+}
+
+impl PricesStoreReader for DbPricesStore {
+    fn get(&self, hash: Hash) -> Result<u64, StoreError> {
+        self.access.read(hash)
+    }
+}
+
+impl PricesStore for DbPricesStore {
+    fn insert(&self, hash: Hash, price: u64) -> Result<(), StoreError> {
+        if self.access.has(hash)? {
+            return Err(StoreError::HashAlreadyExists(hash));
+        }
+        self.access.write(DirectDbWriter::new(&self.db), hash, price)?;
+        Ok(())
+    }
+
+    fn delete(&self, hash: Hash) -> Result<(), StoreError> {
+        self.access.delete(DirectDbWriter::new(&self.db), hash)
+    }
+}
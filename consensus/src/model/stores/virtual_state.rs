@@ -28,9 +28,12 @@ pub struct VirtualState {
     pub accepted_tx_ids: Vec<TransactionId>, // TODO: consider saving `accepted_id_merkle_root` directly
     pub mergeset_rewards: BlockHashMap<BlockRewardData>,
     pub mergeset_non_daa: BlockHashSet,
+    /// The reference KSH/USD price of the virtual block, against which mempool and block template transactions are validated
+    pub reference_price: Option<u64>,
 }
 
 impl VirtualState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         parents: Vec<Hash>,
         daa_score: u64,
@@ -42,6 +45,7 @@ impl VirtualState {
         mergeset_rewards: BlockHashMap<BlockRewardData>,
         mergeset_non_daa: BlockHashSet,
         ghostdag_data: GhostdagData,
+        reference_price: Option<u64>,
     ) -> Self {
         Self {
             parents,
@@ -54,6 +58,7 @@ impl VirtualState {
             accepted_tx_ids,
             mergeset_rewards,
             mergeset_non_daa,
+            reference_price,
         }
     }
 
@@ -69,6 +74,7 @@ impl VirtualState {
             accepted_tx_ids: genesis.build_genesis_transactions().into_iter().map(|tx| tx.id()).collect(),
             mergeset_rewards: BlockHashMap::new(),
             mergeset_non_daa: BlockHashSet::from_iter(std::iter::once(genesis.hash)),
+            reference_price: None,
         }
     }
 
@@ -14,6 +14,7 @@ impl BlockBodyProcessor {
     pub fn validate_body_in_context(self: &Arc<Self>, block: &Block) -> BlockProcessResult<()> {
         self.check_parent_bodies_exist(block)?;
         self.check_coinbase_blue_score_and_subsidy(block)?;
+        self.check_coinbase_price_report(block)?;
         self.check_block_transactions_in_context(block)?;
         self.check_block_is_not_pruned(block)
     }
@@ -61,7 +62,7 @@ impl BlockBodyProcessor {
     }
 
     fn check_coinbase_blue_score_and_subsidy(self: &Arc<Self>, block: &Block) -> BlockProcessResult<()> {
-        match self.coinbase_manager.deserialize_coinbase_payload(&block.transactions[0].payload) {
+        match self.coinbase_manager.deserialize_coinbase_payload(&block.transactions[0].payload, block.header.daa_score) {
             Ok(data) => {
                 if data.blue_score != block.header.blue_score {
                     return Err(RuleError::BadCoinbasePayloadBlueScore(data.blue_score, block.header.blue_score));
@@ -78,6 +79,15 @@ impl BlockBodyProcessor {
             Err(e) => Err(RuleError::BadCoinbasePayload(e)),
         }
     }
+
+    fn check_coinbase_price_report(self: &Arc<Self>, block: &Block) -> BlockProcessResult<()> {
+        // The payload was already successfully deserialized by `check_coinbase_blue_score_and_subsidy`
+        let data = self.coinbase_manager.deserialize_coinbase_payload(&block.transactions[0].payload, block.header.daa_score).unwrap();
+        if let Some(price_report) = data.miner_data.price_report {
+            self.coinbase_manager.verify_price_report(&price_report, block.header.timestamp).map_err(RuleError::BadCoinbasePayload)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
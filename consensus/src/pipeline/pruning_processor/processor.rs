@@ -229,13 +229,18 @@ impl PruningProcessor {
 
     fn assert_utxo_commitment(&self, pruning_point: Hash) {
         info!("Verifying the new pruning point UTXO commitment (sanity test)");
-        let commitment = self.headers_store.get_header(pruning_point).unwrap().utxo_commitment;
+        let header = self.headers_store.get_header(pruning_point).unwrap();
+        let commit_asset_type = self.config.is_asset_commitment_active(header.daa_score);
         let mut multiset = MuHash::new();
         let pruning_utxoset_read = self.pruning_utxoset_stores.read();
         for (outpoint, entry) in pruning_utxoset_read.utxo_set.iterator().map(|r| r.unwrap()) {
-            multiset.add_utxo(&outpoint, &entry);
+            multiset.add_utxo(&outpoint, &entry, commit_asset_type);
         }
-        assert_eq!(multiset.finalize(), commitment, "Updated pruning point utxo set does not match the header utxo commitment");
+        assert_eq!(
+            multiset.finalize(),
+            header.utxo_commitment,
+            "Updated pruning point utxo set does not match the header utxo commitment"
+        );
         info!("Pruning point UTXO commitment was verified correctly (sanity test)");
     }
 
@@ -387,6 +392,7 @@ impl PruningProcessor {
                 self.utxo_multisets_store.delete_batch(&mut batch, current).unwrap();
                 self.utxo_diffs_store.delete_batch(&mut batch, current).unwrap();
                 self.acceptance_data_store.delete_batch(&mut batch, current).unwrap();
+                self.prices_store.delete_batch(&mut batch, current).unwrap();
                 self.block_transactions_store.delete_batch(&mut batch, current).unwrap();
 
                 if keep_relations.contains(&current) {
@@ -21,6 +21,7 @@ use crate::{
             ghostdag::{DbGhostdagStore, GhostdagData, GhostdagStoreReader},
             headers::{DbHeadersStore, HeaderStoreReader},
             past_pruning_points::DbPastPruningPointsStore,
+            prices::DbPricesStore,
             pruning::{DbPruningStore, PruningStoreReader},
             pruning_utxoset::PruningUtxosetStores,
             reachability::DbReachabilityStore,
@@ -52,6 +53,7 @@ use kash_consensus_core::{
     blockstatus::BlockStatus::{StatusDisqualifiedFromChain, StatusUTXOValid},
     coinbase::MinerData,
     config::genesis::GenesisBlock,
+    errors::consensus::{ConsensusError, ConsensusResult},
     header::Header,
     merkle::calc_hash_merkle_root_with_options,
     pruning::PruningPointsList,
@@ -64,8 +66,9 @@ use kash_consensus_core::{
 };
 use kash_consensus_notify::{
     notification::{
-        NewBlockTemplateNotification, Notification, SinkBlueScoreChangedNotification, UtxosChangedNotification,
-        VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
+        FinalityConflictNotification, FinalityConflictResolvedNotification, NewBlockTemplateNotification, Notification,
+        SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification,
+        VirtualDaaScoreChangedNotification,
     },
     root::ConsensusNotificationRoot,
 };
@@ -79,7 +82,7 @@ use kash_notify::{events::EventType, notifier::Notify};
 use crossbeam_channel::{Receiver as CrossbeamReceiver, Sender as CrossbeamSender};
 use itertools::Itertools;
 use kash_utils::binary_heap::BinaryHeapExtensions;
-use parking_lot::{RwLock, RwLockUpgradableReadGuard};
+use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard};
 use rand::{seq::SliceRandom, Rng};
 use rayon::{
     prelude::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
@@ -112,6 +115,7 @@ pub struct VirtualStateProcessor {
     pub(super) max_block_parents: u8,
     pub(super) mergeset_size_limit: u64,
     pub(super) pruning_depth: u64,
+    pub(super) asset_commitment_activation_daa_score: u64,
 
     // Stores
     pub(super) statuses_store: Arc<RwLock<DbStatusesStore>>,
@@ -129,6 +133,7 @@ pub struct VirtualStateProcessor {
     pub(super) utxo_diffs_store: Arc<DbUtxoDiffsStore>,
     pub(super) utxo_multisets_store: Arc<DbUtxoMultisetsStore>,
     pub(super) acceptance_data_store: Arc<DbAcceptanceDataStore>,
+    pub(super) prices_store: Arc<DbPricesStore>,
     pub(super) virtual_stores: Arc<RwLock<VirtualStores>>,
     pub(super) pruning_utxoset_stores: Arc<RwLock<PruningUtxosetStores>>,
 
@@ -153,6 +158,12 @@ pub struct VirtualStateProcessor {
     // Counters
     counters: Arc<ProcessingCounters>,
 
+    // Finality conflicts
+    /// Violating blocks which were already reported via a finality conflict notification
+    reported_finality_conflicts: Mutex<BlockHashSet>,
+    /// A finality point chosen by the node operator in order to resolve a finality conflict (not persisted)
+    finality_point_override: RwLock<Option<Hash>>,
+
     // Storage mass hardfork DAA score
     pub(crate) storage_mass_activation_daa_score: u64,
 }
@@ -182,6 +193,7 @@ impl VirtualStateProcessor {
             max_block_parents: params.max_block_parents,
             mergeset_size_limit: params.mergeset_size_limit,
             pruning_depth: params.pruning_depth,
+            asset_commitment_activation_daa_score: params.asset_commitment_activation_daa_score,
 
             db,
             statuses_store: storage.statuses_store.clone(),
@@ -197,6 +209,7 @@ impl VirtualStateProcessor {
             utxo_diffs_store: storage.utxo_diffs_store.clone(),
             utxo_multisets_store: storage.utxo_multisets_store.clone(),
             acceptance_data_store: storage.acceptance_data_store.clone(),
+            prices_store: storage.prices_store.clone(),
             virtual_stores: storage.virtual_stores.clone(),
             pruning_utxoset_stores: storage.pruning_utxoset_stores.clone(),
 
@@ -214,6 +227,8 @@ impl VirtualStateProcessor {
             pruning_lock,
             notification_root,
             counters,
+            reported_finality_conflicts: Default::default(),
+            finality_point_override: Default::default(),
             storage_mass_activation_daa_score: params.storage_mass_activation_daa_score,
         }
     }
@@ -263,8 +278,11 @@ impl VirtualStateProcessor {
         //      2. Such tips cannot be merged by virtual since they are violating the merge depth
         //         bound (merge depth <= finality depth).
         // (both claims are true by induction for any block in their past as well)
+        //
+        // Tips outside the future of F are still compared with the new sink below, since a heavier such tip
+        // indicates a finality conflict which the node operator should be notified about.
         let prune_guard = self.pruning_lock.blocking_read();
-        let tips = self
+        let (tips, dropped_tips): (Vec<_>, Vec<_>) = self
             .body_tips_store
             .read()
             .get()
@@ -272,14 +290,28 @@ impl VirtualStateProcessor {
             .read()
             .iter()
             .copied()
-            .filter(|&h| self.reachability_service.is_dag_ancestor_of(finality_point, h))
-            .collect_vec();
+            .partition(|&h| self.reachability_service.is_dag_ancestor_of(finality_point, h));
+        let heaviest_dropped_tip = dropped_tips
+            .into_iter()
+            .map(|block| SortableBlock { hash: block, blue_work: self.ghostdag_primary_store.get_blue_work(block).unwrap() })
+            .max();
         drop(prune_guard);
         let prev_sink = prev_state.ghostdag_data.selected_parent;
         let mut accumulated_diff = prev_state.utxo_diff.clone().to_reversed();
 
         let (new_sink, virtual_parent_candidates) =
             self.sink_search_algorithm(&virtual_read, &mut accumulated_diff, prev_sink, tips, finality_point, pruning_point);
+        if let Some(heaviest_dropped_tip) = heaviest_dropped_tip {
+            // `finality_point == pruning_point` indicates we are at IBD start hence no conflict is reported
+            let sink = SortableBlock { hash: new_sink, blue_work: self.ghostdag_primary_store.get_blue_work(new_sink).unwrap() };
+            if finality_point != pruning_point && heaviest_dropped_tip > sink {
+                warn!(
+                    "Finality Violation Detected. Block {} violates finality and is ignored from Virtual chain.",
+                    heaviest_dropped_tip.hash
+                );
+                self.report_finality_conflict(heaviest_dropped_tip.hash);
+            }
+        }
         let (virtual_parents, virtual_ghostdag_data) = self.pick_virtual_parents(new_sink, virtual_parent_candidates, pruning_point);
         assert_eq!(virtual_ghostdag_data.selected_parent, new_sink);
 
@@ -306,11 +338,20 @@ impl VirtualStateProcessor {
         // Emit notifications
         let accumulated_diff = Arc::new(accumulated_diff);
         let virtual_parents = Arc::new(new_virtual_state.parents.clone());
+        let reorg_daa_score = chain_path.removed.last().map(|&removed| {
+            let fork_point = self.ghostdag_primary_store.get_selected_parent(removed).unwrap();
+            self.headers_store.get_daa_score(fork_point).unwrap()
+        });
         self.notification_root
             .notify(Notification::NewBlockTemplate(NewBlockTemplateNotification {}))
             .expect("expecting an open unbounded channel");
         self.notification_root
-            .notify(Notification::UtxosChanged(UtxosChangedNotification::new(accumulated_diff, virtual_parents)))
+            .notify(Notification::UtxosChanged(UtxosChangedNotification::new(
+                accumulated_diff,
+                virtual_parents,
+                new_virtual_state.daa_score,
+                reorg_daa_score,
+            )))
             .expect("expecting an open unbounded channel");
         self.notification_root
             .notify(Notification::SinkBlueScoreChanged(SinkBlueScoreChangedNotification::new(sink_ghostdag_data.blue_score)))
@@ -334,12 +375,80 @@ impl VirtualStateProcessor {
 
     pub(crate) fn virtual_finality_point(&self, virtual_ghostdag_data: &GhostdagData, pruning_point: Hash) -> Hash {
         let finality_point = self.depth_manager.calc_finality_point(virtual_ghostdag_data, pruning_point);
-        if self.reachability_service.is_chain_ancestor_of(pruning_point, finality_point) {
+        let finality_point = if self.reachability_service.is_chain_ancestor_of(pruning_point, finality_point) {
             finality_point
         } else {
             // At the beginning of IBD when virtual finality point might be below the pruning point
             // or disagreeing with the pruning point chain, we take the pruning point itself as the finality point
             pruning_point
+        };
+        let finality_override = *self.finality_point_override.read();
+        match finality_override {
+            // An operator override holds until the finality point derived from virtual passes it on the selected chain
+            Some(finality_override) if !self.reachability_service.is_chain_ancestor_of(finality_override, finality_point) => {
+                finality_override
+            }
+            Some(_) => {
+                // Virtual has moved past the override, hence it is no longer needed
+                self.finality_point_override.write().take();
+                finality_point
+            }
+            None => finality_point,
+        }
+    }
+
+    /// Resolves a finality conflict by forcing `finality_block` to serve as the virtual finality point, thus
+    /// letting virtual reorg to the chain of `finality_block` even if it violates the current finality point.
+    /// Assumes `finality_block` has a block body and has the pruning point on its selected chain.
+    pub fn resolve_finality_conflict(self: &Arc<Self>, finality_block: Hash) -> ConsensusResult<()> {
+        {
+            // Verify the UTXO validity of the new finality block prior to committing to it, since the sink
+            // search algorithm relies on the finality point being a valid sink candidate
+            let virtual_read = self.virtual_stores.upgradable_read();
+            let virtual_state = virtual_read.state.get().unwrap();
+
+            // PRUNE SAFETY: the pruning point might have moved since the caller verified that it is on the selected
+            // chain of `finality_block`. Once this holds, the UTXO walk below only visits chain blocks above the pruning
+            // point which are never pruned, hence the prune lock is not held throughout it.
+            let prune_guard = self.pruning_lock.blocking_read();
+            let pruning_point = self.pruning_point_store.read().pruning_point().unwrap();
+            let above_pruning_point = self.reachability_service.is_chain_ancestor_of(pruning_point, finality_block);
+            drop(prune_guard);
+            if !above_pruning_point {
+                return Err(ConsensusError::InvalidBlock(finality_block));
+            }
+
+            let mut diff = virtual_state.utxo_diff.clone().to_reversed();
+            let diff_point = self.calculate_utxo_state_relatively(
+                &virtual_read,
+                &mut diff,
+                virtual_state.ghostdag_data.selected_parent,
+                finality_block,
+            );
+            if diff_point != finality_block {
+                return Err(ConsensusError::InvalidBlock(finality_block));
+            }
+            self.finality_point_override.write().replace(finality_block);
+        }
+
+        self.resolve_virtual();
+
+        let sink = self.virtual_stores.read().state.get().unwrap().ghostdag_data.selected_parent;
+        if self.reachability_service.is_chain_ancestor_of(finality_block, sink) {
+            self.reported_finality_conflicts.lock().clear();
+            self.notification_root
+                .notify(Notification::FinalityConflictResolved(FinalityConflictResolvedNotification::new(finality_block)))
+                .expect("expecting an open unbounded channel");
+        }
+        Ok(())
+    }
+
+    /// Notifies about a finality conflict caused by `violating_block`, unless it was already reported
+    fn report_finality_conflict(&self, violating_block: Hash) {
+        if self.reported_finality_conflicts.lock().insert(violating_block) {
+            self.notification_root
+                .notify(Notification::FinalityConflict(FinalityConflictNotification::new(violating_block)))
+                .expect("expecting an open unbounded channel");
         }
     }
 
@@ -403,6 +512,7 @@ impl VirtualStateProcessor {
                     let selected_parent_utxo_view = (&stores.utxo_set).compose(&*diff);
 
                     let mut ctx = UtxoProcessingContext::new(mergeset_data.into(), selected_parent_multiset_hash);
+                    self.commit_utxo_set_asset_types(&mut ctx.multiset_hash, &stores.utxo_set, diff, selected_parent, pov_daa_score);
 
                     self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, pov_daa_score);
                     let res = self.verify_expected_utxo_state(&mut ctx, &selected_parent_utxo_view, &header);
@@ -418,7 +528,13 @@ impl VirtualStateProcessor {
                         // Update the diff point
                         diff_point = current;
                         // Commit UTXO data for current chain block
-                        self.commit_utxo_state(current, ctx.mergeset_diff, ctx.multiset_hash, ctx.mergeset_acceptance_data);
+                        self.commit_utxo_state(
+                            current,
+                            ctx.mergeset_diff,
+                            ctx.multiset_hash,
+                            ctx.mergeset_acceptance_data,
+                            ctx.reference_price,
+                        );
                         // Count the number of UTXO-processed chain blocks
                         chain_block_counter += 1;
                     }
@@ -432,11 +548,21 @@ impl VirtualStateProcessor {
         diff_point
     }
 
-    fn commit_utxo_state(&self, current: Hash, mergeset_diff: UtxoDiff, multiset: MuHash, acceptance_data: AcceptanceData) {
+    fn commit_utxo_state(
+        &self,
+        current: Hash,
+        mergeset_diff: UtxoDiff,
+        multiset: MuHash,
+        acceptance_data: AcceptanceData,
+        reference_price: Option<u64>,
+    ) {
         let mut batch = WriteBatch::default();
         self.utxo_diffs_store.insert_batch(&mut batch, current, Arc::new(mergeset_diff)).unwrap();
         self.utxo_multisets_store.insert_batch(&mut batch, current, multiset).unwrap();
         self.acceptance_data_store.insert_batch(&mut batch, current, Arc::new(acceptance_data)).unwrap();
+        if let Some(price) = reference_price {
+            self.prices_store.insert_batch(&mut batch, current, price).unwrap();
+        }
         let write_guard = self.statuses_store.set_batch(&mut batch, current, StatusUTXOValid).unwrap();
         self.db.write(batch).unwrap();
         // Calling the drops explicitly after the batch is written in order to avoid possible errors.
@@ -480,12 +606,20 @@ impl VirtualStateProcessor {
         let virtual_past_median_time = self.window_manager.calc_past_median_time(&virtual_ghostdag_data)?.0;
 
         // Calc virtual UTXO state relative to selected parent
+        self.commit_utxo_set_asset_types(
+            &mut ctx.multiset_hash,
+            &virtual_stores.utxo_set,
+            accumulated_diff,
+            virtual_ghostdag_data.selected_parent,
+            virtual_daa_window.daa_score,
+        );
         self.calculate_utxo_state(&mut ctx, &selected_parent_utxo_view, virtual_daa_window.daa_score);
 
         // Update the accumulated diff
         accumulated_diff.with_diff_in_place(&ctx.mergeset_diff).unwrap();
 
         // Build the new virtual state
+        let reference_price = ctx.reference_price;
         Ok(Arc::new(VirtualState::new(
             virtual_parents,
             virtual_daa_window.daa_score,
@@ -497,6 +631,7 @@ impl VirtualStateProcessor {
             ctx.mergeset_rewards,
             virtual_daa_window.mergeset_non_daa,
             virtual_ghostdag_data,
+            reference_price,
         )))
     }
 
@@ -563,6 +698,9 @@ impl VirtualStateProcessor {
         // The initial diff point is the previous sink
         let mut diff_point = prev_sink;
 
+        // Only the first (i.e., heaviest) violating candidate is reported, its past is part of the same conflict
+        let mut finality_conflict_found = false;
+
         // We maintain the following invariant: `heap` is an antichain.
         // It holds at step 0 since tips are an antichain, and remains through the loop
         // since we check that every pushed block is not in the past of current heap
@@ -590,6 +728,10 @@ impl VirtualStateProcessor {
             } else if finality_point != pruning_point {
                 // `finality_point == pruning_point` indicates we are at IBD start hence no warning required
                 warn!("Finality Violation Detected. Block {} violates finality and is ignored from Virtual chain.", candidate);
+                if !finality_conflict_found {
+                    finality_conflict_found = true;
+                    self.report_finality_conflict(candidate);
+                }
             }
             // PRUNE SAFETY: see comment within [`resolve_virtual`]
             let prune_guard = self.pruning_lock.blocking_read();
@@ -752,10 +894,11 @@ impl VirtualStateProcessor {
         virtual_utxo_view: &impl UtxoView,
         virtual_daa_score: u64,
         virtual_past_median_time: u64,
+        virtual_price: Option<u64>,
     ) -> TxResult<()> {
         self.transaction_validator.validate_tx_in_isolation(&mutable_tx.tx)?;
         self.transaction_validator.utxo_free_tx_validation(&mutable_tx.tx, virtual_daa_score, virtual_past_median_time)?;
-        self.validate_mempool_transaction_in_utxo_context(mutable_tx, virtual_utxo_view, virtual_daa_score)?;
+        self.validate_mempool_transaction_in_utxo_context(mutable_tx, virtual_utxo_view, virtual_daa_score, virtual_price)?;
         Ok(())
     }
 
@@ -765,7 +908,14 @@ impl VirtualStateProcessor {
         let virtual_utxo_view = &virtual_read.utxo_set;
         let virtual_daa_score = virtual_state.daa_score;
         let virtual_past_median_time = virtual_state.past_median_time;
-        self.validate_mempool_transaction_impl(mutable_tx, virtual_utxo_view, virtual_daa_score, virtual_past_median_time)
+        let virtual_price = virtual_state.reference_price;
+        self.validate_mempool_transaction_impl(
+            mutable_tx,
+            virtual_utxo_view,
+            virtual_daa_score,
+            virtual_past_median_time,
+            virtual_price,
+        )
     }
 
     pub fn validate_mempool_transactions_in_parallel(&self, mutable_txs: &mut [MutableTransaction]) -> Vec<TxResult<()>> {
@@ -774,12 +924,19 @@ impl VirtualStateProcessor {
         let virtual_utxo_view = &virtual_read.utxo_set;
         let virtual_daa_score = virtual_state.daa_score;
         let virtual_past_median_time = virtual_state.past_median_time;
+        let virtual_price = virtual_state.reference_price;
 
         self.thread_pool.install(|| {
             mutable_txs
                 .par_iter_mut()
                 .map(|mtx| {
-                    self.validate_mempool_transaction_impl(mtx, &virtual_utxo_view, virtual_daa_score, virtual_past_median_time)
+                    self.validate_mempool_transaction_impl(
+                        mtx,
+                        &virtual_utxo_view,
+                        virtual_daa_score,
+                        virtual_past_median_time,
+                        virtual_price,
+                    )
                 })
                 .collect::<Vec<TxResult<()>>>()
         })
@@ -834,7 +991,13 @@ impl VirtualStateProcessor {
         // which were previously validated through `validate_mempool_transaction_and_populate`, hence we only perform
         // in-context validations
         self.transaction_validator.utxo_free_tx_validation(tx, virtual_state.daa_score, virtual_state.past_median_time)?;
-        self.validate_transaction_in_utxo_context(tx, utxo_view, virtual_state.daa_score, TxValidationFlags::Full)?;
+        self.validate_transaction_in_utxo_context(
+            tx,
+            utxo_view,
+            virtual_state.daa_score,
+            virtual_state.reference_price,
+            TxValidationFlags::Full,
+        )?;
         Ok(())
     }
 
@@ -943,7 +1106,7 @@ impl VirtualStateProcessor {
                 &virtual_state.mergeset_rewards,
                 &virtual_state.mergeset_non_daa,
             )
-            .unwrap();
+            .map_err(RuleError::BadCoinbasePayload)?;
         txs.insert(0, coinbase.tx);
         let version = BLOCK_VERSION;
         let parents_by_level = self.parents_manager.calc_block_parents(pruning_info.pruning_point, &virtual_state.parents);
@@ -976,7 +1139,7 @@ impl VirtualStateProcessor {
         Ok(BlockTemplate::new(
             MutableBlock::new(header, txs),
             miner_data,
-            coinbase.has_red_reward,
+            coinbase.red_reward_outputs,
             selected_parent_timestamp,
             selected_parent_daa_score,
             selected_parent_hash,
@@ -1004,7 +1167,7 @@ impl VirtualStateProcessor {
     /// Note that pruning point-related stores are initialized by `init`
     pub fn process_genesis(self: &Arc<Self>) {
         // Write the UTXO state of genesis
-        self.commit_utxo_state(self.genesis.hash, UtxoDiff::default(), MuHash::new(), AcceptanceData::default());
+        self.commit_utxo_state(self.genesis.hash, UtxoDiff::default(), MuHash::new(), AcceptanceData::default(), None);
         // Init virtual stores
         self.virtual_stores
             .write()
@@ -1059,10 +1222,12 @@ impl VirtualStateProcessor {
 
         // Validate transactions of the pruning point itself
         let new_pruning_point_transactions = self.block_transactions_store.get(new_pruning_point).unwrap();
+        let reference_price = self.pruning_point_reference_price(new_pruning_point);
         let validated_transactions = self.validate_transactions_in_parallel(
             &new_pruning_point_transactions,
             &virtual_read.utxo_set,
             new_pruning_point_header.daa_score,
+            reference_price,
             TxValidationFlags::Full,
         );
         if validated_transactions.len() < new_pruning_point_transactions.len() - 1 {
@@ -4,7 +4,14 @@ use crate::{
         BlockProcessResult,
         RuleError::{BadAcceptedIDMerkleRoot, BadCoinbaseTransaction, BadUTXOCommitment, InvalidTransactionsInUtxoContext},
     },
-    model::stores::{block_transactions::BlockTransactionsStoreReader, daa::DaaStoreReader, ghostdag::GhostdagData},
+    model::stores::{
+        block_transactions::BlockTransactionsStoreReader,
+        daa::DaaStoreReader,
+        ghostdag::{GhostdagData, GhostdagStoreReader},
+        headers::HeaderStoreReader,
+        prices::PricesStoreReader,
+        utxo_set::DbUtxoSetStore,
+    },
     processes::transaction_validator::{
         errors::{TxResult, TxRuleError},
         transaction_validator_populated::TxValidationFlags,
@@ -12,10 +19,12 @@ use crate::{
 };
 use kash_consensus_core::{
     acceptance_data::{AcceptedTxEntry, MergesetBlockAcceptanceData},
+    asset_type::AssetType,
     coinbase::*,
     hashing,
     header::Header,
     muhash::MuHashExtensions,
+    price::{median_price, PriceReport},
     tx::{MutableTransaction, PopulatedTransaction, Transaction, TransactionId, ValidatedTransaction, VerifiableTransaction},
     utxo::{
         utxo_diff::UtxoDiff,
@@ -40,6 +49,8 @@ pub(super) struct UtxoProcessingContext<'a> {
     pub accepted_tx_ids: Vec<TransactionId>,
     pub mergeset_acceptance_data: Vec<MergesetBlockAcceptanceData>,
     pub mergeset_rewards: BlockHashMap<BlockRewardData>,
+    /// The median KSH/USD price reported by the blue mergeset, against which converting transactions are validated in this context
+    pub reference_price: Option<u64>,
 }
 
 impl<'a> UtxoProcessingContext<'a> {
@@ -52,6 +63,7 @@ impl<'a> UtxoProcessingContext<'a> {
             accepted_tx_ids: Vec::with_capacity(1), // We expect at least the selected parent coinbase tx
             mergeset_rewards: BlockHashMap::with_capacity(mergeset_size),
             mergeset_acceptance_data: Vec::with_capacity(mergeset_size),
+            reference_price: None,
         }
     }
 
@@ -61,6 +73,34 @@ impl<'a> UtxoProcessingContext<'a> {
 }
 
 impl VirtualStateProcessor {
+    /// From the asset commitment activation on, every UTXO entry commits to its asset type, entries created before
+    /// activation included. The multiset of the first chain block (or virtual) past activation is therefore obtained
+    /// by rehashing the whole UTXO set of its selected parent, given as the virtual UTXO set composed with `diff`
+    pub(super) fn commit_utxo_set_asset_types(
+        &self,
+        multiset: &mut MuHash,
+        utxo_set: &DbUtxoSetStore,
+        diff: &UtxoDiff,
+        selected_parent: Hash,
+        pov_daa_score: u64,
+    ) {
+        if pov_daa_score < self.asset_commitment_activation_daa_score
+            || self.headers_store.get_daa_score(selected_parent).unwrap() >= self.asset_commitment_activation_daa_score
+        {
+            return;
+        }
+
+        info!("Committing the asset type of the UTXO set entries of {} on asset commitment activation", selected_parent);
+        for (outpoint, entry) in utxo_set.iterator().map(|r| r.unwrap()) {
+            if !diff.add.contains_key(&outpoint) && !diff.remove.contains_key(&outpoint) {
+                multiset.commit_utxo_asset_type(&outpoint, &entry);
+            }
+        }
+        for (outpoint, entry) in diff.add.iter() {
+            multiset.commit_utxo_asset_type(outpoint, entry);
+        }
+    }
+
     /// Calculates UTXO state and transaction acceptance data relative to the selected parent state
     pub(super) fn calculate_utxo_state<V: UtxoView + Sync>(
         &self,
@@ -69,10 +109,11 @@ impl VirtualStateProcessor {
         pov_daa_score: u64,
     ) {
         let selected_parent_transactions = self.block_transactions_store.get(ctx.selected_parent()).unwrap();
+        ctx.reference_price = self.mergeset_reference_price(&ctx.ghostdag_data);
         let validated_coinbase = ValidatedTransaction::new_coinbase(&selected_parent_transactions[0]);
 
         ctx.mergeset_diff.add_transaction(&validated_coinbase, pov_daa_score).unwrap();
-        ctx.multiset_hash.add_transaction(&validated_coinbase, pov_daa_score);
+        ctx.multiset_hash.add_transaction(&validated_coinbase, pov_daa_score, self.asset_commitment_activation_daa_score);
         let validated_coinbase_id = validated_coinbase.id();
         ctx.accepted_tx_ids.push(validated_coinbase_id);
 
@@ -93,14 +134,22 @@ impl VirtualStateProcessor {
             // No need to fully validate selected parent transactions since selected parent txs were already validated
             // as part of selected parent UTXO state verification with the exact same UTXO context.
             let validation_flags = if is_selected_parent { TxValidationFlags::SkipScriptChecks } else { TxValidationFlags::Full };
-            let validated_transactions = self.validate_transactions_in_parallel(&txs, &composed_view, pov_daa_score, validation_flags);
+            let validated_transactions =
+                self.validate_transactions_in_parallel(&txs, &composed_view, pov_daa_score, ctx.reference_price, validation_flags);
 
-            let mut block_fee = 0u64;
+            let mut block_fees = AssetFees::default();
             for (validated_tx, _) in validated_transactions.iter() {
                 ctx.mergeset_diff.add_transaction(validated_tx, pov_daa_score).unwrap();
-                ctx.multiset_hash.add_transaction(validated_tx, pov_daa_score);
+                ctx.multiset_hash.add_transaction(validated_tx, pov_daa_score, self.asset_commitment_activation_daa_score);
                 ctx.accepted_tx_ids.push(validated_tx.id());
-                block_fee += validated_tx.calculated_fee;
+                // From the asset commitment activation on, fees are denominated in the source asset of the tx action.
+                // Before it, fees are computed across assets (see TransactionValidator) and are all rewarded as KSH
+                let fee_asset = if pov_daa_score >= self.asset_commitment_activation_daa_score {
+                    validated_tx.tx.action.asset_transfer_types().0
+                } else {
+                    AssetType::KSH
+                };
+                block_fees.add(fee_asset, validated_tx.calculated_fee);
             }
 
             if is_selected_parent {
@@ -125,10 +174,11 @@ impl VirtualStateProcessor {
                 });
             }
 
-            let coinbase_data = self.coinbase_manager.deserialize_coinbase_payload(&txs[0].payload).unwrap();
+            let merged_block_daa_score = self.headers_store.get_daa_score(merged_block).unwrap();
+            let coinbase_data = self.coinbase_manager.deserialize_coinbase_payload(&txs[0].payload, merged_block_daa_score).unwrap();
             ctx.mergeset_rewards.insert(
                 merged_block,
-                BlockRewardData::new(coinbase_data.subsidy, block_fee, coinbase_data.miner_data.script_public_key),
+                BlockRewardData::new(coinbase_data.subsidy, block_fees, coinbase_data.miner_data.script_public_key),
             );
         }
 
@@ -175,8 +225,13 @@ impl VirtualStateProcessor {
 
         // Verify all transactions are valid in context
         let current_utxo_view = selected_parent_utxo_view.compose(&ctx.mergeset_diff);
-        let validated_transactions =
-            self.validate_transactions_in_parallel(&txs, &current_utxo_view, header.daa_score, TxValidationFlags::Full);
+        let validated_transactions = self.validate_transactions_in_parallel(
+            &txs,
+            &current_utxo_view,
+            header.daa_score,
+            ctx.reference_price,
+            TxValidationFlags::Full,
+        );
         if validated_transactions.len() < txs.len() - 1 {
             // Some non-coinbase transactions are invalid
             return Err(InvalidTransactionsInUtxoContext(txs.len() - 1 - validated_transactions.len(), txs.len() - 1));
@@ -194,7 +249,7 @@ impl VirtualStateProcessor {
         mergeset_non_daa: &BlockHashSet,
     ) -> BlockProcessResult<()> {
         // Extract only miner data from the provided coinbase
-        let miner_data = self.coinbase_manager.deserialize_coinbase_payload(&coinbase.payload).unwrap().miner_data;
+        let miner_data = self.coinbase_manager.deserialize_coinbase_payload(&coinbase.payload, daa_score).unwrap().miner_data;
         let expected_coinbase = self
             .coinbase_manager
             .expected_coinbase_transaction(daa_score, miner_data, ghostdag_data, mergeset_rewards, mergeset_non_daa)
@@ -207,6 +262,40 @@ impl VirtualStateProcessor {
         }
     }
 
+    /// Returns the price report committed to by the coinbase of `block`, or `None` if the block body is unavailable
+    /// or if the block precedes the price oracle activation
+    pub(crate) fn coinbase_price_report(&self, block: Hash) -> Option<PriceReport> {
+        let daa_score = self.headers_store.get_daa_score(block).ok()?;
+        let txs = self.block_transactions_store.get(block).ok()?;
+        self.coinbase_manager.deserialize_coinbase_payload(&txs[0].payload, daa_score).ok()?.miner_data.price_report
+    }
+
+    /// Returns the median of the prices reported by the blue mergeset of the block with the given GHOSTDAG data (which
+    /// includes its selected parent). Red blocks are excluded so that a minority of the hashrate cannot skew the price
+    fn mergeset_reference_price(&self, ghostdag_data: &GhostdagData) -> Option<u64> {
+        median_price(
+            ghostdag_data
+                .mergeset_blues
+                .iter()
+                .filter_map(|&blue| self.coinbase_price_report(blue).map(|price_report| price_report.price))
+                .collect(),
+        )
+    }
+
+    /// Returns the reference price of a pruning point being imported. If the pruning point was not UTXO-validated locally, the
+    /// price is derived from its blue mergeset only if all blue bodies are available. Otherwise no price is returned (rather than
+    /// substituting one), so that any conversion within the pruning point fails validation and the import is rejected
+    pub(super) fn pruning_point_reference_price(&self, pruning_point: Hash) -> Option<u64> {
+        if let Ok(price) = self.prices_store.get(pruning_point) {
+            return Some(price);
+        }
+        let ghostdag_data = self.ghostdag_primary_store.get_data(pruning_point).unwrap();
+        if !ghostdag_data.mergeset_blues.iter().all(|&blue| self.block_transactions_store.has(blue).unwrap()) {
+            return None;
+        }
+        self.mergeset_reference_price(&ghostdag_data)
+    }
+
     /// Validates transactions against the provided `utxo_view` and returns a vector with all transactions
     /// which passed the validation along with their original index within the containing block
     pub(crate) fn validate_transactions_in_parallel<'a, V: UtxoView + Sync>(
@@ -214,6 +303,7 @@ impl VirtualStateProcessor {
         txs: &'a Vec<Transaction>,
         utxo_view: &V,
         pov_daa_score: u64,
+        pov_price: Option<u64>,
         flags: TxValidationFlags,
     ) -> Vec<(ValidatedTransaction<'a>, u32)> {
         self.thread_pool.install(|| {
@@ -222,7 +312,7 @@ impl VirtualStateProcessor {
                             // that all txs within each block are independent
                 .enumerate()
                 .skip(1) // Skip the coinbase tx.
-                .filter_map(|(i, tx)| self.validate_transaction_in_utxo_context(tx, &utxo_view, pov_daa_score, flags).ok().map(|vtx| (vtx, i as u32)))
+                .filter_map(|(i, tx)| self.validate_transaction_in_utxo_context(tx, &utxo_view, pov_daa_score, pov_price, flags).ok().map(|vtx| (vtx, i as u32)))
                 .collect()
         })
     }
@@ -233,6 +323,7 @@ impl VirtualStateProcessor {
         transaction: &'a Transaction,
         utxo_view: &impl UtxoView,
         pov_daa_score: u64,
+        pov_price: Option<u64>,
         flags: TxValidationFlags,
     ) -> TxResult<ValidatedTransaction<'a>> {
         let mut entries = Vec::with_capacity(transaction.inputs.len());
@@ -245,7 +336,8 @@ impl VirtualStateProcessor {
             }
         }
         let populated_tx = PopulatedTransaction::new(transaction, entries);
-        let res = self.transaction_validator.validate_populated_transaction_and_get_fee(&populated_tx, pov_daa_score, flags);
+        let res =
+            self.transaction_validator.validate_populated_transaction_and_get_fee(&populated_tx, pov_daa_score, pov_price, flags);
         match res {
             Ok(calculated_fee) => Ok(ValidatedTransaction::new(populated_tx, calculated_fee)),
             Err(tx_rule_error) => {
@@ -287,6 +379,7 @@ impl VirtualStateProcessor {
         mutable_tx: &mut MutableTransaction,
         utxo_view: &impl UtxoView,
         pov_daa_score: u64,
+        pov_price: Option<u64>,
     ) -> TxResult<()> {
         self.populate_mempool_transaction_in_utxo_context(mutable_tx, utxo_view)?;
 
@@ -305,6 +398,7 @@ impl VirtualStateProcessor {
         let calculated_fee = self.transaction_validator.validate_populated_transaction_and_get_fee(
             &mutable_tx.as_verifiable(),
             pov_daa_score,
+            pov_price,
             TxValidationFlags::SkipMassCheck, // we can skip the mass check since we just set it
         )?;
         mutable_tx.calculated_fee = Some(calculated_fee);
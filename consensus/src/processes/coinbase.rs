@@ -1,13 +1,14 @@
-use kash_consensus_core::asset_type::AssetType::KSH;
+use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::tx::TransactionAction::TransferKSH;
 use kash_consensus_core::{
     coinbase::*,
     errors::coinbase::{CoinbaseError, CoinbaseResult},
+    price::{PriceReport, PRICE_REPORT_SIGNATURE_LEN},
     subnets,
     tx::{ScriptPublicKey, ScriptVec, Transaction, TransactionOutput},
     BlockHashMap, BlockHashSet,
 };
-use std::{convert::TryInto, mem::size_of};
+use std::{convert::TryInto, iter::once, mem::size_of};
 
 use crate::{constants, model::stores::ghostdag::GhostdagData};
 
@@ -15,6 +16,11 @@ const LENGTH_OF_BLUE_SCORE: usize = size_of::<u64>();
 const LENGTH_OF_SUBSIDY: usize = size_of::<u64>();
 const LENGTH_OF_SCRIPT_PUB_KEY_VERSION: usize = size_of::<u16>();
 const LENGTH_OF_SCRIPT_PUB_KEY_LENGTH: usize = size_of::<u8>();
+const LENGTH_OF_PRICE: usize = size_of::<u64>();
+const LENGTH_OF_PRICE_TIMESTAMP: usize = size_of::<u64>();
+const LENGTH_OF_ORACLE_INDEX: usize = size_of::<u8>();
+const LENGTH_OF_PRICE_REPORT: usize =
+    LENGTH_OF_PRICE + LENGTH_OF_PRICE_TIMESTAMP + LENGTH_OF_ORACLE_INDEX + PRICE_REPORT_SIGNATURE_LEN;
 
 const MIN_PAYLOAD_LENGTH: usize =
     LENGTH_OF_BLUE_SCORE + LENGTH_OF_SUBSIDY + LENGTH_OF_SCRIPT_PUB_KEY_VERSION + LENGTH_OF_SCRIPT_PUB_KEY_LENGTH;
@@ -33,6 +39,10 @@ pub struct CoinbaseManager {
     deflationary_phase_daa_score: u64,
     pre_deflationary_phase_base_subsidy: u64,
     target_time_per_block: u64,
+    asset_commitment_activation_daa_score: u64,
+    price_oracle_activation_daa_score: u64,
+    price_oracle_public_keys: &'static [[u8; 32]],
+    max_price_report_age: u64,
 
     /// Precomputed number of blocks per month
     blocks_per_month: u64,
@@ -66,8 +76,14 @@ impl CoinbaseManager {
         deflationary_phase_daa_score: u64,
         pre_deflationary_phase_base_subsidy: u64,
         target_time_per_block: u64,
+        asset_commitment_activation_daa_score: u64,
+        price_oracle_activation_daa_score: u64,
+        price_oracle_public_keys: &'static [[u8; 32]],
+        max_price_report_age: u64,
     ) -> Self {
         assert!(1000 % target_time_per_block == 0);
+        // Converting actions are priced by oracle signed reports only, so the price oracle cannot activate without oracles
+        assert!(price_oracle_activation_daa_score == u64::MAX || !price_oracle_public_keys.is_empty());
         let bps = 1000 / target_time_per_block;
         let blocks_per_month = SECONDS_PER_MONTH * bps;
 
@@ -81,6 +97,10 @@ impl CoinbaseManager {
             deflationary_phase_daa_score,
             pre_deflationary_phase_base_subsidy,
             target_time_per_block,
+            asset_commitment_activation_daa_score,
+            price_oracle_activation_daa_score,
+            price_oracle_public_keys,
+            max_price_report_age,
             blocks_per_month,
             subsidy_by_month_table,
         }
@@ -95,44 +115,81 @@ impl CoinbaseManager {
     pub fn expected_coinbase_transaction<T: AsRef<[u8]>>(
         &self,
         daa_score: u64,
-        miner_data: MinerData<T>,
+        mut miner_data: MinerData<T>,
         ghostdag_data: &GhostdagData,
         mergeset_rewards: &BlockHashMap<BlockRewardData>,
         mergeset_non_daa: &BlockHashSet,
     ) -> CoinbaseResult<CoinbaseTransactionTemplate> {
-        let mut outputs = Vec::with_capacity(ghostdag_data.mergeset_blues.len() + 1); // + 1 for possible red reward
+        // From the asset commitment hardfork on, fees paid in KUSD and KRV are rewarded in their own asset. Before it, coinbase
+        // outputs do not commit to their asset type, so all fees are rewarded as KSH (see `BlockRewardData::legacy_reward`)
+        let asset_commitment_active = daa_score >= self.asset_commitment_activation_daa_score;
+        let rewarded_assets: &[AssetType] = if asset_commitment_active { &AssetType::ALL } else { &[AssetType::KSH] };
+        let reward = |reward_data: &BlockRewardData, asset_type| {
+            if asset_commitment_active {
+                reward_data.reward(asset_type)
+            } else {
+                reward_data.legacy_reward()
+            }
+        };
+        // + 1 for possible red rewards
+        let mut outputs = Vec::with_capacity((ghostdag_data.mergeset_blues.len() + 1) * rewarded_assets.len());
 
-        // Add an output for each mergeset blue block (∩ DAA window), paying to the script reported by the block.
+        // Add an output for each mergeset blue block (∩ DAA window) and rewarded asset, paying to the script reported by the block.
         // Note that combinatorically it is nearly impossible for a blue block to be non-DAA
         for blue in ghostdag_data.mergeset_blues.iter().filter(|h| !mergeset_non_daa.contains(h)) {
             let reward_data = mergeset_rewards.get(blue).unwrap();
-            if reward_data.subsidy + reward_data.total_fees > 0 {
-                outputs.push(TransactionOutput::new(
-                    reward_data.subsidy + reward_data.total_fees,
-                    reward_data.script_public_key.clone(),
-                    KSH,
-                ));
+            for &asset_type in rewarded_assets {
+                let reward = reward(reward_data, asset_type);
+                if reward > 0 {
+                    outputs.push(TransactionOutput::new(reward, reward_data.script_public_key.clone(), asset_type));
+                }
             }
         }
 
-        // Collect all rewards from mergeset reds ∩ DAA window and create a
-        // single output rewarding all to the current block (the "merging" block)
-        let mut red_reward = 0u64;
+        // Collect all rewards from mergeset reds ∩ DAA window and create a single output per
+        // rewarded asset, rewarding all to the current block (the "merging" block)
+        let mut red_rewards = [0u64; AssetType::ALL.len()];
         for red in ghostdag_data.mergeset_reds.iter().filter(|h| !mergeset_non_daa.contains(h)) {
             let reward_data = mergeset_rewards.get(red).unwrap();
-            red_reward += reward_data.subsidy + reward_data.total_fees;
+            for &asset_type in rewarded_assets {
+                red_rewards[asset_type as usize] += reward(reward_data, asset_type);
+            }
         }
-        if red_reward > 0 {
-            outputs.push(TransactionOutput::new(red_reward, miner_data.script_public_key.clone(), KSH));
+        let mut red_reward_outputs = 0;
+        for &asset_type in rewarded_assets {
+            if red_rewards[asset_type as usize] > 0 {
+                outputs.push(TransactionOutput::new(
+                    red_rewards[asset_type as usize],
+                    miner_data.script_public_key.clone(),
+                    asset_type,
+                ));
+                red_reward_outputs += 1;
+            }
+        }
+
+        // The price report is committed to only from the price oracle activation on, where it is mandatory
+        if daa_score >= self.price_oracle_activation_daa_score {
+            if miner_data.price_report.is_none() {
+                return Err(CoinbaseError::PayloadMissingPriceReport(self.price_oracle_activation_daa_score));
+            }
+        } else {
+            miner_data.price_report = None;
         }
 
         // Build the current block's payload
         let subsidy = self.calc_block_subsidy(daa_score);
         let payload = self.serialize_coinbase_payload(&CoinbaseData { blue_score: ghostdag_data.blue_score, subsidy, miner_data })?;
 
+        // From the asset commitment hardfork on, the coinbase tx uses the committing tx version as well
+        let version = if daa_score >= self.asset_commitment_activation_daa_score {
+            constants::TX_VERSION_ASSET_COMMITMENT
+        } else {
+            constants::TX_VERSION
+        };
+
         Ok(CoinbaseTransactionTemplate {
-            tx: Transaction::new(constants::TX_VERSION, vec![], outputs, TransferKSH, 0, subnets::SUBNETWORK_ID_COINBASE, 0, payload),
-            has_red_reward: red_reward > 0,
+            tx: Transaction::new(version, vec![], outputs, TransferKSH, 0, subnets::SUBNETWORK_ID_COINBASE, 0, payload),
+            red_reward_outputs,
         })
     }
 
@@ -149,13 +206,30 @@ impl CoinbaseManager {
             .chain(data.miner_data.script_public_key.version().to_le_bytes().iter().copied())   // Script public key version    (u16)
             .chain((script_pub_key_len as u8).to_le_bytes().iter().copied())                    // Script public key length     (u8)
             .chain(data.miner_data.script_public_key.script().iter().copied())                  // Script public key            
+            .chain(data.miner_data.price_report.iter().flat_map(serialize_price_report))        // Price report                 (Optional)
             .chain(data.miner_data.extra_data.as_ref().iter().copied())                         // Extra data
             .collect();
 
         Ok(payload)
     }
 
-    pub fn modify_coinbase_payload<T: AsRef<[u8]>>(&self, mut payload: Vec<u8>, miner_data: &MinerData<T>) -> CoinbaseResult<Vec<u8>> {
+    /// Replaces the miner data of the coinbase payload of a block with the given DAA score. As in
+    /// [`Self::expected_coinbase_transaction`], the price report is mandatory from the price oracle activation on and dropped before it
+    pub fn modify_coinbase_payload<T: AsRef<[u8]>>(
+        &self,
+        mut payload: Vec<u8>,
+        miner_data: &MinerData<T>,
+        daa_score: u64,
+    ) -> CoinbaseResult<Vec<u8>> {
+        let price_report = if daa_score >= self.price_oracle_activation_daa_score {
+            if miner_data.price_report.is_none() {
+                return Err(CoinbaseError::PayloadMissingPriceReport(self.price_oracle_activation_daa_score));
+            }
+            miner_data.price_report.as_ref()
+        } else {
+            None
+        };
+
         let script_pub_key_len = miner_data.script_public_key.script().len();
         if script_pub_key_len > self.coinbase_payload_script_public_key_max_len as usize {
             return Err(CoinbaseError::PayloadScriptPublicKeyLenAboveMax(
@@ -171,13 +245,16 @@ impl CoinbaseManager {
             miner_data.script_public_key.version().to_le_bytes().iter().copied() // Script public key version (u16)
                 .chain((script_pub_key_len as u8).to_le_bytes().iter().copied()) // Script public key length  (u8)
                 .chain(miner_data.script_public_key.script().iter().copied())    // Script public key
+                .chain(price_report.into_iter().flat_map(serialize_price_report)) // Price report (Optional)
                 .chain(miner_data.extra_data.as_ref().iter().copied()), // Extra data
         );
 
         Ok(payload)
     }
 
-    pub fn deserialize_coinbase_payload<'a>(&self, payload: &'a [u8]) -> CoinbaseResult<CoinbaseData<&'a [u8]>> {
+    /// Deserializes the coinbase payload of a block with the given DAA score. The DAA score determines whether the payload
+    /// is expected to commit to a price report. Note that the report signature is not verified here (see [`Self::verify_price_report`])
+    pub fn deserialize_coinbase_payload<'a>(&self, payload: &'a [u8], daa_score: u64) -> CoinbaseResult<CoinbaseData<&'a [u8]>> {
         if payload.len() < MIN_PAYLOAD_LENGTH {
             return Err(CoinbaseError::PayloadLenBelowMin(payload.len(), MIN_PAYLOAD_LENGTH));
         }
@@ -209,9 +286,40 @@ impl CoinbaseManager {
 
         let script_public_key =
             ScriptPublicKey::new(script_pub_key_version, ScriptVec::from_slice(parser.take(script_pub_key_len as usize)));
+
+        let price_report = if daa_score >= self.price_oracle_activation_daa_score {
+            if parser.remaining.len() < LENGTH_OF_PRICE_REPORT {
+                return Err(CoinbaseError::PayloadCantContainPriceReport(
+                    payload.len(),
+                    MIN_PAYLOAD_LENGTH + script_pub_key_len as usize + LENGTH_OF_PRICE_REPORT,
+                ));
+            }
+            let price = u64::from_le_bytes(parser.take(LENGTH_OF_PRICE).try_into().unwrap());
+            if price == 0 {
+                return Err(CoinbaseError::PayloadZeroPrice);
+            }
+            let timestamp = u64::from_le_bytes(parser.take(LENGTH_OF_PRICE_TIMESTAMP).try_into().unwrap());
+            let oracle_index = u8::from_le_bytes(parser.take(LENGTH_OF_ORACLE_INDEX).try_into().unwrap());
+            let signature = parser.take(PRICE_REPORT_SIGNATURE_LEN).try_into().unwrap();
+            Some(PriceReport { price, timestamp, oracle_index, signature })
+        } else {
+            None
+        };
         let extra_data = parser.remaining;
 
-        Ok(CoinbaseData { blue_score, subsidy, miner_data: MinerData { script_public_key, extra_data } })
+        Ok(CoinbaseData { blue_score, subsidy, miner_data: MinerData { script_public_key, extra_data, price_report } })
+    }
+
+    /// Verifies that the price report is signed by a known price oracle and that it was signed no later than
+    /// the block reporting it and no earlier than the maximal report age before it
+    pub fn verify_price_report(&self, price_report: &PriceReport, block_timestamp: u64) -> CoinbaseResult<()> {
+        if price_report.timestamp > block_timestamp || block_timestamp - price_report.timestamp > self.max_price_report_age {
+            return Err(CoinbaseError::PriceReportTimestampOutOfRange(price_report.timestamp, block_timestamp));
+        }
+        if !price_report.verify(self.price_oracle_public_keys) {
+            return Err(CoinbaseError::InvalidPriceReportSignature(price_report.oracle_index));
+        }
+        Ok(())
     }
 
     pub fn calc_block_subsidy(&self, daa_score: u64) -> u64 {
@@ -246,6 +354,14 @@ impl CoinbaseManager {
     }
 }
 
+#[rustfmt::skip]
+fn serialize_price_report(price_report: &PriceReport) -> impl Iterator<Item = u8> + '_ {
+    price_report.price.to_le_bytes().into_iter()                        // Price                (u64)
+        .chain(price_report.timestamp.to_le_bytes())                    // Timestamp            (u64)
+        .chain(once(price_report.oracle_index))                         // Oracle index         (u8)
+        .chain(price_report.signature.iter().copied())                  // Signature            (64 bytes)
+}
+
 /*
     This table was pre-calculated by calling `calcDeflationaryPeriodBlockSubsidyFloatCalc` (in kashd-go) for all months until reaching 0 subsidy.
     To regenerate this table, run `TestBuildSubsidyTable` in coinbasemanager_test.go (note the `deflationaryPhaseBaseSubsidy` therein).
@@ -278,11 +394,14 @@ mod tests {
     use super::*;
     use crate::params::MAINNET_PARAMS;
     use kash_consensus_core::{
+        blockhash::BlockHashes,
         config::params::{Params, TESTNET11_PARAMS},
         constants::SOMPI_PER_KASH,
         network::NetworkId,
         tx::scriptvec,
     };
+    use kash_hashes::Hash;
+    use secp256k1::{KeyPair, SECP256K1};
 
     #[test]
     fn calc_high_bps_total_rewards_delta() {
@@ -416,11 +535,12 @@ mod tests {
             miner_data: MinerData {
                 script_public_key: ScriptPublicKey::new(0, ScriptVec::from_slice(&script_data)),
                 extra_data: &extra_data as &[u8],
+                price_report: None,
             },
         };
 
         let payload = cbm.serialize_coinbase_payload(&data).unwrap();
-        let deserialized_data = cbm.deserialize_coinbase_payload(&payload).unwrap();
+        let deserialized_data = cbm.deserialize_coinbase_payload(&payload, 0).unwrap();
 
         assert_eq!(data, deserialized_data);
 
@@ -429,7 +549,7 @@ mod tests {
             "b612c90100000000041a763e07000000000022202b32443ff740012157716d81216d09aebc39e5493c93a7181d92cb756c02c560ac302e31322e382f";
         let mut payload = vec![0u8; payload_hex.len() / 2];
         faster_hex::hex_decode(payload_hex.as_bytes(), &mut payload).unwrap();
-        let deserialized_data = cbm.deserialize_coinbase_payload(&payload).unwrap();
+        let deserialized_data = cbm.deserialize_coinbase_payload(&payload, 0).unwrap();
 
         let expected_data = CoinbaseData {
             blue_score: 29954742,
@@ -443,6 +563,7 @@ mod tests {
                     ],
                 ),
                 extra_data: &[48u8, 46, 49, 50, 46, 56, 47] as &[u8],
+                price_report: None,
             },
         };
         assert_eq!(expected_data, deserialized_data);
@@ -460,6 +581,7 @@ mod tests {
             miner_data: MinerData {
                 script_public_key: ScriptPublicKey::new(0, ScriptVec::from_slice(&script_data)),
                 extra_data: &extra_data,
+                price_report: None,
             },
         };
 
@@ -470,16 +592,145 @@ mod tests {
                 // Modify only miner data
                 script_public_key: ScriptPublicKey::new(0, ScriptVec::from_slice(&[33u8, 255, 33])),
                 extra_data: &[2u8, 3, 23, 98, 34, 34] as &[u8],
+                price_report: None,
             },
         };
 
         let mut payload = cbm.serialize_coinbase_payload(&data).unwrap();
-        payload = cbm.modify_coinbase_payload(payload, &data2.miner_data).unwrap(); // Update the payload with the modified miner data
-        let deserialized_data = cbm.deserialize_coinbase_payload(&payload).unwrap();
+        payload = cbm.modify_coinbase_payload(payload, &data2.miner_data, 0).unwrap(); // Update the payload with the modified miner data
+        let deserialized_data = cbm.deserialize_coinbase_payload(&payload, 0).unwrap();
 
         assert_eq!(data2, deserialized_data);
     }
 
+    #[test]
+    fn payload_price_report_test() {
+        let oracle = KeyPair::from_seckey_slice(SECP256K1, &[1; 32]).unwrap();
+        let mut params = MAINNET_PARAMS.clone();
+        params.price_oracle_activation_daa_score = 1000;
+        params.price_oracle_public_keys = Box::leak(Box::new([oracle.x_only_public_key().0.serialize()]));
+        let cbm = create_manager(&params);
+
+        let report = PriceReport::new_signed(5_000_000, 1_000, 0, &oracle);
+        let extra_data = [2u8, 3];
+        let miner_data = MinerData::new(ScriptPublicKey::new(0, ScriptVec::from_slice(&[33u8, 255])), &extra_data as &[u8])
+            .with_price_report(Some(report));
+        let data = CoinbaseData { blue_score: 56, subsidy: 44000000000, miner_data: miner_data.clone() };
+
+        // From activation on, the price report is committed to and the extra data follows it
+        let payload = cbm.serialize_coinbase_payload(&data).unwrap();
+        assert_eq!(data, cbm.deserialize_coinbase_payload(&payload, 1000).unwrap());
+
+        // Before activation the price report bytes are indistinguishable from extra data
+        let deserialized_data = cbm.deserialize_coinbase_payload(&payload, 999).unwrap();
+        assert_eq!(None, deserialized_data.miner_data.price_report);
+        assert_eq!(LENGTH_OF_PRICE_REPORT + extra_data.len(), deserialized_data.miner_data.extra_data.len());
+
+        // Modifying the miner data replaces the price report as well
+        let modified_miner_data = miner_data.with_price_report(Some(PriceReport::new_signed(6_000_000, 2_000, 0, &oracle)));
+        let modified_payload = cbm.modify_coinbase_payload(payload.clone(), &modified_miner_data, 1000).unwrap();
+        assert_eq!(modified_miner_data, cbm.deserialize_coinbase_payload(&modified_payload, 1000).unwrap().miner_data);
+
+        // Before activation the modified payload drops the price report, and from activation on it is mandatory
+        let modified_payload = cbm.modify_coinbase_payload(payload.clone(), &modified_miner_data, 999).unwrap();
+        assert_eq!(extra_data.as_slice(), cbm.deserialize_coinbase_payload(&modified_payload, 999).unwrap().miner_data.extra_data);
+        let no_report_miner_data = modified_miner_data.with_price_report(None);
+        assert!(matches!(
+            cbm.modify_coinbase_payload(payload, &no_report_miner_data, 1000),
+            Err(CoinbaseError::PayloadMissingPriceReport(1000))
+        ));
+
+        // A payload of an activated block must contain a price report with a non-zero price
+        let no_report_data =
+            CoinbaseData { blue_score: 56, subsidy: 44000000000, miner_data: MinerData::new(ScriptPublicKey::default(), vec![]) };
+        let payload = cbm.serialize_coinbase_payload(&no_report_data).unwrap();
+        assert!(matches!(cbm.deserialize_coinbase_payload(&payload, 1000), Err(CoinbaseError::PayloadCantContainPriceReport(_, _))));
+        let zero_price_report = PriceReport::new_signed(0, 1_000, 0, &oracle);
+        let zero_price_data =
+            CoinbaseData { miner_data: no_report_data.miner_data.with_price_report(Some(zero_price_report)), ..no_report_data };
+        let payload = cbm.serialize_coinbase_payload(&zero_price_data).unwrap();
+        assert!(matches!(cbm.deserialize_coinbase_payload(&payload, 1000), Err(CoinbaseError::PayloadZeroPrice)));
+    }
+
+    #[test]
+    fn verify_price_report_test() {
+        let oracle_public_keys =
+            [[1; 32], [2; 32]].map(|secret| KeyPair::from_seckey_slice(SECP256K1, &secret).unwrap().x_only_public_key().0.serialize());
+        let mut params = MAINNET_PARAMS.clone();
+        params.price_oracle_public_keys = Box::leak(Box::new(oracle_public_keys));
+        params.max_price_report_age = 60_000;
+        let cbm = create_manager(&params);
+        let oracle = KeyPair::from_seckey_slice(SECP256K1, &[2; 32]).unwrap();
+
+        let report = PriceReport::new_signed(5_000_000, 100_000, 1, &oracle);
+        assert!(cbm.verify_price_report(&report, 100_000).is_ok());
+        assert!(cbm.verify_price_report(&report, 160_000).is_ok());
+
+        // Reports must not be stale nor be signed after the block reporting them
+        assert!(matches!(
+            cbm.verify_price_report(&report, 160_001),
+            Err(CoinbaseError::PriceReportTimestampOutOfRange(100_000, 160_001))
+        ));
+        assert!(matches!(
+            cbm.verify_price_report(&report, 99_999),
+            Err(CoinbaseError::PriceReportTimestampOutOfRange(100_000, 99_999))
+        ));
+
+        // Reports must be signed by the oracle they claim
+        let report = PriceReport::new_signed(5_000_000, 100_000, 0, &oracle);
+        assert!(matches!(cbm.verify_price_report(&report, 100_000), Err(CoinbaseError::InvalidPriceReportSignature(0))));
+        let report = PriceReport::new_signed(5_000_000, 100_000, 2, &oracle);
+        assert!(matches!(cbm.verify_price_report(&report, 100_000), Err(CoinbaseError::InvalidPriceReportSignature(2))));
+    }
+
+    #[test]
+    fn expected_coinbase_asset_rewards_test() {
+        let mut params = MAINNET_PARAMS.clone();
+        params.asset_commitment_activation_daa_score = 1000;
+        let cbm = create_manager(&params);
+
+        let script = |b: u8| ScriptPublicKey::new(0, ScriptVec::from_slice(&[b; 2]));
+        let (blue, red) = (Hash::from_u64_word(1), Hash::from_u64_word(2));
+        let ghostdag_data = GhostdagData::new(
+            1,
+            Default::default(),
+            blue,
+            BlockHashes::new(vec![blue]),
+            BlockHashes::new(vec![red]),
+            Default::default(),
+        );
+        let mergeset_rewards = BlockHashMap::from_iter([
+            (blue, BlockRewardData::new(100, AssetFees { ksh: 10, kusd: 20, krv: 0 }, script(1))),
+            (red, BlockRewardData::new(50, AssetFees { ksh: 0, kusd: 5, krv: 7 }, script(2))),
+        ]);
+        let miner_data = MinerData::new(script(3), vec![]);
+        let outputs = |daa_score| {
+            let template = cbm
+                .expected_coinbase_transaction(daa_score, miner_data.clone(), &ghostdag_data, &mergeset_rewards, &Default::default())
+                .unwrap();
+            let outputs = template.tx.outputs.iter().map(|o| (o.value, o.script_public_key.clone(), o.asset_type)).collect::<Vec<_>>();
+            (outputs, template.red_reward_outputs)
+        };
+
+        // Before activation, fees of all assets are rewarded as KSH along with the subsidy, so none are burned
+        assert_eq!(outputs(999), (vec![(130, script(1), AssetType::KSH), (62, script(3), AssetType::KSH)], 1));
+
+        // From activation on, fees are rewarded in the asset they were paid in, and red rewards are paid per asset to the merger
+        assert_eq!(
+            outputs(1000),
+            (
+                vec![
+                    (110, script(1), AssetType::KSH),
+                    (20, script(1), AssetType::KUSD),
+                    (50, script(3), AssetType::KSH),
+                    (5, script(3), AssetType::KUSD),
+                    (7, script(3), AssetType::KRV),
+                ],
+                3
+            )
+        );
+    }
+
     fn create_manager(params: &Params) -> CoinbaseManager {
         CoinbaseManager::new(
             params.coinbase_payload_script_public_key_max_len,
@@ -487,11 +738,15 @@ mod tests {
             params.deflationary_phase_daa_score,
             params.pre_deflationary_phase_base_subsidy,
             params.target_time_per_block,
+            params.asset_commitment_activation_daa_score,
+            params.price_oracle_activation_daa_score,
+            params.price_oracle_public_keys,
+            params.max_price_report_age,
         )
     }
 
     /// Return a CoinbaseManager with legacy golang 1 BPS properties
     fn create_legacy_manager() -> CoinbaseManager {
-        CoinbaseManager::new(150, 204, 15778800 - 259200, 50000000000, 1000)
+        CoinbaseManager::new(150, 204, 15778800 - 259200, 50000000000, 1000, u64::MAX, u64::MAX, &[], 0)
     }
 }
@@ -28,9 +28,24 @@ pub struct TransactionValidator {
 
     /// Storage mass hardfork DAA score
     storage_mass_activation_daa_score: u64,
+
+    /// Asset commitment (tx version 1) hardfork DAA score
+    asset_commitment_activation_daa_score: u64,
+
+    /// Price oracle hardfork DAA score, from which converting actions are validated against the reference price
+    price_oracle_activation_daa_score: u64,
+    conversion_rate_tolerance_bps: u64,
+
+    /// KRV unbonding hardfork DAA score, from which `RedeemKSH` may only spend KRV which completed its unbonding maturity
+    krv_unbonding_activation_daa_score: u64,
+    krv_unbonding_maturity: u64,
+
+    /// Introspection hardfork DAA score, from which input scripts may inspect the transaction they are executed on
+    introspection_activation_daa_score: u64,
 }
 
 impl TransactionValidator {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         max_tx_inputs: usize,
         max_tx_outputs: usize,
@@ -42,6 +57,12 @@ impl TransactionValidator {
         counters: Arc<TxScriptCacheCounters>,
         mass_calculator: MassCalculator,
         storage_mass_activation_daa_score: u64,
+        asset_commitment_activation_daa_score: u64,
+        price_oracle_activation_daa_score: u64,
+        conversion_rate_tolerance_bps: u64,
+        krv_unbonding_activation_daa_score: u64,
+        krv_unbonding_maturity: u64,
+        introspection_activation_daa_score: u64,
     ) -> Self {
         Self {
             max_tx_inputs,
@@ -54,6 +75,12 @@ impl TransactionValidator {
             sig_cache: Cache::with_counters(10_000, counters),
             mass_calculator,
             storage_mass_activation_daa_score,
+            asset_commitment_activation_daa_score,
+            price_oracle_activation_daa_score,
+            conversion_rate_tolerance_bps,
+            krv_unbonding_activation_daa_score,
+            krv_unbonding_maturity,
+            introspection_activation_daa_score,
         }
     }
 
@@ -78,6 +105,12 @@ impl TransactionValidator {
             sig_cache: Cache::with_counters(10_000, counters),
             mass_calculator: MassCalculator::new(0, 0, 0, 0),
             storage_mass_activation_daa_score: u64::MAX,
+            asset_commitment_activation_daa_score: u64::MAX,
+            price_oracle_activation_daa_score: u64::MAX,
+            conversion_rate_tolerance_bps: 0,
+            krv_unbonding_activation_daa_score: u64::MAX,
+            krv_unbonding_maturity: 0,
+            introspection_activation_daa_score: u64::MAX,
         }
     }
 }
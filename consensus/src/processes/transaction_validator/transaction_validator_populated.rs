@@ -1,5 +1,9 @@
-use crate::constants::{MAX_SOMPI, SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK};
-use kash_consensus_core::{hashing::sighash::SigHashReusedValues, tx::VerifiableTransaction};
+use crate::constants::{MAX_SOMPI, SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK, SOMPI_PER_KASH};
+use kash_consensus_core::{
+    asset_type::AssetType,
+    hashing::sighash::SigHashReusedValues,
+    tx::{TransactionAction, VerifiableTransaction},
+};
 use kash_core::warn;
 use kash_txscript::{get_sig_op_count, TxScriptEngine};
 
@@ -22,15 +26,22 @@ pub enum TxValidationFlags {
 }
 
 impl TransactionValidator {
+    /// Validates the populated transaction and returns its fee, which is denominated in the
+    /// source asset of the transaction action (see [`TransactionAction::asset_transfer_types`]).
+    ///
+    /// `pov_price` is the reference KSH/USD price (in KUSD sompi per one KSH) of the validation context,
+    /// required for validating converting actions from the price oracle activation on
     pub fn validate_populated_transaction_and_get_fee(
         &self,
         tx: &impl VerifiableTransaction,
         pov_daa_score: u64,
+        pov_price: Option<u64>,
         flags: TxValidationFlags,
     ) -> TxResult<u64> {
         self.check_transaction_coinbase_maturity(tx, pov_daa_score)?;
-        let total_in = self.check_transaction_input_amounts(tx)?;
-        let total_out = Self::check_transaction_output_values(tx, total_in)?;
+        self.check_transaction_krv_unbonding_maturity(tx, pov_daa_score)?;
+        let total_in = self.check_transaction_input_amounts(tx, pov_daa_score)?;
+        let fee = self.check_transaction_output_values(tx, total_in, pov_daa_score, pov_price)?;
         if flags != TxValidationFlags::SkipMassCheck && pov_daa_score > self.storage_mass_activation_daa_score {
             // Storage mass hardfork was activated
             self.check_mass_commitment(tx)?;
@@ -43,11 +54,11 @@ impl TransactionValidator {
         match flags {
             TxValidationFlags::Full | TxValidationFlags::SkipMassCheck => {
                 Self::check_sig_op_counts(tx)?;
-                self.check_scripts(tx)?;
+                self.check_scripts(tx, pov_daa_score)?;
             }
             TxValidationFlags::SkipScriptChecks => {}
         }
-        Ok(total_in - total_out)
+        Ok(fee)
     }
 
     fn check_transaction_coinbase_maturity(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> TxResult<()> {
@@ -68,9 +79,39 @@ impl TransactionValidator {
         Ok(())
     }
 
-    fn check_transaction_input_amounts(&self, tx: &impl VerifiableTransaction) -> TxResult<u64> {
+    /// Checks that a `RedeemKSH` action only spends KRV which completed its unbonding maturity, counted from the DAA
+    /// score of the block which created the KRV UTXO (e.g., by staking KSH). Enforced from the KRV unbonding activation on
+    fn check_transaction_krv_unbonding_maturity(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> TxResult<()> {
+        if tx.tx().action != TransactionAction::RedeemKSH || pov_daa_score < self.krv_unbonding_activation_daa_score {
+            return Ok(());
+        }
+        if let Some((index, (input, entry))) = tx.populated_inputs().enumerate().find(|(_, (_, entry))| {
+            entry.asset_type == AssetType::KRV && entry.block_daa_score + self.krv_unbonding_maturity > pov_daa_score
+        }) {
+            return Err(TxRuleError::ImmatureKrvRedeem(
+                index,
+                input.previous_outpoint,
+                entry.block_daa_score,
+                pov_daa_score,
+                self.krv_unbonding_maturity,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sums the input amounts. From the asset commitment activation on, inputs are all required to be denominated
+    /// in the source asset of the transaction action, while before it amounts are summed regardless of their asset type
+    fn check_transaction_input_amounts(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> TxResult<u64> {
+        let action = tx.tx().action;
+        let (from_asset, _) = action.asset_transfer_types();
+        let asset_conservation_enabled = pov_daa_score >= self.asset_commitment_activation_daa_score;
         let mut total: u64 = 0;
-        for (_, entry) in tx.populated_inputs() {
+        for (i, (_, entry)) in tx.populated_inputs().enumerate() {
+            if asset_conservation_enabled && entry.asset_type != from_asset {
+                return Err(TxRuleError::WrongInputAssetType(i, entry.asset_type, action, from_asset));
+            }
+
             if let Some(new_total) = total.checked_add(entry.amount) {
                 total = new_total
             } else {
@@ -85,14 +126,68 @@ impl TransactionValidator {
         Ok(total)
     }
 
-    fn check_transaction_output_values(tx: &impl VerifiableTransaction, total_in: u64) -> TxResult<u64> {
+    /// Verifies the output values against the total input amount and returns the fee. Outputs must either be of the
+    /// destination asset of the transaction action or of its source asset (i.e., change), so the resulting fee is
+    /// always denominated in the source asset. Asset types are enforced from the asset commitment activation on, before
+    /// which the fee is simply the total input amount minus the total output value.
+    ///
+    /// Converting actions are valued at the reference price from the price oracle activation on (see
+    /// [`Self::check_conversion_rate`]), and are rejected between the asset commitment and the price oracle activations
+    fn check_transaction_output_values(
+        &self,
+        tx: &impl VerifiableTransaction,
+        total_in: u64,
+        pov_daa_score: u64,
+        pov_price: Option<u64>,
+    ) -> TxResult<u64> {
+        let action = tx.tx().action;
+        let (from_asset, to_asset) = action.asset_transfer_types();
+        let asset_conservation_enabled = pov_daa_score >= self.asset_commitment_activation_daa_score;
+        if asset_conservation_enabled {
+            if let Some((i, output)) =
+                tx.outputs().iter().enumerate().find(|(_, output)| output.asset_type != from_asset && output.asset_type != to_asset)
+            {
+                return Err(TxRuleError::WrongOutputAssetType(i, output.asset_type, action));
+            }
+        }
+
         // There's no need to check for overflow here because it was already checked by check_transaction_output_value_ranges
-        let total_out: u64 = tx.outputs().iter().map(|out| out.value).sum();
-        if total_in < total_out {
-            return Err(TxRuleError::SpendTooHigh(total_out, total_in));
+        if !asset_conservation_enabled || from_asset == to_asset {
+            let total_out: u64 = tx.outputs().iter().map(|out| out.value).sum();
+            if total_in < total_out {
+                return Err(TxRuleError::SpendTooHigh(total_out, total_in));
+            }
+            return Ok(total_in - total_out);
+        }
+
+        // Converting actions are only valid once a trusted (oracle signed) reference price is committed to by consensus
+        if pov_daa_score < self.price_oracle_activation_daa_score {
+            return Err(TxRuleError::ConversionBeforePriceOracleActivation(action, self.price_oracle_activation_daa_score));
         }
 
-        Ok(total_out)
+        let change: u64 = tx.outputs().iter().filter(|out| out.asset_type == from_asset).map(|out| out.value).sum();
+        if total_in < change {
+            return Err(TxRuleError::SpendTooHigh(change, total_in));
+        }
+        let converted: u64 = tx.outputs().iter().filter(|out| out.asset_type == to_asset).map(|out| out.value).sum();
+        let price = pov_price.ok_or(TxRuleError::MissingConversionPrice(action))?;
+        self.check_conversion_rate(action, total_in - change, converted, price)
+    }
+
+    /// Checks that the amount of destination asset produced by a converting action does not exceed the consumed amount of
+    /// source asset (i.e., inputs minus change) at the reference price, and falls short of it by no more than the configured
+    /// tolerance. The tolerance is one-sided so that no action can ever create more value than it consumes. KUSD and KRV are
+    /// both accounted in USD, so the KSH/USD price applies to minting, staking and redeeming alike.
+    ///
+    /// Returns the fee, which is the part of the consumed amount not covered by the converted amount at the reference price
+    fn check_conversion_rate(&self, action: TransactionAction, consumed: u64, converted: u64, price: u64) -> TxResult<u64> {
+        let (from_asset, to_asset) = action.asset_transfer_types();
+        let expected = convert_at_price(consumed, from_asset, price);
+        let tolerance = (expected as u128 * self.conversion_rate_tolerance_bps as u128 / 10_000) as u64;
+        if converted > expected || expected - converted > tolerance {
+            return Err(TxRuleError::ConversionRateOutOfBand(action, converted, expected, tolerance));
+        }
+        Ok(consumed.saturating_sub(convert_at_price(converted, to_asset, price)))
     }
 
     fn check_mass_commitment(&self, tx: &impl VerifiableTransaction) -> TxResult<()> {
@@ -145,11 +240,20 @@ impl TransactionValidator {
         Ok(())
     }
 
-    pub fn check_scripts(&self, tx: &impl VerifiableTransaction) -> TxResult<()> {
+    pub fn check_scripts(&self, tx: &impl VerifiableTransaction, pov_daa_score: u64) -> TxResult<()> {
+        let introspection_enabled = pov_daa_score >= self.introspection_activation_daa_score;
         let mut reused_values = SigHashReusedValues::new();
         for (i, (input, entry)) in tx.populated_inputs().enumerate() {
-            let mut engine = TxScriptEngine::from_transaction_input(tx, input, i, entry, &mut reused_values, &self.sig_cache)
-                .map_err(TxRuleError::SignatureInvalid)?;
+            let mut engine = TxScriptEngine::from_transaction_input(
+                tx,
+                input,
+                i,
+                entry,
+                &mut reused_values,
+                &self.sig_cache,
+                introspection_enabled,
+            )
+            .map_err(TxRuleError::SignatureInvalid)?;
             engine.execute().map_err(TxRuleError::SignatureInvalid)?;
         }
 
@@ -157,15 +261,25 @@ impl TransactionValidator {
     }
 }
 
+/// Converts `amount` of `from_asset` at the given KSH/USD price: KSH is converted to USD-denominated
+/// units (KUSD or KRV), and USD-denominated units are converted to KSH
+fn convert_at_price(amount: u64, from_asset: AssetType, price: u64) -> u64 {
+    let converted = match from_asset {
+        AssetType::KSH => amount as u128 * price as u128 / SOMPI_PER_KASH as u128,
+        AssetType::KUSD | AssetType::KRV => amount as u128 * SOMPI_PER_KASH as u128 / price as u128,
+    };
+    converted.min(u64::MAX as u128) as u64
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::errors::TxRuleError;
     use core::str::FromStr;
     use itertools::Itertools;
-    use kash_consensus_core::asset_type::AssetType::KSH;
+    use kash_consensus_core::asset_type::AssetType::{self, KRV, KSH, KUSD};
     use kash_consensus_core::sign::sign;
     use kash_consensus_core::subnets::SubnetworkId;
-    use kash_consensus_core::tx::TransactionAction::TransferKSH;
+    use kash_consensus_core::tx::TransactionAction::{self, MintKUSD, RedeemKSH, StakeKSH, TransferKRV, TransferKSH, TransferKUSD};
     use kash_consensus_core::tx::{MutableTransaction, PopulatedTransaction, ScriptVec, TransactionId, UtxoEntry};
     use kash_consensus_core::tx::{ScriptPublicKey, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput};
     use kash_txscript_errors::TxScriptError;
@@ -173,6 +287,8 @@ mod tests {
     use smallvec::SmallVec;
     use std::iter::once;
 
+    use super::TxValidationFlags;
+    use crate::constants::SOMPI_PER_KASH;
     use crate::{params::MAINNET_PARAMS, processes::transaction_validator::TransactionValidator};
 
     #[test]
@@ -243,7 +359,7 @@ mod tests {
             }],
         );
 
-        tv.check_scripts(&populated_tx).expect("Signature check failed");
+        tv.check_scripts(&populated_tx, 0).expect("Signature check failed");
     }
 
     #[test]
@@ -315,7 +431,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx).is_err(), "Failing Signature Test Failed");
+        assert!(tv.check_scripts(&populated_tx, 0).is_err(), "Failing Signature Test Failed");
     }
 
     #[test]
@@ -387,7 +503,7 @@ mod tests {
                 asset_type: KSH,
             }],
         );
-        tv.check_scripts(&populated_tx).expect("Signature check failed");
+        tv.check_scripts(&populated_tx, 0).expect("Signature check failed");
     }
 
     #[test]
@@ -460,7 +576,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
+        assert!(tv.check_scripts(&populated_tx, 0) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
     }
 
     #[test]
@@ -533,7 +649,7 @@ mod tests {
             }],
         );
 
-        assert!(tv.check_scripts(&populated_tx) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
+        assert!(tv.check_scripts(&populated_tx, 0) == Err(TxRuleError::SignatureInvalid(TxScriptError::NullFail)));
     }
 
     #[test]
@@ -606,7 +722,7 @@ mod tests {
             }],
         );
 
-        let result = tv.check_scripts(&populated_tx);
+        let result = tv.check_scripts(&populated_tx, 0);
         assert!(result == Err(TxRuleError::SignatureInvalid(TxScriptError::EvalFalse)));
     }
 
@@ -667,7 +783,7 @@ mod tests {
             }],
         );
 
-        let result = tv.check_scripts(&populated_tx);
+        let result = tv.check_scripts(&populated_tx, 0);
         assert!(result == Err(TxRuleError::SignatureInvalid(TxScriptError::SignatureScriptNotPushOnly)));
     }
 
@@ -751,7 +867,302 @@ mod tests {
         let schnorr_key = secp256k1::KeyPair::from_seckey_slice(secp256k1::SECP256K1, &secret_key.secret_bytes()).unwrap();
         let signed_tx = sign(MutableTransaction::with_entries(unsigned_tx, entries), schnorr_key);
         let populated_tx = signed_tx.as_verifiable();
-        assert_eq!(tv.check_scripts(&populated_tx), Ok(()));
+        assert_eq!(tv.check_scripts(&populated_tx, 0), Ok(()));
         assert_eq!(TransactionValidator::check_sig_op_counts(&populated_tx), Ok(()));
     }
+
+    #[test]
+    fn check_asset_conservation_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.asset_commitment_activation_daa_score = 100;
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let script_public_key = ScriptPublicKey::new(0, ScriptVec::from_slice(&[0x51]));
+        let build = |action: TransactionAction, input_assets: &[AssetType], outputs: &[(u64, AssetType)]| {
+            let tx = Transaction::new(
+                0,
+                (0..input_assets.len())
+                    .map(|i| TransactionInput {
+                        previous_outpoint: TransactionOutpoint { transaction_id: prev_tx_id, index: i as u32 },
+                        signature_script: vec![],
+                        sequence: u64::MAX,
+                        sig_op_count: 0,
+                    })
+                    .collect(),
+                outputs
+                    .iter()
+                    .map(|&(value, asset_type)| TransactionOutput { value, script_public_key: script_public_key.clone(), asset_type })
+                    .collect(),
+                action,
+                0,
+                SubnetworkId::from_bytes([0; 20]),
+                0,
+                vec![],
+            );
+            let entries = input_assets
+                .iter()
+                .map(|&asset_type| UtxoEntry {
+                    amount: 1000,
+                    script_public_key: script_public_key.clone(),
+                    block_daa_score: 0,
+                    is_coinbase: false,
+                    asset_type,
+                })
+                .collect();
+            MutableTransaction::with_entries(tx, entries)
+        };
+        let validate_at = |mtx: &MutableTransaction<Transaction>, pov_daa_score: u64| {
+            tv.validate_populated_transaction_and_get_fee(
+                &mtx.as_verifiable(),
+                pov_daa_score,
+                None,
+                TxValidationFlags::SkipScriptChecks,
+            )
+        };
+        let validate = |mtx: &MutableTransaction<Transaction>| validate_at(mtx, 100);
+
+        // Same-asset transfers pay their fee in the transferred asset
+        let mtx = build(TransferKUSD, &[KUSD, KUSD], &[(1500, KUSD), (400, KUSD)]);
+        assert_eq!(validate(&mtx), Ok(100));
+
+        // Spending a KSH UTXO to create a KUSD output of equal value is rejected
+        let mtx = build(TransferKSH, &[KSH], &[(1000, KUSD)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::WrongOutputAssetType(0, KUSD, TransferKSH)));
+        let mtx = build(TransferKUSD, &[KUSD, KSH], &[(2000, KUSD)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::WrongInputAssetType(1, KSH, TransferKUSD, KUSD)));
+
+        // Converting actions may only spend the source asset and create the destination asset plus source asset change
+        let mtx = build(MintKUSD, &[KSH, KSH], &[(1500, KUSD), (400, KSH)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::ConversionBeforePriceOracleActivation(MintKUSD, u64::MAX)));
+        let mtx = build(MintKUSD, &[KSH], &[(500, KUSD), (400, KRV)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::WrongOutputAssetType(1, KRV, MintKUSD)));
+        let mtx = build(RedeemKSH, &[KSH], &[(500, KSH)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::WrongInputAssetType(0, KSH, RedeemKSH, KRV)));
+
+        // Value conservation still applies
+        let mtx = build(TransferKRV, &[KRV], &[(1001, KRV)]);
+        assert_eq!(validate(&mtx), Err(TxRuleError::SpendTooHigh(1001, 1000)));
+
+        // Before the asset commitment activation, asset types are not enforced and the fee sums up all assets
+        let mtx = build(TransferKSH, &[KSH], &[(1000, KUSD)]);
+        assert_eq!(validate_at(&mtx, 99), Ok(0));
+        let mtx = build(TransferKUSD, &[KUSD, KSH], &[(1500, KUSD), (400, KRV)]);
+        assert_eq!(validate_at(&mtx, 99), Ok(100));
+        let mtx = build(TransferKRV, &[KRV], &[(1001, KRV)]);
+        assert_eq!(validate_at(&mtx, 99), Err(TxRuleError::SpendTooHigh(1001, 1000)));
+    }
+
+    #[test]
+    fn check_priced_conversion_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.asset_commitment_activation_daa_score = 50;
+        tv.price_oracle_activation_daa_score = 100;
+        tv.conversion_rate_tolerance_bps = 100;
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let script_public_key = ScriptPublicKey::new(0, ScriptVec::from_slice(&[0x51]));
+        let build = |action: TransactionAction, input: (u64, AssetType), outputs: &[(u64, AssetType)]| {
+            let tx = Transaction::new(
+                0,
+                vec![TransactionInput {
+                    previous_outpoint: TransactionOutpoint { transaction_id: prev_tx_id, index: 0 },
+                    signature_script: vec![],
+                    sequence: u64::MAX,
+                    sig_op_count: 0,
+                }],
+                outputs
+                    .iter()
+                    .map(|&(value, asset_type)| TransactionOutput { value, script_public_key: script_public_key.clone(), asset_type })
+                    .collect(),
+                action,
+                0,
+                SubnetworkId::from_bytes([0; 20]),
+                0,
+                vec![],
+            );
+            let entry = UtxoEntry {
+                amount: input.0,
+                script_public_key: script_public_key.clone(),
+                block_daa_score: 0,
+                is_coinbase: false,
+                asset_type: input.1,
+            };
+            MutableTransaction::with_entries(tx, vec![entry])
+        };
+        let validate = |mtx: &MutableTransaction<Transaction>, pov_daa_score: u64, pov_price: Option<u64>| {
+            tv.validate_populated_transaction_and_get_fee(
+                &mtx.as_verifiable(),
+                pov_daa_score,
+                pov_price,
+                TxValidationFlags::SkipScriptChecks,
+            )
+        };
+
+        // 1 KSH = 2 USD
+        let price = Some(2 * SOMPI_PER_KASH);
+
+        // Minting against 1000 KSH sompi, with 100 KSH sompi of change and the shortfall at the reference price as fee
+        let mtx = build(MintKUSD, (1100, KSH), &[(1980, KUSD), (100, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Ok(10));
+        let mtx = build(MintKUSD, (1100, KSH), &[(2000, KUSD), (100, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Ok(0));
+
+        // The tolerance only covers shortfalls, so converting to more than the expected amount is rejected
+        let mtx = build(MintKUSD, (1100, KSH), &[(2001, KUSD), (100, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Err(TxRuleError::ConversionRateOutOfBand(MintKUSD, 2001, 2000, 20)));
+        let mtx = build(MintKUSD, (1100, KSH), &[(2020, KUSD), (100, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Err(TxRuleError::ConversionRateOutOfBand(MintKUSD, 2020, 2000, 20)));
+        let mtx = build(MintKUSD, (1100, KSH), &[(1000, KUSD), (100, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Err(TxRuleError::ConversionRateOutOfBand(MintKUSD, 1000, 2000, 20)));
+        let mtx = build(MintKUSD, (1100, KSH), &[(2000, KUSD), (1101, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Err(TxRuleError::SpendTooHigh(1101, 1100)));
+
+        // Staking and redeeming use the same price
+        let mtx = build(StakeKSH, (1000, KSH), &[(2000, KRV)]);
+        assert_eq!(validate(&mtx, 100, price), Ok(0));
+        let mtx = build(RedeemKSH, (2000, KRV), &[(990, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Ok(20));
+        let mtx = build(RedeemKSH, (2000, KRV), &[(2000, KSH)]);
+        assert_eq!(validate(&mtx, 100, price), Err(TxRuleError::ConversionRateOutOfBand(RedeemKSH, 2000, 1000, 10)));
+
+        // A converting action cannot be validated without a reference price, while transfers are unaffected
+        let mtx = build(MintKUSD, (1000, KSH), &[(2000, KUSD)]);
+        assert_eq!(validate(&mtx, 100, None), Err(TxRuleError::MissingConversionPrice(MintKUSD)));
+        let mtx = build(TransferKSH, (1000, KSH), &[(900, KSH)]);
+        assert_eq!(validate(&mtx, 100, None), Ok(100));
+
+        // Between the asset commitment and the price oracle activations, converting actions are rejected
+        let mtx = build(MintKUSD, (1000, KSH), &[(900, KUSD)]);
+        assert_eq!(validate(&mtx, 99, price), Err(TxRuleError::ConversionBeforePriceOracleActivation(MintKUSD, 100)));
+        let mtx = build(TransferKSH, (1000, KSH), &[(900, KSH)]);
+        assert_eq!(validate(&mtx, 99, None), Ok(100));
+
+        // Before the asset commitment activation, values are summed regardless of their asset types
+        let mtx = build(MintKUSD, (1000, KSH), &[(900, KUSD)]);
+        assert_eq!(validate(&mtx, 49, None), Ok(100));
+        let mtx = build(MintKUSD, (1000, KSH), &[(2000, KUSD)]);
+        assert_eq!(validate(&mtx, 49, price), Err(TxRuleError::SpendTooHigh(2000, 1000)));
+    }
+
+    #[test]
+    fn check_krv_unbonding_maturity_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.krv_unbonding_activation_daa_score = 100;
+        tv.krv_unbonding_maturity = 1000;
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let previous_outpoint = TransactionOutpoint { transaction_id: prev_tx_id, index: 0 };
+        let script_public_key = ScriptPublicKey::new(0, ScriptVec::from_slice(&[0x51]));
+        let build = |action: TransactionAction, input_asset: AssetType, output_asset: AssetType, block_daa_score: u64| {
+            let tx = Transaction::new(
+                0,
+                vec![TransactionInput { previous_outpoint, signature_script: vec![], sequence: u64::MAX, sig_op_count: 0 }],
+                vec![TransactionOutput { value: 1000, script_public_key: script_public_key.clone(), asset_type: output_asset }],
+                action,
+                0,
+                SubnetworkId::from_bytes([0; 20]),
+                0,
+                vec![],
+            );
+            let entry = UtxoEntry {
+                amount: 1000,
+                script_public_key: script_public_key.clone(),
+                block_daa_score,
+                is_coinbase: false,
+                asset_type: input_asset,
+            };
+            MutableTransaction::with_entries(tx, vec![entry])
+        };
+        let validate = |mtx: &MutableTransaction<Transaction>, pov_daa_score: u64| {
+            tv.validate_populated_transaction_and_get_fee(
+                &mtx.as_verifiable(),
+                pov_daa_score,
+                None,
+                TxValidationFlags::SkipScriptChecks,
+            )
+        };
+
+        // Redeeming bonded KRV is rejected until the unbonding maturity has passed
+        let mtx = build(RedeemKSH, KRV, KSH, 500);
+        assert_eq!(validate(&mtx, 1499), Err(TxRuleError::ImmatureKrvRedeem(0, previous_outpoint, 500, 1499, 1000)));
+        assert_eq!(validate(&mtx, 1500), Ok(0));
+
+        // Bonded KRV can still be transferred
+        let mtx = build(TransferKRV, KRV, KRV, 500);
+        assert_eq!(validate(&mtx, 1499), Ok(0));
+
+        // Before activation, KRV can be redeemed right away
+        let mtx = build(RedeemKSH, KRV, KSH, 50);
+        assert_eq!(validate(&mtx, 99), Ok(0));
+    }
+
+    #[test]
+    fn check_introspection_activation_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.introspection_activation_daa_score = 100;
+
+        let prev_tx_id = TransactionId::from_str("880eb9819a31821d9d2399e2f35e2433b72637e393d71ecc9b8d0250f49153c3").unwrap();
+        let previous_outpoint = TransactionOutpoint { transaction_id: prev_tx_id, index: 0 };
+        // OpTxInputCount OpTrue OpEqual
+        let script_public_key = ScriptPublicKey::new(0, ScriptVec::from_slice(&[0xb2, 0x51, 0x87]));
+        let tx = Transaction::new(
+            0,
+            vec![TransactionInput { previous_outpoint, signature_script: vec![], sequence: 0, sig_op_count: 0 }],
+            vec![TransactionOutput { value: 1000, script_public_key: script_public_key.clone(), asset_type: KSH }],
+            TransferKSH,
+            0,
+            SubnetworkId::from_bytes([0; 20]),
+            0,
+            vec![],
+        );
+        let entry = UtxoEntry { amount: 1000, script_public_key, block_daa_score: 0, is_coinbase: false, asset_type: KSH };
+        let mtx = MutableTransaction::with_entries(tx, vec![entry]);
+
+        // Before activation, introspection opcodes are undefined
+        assert!(matches!(
+            tv.check_scripts(&mtx.as_verifiable(), 99),
+            Err(TxRuleError::SignatureInvalid(TxScriptError::InvalidOpcode(_)))
+        ));
+        assert_eq!(tv.check_scripts(&mtx.as_verifiable(), 100), Ok(()));
+    }
 }
@@ -1,5 +1,5 @@
-use crate::constants::{MAX_SOMPI, TX_VERSION};
-use kash_consensus_core::tx::Transaction;
+use crate::constants::{MAX_SOMPI, TX_VERSION_ASSET_COMMITMENT};
+use kash_consensus_core::{asset_type::AssetType, tx::Transaction};
 use std::collections::HashSet;
 
 use super::{
@@ -37,7 +37,9 @@ impl TransactionValidator {
         if !tx.inputs.is_empty() {
             return Err(TxRuleError::CoinbaseHasInputs(tx.inputs.len()));
         }
-        let outputs_limit = self.ghostdag_k as u64 + 2;
+        // The outputs limit of the DAA score context is checked as part of the in-context validation, here we only bound
+        // the outputs count by the limit under multi-asset rewards (see [`TransactionValidator::coinbase_outputs_limit`])
+        let outputs_limit = (self.ghostdag_k as u64 + 2) * AssetType::ALL.len() as u64;
         if tx.outputs.len() as u64 > outputs_limit {
             return Err(TxRuleError::CoinbaseTooManyOutputs(tx.outputs.len(), outputs_limit));
         }
@@ -115,7 +117,7 @@ fn check_transaction_payload(tx: &Transaction) -> TxResult<()> {
 }
 
 fn check_transaction_version(tx: &Transaction) -> TxResult<()> {
-    if tx.version != TX_VERSION {
+    if tx.version > TX_VERSION_ASSET_COMMITMENT {
         return Err(TxRuleError::UnknownTxVersion(tx.version));
     }
     Ok(())
@@ -157,7 +159,7 @@ mod tests {
     use kash_core::assert_match;
 
     use crate::{
-        constants::TX_VERSION,
+        constants::TX_VERSION_ASSET_COMMITMENT,
         params::MAINNET_PARAMS,
         processes::transaction_validator::{errors::TxRuleError, TransactionValidator},
     };
@@ -301,7 +303,7 @@ mod tests {
         assert_match!(tv.validate_tx_in_isolation(&tx), Err(TxRuleError::NonCoinbaseTxHasPayload));
 
         let mut tx = valid_tx;
-        tx.version = TX_VERSION + 1;
+        tx.version = TX_VERSION_ASSET_COMMITMENT + 1;
         assert_match!(tv.validate_tx_in_isolation(&tx), Err(TxRuleError::UnknownTxVersion(_)));
     }
 }
@@ -1,6 +1,6 @@
-use kash_consensus_core::tx::Transaction;
+use kash_consensus_core::{asset_type::AssetType, tx::Transaction};
 
-use crate::constants::LOCK_TIME_THRESHOLD;
+use crate::constants::{LOCK_TIME_THRESHOLD, TX_VERSION, TX_VERSION_ASSET_COMMITMENT};
 
 use super::{
     errors::{TxResult, TxRuleError},
@@ -9,9 +9,40 @@ use super::{
 
 impl TransactionValidator {
     pub fn utxo_free_tx_validation(&self, tx: &Transaction, ctx_daa_score: u64, ctx_block_time: u64) -> TxResult<()> {
+        self.check_tx_version_is_active(tx, ctx_daa_score)?;
+        self.check_coinbase_outputs_count(tx, ctx_daa_score)?;
         self.check_tx_is_finalized(tx, ctx_daa_score, ctx_block_time)
     }
 
+    /// Returns the maximal number of coinbase outputs in the given DAA score context: an output per mergeset blue block
+    /// and one for the red blocks reward, for each rewarded asset. Non-KSH fees are rewarded from the asset commitment hardfork on
+    fn coinbase_outputs_limit(&self, ctx_daa_score: u64) -> u64 {
+        let rewarded_assets = if ctx_daa_score >= self.asset_commitment_activation_daa_score { AssetType::ALL.len() } else { 1 };
+        (self.ghostdag_k as u64 + 2) * rewarded_assets as u64
+    }
+
+    fn check_coinbase_outputs_count(&self, tx: &Transaction, ctx_daa_score: u64) -> TxResult<()> {
+        if !tx.is_coinbase() {
+            return Ok(());
+        }
+        let outputs_limit = self.coinbase_outputs_limit(ctx_daa_score);
+        if tx.outputs.len() as u64 > outputs_limit {
+            return Err(TxRuleError::CoinbaseTooManyOutputs(tx.outputs.len(), outputs_limit));
+        }
+        Ok(())
+    }
+
+    /// Checks the transaction version against the asset commitment hardfork: before activation only the legacy
+    /// version is allowed, and from activation on transactions must commit to the action and output asset types
+    fn check_tx_version_is_active(&self, tx: &Transaction, ctx_daa_score: u64) -> TxResult<()> {
+        let expected_version =
+            if ctx_daa_score >= self.asset_commitment_activation_daa_score { TX_VERSION_ASSET_COMMITMENT } else { TX_VERSION };
+        if tx.version != expected_version {
+            return Err(TxRuleError::UnexpectedTxVersion(tx.version, ctx_daa_score, expected_version));
+        }
+        Ok(())
+    }
+
     fn check_tx_is_finalized(&self, tx: &Transaction, ctx_daa_score: u64, ctx_block_time: u64) -> TxResult<()> {
         // Lock time of zero means the transaction is finalized.
         if tx.lock_time == 0 {
@@ -39,3 +70,89 @@ impl TransactionValidator {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kash_consensus_core::{
+        asset_type::AssetType,
+        subnets::{SUBNETWORK_ID_COINBASE, SUBNETWORK_ID_NATIVE},
+        tx::{ScriptPublicKey, Transaction, TransactionAction, TransactionOutput},
+    };
+    use kash_core::assert_match;
+
+    use crate::{
+        constants::{TX_VERSION, TX_VERSION_ASSET_COMMITMENT},
+        params::MAINNET_PARAMS,
+        processes::transaction_validator::{errors::TxRuleError, TransactionValidator},
+    };
+
+    #[test]
+    fn check_tx_version_is_active_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.asset_commitment_activation_daa_score = 100;
+
+        let tx =
+            |version| Transaction::new(version, vec![], vec![], TransactionAction::TransferKSH, 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+
+        // Before activation only the legacy version is allowed
+        assert_match!(tv.utxo_free_tx_validation(&tx(TX_VERSION), 99, 0), Ok(()));
+        assert_match!(
+            tv.utxo_free_tx_validation(&tx(TX_VERSION_ASSET_COMMITMENT), 99, 0),
+            Err(TxRuleError::UnexpectedTxVersion(TX_VERSION_ASSET_COMMITMENT, 99, TX_VERSION))
+        );
+
+        // From activation on transactions must commit to asset types
+        assert_match!(tv.utxo_free_tx_validation(&tx(TX_VERSION_ASSET_COMMITMENT), 100, 0), Ok(()));
+        assert_match!(
+            tv.utxo_free_tx_validation(&tx(TX_VERSION), 100, 0),
+            Err(TxRuleError::UnexpectedTxVersion(TX_VERSION, 100, TX_VERSION_ASSET_COMMITMENT))
+        );
+    }
+
+    #[test]
+    fn check_coinbase_outputs_count_test() {
+        let params = MAINNET_PARAMS.clone();
+        let mut tv = TransactionValidator::new_for_tests(
+            params.max_tx_inputs,
+            params.max_tx_outputs,
+            params.max_signature_script_len,
+            params.max_script_public_key_len,
+            params.ghostdag_k,
+            params.coinbase_payload_script_public_key_max_len,
+            params.coinbase_maturity,
+            Default::default(),
+        );
+        tv.asset_commitment_activation_daa_score = 100;
+
+        let coinbase = |version, outputs_count: u64| {
+            let outputs = (0..outputs_count).map(|_| TransactionOutput::new(1, ScriptPublicKey::default(), AssetType::KSH)).collect();
+            Transaction::new(version, vec![], outputs, TransactionAction::TransferKSH, 0, SUBNETWORK_ID_COINBASE, 0, vec![])
+        };
+        let legacy_limit = params.ghostdag_k as u64 + 2;
+        let multi_asset_limit = legacy_limit * AssetType::ALL.len() as u64;
+
+        // Before activation only KSH is rewarded, so there is a single output per rewarded block
+        assert_match!(tv.utxo_free_tx_validation(&coinbase(TX_VERSION, legacy_limit), 99, 0), Ok(()));
+        assert_match!(
+            tv.utxo_free_tx_validation(&coinbase(TX_VERSION, legacy_limit + 1), 99, 0),
+            Err(TxRuleError::CoinbaseTooManyOutputs(_, limit)) if limit == legacy_limit
+        );
+
+        // From activation on, every rewarded block may be paid an output per asset
+        assert_match!(tv.utxo_free_tx_validation(&coinbase(TX_VERSION_ASSET_COMMITMENT, multi_asset_limit), 100, 0), Ok(()));
+        assert_match!(
+            tv.utxo_free_tx_validation(&coinbase(TX_VERSION_ASSET_COMMITMENT, multi_asset_limit + 1), 100, 0),
+            Err(TxRuleError::CoinbaseTooManyOutputs(_, limit)) if limit == multi_asset_limit
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use kash_consensus_core::asset_type::AssetType::KSH;
+use kash_consensus_core::asset_type::AssetType::{self, KSH};
 use kash_consensus_core::tx::TransactionAction::TransferKSH;
 use kash_consensus_core::{
     block::Block,
@@ -51,7 +51,7 @@ pub fn generate_random_utxo_from_script_public_key_pool(
         script_public_key_pool.choose(rng).expect("expected_script_public key").clone(),
         rng.gen(),
         rng.gen_bool(0.5),
-        KSH,
+        AssetType::from(rng.gen_range(0u32..3)), // spread utxos over all asset types so that per-asset accounting gets exercised
     )
 }
 
@@ -8,6 +8,8 @@ pub enum TxScriptError {
     MalformedPush(usize, usize),
     #[error("transaction input index {0} >= {1}")]
     InvalidIndex(usize, usize),
+    #[error("transaction output index {0} is out of range of {1} outputs")]
+    InvalidOutputIndex(i32, usize),
     #[error("combined stack size {0} > max allowed {1}")]
     StackSizeExceeded(usize, usize),
     #[error("attempt to execute invalid opcode {0}")]
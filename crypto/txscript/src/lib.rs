@@ -80,6 +80,9 @@ pub struct TxScriptEngine<'a, T: VerifiableTransaction> {
     cond_stack: Vec<OpCond>, // Following if stacks, and whether it is running
 
     num_ops: i32,
+
+    // Whether the transaction introspection opcodes are enabled, see the introspection activation DAA score
+    introspection_enabled: bool,
 }
 
 fn parse_script<T: VerifiableTransaction>(
@@ -152,6 +155,7 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
             sig_cache,
             cond_stack: vec![],
             num_ops: 0,
+            introspection_enabled: false,
         }
     }
 
@@ -162,6 +166,7 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
         utxo_entry: &'a UtxoEntry,
         reused_values: &'a mut SigHashReusedValues,
         sig_cache: &'a Cache<SigCacheKey, bool>,
+        introspection_enabled: bool,
     ) -> Result<Self, TxScriptError> {
         let script_public_key = utxo_entry.script_public_key.script();
         // The script_public_key in P2SH is just validating the hash on the OpMultiSig script
@@ -176,6 +181,7 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
                 sig_cache,
                 cond_stack: Default::default(),
                 num_ops: 0,
+                introspection_enabled,
             }),
             false => Err(TxScriptError::InvalidIndex(input_idx, tx.tx().inputs.len())),
         }
@@ -190,6 +196,7 @@ impl<'a, T: VerifiableTransaction> TxScriptEngine<'a, T> {
             sig_cache,
             cond_stack: Default::default(),
             num_ops: 0,
+            introspection_enabled: false,
         }
     }
 
@@ -567,8 +574,9 @@ mod tests {
 
             let populated_tx = PopulatedTransaction::new(&tx, vec![utxo_entry.clone()]);
 
-            let mut vm = TxScriptEngine::from_transaction_input(&populated_tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
-                .expect("Script creation failed");
+            let mut vm =
+                TxScriptEngine::from_transaction_input(&populated_tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, false)
+                    .expect("Script creation failed");
             assert_eq!(vm.execute(), test.expected_result);
         }
     }
@@ -1031,6 +1039,7 @@ mod bitcoind_tests {
                 &populated_tx.entries[0],
                 &mut reused_values,
                 &sig_cache,
+                false,
             )
             .map_err(UnifiedError::TxScriptError)?;
             vm.execute().map_err(UnifiedError::TxScriptError)
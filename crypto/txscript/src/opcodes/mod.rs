@@ -3,7 +3,7 @@ use core::mem::size_of;
 #[macro_use]
 mod macros;
 
-use crate::data_stack::{DataStack, OpcodeData};
+use crate::data_stack::{DataStack, OpcodeData, SizedEncodeInt};
 use crate::{
     ScriptSource, TxScriptEngine, TxScriptError, LOCK_TIME_THRESHOLD, MAX_TX_IN_SEQUENCE_NUM, NO_COST_OPCODE,
     SEQUENCE_LOCK_TIME_DISABLED, SEQUENCE_LOCK_TIME_MASK,
@@ -11,7 +11,7 @@ use crate::{
 use blake2b_simd::Params;
 use core::cmp::{max, min};
 use kash_consensus_core::hashing::sighash_type::SigHashType;
-use kash_consensus_core::tx::VerifiableTransaction;
+use kash_consensus_core::tx::{TransactionOutput, UtxoEntry, VerifiableTransaction};
 use sha2::{Digest, Sha256};
 use std::fmt::{Debug, Formatter};
 
@@ -206,6 +206,62 @@ fn push_number<T: VerifiableTransaction>(number: i64, vm: &mut TxScriptEngine<T>
     Ok(())
 }
 
+#[inline]
+fn push_amount<T: VerifiableTransaction>(amount: u64, vm: &mut TxScriptEngine<T>) -> OpCodeResult {
+    let amount = i64::try_from(amount).map_err(|_| TxScriptError::NumberTooBig(format!("amount {amount} exceeds a script number")))?;
+    push_number(amount, vm)
+}
+
+// Pops numeric operands for the arithmetic and comparison opcodes. From the introspection activation
+// on, operands may be up to 8 bytes long so amounts pushed by the introspection opcodes can be
+// computed with, while prior to it they are limited to 4 bytes.
+#[inline]
+fn pop_numbers<const SIZE: usize, T: VerifiableTransaction>(vm: &mut TxScriptEngine<T>) -> Result<[i64; SIZE], TxScriptError> {
+    if vm.introspection_enabled {
+        let numbers: [SizedEncodeInt<8>; SIZE] = vm.dstack.pop_items()?;
+        Ok(numbers.map(|number| number.0))
+    } else {
+        vm.dstack.pop_items()
+    }
+}
+
+// Returns the result of an arithmetic opcode, failing if it does not fit an 8-byte script number
+#[inline]
+fn checked_number(result: Option<i64>) -> Result<i64, TxScriptError> {
+    result
+        .filter(|&number| number != i64::MIN)
+        .ok_or_else(|| TxScriptError::NumberTooBig("arithmetic result exceeds a script number".to_string()))
+}
+
+// Returns the transaction, the input index and the spent UTXO entry the engine is running on, for
+// the use of the transaction introspection opcodes. Prior to the introspection activation these
+// opcodes are undefined, hence they fail exactly like any other unknown opcode.
+#[inline]
+fn introspection_source<'a, T: VerifiableTransaction>(
+    vm: &TxScriptEngine<'a, T>,
+    opcode: &impl Debug,
+) -> Result<(&'a T, usize, &'a UtxoEntry), TxScriptError> {
+    if !vm.introspection_enabled {
+        return Err(TxScriptError::InvalidOpcode(format!("{opcode:?}")));
+    }
+    match vm.script_source {
+        ScriptSource::TxInput { tx, id, utxo_entry, .. } => Ok((tx, id, utxo_entry)),
+        _ => Err(TxScriptError::InvalidSource(format!("{opcode:?} only applies to transaction inputs"))),
+    }
+}
+
+// Pops an output index from the stack and returns the matching output of the introspected transaction
+#[inline]
+fn introspected_output<'a, T: VerifiableTransaction>(
+    vm: &mut TxScriptEngine<'a, T>,
+    opcode: &impl Debug,
+) -> Result<&'a TransactionOutput, TxScriptError> {
+    let (tx, ..) = introspection_source(vm, opcode)?;
+    let [index]: [i32; 1] = vm.dstack.pop_items()?;
+    let outputs = &tx.tx().outputs;
+    usize::try_from(index).ok().and_then(|i| outputs.get(i)).ok_or(TxScriptError::InvalidOutputIndex(index, outputs.len()))
+}
+
 /*
 The following is the implementation and metadata of all opcodes. Each opcode has unique
 number (and template system makes it impossible to use two opcodes), length specification,
@@ -558,14 +614,14 @@ opcode_list! {
 
     // Numeric related opcodes.
     opcode Op1Add<0x8b, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(value + 1);
+        let [value]: [i64; 1] = pop_numbers(vm)?;
+        vm.dstack.push_item(checked_number(value.checked_add(1))?);
         Ok(())
     }
 
     opcode Op1Sub<0x8c, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(value - 1);
+        let [value]: [i64; 1] = pop_numbers(vm)?;
+        vm.dstack.push_item(checked_number(value.checked_sub(1))?);
         Ok(())
     }
 
@@ -573,38 +629,38 @@ opcode_list! {
     opcode Op2Div<0x8e, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
 
     opcode OpNegate<0x8f, 1>(self, vm) {
-        let [value]: [i64; 1] = vm.dstack.pop_items()?;
+        let [value]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item(-value);
         Ok(())
     }
 
     opcode OpAbs<0x90, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item(m.abs());
         Ok(())
     }
 
     opcode OpNot<0x91, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item((m == 0) as i64);
         Ok(())
     }
 
     opcode Op0NotEqual<0x92, 1>(self, vm) {
-        let [m]: [i64; 1] = vm.dstack.pop_items()?;
+        let [m]: [i64; 1] = pop_numbers(vm)?;
         vm.dstack.push_item((m != 0) as i64 );
         Ok(())
     }
 
     opcode OpAdd<0x93, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(a+b);
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        vm.dstack.push_item(checked_number(a.checked_add(b))?);
         Ok(())
     }
 
     opcode OpSub<0x94, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
-        vm.dstack.push_item(a-b);
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
+        vm.dstack.push_item(checked_number(a.checked_sub(b))?);
         Ok(())
     }
 
@@ -615,25 +671,25 @@ opcode_list! {
     opcode OpRShift<0x99, 1>(self, vm) Err(TxScriptError::OpcodeDisabled(format!("{self:?}")))
 
     opcode OpBoolAnd<0x9a, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(((a != 0) && (b != 0)) as i64);
         Ok(())
     }
 
     opcode OpBoolOr<0x9b, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(((a != 0) || (b != 0)) as i64);
         Ok(())
     }
 
     opcode OpNumEqual<0x9c, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a == b) as i64);
         Ok(())
     }
 
     opcode OpNumEqualVerify<0x9d, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         match a == b {
             true => Ok(()),
             false => Err(TxScriptError::VerifyError)
@@ -641,49 +697,49 @@ opcode_list! {
     }
 
     opcode OpNumNotEqual<0x9e, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a != b) as i64);
         Ok(())
     }
 
     opcode OpLessThan<0x9f, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a < b) as i64);
         Ok(())
     }
 
     opcode OpGreaterThan<0xa0, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a > b) as i64);
         Ok(())
     }
 
     opcode OpLessThanOrEqual<0xa1, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a <= b) as i64);
         Ok(())
     }
 
     opcode OpGreaterThanOrEqual<0xa2, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item((a >= b) as i64);
         Ok(())
     }
 
     opcode OpMin<0xa3, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(min(a,b));
         Ok(())
     }
 
     opcode OpMax<0xa4, 1>(self, vm) {
-        let [a,b]: [i64; 2] = vm.dstack.pop_items()?;
+        let [a,b]: [i64; 2] = pop_numbers(vm)?;
         vm.dstack.push_item(max(a,b));
         Ok(())
     }
 
     opcode OpWithin<0xa5, 1>(self, vm) {
-        let [x,l,u]: [i64; 3] = vm.dstack.pop_items()?;
+        let [x,l,u]: [i64; 3] = pop_numbers(vm)?;
         vm.dstack.push_item((x >= l && x < u) as i64);
         Ok(())
     }
@@ -876,16 +932,56 @@ opcode_list! {
         }
     }
 
+    // Transaction introspection opcodes, enabled from the introspection activation DAA score.
+    // Amounts are pushed as numbers of up to 8 bytes, which the numeric opcodes accept from the
+    // same activation on.
+    opcode OpTxInputCount<0xb2, 1>(self, vm) {
+        let (tx, ..) = introspection_source(vm, self)?;
+        push_number(tx.tx().inputs.len() as i64, vm)
+    }
+
+    opcode OpTxOutputCount<0xb3, 1>(self, vm) {
+        let (tx, ..) = introspection_source(vm, self)?;
+        push_number(tx.tx().outputs.len() as i64, vm)
+    }
+
+    opcode OpTxAction<0xb4, 1>(self, vm) {
+        let (tx, ..) = introspection_source(vm, self)?;
+        push_number(u32::from(tx.tx().action) as i64, vm)
+    }
+
+    opcode OpTxInputIndex<0xb5, 1>(self, vm) {
+        let (_, id, _) = introspection_source(vm, self)?;
+        push_number(id as i64, vm)
+    }
+
+    opcode OpTxInputAmount<0xb6, 1>(self, vm) {
+        let (_, _, utxo_entry) = introspection_source(vm, self)?;
+        push_amount(utxo_entry.amount, vm)
+    }
+
+    opcode OpTxInputAssetType<0xb7, 1>(self, vm) {
+        let (_, _, utxo_entry) = introspection_source(vm, self)?;
+        push_number(u32::from(utxo_entry.asset_type) as i64, vm)
+    }
+
+    opcode OpTxOutputAmount<0xb8, 1>(self, vm) {
+        let output = introspected_output(vm, self)?;
+        push_amount(output.value, vm)
+    }
+
+    opcode OpTxOutputAssetType<0xb9, 1>(self, vm) {
+        let output = introspected_output(vm, self)?;
+        push_number(u32::from(output.asset_type) as i64, vm)
+    }
+
+    opcode OpTxOutputSpk<0xba, 1>(self, vm) {
+        let output = introspected_output(vm, self)?;
+        let script_public_key = &output.script_public_key;
+        push_data([script_public_key.version().to_be_bytes().as_slice(), script_public_key.script()].concat(), vm)
+    }
+
     // Undefined opcodes.
-    opcode OpUnknown178<0xb2, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown179<0xb3, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown180<0xb4, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown181<0xb5, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown182<0xb6, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown183<0xb7, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown184<0xb8, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown185<0xb9, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
-    opcode OpUnknown186<0xba, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown187<0xbb, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown188<0xbc, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
     opcode OpUnknown189<0xbd, 1>(self, vm) Err(TxScriptError::InvalidOpcode(format!("{self:?}")))
@@ -973,8 +1069,9 @@ pub fn to_small_int<T: VerifiableTransaction>(opcode: &Box<dyn OpCodeImplementat
 #[cfg(test)]
 mod test {
     use crate::caches::Cache;
-    use crate::data_stack::Stack;
-    use crate::opcodes::{OpCodeExecution, OpCodeImplementation};
+    use crate::data_stack::{DataStack, Stack};
+    use crate::opcodes::{codes, OpCodeExecution, OpCodeImplementation};
+    use crate::script_builder::ScriptBuilder;
     use crate::{opcodes, pay_to_address_script, TxScriptEngine, TxScriptError, LOCK_TIME_THRESHOLD};
     use kash_addresses::{Address, Prefix, Version};
     use kash_consensus_core::asset_type::AssetType;
@@ -1086,15 +1183,15 @@ mod test {
         let tests: Vec<Box<dyn OpCodeImplementation<PopulatedTransaction>>> = vec![
             opcodes::OpUnknown166::empty().expect("Should accept empty"),
             opcodes::OpUnknown167::empty().expect("Should accept empty"),
-            opcodes::OpUnknown178::empty().expect("Should accept empty"),
-            opcodes::OpUnknown179::empty().expect("Should accept empty"),
-            opcodes::OpUnknown180::empty().expect("Should accept empty"),
-            opcodes::OpUnknown181::empty().expect("Should accept empty"),
-            opcodes::OpUnknown182::empty().expect("Should accept empty"),
-            opcodes::OpUnknown183::empty().expect("Should accept empty"),
-            opcodes::OpUnknown184::empty().expect("Should accept empty"),
-            opcodes::OpUnknown185::empty().expect("Should accept empty"),
-            opcodes::OpUnknown186::empty().expect("Should accept empty"),
+            opcodes::OpTxInputCount::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputCount::empty().expect("Should accept empty"),
+            opcodes::OpTxAction::empty().expect("Should accept empty"),
+            opcodes::OpTxInputIndex::empty().expect("Should accept empty"),
+            opcodes::OpTxInputAmount::empty().expect("Should accept empty"),
+            opcodes::OpTxInputAssetType::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputAmount::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputAssetType::empty().expect("Should accept empty"),
+            opcodes::OpTxOutputSpk::empty().expect("Should accept empty"),
             opcodes::OpUnknown187::empty().expect("Should accept empty"),
             opcodes::OpUnknown188::empty().expect("Should accept empty"),
             opcodes::OpUnknown189::empty().expect("Should accept empty"),
@@ -2755,7 +2852,7 @@ mod test {
         ] {
             let mut tx = base_tx.clone();
             tx.0.lock_time = tx_lock_time;
-            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
+            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, false)
                 .expect("Shouldn't fail");
             vm.dstack = vec![lock_time.clone()];
             match code.execute(&mut vm) {
@@ -2798,7 +2895,7 @@ mod test {
         ] {
             let mut input = base_input.clone();
             input.sequence = tx_sequence;
-            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache)
+            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, false)
                 .expect("Shouldn't fail");
             vm.dstack = vec![sequence.clone()];
             match code.execute(&mut vm) {
@@ -2818,6 +2915,125 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_introspection_opcodes() {
+        // Everything we need to build a script source
+        let (mut tx, input, mut utxo_entry) = make_mock_transaction(1);
+        tx.0.action = TransactionAction::RedeemKSH;
+        utxo_entry.amount = 5 * SOMPI_PER_KASH;
+        utxo_entry.asset_type = AssetType::KRV;
+        let output = tx.0.outputs[0].clone();
+
+        let sig_cache = Cache::new(10_000);
+        let mut reused_values = SigHashReusedValues::new();
+
+        let number = |n: i64| -> Vec<u8> {
+            let mut stack: Stack = vec![];
+            stack.push_item(n);
+            stack.pop().expect("just pushed")
+        };
+        let output_script_public_key =
+            [output.script_public_key.version().to_be_bytes().as_slice(), output.script_public_key.script()].concat();
+
+        let tests: Vec<(Box<dyn OpCodeImplementation<VerifiableTransactionMock>>, Stack, Stack)> = vec![
+            (opcodes::OpTxInputCount::empty().expect("Should accept empty"), vec![], vec![number(1)]),
+            (opcodes::OpTxOutputCount::empty().expect("Should accept empty"), vec![], vec![number(1)]),
+            (
+                opcodes::OpTxAction::empty().expect("Should accept empty"),
+                vec![],
+                vec![number(u32::from(TransactionAction::RedeemKSH) as i64)],
+            ),
+            (opcodes::OpTxInputIndex::empty().expect("Should accept empty"), vec![], vec![number(0)]),
+            (opcodes::OpTxInputAmount::empty().expect("Should accept empty"), vec![], vec![number(5 * SOMPI_PER_KASH as i64)]),
+            (
+                opcodes::OpTxInputAssetType::empty().expect("Should accept empty"),
+                vec![],
+                vec![number(u32::from(AssetType::KRV) as i64)],
+            ),
+            (opcodes::OpTxOutputAmount::empty().expect("Should accept empty"), vec![number(0)], vec![number(output.value as i64)]),
+            (
+                opcodes::OpTxOutputAssetType::empty().expect("Should accept empty"),
+                vec![number(0)],
+                vec![number(u32::from(AssetType::KSH) as i64)],
+            ),
+            (opcodes::OpTxOutputSpk::empty().expect("Should accept empty"), vec![number(0)], vec![output_script_public_key]),
+        ];
+
+        for (code, init, expected) in tests {
+            // Prior to the introspection activation the opcodes are undefined
+            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, false)
+                .expect("Shouldn't fail");
+            vm.dstack = init.clone();
+            match code.execute(&mut vm) {
+                Err(TxScriptError::InvalidOpcode(_)) => {}
+                _ => panic!("Opcode {code:?} should be invalid prior to the introspection activation"),
+            }
+
+            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, true)
+                .expect("Shouldn't fail");
+            vm.dstack = init;
+            code.execute(&mut vm).unwrap_or_else(|e| panic!("Opcode {code:?} should not fail. Got {e}"));
+            assert_eq!(vm.dstack, expected, "Opcode {code:?} pushed an unexpected stack");
+        }
+
+        let code: Box<dyn OpCodeImplementation<VerifiableTransactionMock>> =
+            opcodes::OpTxOutputAmount::empty().expect("Should accept empty");
+        for index in [-1, 1] {
+            let mut vm = TxScriptEngine::from_transaction_input(&tx, &input, 0, &utxo_entry, &mut reused_values, &sig_cache, true)
+                .expect("Shouldn't fail");
+            vm.dstack = vec![number(index as i64)];
+            assert_eq!(code.execute(&mut vm), Err(TxScriptError::InvalidOutputIndex(index, 1)));
+        }
+    }
+
+    #[test]
+    fn test_introspected_amount_arithmetic() {
+        let (mut tx, mut input, mut utxo_entry) = make_mock_transaction(1);
+        // An amount which does not fit the 4 bytes accepted by numeric opcodes prior to the introspection activation
+        tx.0.outputs[0].value = 50 * SOMPI_PER_KASH;
+        input.signature_script = vec![];
+
+        let sig_cache = Cache::new(10_000);
+        let mut reused_values = SigHashReusedValues::new();
+
+        let output_amount: Box<dyn OpCodeImplementation<VerifiableTransactionMock>> =
+            opcodes::OpTxOutputAmount::empty().expect("Should accept empty");
+        let tests = [
+            (codes::OpGreaterThanOrEqual, 10 * SOMPI_PER_KASH as i64, true, Ok(())),
+            (codes::OpNumEqual, 50 * SOMPI_PER_KASH as i64, true, Ok(())),
+            (codes::OpGreaterThan, 100 * SOMPI_PER_KASH as i64, true, Err(TxScriptError::EvalFalse)),
+            (codes::OpAdd, i64::MAX, true, Err(TxScriptError::NumberTooBig("arithmetic result exceeds a script number".to_string()))),
+            (codes::OpNumEqual, 50 * SOMPI_PER_KASH as i64, false, Err(TxScriptError::InvalidOpcode(format!("{output_amount:?}")))),
+        ];
+
+        for (opcode, constant, introspection_enabled, expected) in tests {
+            let script = ScriptBuilder::new()
+                .add_op(codes::Op0)
+                .and_then(|builder| builder.add_op(codes::OpTxOutputAmount))
+                .and_then(|builder| builder.add_i64(constant))
+                .and_then(|builder| builder.add_op(opcode))
+                .expect("Should build")
+                .drain();
+            utxo_entry.script_public_key = ScriptPublicKey::from_vec(0, script);
+
+            let mut vm = TxScriptEngine::from_transaction_input(
+                &tx,
+                &input,
+                0,
+                &utxo_entry,
+                &mut reused_values,
+                &sig_cache,
+                introspection_enabled,
+            )
+            .expect("Shouldn't fail");
+            assert_eq!(
+                vm.execute(),
+                expected,
+                "Comparing the output amount with {opcode:#x} and {constant} gave an unexpected result"
+            );
+        }
+    }
+
     #[test]
     fn test_opreturn() {
         run_error_test_cases(vec![ErrorTestCase {
@@ -36,6 +36,7 @@ pub enum DatabaseStorePrefixes {
     UtxoMultisets = 26,
     VirtualUtxoset = 27,
     VirtualState = 28,
+    Prices = 29,
 
     // ---- Decomposed reachability stores ----
     ReachabilityTreeChildren = 30,
@@ -53,6 +54,12 @@ pub enum DatabaseStorePrefixes {
     UtxoIndex = 192,
     UtxoIndexTips = 193,
     CirculatingSupply = 194,
+    CirculatingSupplySnapshots = 195,
+    TxIndexAcceptedTransactions = 196,
+    TxIndexChainBlocks = 197,
+    TxIndexAddressTransactions = 198,
+    TxIndexSink = 199,
+    TxIndexChainBlockScores = 200,
 
     // ---- Separator ----
     /// Reserved as a separator
@@ -1,3 +1,4 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::tx::{ScriptPublicKey, TransactionOutpoint, UtxoEntry};
 use kash_utils::mem_size::MemSizeEstimator;
@@ -12,7 +13,45 @@ pub type CompactUtxoCollection = HashMap<TransactionOutpoint, CompactUtxoEntry>;
 pub type UtxoSetByScriptPublicKey = HashMap<ScriptPublicKey, CompactUtxoCollection>;
 
 /// A map of balance by script public key
-pub type BalanceByScriptPublicKey = HashMap<ScriptPublicKey, u64>;
+pub type BalanceByScriptPublicKey = HashMap<ScriptPublicKey, AssetBalance>;
+
+/// The balance of a script public key broken down by [`AssetType`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct AssetBalance {
+    pub ksh: u64,
+    pub kusd: u64,
+    pub krv: u64,
+}
+
+impl AssetBalance {
+    pub fn new(ksh: u64, kusd: u64, krv: u64) -> Self {
+        Self { ksh, kusd, krv }
+    }
+
+    /// Returns the balance of `asset_type`.
+    pub fn get(&self, asset_type: AssetType) -> u64 {
+        match asset_type {
+            AssetType::KSH => self.ksh,
+            AssetType::KUSD => self.kusd,
+            AssetType::KRV => self.krv,
+        }
+    }
+
+    /// Returns the sum of the balances of all asset types.
+    pub fn total(&self) -> u64 {
+        self.ksh + self.kusd + self.krv
+    }
+
+    /// Adds `amount` to the balance of `asset_type`.
+    pub fn add_amount(&mut self, asset_type: AssetType, amount: u64) {
+        let balance = match asset_type {
+            AssetType::KSH => &mut self.ksh,
+            AssetType::KUSD => &mut self.kusd,
+            AssetType::KRV => &mut self.krv,
+        };
+        *balance += amount;
+    }
+}
 
 // Note: memory optimization compared to go-lang kashd:
 // Unlike `consensus_core::tx::UtxoEntry` the utxoindex utilizes a compacted utxo form, where `script_public_key` field is removed.
@@ -1,5 +1,6 @@
-use crate::indexed_utxos::{UtxoChanges, UtxoSetByScriptPublicKey};
+use crate::indexed_utxos::{CompactUtxoCollection, UtxoChanges, UtxoSetByScriptPublicKey};
 use derive_more::Display;
+use kash_consensus_core::tx::ScriptPublicKey;
 use kash_notify::{
     events::EventType,
     full_featured,
@@ -70,7 +71,7 @@ impl UtxosChangedNotification {
     }
 
     pub(crate) fn apply_utxos_changed_subscription(&self, subscription: &UtxosChangedSubscription) -> Option<Self> {
-        if subscription.to_all() {
+        if subscription.to_all() && !subscription.is_filtered() {
             Some(self.clone())
         } else {
             let added = Self::filter_utxo_set(&self.added, subscription);
@@ -87,21 +88,42 @@ impl UtxosChangedNotification {
         // As an optimization, we iterate over the smaller set (O(n)) among the two below
         // and check existence over the larger set (O(1))
         let mut result = HashMap::default();
-        if utxo_set.len() < subscription.addresses().len() {
+        if subscription.to_all() || utxo_set.len() < subscription.addresses().len() {
             utxo_set.iter().for_each(|(script_public_key, collection)| {
-                if subscription.addresses().contains_key(script_public_key) {
-                    result.insert(script_public_key.clone(), collection.clone());
+                if subscription.to_all() || subscription.addresses().contains_key(script_public_key) {
+                    Self::insert_filtered_collection(&mut result, script_public_key, collection, subscription);
                 }
             });
         } else {
             subscription.addresses().iter().filter(|(script_public_key, _)| utxo_set.contains_key(script_public_key)).for_each(
                 |(script_public_key, _)| {
                     if let Some(collection) = utxo_set.get(script_public_key) {
-                        result.insert(script_public_key.clone(), collection.clone());
+                        Self::insert_filtered_collection(&mut result, script_public_key, collection, subscription);
                     }
                 },
             );
         }
         result
     }
+
+    fn insert_filtered_collection(
+        result: &mut UtxoSetByScriptPublicKey,
+        script_public_key: &ScriptPublicKey,
+        collection: &CompactUtxoCollection,
+        subscription: &UtxosChangedSubscription,
+    ) {
+        let filter = subscription.filter(script_public_key);
+        if !filter.is_filtered() {
+            result.insert(script_public_key.clone(), collection.clone());
+            return;
+        }
+        let filtered: CompactUtxoCollection = collection
+            .iter()
+            .filter(|(_, entry)| filter.matches(entry.asset_type, entry.amount))
+            .map(|(outpoint, entry)| (*outpoint, *entry))
+            .collect();
+        if !filtered.is_empty() {
+            result.insert(script_public_key.clone(), filtered);
+        }
+    }
 }
@@ -13,6 +13,7 @@ use kash_notify::{
     notification::Notification as NotificationTrait,
     notifier::DynNotify,
 };
+use kash_txindex::api::TxIndexProxy;
 use kash_utils::triggers::SingleTrigger;
 use kash_utxoindex::api::UtxoIndexProxy;
 use std::sync::{
@@ -21,7 +22,8 @@ use std::sync::{
 };
 
 /// Processor processes incoming consensus UtxosChanged and PruningPointUtxoSetOverride
-/// notifications submitting them to a UtxoIndex.
+/// notifications submitting them to a UtxoIndex, and VirtualChainChanged notifications
+/// submitting them to a TxIndex.
 ///
 /// It also acts as a [`Collector`], converting the incoming consensus notifications
 /// into their pending local versions and relaying them to a local notifier.
@@ -30,6 +32,9 @@ pub struct Processor {
     /// An optional UTXO indexer
     utxoindex: Option<UtxoIndexProxy>,
 
+    /// An optional transaction indexer
+    txindex: Option<TxIndexProxy>,
+
     recv_channel: CollectorNotificationReceiver<ConsensusNotification>,
 
     /// Has this collector been started?
@@ -39,9 +44,14 @@ pub struct Processor {
 }
 
 impl Processor {
-    pub fn new(utxoindex: Option<UtxoIndexProxy>, recv_channel: CollectorNotificationReceiver<ConsensusNotification>) -> Self {
+    pub fn new(
+        utxoindex: Option<UtxoIndexProxy>,
+        txindex: Option<TxIndexProxy>,
+        recv_channel: CollectorNotificationReceiver<ConsensusNotification>,
+    ) -> Self {
         Self {
             utxoindex,
+            txindex,
             recv_channel,
             collect_shutdown: Arc::new(SingleTrigger::new()),
             is_started: Arc::new(AtomicBool::new(false)),
@@ -58,12 +68,13 @@ impl Processor {
 
             while let Ok(notification) = self.recv_channel.recv().await {
                 match self.process_notification(notification).await {
-                    Ok(notification) => match notifier.notify(notification) {
+                    Ok(Some(notification)) => match notifier.notify(notification) {
                         Ok(_) => (),
                         Err(err) => {
                             trace!("[Index processor] notification sender error: {err:?}");
                         }
                     },
+                    Ok(None) => (),
                     Err(err) => {
                         trace!("[Index processor] error while processing a consensus notification: {err:?}");
                     }
@@ -76,13 +87,18 @@ impl Processor {
         });
     }
 
-    async fn process_notification(self: &Arc<Self>, notification: ConsensusNotification) -> IndexResult<Notification> {
+    /// Processes a consensus notification, returning the index notification it converts into, if any.
+    async fn process_notification(self: &Arc<Self>, notification: ConsensusNotification) -> IndexResult<Option<Notification>> {
         match notification {
             ConsensusNotification::UtxosChanged(utxos_changed) => {
-                Ok(Notification::UtxosChanged(self.process_utxos_changed(utxos_changed).await?))
+                Ok(Some(Notification::UtxosChanged(self.process_utxos_changed(utxos_changed).await?)))
             }
             ConsensusNotification::PruningPointUtxoSetOverride(_) => {
-                Ok(Notification::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideNotification {}))
+                Ok(Some(Notification::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideNotification {})))
+            }
+            ConsensusNotification::VirtualChainChanged(virtual_chain_changed) => {
+                self.process_virtual_chain_changed(virtual_chain_changed).await?;
+                Ok(None)
             }
             _ => Err(IndexError::NotSupported(notification.event_type())),
         }
@@ -94,8 +110,15 @@ impl Processor {
     ) -> IndexResult<UtxosChangedNotification> {
         trace!("[{IDENT}]: processing {:?}", notification);
         if let Some(utxoindex) = self.utxoindex.clone() {
-            let converted_notification: UtxosChangedNotification =
-                utxoindex.update(notification.accumulated_utxo_diff.clone(), notification.virtual_parents).await?.into();
+            let converted_notification: UtxosChangedNotification = utxoindex
+                .update(
+                    notification.accumulated_utxo_diff.clone(),
+                    notification.virtual_parents,
+                    notification.virtual_daa_score,
+                    notification.reorg_daa_score,
+                )
+                .await?
+                .into();
             debug!(
                 "IDXPRC, Creating UtxosChanged notifications with {} added and {} removed utxos",
                 converted_notification.added.len(),
@@ -106,6 +129,24 @@ impl Processor {
         Err(IndexError::NotSupported(EventType::UtxosChanged))
     }
 
+    async fn process_virtual_chain_changed(
+        self: &Arc<Self>,
+        notification: consensus_notification::VirtualChainChangedNotification,
+    ) -> IndexResult<()> {
+        trace!("[{IDENT}]: processing {:?}", notification);
+        if let Some(txindex) = self.txindex.clone() {
+            txindex
+                .update(
+                    notification.added_chain_block_hashes,
+                    notification.removed_chain_block_hashes,
+                    notification.added_chain_blocks_acceptance_data,
+                )
+                .await?;
+            return Ok(());
+        };
+        Err(IndexError::NotSupported(EventType::VirtualChainChanged))
+    }
+
     async fn join_collecting_task(&self) -> Result<()> {
         trace!("[Index processor] joining");
         self.collect_shutdown.listener.clone().await;
@@ -137,7 +178,7 @@ mod tests {
     use kash_database::utils::DbLifetime;
     use kash_notify::notifier::test_helpers::NotifyMock;
     use kash_utxoindex::UtxoIndex;
-    use rand::{rngs::SmallRng, SeedableRng};
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
     use std::sync::Arc;
 
     // TODO: rewrite with Simnet, when possible.
@@ -160,7 +201,7 @@ mod tests {
             tc.init();
             let consensus_manager = Arc::new(ConsensusManager::from_consensus(tc.consensus_clone()));
             let utxoindex = Some(UtxoIndexProxy::new(UtxoIndex::new(consensus_manager, utxoindex_db).unwrap()));
-            let processor = Arc::new(Processor::new(utxoindex, consensus_receiver));
+            let processor = Arc::new(Processor::new(utxoindex, None, consensus_receiver));
             let (processor_sender, processor_receiver) = unbounded();
             let notifier = Arc::new(NotifyMock::new(processor_sender));
             processor.clone().start(notifier);
@@ -183,6 +224,8 @@ mod tests {
         let test_notification = consensus_notification::UtxosChangedNotification::new(
             Arc::new(UtxoDiff { add: to_add_collection, remove: to_remove_collection }),
             Arc::new(generate_random_hashes(rng, 2)),
+            rng.gen(),
+            None,
         );
 
         pipeline.consensus_sender.send(ConsensusNotification::UtxosChanged(test_notification.clone())).await.expect("expected send");
@@ -10,8 +10,9 @@ use kash_index_core::notifier::IndexNotifier;
 use kash_notify::{
     connection::ChannelType,
     events::{EventSwitches, EventType},
-    scope::{PruningPointUtxoSetOverrideScope, Scope, UtxosChangedScope},
+    scope::{PruningPointUtxoSetOverrideScope, Scope, UtxosChangedScope, VirtualChainChangedScope},
 };
+use kash_txindex::api::TxIndexProxy;
 use kash_utils::{channel::Channel, triggers::SingleTrigger};
 use kash_utxoindex::api::UtxoIndexProxy;
 use std::sync::Arc;
@@ -20,12 +21,13 @@ const INDEX_SERVICE: &str = IDENT;
 
 pub struct IndexService {
     utxoindex: Option<UtxoIndexProxy>,
+    txindex: Option<TxIndexProxy>,
     notifier: Arc<IndexNotifier>,
     shutdown: SingleTrigger,
 }
 
 impl IndexService {
-    pub fn new(consensus_notifier: &Arc<ConsensusNotifier>, utxoindex: Option<UtxoIndexProxy>) -> Self {
+    pub fn new(consensus_notifier: &Arc<ConsensusNotifier>, utxoindex: Option<UtxoIndexProxy>, txindex: Option<TxIndexProxy>) -> Self {
         // Prepare consensus-notify objects
         let consensus_notify_channel = Channel::<ConsensusNotification>::default();
         let consensus_notify_listener_id = consensus_notifier
@@ -34,18 +36,26 @@ impl IndexService {
         // Prepare the index-processor notifier
         // No subscriber is defined here because the subscription are manually created during the construction and never changed after that.
         let events: EventSwitches = [EventType::UtxosChanged, EventType::PruningPointUtxoSetOverride].as_ref().into();
-        let collector = Arc::new(Processor::new(utxoindex.clone(), consensus_notify_channel.receiver()));
+        let collector = Arc::new(Processor::new(utxoindex.clone(), txindex.clone(), consensus_notify_channel.receiver()));
         let notifier = Arc::new(IndexNotifier::new(INDEX_SERVICE, events, vec![collector], vec![], 1));
 
         // Manually subscribe to index-processor related event types
-        consensus_notifier
-            .try_start_notify(consensus_notify_listener_id, Scope::UtxosChanged(UtxosChangedScope::default()))
-            .expect("the subscription always succeeds");
+        if utxoindex.is_some() {
+            consensus_notifier
+                .try_start_notify(consensus_notify_listener_id, Scope::UtxosChanged(UtxosChangedScope::default()))
+                .expect("the subscription always succeeds");
+        }
         consensus_notifier
             .try_start_notify(consensus_notify_listener_id, Scope::PruningPointUtxoSetOverride(PruningPointUtxoSetOverrideScope {}))
             .expect("the subscription always succeeds");
+        if txindex.is_some() {
+            // The txindex needs the acceptance data of the added chain blocks
+            consensus_notifier
+                .try_start_notify(consensus_notify_listener_id, Scope::VirtualChainChanged(VirtualChainChangedScope::new(true)))
+                .expect("the subscription always succeeds");
+        }
 
-        Self { utxoindex, notifier, shutdown: SingleTrigger::default() }
+        Self { utxoindex, txindex, notifier, shutdown: SingleTrigger::default() }
     }
 
     pub fn notifier(&self) -> Arc<IndexNotifier> {
@@ -55,6 +65,10 @@ impl IndexService {
     pub fn utxoindex(&self) -> Option<UtxoIndexProxy> {
         self.utxoindex.clone()
     }
+
+    pub fn txindex(&self) -> Option<TxIndexProxy> {
+        self.txindex.clone()
+    }
 }
 
 impl AsyncService for IndexService {
@@ -0,0 +1,105 @@
+use kash_consensus_core::{
+    acceptance_data::AcceptanceData,
+    tx::{ScriptPublicKey, TransactionId},
+};
+use kash_consensusmanager::spawn_blocking;
+use kash_database::prelude::StoreResult;
+use kash_hashes::Hash;
+use parking_lot::RwLock;
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{
+    errors::TxIndexResult,
+    model::{AddressTransaction, TxAcceptance},
+};
+
+///Txindex API targeted at retrieval calls.
+pub trait TxIndexApi: Send + Sync + Debug {
+    /// Retrieve where and by which chain block a transaction was accepted.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_transaction_acceptance(&self, transaction_id: TransactionId) -> StoreResult<TxAcceptance>;
+
+    /// Retrieve up to `limit` accepted transactions involving `script_public_key`, ordered by the blue score of their
+    /// accepting chain block and transaction id, and starting at `start_blue_score`. If `start_transaction_id` is set,
+    /// the retrieval resumes right after the transaction `start_transaction_id` accepted at `start_blue_score`.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_transactions_by_script_public_key(
+        &self,
+        script_public_key: ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>>;
+
+    /// Retrieve the last virtual selected chain block processed by the txindex.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_sink(&self) -> StoreResult<Hash>;
+
+    /// Checks if the txindex's db is synced with consensus.
+    ///
+    /// Note:
+    /// 1) Use a read lock when accessing this method
+    /// 2) due to potential sync-gaps is_synced is unreliable while consensus is actively resolving virtual states.
+    fn is_synced(&self) -> TxIndexResult<bool>;
+
+    /// Update the txindex with a virtual selected chain change.
+    ///
+    /// Note: Use a write lock when accessing this method
+    fn update(
+        &mut self,
+        added_chain_block_hashes: Arc<Vec<Hash>>,
+        removed_chain_block_hashes: Arc<Vec<Hash>>,
+        added_chain_blocks_acceptance_data: Arc<Vec<Arc<AcceptanceData>>>,
+    ) -> TxIndexResult<()>;
+
+    /// Resync the txindex from the consensus db
+    ///
+    /// Note: Use a write lock when accessing this method
+    fn resync(&mut self) -> TxIndexResult<()>;
+}
+
+/// Async proxy for the transaction index
+#[derive(Debug, Clone)]
+pub struct TxIndexProxy {
+    inner: Arc<RwLock<dyn TxIndexApi>>,
+}
+
+impl TxIndexProxy {
+    pub fn new(inner: Arc<RwLock<dyn TxIndexApi>>) -> Self {
+        Self { inner }
+    }
+
+    pub async fn get_transaction_acceptance(self, transaction_id: TransactionId) -> StoreResult<TxAcceptance> {
+        spawn_blocking(move || self.inner.read().get_transaction_acceptance(transaction_id)).await.unwrap()
+    }
+
+    pub async fn get_transactions_by_script_public_key(
+        self,
+        script_public_key: ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>> {
+        spawn_blocking(move || {
+            self.inner.read().get_transactions_by_script_public_key(script_public_key, start_blue_score, start_transaction_id, limit)
+        })
+        .await
+        .unwrap()
+    }
+
+    pub async fn update(
+        self,
+        added_chain_block_hashes: Arc<Vec<Hash>>,
+        removed_chain_block_hashes: Arc<Vec<Hash>>,
+        added_chain_blocks_acceptance_data: Arc<Vec<Arc<AcceptanceData>>>,
+    ) -> TxIndexResult<()> {
+        spawn_blocking(move || {
+            self.inner.write().update(added_chain_block_hashes, removed_chain_block_hashes, added_chain_blocks_acceptance_data)
+        })
+        .await
+        .unwrap()
+    }
+}
@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use crate::IDENT;
+use kash_consensus_core::errors::consensus::ConsensusError;
+use kash_database::prelude::StoreError;
+
+/// Errors originating from the [`TxIndex`].
+#[derive(Error, Debug)]
+pub enum TxIndexError {
+    #[error("[{IDENT}]: {0}")]
+    StoreAccessError(#[from] StoreError),
+
+    #[error("[{IDENT}]: {0}")]
+    ConsensusError(#[from] ConsensusError),
+}
+
+/// Results originating from the [`TxIndex`].
+pub type TxIndexResult<T> = Result<T, TxIndexError>;
@@ -0,0 +1,38 @@
+use kash_consensus_core::tx::{ScriptPublicKey, TransactionId};
+use kash_hashes::Hash;
+use kash_utils::mem_size::MemSizeEstimator;
+use serde::{Deserialize, Serialize};
+
+/// Locates a transaction accepted by the virtual selected chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxAcceptance {
+    /// The chain block whose mergeset accepted the transaction
+    pub accepting_block_hash: Hash,
+    /// The blue score of the accepting chain block
+    pub accepting_block_blue_score: u64,
+    /// The merged block which includes the transaction
+    pub including_block_hash: Hash,
+    /// The index of the transaction within the including block
+    pub index_within_block: u32,
+}
+
+impl MemSizeEstimator for TxAcceptance {}
+
+/// A transaction accepted by the virtual selected chain which involves some address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressTransaction {
+    pub transaction_id: TransactionId,
+    pub accepting_block_hash: Hash,
+    pub accepting_block_blue_score: u64,
+}
+
+/// The transactions accepted by a chain block, along with the script public keys each one involves.
+///
+/// Kept per chain block so that the index can be reverted when the block leaves the virtual selected chain.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ChainBlockTransactions {
+    pub blue_score: u64,
+    pub transactions: Vec<(TransactionId, Vec<ScriptPublicKey>)>,
+}
+
+impl MemSizeEstimator for ChainBlockTransactions {}
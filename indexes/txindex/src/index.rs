@@ -0,0 +1,404 @@
+use crate::{
+    api::TxIndexApi,
+    errors::{TxIndexError, TxIndexResult},
+    model::{AddressTransaction, ChainBlockTransactions, TxAcceptance},
+    stores::store_manager::Store,
+    IDENT,
+};
+use kash_consensus_core::{
+    acceptance_data::AcceptanceData,
+    api::ConsensusApi,
+    tx::{ScriptPublicKey, Transaction, TransactionId, TransactionOutpoint},
+    utxo::utxo_diff::UtxoDiff,
+};
+use kash_consensusmanager::{ConsensusManager, ConsensusResetHandler};
+use kash_core::{info, trace};
+use kash_database::prelude::{StoreError, StoreResult, DB};
+use kash_hashes::Hash;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Weak},
+};
+
+const RESYNC_CHUNK_SIZE: usize = 256;
+
+/// TxIndex indexes the transactions accepted by the virtual selected chain, by transaction id and by the script public keys they involve.
+/// Only transactions accepted above the pruning point are kept, since the bodies of the blocks below it are pruned.
+/// Note: The TxIndex struct by itself is not thread save, only correct usage of the supplied RwLock via `new` makes it so.
+/// please follow guidelines found in the comments under `txindex::core::api::TxIndexApi` for proper thread safety.
+pub struct TxIndex {
+    consensus_manager: Arc<ConsensusManager>,
+    store: Store,
+}
+
+impl TxIndex {
+    /// Creates a new [`TxIndex`] within a [`RwLock`]
+    pub fn new(consensus_manager: Arc<ConsensusManager>, db: Arc<DB>) -> TxIndexResult<Arc<RwLock<Self>>> {
+        let mut txindex = Self { consensus_manager: consensus_manager.clone(), store: Store::new(db) };
+        if !txindex.is_synced()? {
+            txindex.resync()?;
+        }
+        let txindex = Arc::new(RwLock::new(txindex));
+        consensus_manager.register_consensus_reset_handler(Arc::new(TxIndexConsensusResetHandler::new(Arc::downgrade(&txindex))));
+        Ok(txindex)
+    }
+
+    /// Indexes the transactions accepted by the given chain blocks, in chain order.
+    fn add_chain_blocks(
+        &mut self,
+        consensus: &dyn ConsensusApi,
+        chain_block_hashes: &[Hash],
+        acceptance_data: &[Arc<AcceptanceData>],
+    ) -> TxIndexResult<()> {
+        // Block bodies fetched while processing this batch, shared by the resolution of transactions and of their inputs
+        let mut block_transactions: HashMap<Hash, Option<Arc<Vec<Transaction>>>> = HashMap::new();
+
+        for (chain_block_hash, acceptance_data) in chain_block_hashes.iter().copied().zip(acceptance_data.iter()) {
+            let blue_score = consensus.get_header(chain_block_hash)?.blue_score;
+            // The UTXOs spent by the mergeset of the chain block, including those created before the index window
+            let utxo_diff = consensus.get_block_utxo_diff(chain_block_hash).ok();
+
+            // Record the acceptance of every transaction first, so that inputs spending a transaction accepted by
+            // the same chain block can be resolved below
+            let mut acceptances = Vec::new();
+            for mergeset_block in acceptance_data.iter() {
+                for entry in mergeset_block.accepted_transactions.iter() {
+                    acceptances.push((
+                        entry.transaction_id,
+                        TxAcceptance {
+                            accepting_block_hash: chain_block_hash,
+                            accepting_block_blue_score: blue_score,
+                            including_block_hash: mergeset_block.block_hash,
+                            index_within_block: entry.index_within_block,
+                        },
+                    ));
+                }
+            }
+            self.store.insert_transaction_acceptances(acceptances.clone())?;
+
+            let mut transactions = Vec::with_capacity(acceptances.len());
+            for (transaction_id, acceptance) in acceptances {
+                let Some(transaction) = self.get_transaction(
+                    consensus,
+                    &mut block_transactions,
+                    acceptance.including_block_hash,
+                    acceptance.index_within_block,
+                ) else {
+                    continue;
+                };
+                let mut script_public_keys: Vec<ScriptPublicKey> = Vec::new();
+                for input in transaction.inputs.iter() {
+                    if let Some(script_public_key) = self.get_spent_script_public_key(
+                        consensus,
+                        &mut block_transactions,
+                        utxo_diff.as_deref(),
+                        input.previous_outpoint,
+                    ) {
+                        if !script_public_keys.contains(&script_public_key) {
+                            script_public_keys.push(script_public_key);
+                        }
+                    }
+                }
+                for output in transaction.outputs.iter() {
+                    if !script_public_keys.contains(&output.script_public_key) {
+                        script_public_keys.push(output.script_public_key.clone());
+                    }
+                }
+                transactions.push((transaction_id, script_public_keys));
+            }
+
+            self.store.add_chain_block(chain_block_hash, ChainBlockTransactions { blue_score, transactions })?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the transaction at `index_within_block` in the body of `block_hash`, if the body is still available.
+    fn get_transaction(
+        &self,
+        consensus: &dyn ConsensusApi,
+        block_transactions: &mut HashMap<Hash, Option<Arc<Vec<Transaction>>>>,
+        block_hash: Hash,
+        index_within_block: u32,
+    ) -> Option<Transaction> {
+        block_transactions
+            .entry(block_hash)
+            .or_insert_with(|| consensus.get_block(block_hash).ok().map(|block| block.transactions))
+            .as_ref()
+            .and_then(|transactions| transactions.get(index_within_block as usize).cloned())
+    }
+
+    /// Resolves the script public key of a previous output, first through the UTXO diff of the accepting chain block,
+    /// which covers every output spent from the UTXO set, then through the index itself, which covers the outputs
+    /// created and spent within the same mergeset (these cancel out in the diff).
+    fn get_spent_script_public_key(
+        &self,
+        consensus: &dyn ConsensusApi,
+        block_transactions: &mut HashMap<Hash, Option<Arc<Vec<Transaction>>>>,
+        utxo_diff: Option<&UtxoDiff>,
+        outpoint: TransactionOutpoint,
+    ) -> Option<ScriptPublicKey> {
+        if let Some(entry) = utxo_diff.and_then(|utxo_diff| utxo_diff.remove.get(&outpoint)) {
+            return Some(entry.script_public_key.clone());
+        }
+        let acceptance = self.store.get_transaction_acceptance(outpoint.transaction_id).ok()?;
+        let transaction =
+            self.get_transaction(consensus, block_transactions, acceptance.including_block_hash, acceptance.index_within_block)?;
+        transaction.outputs.get(outpoint.index as usize).map(|output| output.script_public_key.clone())
+    }
+}
+
+impl TxIndexApi for TxIndex {
+    /// Retrieve the acceptance of a transaction from the txindex db.
+    fn get_transaction_acceptance(&self, transaction_id: TransactionId) -> StoreResult<TxAcceptance> {
+        trace!("[{0}] retrieving acceptance of transaction {1}", IDENT, transaction_id);
+
+        self.store.get_transaction_acceptance(transaction_id)
+    }
+
+    /// Retrieve the transactions involving a script public key from the txindex db.
+    fn get_transactions_by_script_public_key(
+        &self,
+        script_public_key: ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>> {
+        trace!("[{0}] retrieving up to {1} transactions from blue score {2}", IDENT, limit, start_blue_score);
+
+        self.store.get_transactions_by_script_public_key(&script_public_key, start_blue_score, start_transaction_id, limit)
+    }
+
+    /// Retrieve the stored sink of the txindex.
+    fn get_sink(&self) -> StoreResult<Hash> {
+        trace!("[{0}] retrieving sink", IDENT);
+
+        self.store.get_sink()
+    }
+
+    /// Checks to see if the [TxIndex] is sync'd. This is done via comparing the txindex committed sink with the sink of the consensus database.
+    ///
+    /// **Note:** Due to sync gaps between the txindex and consensus, this function is only reliable while consensus is not processing new blocks.
+    fn is_synced(&self) -> TxIndexResult<bool> {
+        trace!("[{0}] checking sync status...", IDENT);
+
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+
+        match self.store.get_sink() {
+            Ok(txindex_sink) => {
+                let res = txindex_sink == session.get_sink();
+                trace!("[{0}] sync status is {1}", IDENT, res);
+                Ok(res)
+            }
+            Err(StoreError::KeyNotFound(_)) => {
+                // Means txindex sink database is empty i.e. not sync'd.
+                trace!("[{0}] sync status is {1}", IDENT, false);
+                Ok(false)
+            }
+            Err(other_store_errors) => Err(TxIndexError::StoreAccessError(other_store_errors)),
+        }
+    }
+
+    /// Updates the [TxIndex] via the virtual selected chain change supplied:
+    /// 1) Reverts the transactions accepted by the removed chain blocks.
+    /// 2) Indexes the transactions accepted by the added chain blocks and commits the new sink.
+    /// 3) Prunes the transactions accepted below the pruning point.
+    fn update(
+        &mut self,
+        added_chain_block_hashes: Arc<Vec<Hash>>,
+        removed_chain_block_hashes: Arc<Vec<Hash>>,
+        added_chain_blocks_acceptance_data: Arc<Vec<Arc<AcceptanceData>>>,
+    ) -> TxIndexResult<()> {
+        trace!("[{0}] updating...", IDENT);
+        trace!("[{0}] adding {1} chain blocks", IDENT, added_chain_block_hashes.len());
+        trace!("[{0}] removing {1} chain blocks", IDENT, removed_chain_block_hashes.len());
+
+        for chain_block_hash in removed_chain_block_hashes.iter().copied() {
+            self.store.remove_chain_block(chain_block_hash)?;
+        }
+
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+        self.add_chain_blocks(&*session, &added_chain_block_hashes, &added_chain_blocks_acceptance_data)?;
+
+        if let Some(sink) = added_chain_block_hashes.last() {
+            self.store.set_sink(*sink)?;
+        }
+
+        let pruning_point_blue_score = session.get_header(session.pruning_point())?.blue_score;
+        self.store.prune_below(pruning_point_blue_score)?;
+
+        Ok(())
+    }
+
+    /// Deletes and reinstates the txindex database, syncing it from scratch via the virtual selected chain above the pruning point.
+    ///
+    /// **Note:** resyncing while consensus notifies of virtual chain changes, may result in a corrupted db.
+    fn resync(&mut self) -> TxIndexResult<()> {
+        info!("Resyncing the txindex...");
+
+        self.store.delete_all()?;
+        let consensus = self.consensus_manager.consensus();
+        let session = futures::executor::block_on(consensus.session_blocking());
+
+        let sink = session.get_sink();
+        let chain_path = session.get_virtual_chain_from_block(session.pruning_point())?;
+        for chain_block_hashes in chain_path.added.chunks(RESYNC_CHUNK_SIZE) {
+            trace!("[{0}] resyncing with batch of {1} chain blocks from consensus db", IDENT, chain_block_hashes.len());
+            let acceptance_data = session.get_blocks_acceptance_data(chain_block_hashes)?;
+            self.add_chain_blocks(&*session, chain_block_hashes, &acceptance_data)?;
+        }
+
+        trace!("[{0}] committing sink {sink} from consensus db", IDENT);
+        self.store.set_sink(sink)?;
+
+        Ok(())
+    }
+}
+
+impl Debug for TxIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TxIndex").finish()
+    }
+}
+
+struct TxIndexConsensusResetHandler {
+    txindex: Weak<RwLock<TxIndex>>,
+}
+
+impl TxIndexConsensusResetHandler {
+    fn new(txindex: Weak<RwLock<TxIndex>>) -> Self {
+        Self { txindex }
+    }
+}
+
+impl ConsensusResetHandler for TxIndexConsensusResetHandler {
+    fn handle_consensus_reset(&self) {
+        if let Some(txindex) = self.txindex.upgrade() {
+            txindex.write().resync().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{api::TxIndexApi, TxIndex};
+    use kash_consensus::{config::ConfigBuilder, consensus::test_consensus::TestConsensus, params::DEVNET_PARAMS};
+    use kash_consensus_core::{api::ConsensusApi, tx::ScriptPublicKey};
+    use kash_consensusmanager::ConsensusManager;
+    use kash_database::create_temp_db;
+    use kash_database::prelude::{ConnBuilder, StoreError};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_txindex() {
+        kash_core::log::try_init_logger("INFO");
+
+        let config = ConfigBuilder::new(DEVNET_PARAMS).skip_proof_of_work().build();
+        let tc = Arc::new(TestConsensus::new(&config));
+        let wait_handles = tc.init();
+
+        let chain_length = 5u64;
+        futures::executor::block_on(tc.add_utxo_valid_block_with_parents(1.into(), vec![config.genesis.hash], vec![])).unwrap();
+        for i in 2..=chain_length {
+            futures::executor::block_on(tc.add_utxo_valid_block_with_parents(i.into(), vec![(i - 1).into()], vec![])).unwrap();
+        }
+
+        // Sync txindex from scratch.
+        let (_txindex_db_lifetime, txindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let consensus_manager = Arc::new(ConsensusManager::from_consensus(tc.consensus_clone()));
+        let txindex = TxIndex::new(consensus_manager, txindex_db).unwrap();
+        assert!(txindex.read().is_synced().expect("expected bool"));
+        assert_eq!(txindex.read().get_sink().expect("expected sink"), tc.get_sink());
+
+        // Every transaction accepted by the chain must be indexed along with its accepting chain block.
+        let chain_path = tc.get_virtual_chain_from_block(config.genesis.hash).unwrap();
+        let acceptance_data = tc.get_blocks_acceptance_data(&chain_path.added).unwrap();
+        let mut accepted_transaction_ids = Vec::new();
+        for (chain_block_hash, acceptance_data) in chain_path.added.iter().copied().zip(acceptance_data.iter()) {
+            let blue_score = tc.get_header(chain_block_hash).unwrap().blue_score;
+            for mergeset_block in acceptance_data.iter() {
+                for entry in mergeset_block.accepted_transactions.iter() {
+                    let acceptance =
+                        txindex.read().get_transaction_acceptance(entry.transaction_id).expect("expected indexed transaction");
+                    assert_eq!(acceptance.accepting_block_hash, chain_block_hash);
+                    assert_eq!(acceptance.accepting_block_blue_score, blue_score);
+                    assert_eq!(acceptance.including_block_hash, mergeset_block.block_hash);
+                    assert_eq!(acceptance.index_within_block, entry.index_within_block);
+                    accepted_transaction_ids.push((chain_block_hash, entry.transaction_id));
+                }
+            }
+        }
+        assert!(!accepted_transaction_ids.is_empty());
+
+        // Address transactions are ordered by the blue score of their accepting chain block.
+        let miner_script_public_key = ScriptPublicKey::from_vec(0, vec![]);
+        let address_transactions =
+            txindex.read().get_transactions_by_script_public_key(miner_script_public_key.clone(), 0, None, usize::MAX).unwrap();
+        assert!(address_transactions.len() > 1);
+        assert!(address_transactions.windows(2).all(|w| w[0].accepting_block_blue_score <= w[1].accepting_block_blue_score));
+        assert!(txindex
+            .read()
+            .get_transactions_by_script_public_key(miner_script_public_key.clone(), u64::MAX, None, usize::MAX)
+            .unwrap()
+            .is_empty());
+
+        // Paging with the (blue score, transaction id) cursor of the last entry returns every entry exactly once.
+        let mut paged_transactions = Vec::new();
+        let (mut start_blue_score, mut start_transaction_id) = (0, None);
+        loop {
+            let page = txindex
+                .read()
+                .get_transactions_by_script_public_key(miner_script_public_key.clone(), start_blue_score, start_transaction_id, 1)
+                .unwrap();
+            let Some(last) = page.last() else {
+                break;
+            };
+            (start_blue_score, start_transaction_id) = (last.accepting_block_blue_score, Some(last.transaction_id));
+            paged_transactions.extend(page);
+        }
+        assert_eq!(paged_transactions, address_transactions);
+
+        // Reverting the sink from the chain drops the transactions it accepted.
+        let sink = tc.get_sink();
+        txindex.write().update(Arc::new(vec![]), Arc::new(vec![sink]), Arc::new(vec![])).expect("expected update");
+        for (chain_block_hash, transaction_id) in accepted_transaction_ids.iter().copied() {
+            let res = txindex.read().get_transaction_acceptance(transaction_id);
+            if chain_block_hash == sink {
+                assert!(matches!(res, Err(StoreError::KeyNotFound(_))));
+            } else {
+                assert!(res.is_ok());
+            }
+        }
+
+        // Resync restores the index from consensus.
+        txindex.write().resync().expect("expected resync");
+        for (_, transaction_id) in accepted_transaction_ids.iter().copied() {
+            assert!(txindex.read().get_transaction_acceptance(transaction_id).is_ok());
+        }
+
+        // Pruning drops the transactions accepted below the given blue score only.
+        let prune_blue_score = address_transactions.last().unwrap().accepting_block_blue_score;
+        txindex.write().store.prune_below(prune_blue_score).expect("expected pruning");
+        for address_transaction in address_transactions.iter() {
+            let res = txindex.read().get_transaction_acceptance(address_transaction.transaction_id);
+            if address_transaction.accepting_block_blue_score < prune_blue_score {
+                assert!(matches!(res, Err(StoreError::KeyNotFound(_))));
+            } else {
+                assert!(res.is_ok());
+            }
+        }
+        assert!(txindex
+            .read()
+            .get_transactions_by_script_public_key(miner_script_public_key, 0, None, usize::MAX)
+            .unwrap()
+            .iter()
+            .all(|x| x.accepting_block_blue_score >= prune_blue_score));
+
+        drop(txindex);
+        tc.shutdown(wait_handles);
+    }
+}
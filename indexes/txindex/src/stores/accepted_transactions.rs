@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use kash_consensus_core::tx::TransactionId;
+use kash_database::{
+    prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+
+use crate::model::TxAcceptance;
+
+/// Reader API for `AcceptedTransactionsStore`.
+pub trait AcceptedTransactionsStoreReader {
+    fn get(&self, transaction_id: TransactionId) -> StoreResult<TxAcceptance>;
+}
+
+pub trait AcceptedTransactionsStore: AcceptedTransactionsStoreReader {
+    fn insert(&mut self, transaction_id: TransactionId, acceptance: TxAcceptance) -> StoreResult<()>;
+    fn delete(&mut self, transaction_id: TransactionId) -> StoreResult<()>;
+    fn delete_all(&mut self) -> StoreResult<()>;
+}
+
+/// A DB + cache implementation of `AcceptedTransactionsStore` trait, keyed by transaction id
+#[derive(Clone)]
+pub struct DbAcceptedTransactionsStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<TransactionId, TxAcceptance>,
+}
+
+impl DbAcceptedTransactionsStore {
+    pub fn new(db: Arc<DB>, cache_policy: CachePolicy) -> Self {
+        Self {
+            db: Arc::clone(&db),
+            access: CachedDbAccess::new(db, cache_policy, DatabaseStorePrefixes::TxIndexAcceptedTransactions.into()),
+        }
+    }
+}
+
+impl AcceptedTransactionsStoreReader for DbAcceptedTransactionsStore {
+    fn get(&self, transaction_id: TransactionId) -> StoreResult<TxAcceptance> {
+        self.access.read(transaction_id)
+    }
+}
+
+impl AcceptedTransactionsStore for DbAcceptedTransactionsStore {
+    fn insert(&mut self, transaction_id: TransactionId, acceptance: TxAcceptance) -> StoreResult<()> {
+        self.access.write(DirectDbWriter::new(&self.db), transaction_id, acceptance)
+    }
+
+    fn delete(&mut self, transaction_id: TransactionId) -> StoreResult<()> {
+        self.access.delete(DirectDbWriter::new(&self.db), transaction_id)
+    }
+
+    fn delete_all(&mut self) -> StoreResult<()> {
+        self.access.delete_all(DirectDbWriter::new(&self.db))
+    }
+}
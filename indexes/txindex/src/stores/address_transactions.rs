@@ -0,0 +1,145 @@
+use std::mem::size_of;
+use std::sync::Arc;
+
+use kash_consensus_core::tx::{ScriptPublicKey, ScriptPublicKeyVersion, TransactionId};
+use kash_database::{
+    prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use kash_hashes::{Hash, HASH_SIZE};
+
+use crate::model::AddressTransaction;
+
+pub const VERSION_TYPE_SIZE: usize = size_of::<ScriptPublicKeyVersion>();
+
+/// [`ScriptPublicKeyBucket`].
+/// Consists of 2 bytes of little endian [ScriptPublicKeyVersion] bytes, followed by 8 bytes of little endian script length and the script itself.
+#[derive(Eq, Hash, PartialEq, Debug, Clone)]
+struct ScriptPublicKeyBucket(Vec<u8>);
+
+impl From<&ScriptPublicKey> for ScriptPublicKeyBucket {
+    fn from(script_public_key: &ScriptPublicKey) -> Self {
+        let mut bytes: Vec<u8> = Vec::with_capacity(VERSION_TYPE_SIZE + size_of::<u64>() + script_public_key.script().len());
+        bytes.extend_from_slice(&script_public_key.version().to_le_bytes());
+        bytes.extend_from_slice(&(script_public_key.script().len() as u64).to_le_bytes());
+        bytes.extend_from_slice(script_public_key.script());
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for ScriptPublicKeyBucket {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Size of the part of an [`AddressTransactionKey`] following its [`ScriptPublicKeyBucket`].
+pub const BLUE_SCORE_TRANSACTION_ID_SIZE: usize = size_of::<u64>() + HASH_SIZE;
+
+/// Key of an address transaction entry.
+/// Consists of a [`ScriptPublicKeyBucket`], followed by 8 bytes of big endian accepting block blue score and 32 bytes of [TransactionId].
+///
+/// The big endian blue score keeps the entries of a bucket ordered by acceptance.
+#[derive(Eq, Hash, PartialEq, Debug, Clone)]
+struct AddressTransactionKey(Vec<u8>);
+
+impl AddressTransactionKey {
+    fn new(bucket: &ScriptPublicKeyBucket, blue_score: u64, transaction_id: Option<TransactionId>) -> Self {
+        let mut bytes = Vec::with_capacity(bucket.0.len() + BLUE_SCORE_TRANSACTION_ID_SIZE);
+        bytes.extend_from_slice(bucket.as_ref());
+        bytes.extend_from_slice(&blue_score.to_be_bytes());
+        if let Some(transaction_id) = transaction_id {
+            bytes.extend_from_slice(&transaction_id.as_bytes());
+        }
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for AddressTransactionKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/// Reader API for `AddressTransactionsStore`.
+pub trait AddressTransactionsStoreReader {
+    /// Returns up to `limit` entries of `script_public_key` starting at `start_blue_score`. If `start_transaction_id` is
+    /// set, the entries up to and including the one of `start_transaction_id` at `start_blue_score` are skipped.
+    fn get_transactions(
+        &self,
+        script_public_key: &ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>>;
+}
+
+pub trait AddressTransactionsStore: AddressTransactionsStoreReader {
+    fn insert(&mut self, script_public_key: &ScriptPublicKey, address_transaction: AddressTransaction) -> StoreResult<()>;
+    fn delete(&mut self, script_public_key: &ScriptPublicKey, blue_score: u64, transaction_id: TransactionId) -> StoreResult<()>;
+    fn delete_all(&mut self) -> StoreResult<()>;
+}
+
+/// A DB implementation of `AddressTransactionsStore` trait, with script public keys as buckets.
+#[derive(Clone)]
+pub struct DbAddressTransactionsStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<AddressTransactionKey, Hash>,
+}
+
+impl DbAddressTransactionsStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+            access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::TxIndexAddressTransactions.into()),
+        }
+    }
+}
+
+impl AddressTransactionsStoreReader for DbAddressTransactionsStore {
+    fn get_transactions(
+        &self,
+        script_public_key: &ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>> {
+        let bucket = ScriptPublicKeyBucket::from(script_public_key);
+        let seek_from = AddressTransactionKey::new(&bucket, start_blue_score, start_transaction_id);
+        // The cursor entry itself is skipped only if it is still indexed, since it might have been reverted meanwhile
+        self.access
+            .seek_iterator(Some(bucket.as_ref()), Some(seek_from), limit.saturating_add(1), false)
+            .map(|res| {
+                let (key, accepting_block_hash) = res.map_err(|err| StoreError::DataInconsistency(err.to_string()))?;
+                let accepting_block_blue_score = u64::from_be_bytes(key[..size_of::<u64>()].try_into().unwrap());
+                let transaction_id = TransactionId::from_slice(&key[size_of::<u64>()..BLUE_SCORE_TRANSACTION_ID_SIZE]);
+                Ok(AddressTransaction { transaction_id, accepting_block_hash, accepting_block_blue_score })
+            })
+            .filter(|res| {
+                !matches!(res, Ok(entry) if entry.accepting_block_blue_score == start_blue_score
+                    && Some(entry.transaction_id) == start_transaction_id)
+            })
+            .take(limit)
+            .collect()
+    }
+}
+
+impl AddressTransactionsStore for DbAddressTransactionsStore {
+    fn insert(&mut self, script_public_key: &ScriptPublicKey, address_transaction: AddressTransaction) -> StoreResult<()> {
+        let key = AddressTransactionKey::new(
+            &ScriptPublicKeyBucket::from(script_public_key),
+            address_transaction.accepting_block_blue_score,
+            Some(address_transaction.transaction_id),
+        );
+        self.access.write(DirectDbWriter::new(&self.db), key, address_transaction.accepting_block_hash)
+    }
+
+    fn delete(&mut self, script_public_key: &ScriptPublicKey, blue_score: u64, transaction_id: TransactionId) -> StoreResult<()> {
+        let key = AddressTransactionKey::new(&ScriptPublicKeyBucket::from(script_public_key), blue_score, Some(transaction_id));
+        self.access.delete(DirectDbWriter::new(&self.db), key)
+    }
+
+    fn delete_all(&mut self) -> StoreResult<()> {
+        self.access.delete_all(DirectDbWriter::new(&self.db))
+    }
+}
@@ -0,0 +1,88 @@
+use std::mem::size_of;
+use std::sync::Arc;
+
+use kash_database::{
+    prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use kash_hashes::{Hash, HASH_SIZE};
+
+/// Key of a chain block entry.
+/// Consists of 8 bytes of big endian blue score followed by the 32 bytes of the chain block hash.
+///
+/// The big endian blue score keeps the entries ordered along the virtual selected chain.
+#[derive(Eq, Hash, PartialEq, Debug, Clone)]
+struct ChainBlockScoreKey([u8; size_of::<u64>() + HASH_SIZE]);
+
+impl ChainBlockScoreKey {
+    fn new(blue_score: u64, chain_block_hash: Hash) -> Self {
+        let mut bytes = [0u8; size_of::<u64>() + HASH_SIZE];
+        bytes[..size_of::<u64>()].copy_from_slice(&blue_score.to_be_bytes());
+        bytes[size_of::<u64>()..].copy_from_slice(&chain_block_hash.as_bytes());
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for ChainBlockScoreKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Reader API for `ChainBlockScoresStore`.
+pub trait ChainBlockScoresStoreReader {
+    /// Returns up to `limit` indexed chain blocks with a blue score lower than `blue_score`, along with their blue score,
+    /// in ascending blue score order.
+    fn get_below(&self, blue_score: u64, limit: usize) -> StoreResult<Vec<(u64, Hash)>>;
+}
+
+pub trait ChainBlockScoresStore: ChainBlockScoresStoreReader {
+    fn insert(&mut self, blue_score: u64, chain_block_hash: Hash) -> StoreResult<()>;
+    fn delete(&mut self, blue_score: u64, chain_block_hash: Hash) -> StoreResult<()>;
+    fn delete_all(&mut self) -> StoreResult<()>;
+}
+
+/// A DB implementation of `ChainBlockScoresStore` trait, ordering the indexed chain blocks by blue score
+#[derive(Clone)]
+pub struct DbChainBlockScoresStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<ChainBlockScoreKey, Hash>,
+}
+
+impl DbChainBlockScoresStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+            access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::TxIndexChainBlockScores.into()),
+        }
+    }
+}
+
+impl ChainBlockScoresStoreReader for DbChainBlockScoresStore {
+    fn get_below(&self, blue_score: u64, limit: usize) -> StoreResult<Vec<(u64, Hash)>> {
+        let mut chain_blocks = Vec::new();
+        for res in self.access.seek_iterator(None, None, limit, false) {
+            let (key, chain_block_hash) = res.map_err(|err| StoreError::DataInconsistency(err.to_string()))?;
+            let chain_block_blue_score = u64::from_be_bytes(key[..size_of::<u64>()].try_into().unwrap());
+            if chain_block_blue_score >= blue_score {
+                break;
+            }
+            chain_blocks.push((chain_block_blue_score, chain_block_hash));
+        }
+        Ok(chain_blocks)
+    }
+}
+
+impl ChainBlockScoresStore for DbChainBlockScoresStore {
+    fn insert(&mut self, blue_score: u64, chain_block_hash: Hash) -> StoreResult<()> {
+        self.access.write(DirectDbWriter::new(&self.db), ChainBlockScoreKey::new(blue_score, chain_block_hash), chain_block_hash)
+    }
+
+    fn delete(&mut self, blue_score: u64, chain_block_hash: Hash) -> StoreResult<()> {
+        self.access.delete(DirectDbWriter::new(&self.db), ChainBlockScoreKey::new(blue_score, chain_block_hash))
+    }
+
+    fn delete_all(&mut self) -> StoreResult<()> {
+        self.access.delete_all(DirectDbWriter::new(&self.db))
+    }
+}
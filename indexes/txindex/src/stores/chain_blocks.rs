@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use kash_database::{
+    prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use kash_hashes::Hash;
+
+use crate::model::ChainBlockTransactions;
+
+/// Reader API for `ChainBlocksStore`.
+pub trait ChainBlocksStoreReader {
+    fn get(&self, chain_block_hash: Hash) -> StoreResult<ChainBlockTransactions>;
+}
+
+pub trait ChainBlocksStore: ChainBlocksStoreReader {
+    fn insert(&mut self, chain_block_hash: Hash, transactions: ChainBlockTransactions) -> StoreResult<()>;
+    fn delete(&mut self, chain_block_hash: Hash) -> StoreResult<()>;
+    fn delete_all(&mut self) -> StoreResult<()>;
+}
+
+/// A DB implementation of `ChainBlocksStore` trait, keyed by the hash of the accepting chain block
+#[derive(Clone)]
+pub struct DbChainBlocksStore {
+    db: Arc<DB>,
+    access: CachedDbAccess<Hash, ChainBlockTransactions>,
+}
+
+impl DbChainBlocksStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            db: Arc::clone(&db),
+            access: CachedDbAccess::new(db, CachePolicy::Empty, DatabaseStorePrefixes::TxIndexChainBlocks.into()),
+        }
+    }
+}
+
+impl ChainBlocksStoreReader for DbChainBlocksStore {
+    fn get(&self, chain_block_hash: Hash) -> StoreResult<ChainBlockTransactions> {
+        self.access.read(chain_block_hash)
+    }
+}
+
+impl ChainBlocksStore for DbChainBlocksStore {
+    fn insert(&mut self, chain_block_hash: Hash, transactions: ChainBlockTransactions) -> StoreResult<()> {
+        self.access.write(DirectDbWriter::new(&self.db), chain_block_hash, transactions)
+    }
+
+    fn delete(&mut self, chain_block_hash: Hash) -> StoreResult<()> {
+        self.access.delete(DirectDbWriter::new(&self.db), chain_block_hash)
+    }
+
+    fn delete_all(&mut self) -> StoreResult<()> {
+        self.access.delete_all(DirectDbWriter::new(&self.db))
+    }
+}
@@ -0,0 +1,6 @@
+mod accepted_transactions;
+mod address_transactions;
+mod chain_block_scores;
+mod chain_blocks;
+mod sink;
+pub mod store_manager;
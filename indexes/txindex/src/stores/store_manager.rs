@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use kash_consensus_core::tx::{ScriptPublicKey, TransactionId};
+use kash_core::trace;
+use kash_database::prelude::{CachePolicy, StoreError, StoreResult, DB};
+use kash_hashes::Hash;
+
+use crate::{
+    model::{AddressTransaction, ChainBlockTransactions, TxAcceptance},
+    stores::{
+        accepted_transactions::{AcceptedTransactionsStore, AcceptedTransactionsStoreReader, DbAcceptedTransactionsStore},
+        address_transactions::{AddressTransactionsStore, AddressTransactionsStoreReader, DbAddressTransactionsStore},
+        chain_block_scores::{ChainBlockScoresStore, ChainBlockScoresStoreReader, DbChainBlockScoresStore},
+        chain_blocks::{ChainBlocksStore, ChainBlocksStoreReader, DbChainBlocksStore},
+        sink::{DbTxIndexSinkStore, TxIndexSinkStore, TxIndexSinkStoreReader},
+    },
+    IDENT,
+};
+
+const ACCEPTED_TRANSACTIONS_CACHE_SIZE: usize = 10_000;
+const PRUNING_CHUNK_SIZE: usize = 256;
+
+#[derive(Clone)]
+pub struct Store {
+    sink_store: DbTxIndexSinkStore,
+    accepted_transactions_store: DbAcceptedTransactionsStore,
+    chain_blocks_store: DbChainBlocksStore,
+    chain_block_scores_store: DbChainBlockScoresStore,
+    address_transactions_store: DbAddressTransactionsStore,
+}
+
+impl Store {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self {
+            sink_store: DbTxIndexSinkStore::new(db.clone()),
+            accepted_transactions_store: DbAcceptedTransactionsStore::new(
+                db.clone(),
+                CachePolicy::Count(ACCEPTED_TRANSACTIONS_CACHE_SIZE),
+            ),
+            chain_blocks_store: DbChainBlocksStore::new(db.clone()),
+            chain_block_scores_store: DbChainBlockScoresStore::new(db.clone()),
+            address_transactions_store: DbAddressTransactionsStore::new(db),
+        }
+    }
+
+    pub fn get_transaction_acceptance(&self, transaction_id: TransactionId) -> StoreResult<TxAcceptance> {
+        self.accepted_transactions_store.get(transaction_id)
+    }
+
+    pub fn get_transactions_by_script_public_key(
+        &self,
+        script_public_key: &ScriptPublicKey,
+        start_blue_score: u64,
+        start_transaction_id: Option<TransactionId>,
+        limit: usize,
+    ) -> StoreResult<Vec<AddressTransaction>> {
+        self.address_transactions_store.get_transactions(script_public_key, start_blue_score, start_transaction_id, limit)
+    }
+
+    pub fn insert_transaction_acceptances(&mut self, acceptances: Vec<(TransactionId, TxAcceptance)>) -> StoreResult<()> {
+        for (transaction_id, acceptance) in acceptances {
+            self.accepted_transactions_store.insert(transaction_id, acceptance)?;
+        }
+        Ok(())
+    }
+
+    /// Indexes the script public keys involved in the transactions accepted by a chain block.
+    pub fn add_chain_block(&mut self, chain_block_hash: Hash, chain_block_transactions: ChainBlockTransactions) -> StoreResult<()> {
+        for (transaction_id, script_public_keys) in chain_block_transactions.transactions.iter() {
+            let address_transaction = AddressTransaction {
+                transaction_id: *transaction_id,
+                accepting_block_hash: chain_block_hash,
+                accepting_block_blue_score: chain_block_transactions.blue_score,
+            };
+            for script_public_key in script_public_keys.iter() {
+                self.address_transactions_store.insert(script_public_key, address_transaction)?;
+            }
+        }
+        self.chain_block_scores_store.insert(chain_block_transactions.blue_score, chain_block_hash)?;
+        self.chain_blocks_store.insert(chain_block_hash, chain_block_transactions)
+    }
+
+    /// Removes the transactions accepted by a chain block which left the virtual selected chain.
+    ///
+    /// A transaction accepted by some other chain block in the meantime is left untouched.
+    pub fn remove_chain_block(&mut self, chain_block_hash: Hash) -> StoreResult<()> {
+        let chain_block_transactions = match self.chain_blocks_store.get(chain_block_hash) {
+            Ok(chain_block_transactions) => chain_block_transactions,
+            Err(StoreError::KeyNotFound(_)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        for (transaction_id, script_public_keys) in chain_block_transactions.transactions.iter() {
+            match self.accepted_transactions_store.get(*transaction_id) {
+                Ok(acceptance) if acceptance.accepting_block_hash == chain_block_hash => {
+                    self.accepted_transactions_store.delete(*transaction_id)?
+                }
+                Ok(_) | Err(StoreError::KeyNotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+            for script_public_key in script_public_keys.iter() {
+                self.address_transactions_store.delete(script_public_key, chain_block_transactions.blue_score, *transaction_id)?;
+            }
+        }
+        self.chain_block_scores_store.delete(chain_block_transactions.blue_score, chain_block_hash)?;
+        self.chain_blocks_store.delete(chain_block_hash)
+    }
+
+    /// Removes the transactions accepted by the chain blocks with a blue score lower than `blue_score`.
+    pub fn prune_below(&mut self, blue_score: u64) -> StoreResult<()> {
+        loop {
+            let chain_blocks = self.chain_block_scores_store.get_below(blue_score, PRUNING_CHUNK_SIZE)?;
+            if chain_blocks.is_empty() {
+                return Ok(());
+            }
+            trace!("[{0}] pruning {1} chain blocks below blue score {2}", IDENT, chain_blocks.len(), blue_score);
+            for (chain_block_blue_score, chain_block_hash) in chain_blocks {
+                self.remove_chain_block(chain_block_hash)?;
+                self.chain_block_scores_store.delete(chain_block_blue_score, chain_block_hash)?;
+            }
+        }
+    }
+
+    pub fn get_sink(&self) -> StoreResult<Hash> {
+        self.sink_store.get()
+    }
+
+    pub fn set_sink(&mut self, sink: Hash) -> StoreResult<()> {
+        self.sink_store.set(sink)
+    }
+
+    /// Resets the txindex database:
+    pub fn delete_all(&mut self) -> StoreResult<()> {
+        trace!("[{0}] attempting to clear txindex database...", IDENT);
+
+        // Clear all
+        self.sink_store.remove()?;
+        self.accepted_transactions_store.delete_all()?;
+        self.chain_blocks_store.delete_all()?;
+        self.chain_block_scores_store.delete_all()?;
+        self.address_transactions_store.delete_all()?;
+
+        trace!("[{0}] cleared txindex database", IDENT);
+
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use kash_index_core::indexed_utxos::BalanceByScriptPublicKey;
 use parking_lot::RwLock;
 use std::{collections::HashSet, fmt::Debug, sync::Arc};
 
-use crate::model::AssetCirculatingSupply;
+use crate::model::{AssetCirculatingSupply, CirculatingSupplySnapshot};
 use crate::{
     errors::UtxoIndexResult,
     model::{UtxoChanges, UtxoSetByScriptPublicKey},
@@ -23,6 +23,18 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     /// Note: Use a read lock when accessing this method
     fn get_circulating_supply(&self) -> StoreResult<AssetCirculatingSupply>;
 
+    /// Retrieve the circulating supply as of virtual DAA score `daa_score` from the utxoindex db, i.e.,
+    /// the most recent supply snapshot taken at a virtual DAA score lower or equal to `daa_score`.
+    ///
+    /// Note: Use a read lock when accessing this method
+    fn get_circulating_supply_at(&self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot>;
+
+    /// Audits the circulating supply, which is maintained incrementally from utxo diffs, against a full
+    /// recount of the indexed utxos. Returns the audited supply, or an error if the two do not match.
+    ///
+    /// Note: Use a read lock when accessing this method. The audit iterates over the whole indexed utxo set.
+    fn audit_circulating_supply(&self) -> UtxoIndexResult<AssetCirculatingSupply>;
+
     /// Retrieve utxos by script public keys supply from the utxoindex db.
     ///
     /// Note: Use a read lock when accessing this method
@@ -46,9 +58,16 @@ pub trait UtxoIndexApi: Send + Sync + Debug {
     fn is_synced(&self) -> UtxoIndexResult<bool>;
 
     /// Update the utxoindex with the given utxo_diff, and tips.
+    /// `reorg_daa_score` is the DAA score of the fork point if the virtual selected chain was reorged.
     ///
     /// Note: Use a write lock when accessing this method
-    fn update(&mut self, utxo_diff: Arc<UtxoDiff>, tips: Arc<Vec<Hash>>) -> UtxoIndexResult<UtxoChanges>;
+    fn update(
+        &mut self,
+        utxo_diff: Arc<UtxoDiff>,
+        tips: Arc<Vec<Hash>>,
+        virtual_daa_score: u64,
+        reorg_daa_score: Option<u64>,
+    ) -> UtxoIndexResult<UtxoChanges>;
 
     /// Resync the utxoindex from the consensus db
     ///
@@ -71,6 +90,14 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_circulating_supply()).await.unwrap()
     }
 
+    pub async fn get_circulating_supply_at(self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot> {
+        spawn_blocking(move || self.inner.read().get_circulating_supply_at(daa_score)).await.unwrap()
+    }
+
+    pub async fn audit_circulating_supply(self) -> UtxoIndexResult<AssetCirculatingSupply> {
+        spawn_blocking(move || self.inner.read().audit_circulating_supply()).await.unwrap()
+    }
+
     pub async fn get_utxos_by_script_public_keys(self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey> {
         spawn_blocking(move || self.inner.read().get_utxos_by_script_public_keys(script_public_keys)).await.unwrap()
     }
@@ -82,7 +109,13 @@ impl UtxoIndexProxy {
         spawn_blocking(move || self.inner.read().get_balance_by_script_public_keys(script_public_keys)).await.unwrap()
     }
 
-    pub async fn update(self, utxo_diff: Arc<UtxoDiff>, tips: Arc<Vec<Hash>>) -> UtxoIndexResult<UtxoChanges> {
-        spawn_blocking(move || self.inner.write().update(utxo_diff, tips)).await.unwrap()
+    pub async fn update(
+        self,
+        utxo_diff: Arc<UtxoDiff>,
+        tips: Arc<Vec<Hash>>,
+        virtual_daa_score: u64,
+        reorg_daa_score: Option<u64>,
+    ) -> UtxoIndexResult<UtxoChanges> {
+        spawn_blocking(move || self.inner.write().update(utxo_diff, tips, virtual_daa_score, reorg_daa_score)).await.unwrap()
     }
 }
@@ -1,7 +1,7 @@
 use std::io;
 use thiserror::Error;
 
-use crate::IDENT;
+use crate::{model::AssetCirculatingSupply, IDENT};
 use kash_database::prelude::StoreError;
 
 /// Errors originating from the [`UtxoIndex`].
@@ -12,6 +12,9 @@ pub enum UtxoIndexError {
 
     #[error("[{IDENT}]: {0}")]
     DBResetError(#[from] io::Error),
+
+    #[error("[{IDENT}]: indexed circulating supply {0:?} does not match the supply {1:?} recounted from the indexed utxos")]
+    CirculatingSupplyMismatch(AssetCirculatingSupply, AssetCirculatingSupply),
 }
 
 /// Results originating from the [`UtxoIndex`].
@@ -1,3 +1,4 @@
+use kash_consensus_core::{asset_type::AssetType, tx::UtxoEntry};
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
@@ -15,6 +16,27 @@ impl PartialEq for AssetCirculatingSupply {
     }
 }
 
+impl AssetCirculatingSupply {
+    /// Returns the circulating supply of `asset_type`.
+    pub fn get(&self, asset_type: AssetType) -> u64 {
+        match asset_type {
+            AssetType::KSH => self.ksh_supply,
+            AssetType::KUSD => self.kusd_supply,
+            AssetType::KRV => self.krv_supply,
+        }
+    }
+
+    /// Adds `amount` to the circulating supply of `asset_type`.
+    pub fn add_amount(&mut self, asset_type: AssetType, amount: u64) {
+        let supply = match asset_type {
+            AssetType::KSH => &mut self.ksh_supply,
+            AssetType::KUSD => &mut self.kusd_supply,
+            AssetType::KRV => &mut self.krv_supply,
+        };
+        *supply = supply.checked_add(amount).expect("Overflow in circulating supply");
+    }
+}
+
 impl AddAssign<AssetCirculatingSupplyDiffs> for AssetCirculatingSupply {
     fn add_assign(&mut self, other: AssetCirculatingSupplyDiffs) {
         // Adjusting ksh_supply considering positive and negative differences
@@ -86,11 +108,42 @@ impl SubAssign for AssetCirculatingSupplyDiffs {
 }
 
 impl AssetCirculatingSupplyDiffs {
+    /// Accounts for a UTXO entry added to the UTXO set.
+    pub fn add_utxo_entry(&mut self, utxo_entry: &UtxoEntry) {
+        *self.diff_mut(utxo_entry.asset_type) += utxo_entry.amount as i64;
+    }
+
+    /// Accounts for a UTXO entry removed from the UTXO set.
+    pub fn remove_utxo_entry(&mut self, utxo_entry: &UtxoEntry) {
+        *self.diff_mut(utxo_entry.asset_type) -= utxo_entry.amount as i64;
+    }
+
+    fn diff_mut(&mut self, asset_type: AssetType) -> &mut i64 {
+        match asset_type {
+            AssetType::KSH => &mut self.ksh_supply_diff,
+            AssetType::KUSD => &mut self.kusd_supply_diff,
+            AssetType::KRV => &mut self.krv_supply_diff,
+        }
+    }
+
     /// Returns true if the circulating supply difference is zero.
     pub fn is_unchanged(&self) -> bool {
         self.ksh_supply_diff == 0 && self.kusd_supply_diff == 0 && self.krv_supply_diff == 0
     }
 }
 
+/// The circulating supply of each asset type as of a given virtual DAA score.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct CirculatingSupplySnapshot {
+    pub daa_score: u64,
+    pub supply: AssetCirculatingSupply,
+}
+
+impl CirculatingSupplySnapshot {
+    pub fn new(daa_score: u64, supply: AssetCirculatingSupply) -> Self {
+        Self { daa_score, supply }
+    }
+}
+
 /// Type for circulating supply
 pub type CirculatingSupply = u64;
@@ -1,4 +1,4 @@
-use crate::model::AssetCirculatingSupply;
+use crate::model::{AssetCirculatingSupply, CirculatingSupplySnapshot};
 use crate::{
     api::UtxoIndexApi,
     errors::{UtxoIndexError, UtxoIndexResult},
@@ -21,6 +21,10 @@ use std::{
 };
 
 const RESYNC_CHUNK_SIZE: usize = 2048; //Increased from 1k (used in go-kashd), for quicker resets, while still having a low memory footprint.
+/// Circulating supply snapshots are retained for this many DAA scores below the virtual DAA score (~1 day at 10 BPS).
+const SUPPLY_SNAPSHOTS_RETENTION: u64 = 864_000;
+/// Expired circulating supply snapshots are pruned once every this many DAA scores, rather than on every update.
+const SUPPLY_SNAPSHOTS_PRUNING_INTERVAL: u64 = 36_000;
 
 /// UtxoIndex indexes [`CompactUtxoEntryCollections`] by [`ScriptPublicKey`], commits them to its owns store, and emits changes.
 /// Note: The UtxoIndex struct by itself is not thread save, only correct usage of the supplied RwLock via `new` makes it so.
@@ -28,12 +32,15 @@ const RESYNC_CHUNK_SIZE: usize = 2048; //Increased from 1k (used in go-kashd), f
 pub struct UtxoIndex {
     consensus_manager: Arc<ConsensusManager>,
     store: Store,
+    /// Virtual DAA score at which expired circulating supply snapshots were last pruned
+    snapshots_pruning_daa_score: u64,
 }
 
 impl UtxoIndex {
     /// Creates a new [`UtxoIndex`] within a [`RwLock`]
     pub fn new(consensus_manager: Arc<ConsensusManager>, db: Arc<DB>) -> UtxoIndexResult<Arc<RwLock<Self>>> {
-        let mut utxoindex = Self { consensus_manager: consensus_manager.clone(), store: Store::new(db) };
+        let mut utxoindex =
+            Self { consensus_manager: consensus_manager.clone(), store: Store::new(db), snapshots_pruning_daa_score: 0 };
         if !utxoindex.is_synced()? {
             utxoindex.resync()?;
         }
@@ -51,6 +58,27 @@ impl UtxoIndexApi for UtxoIndex {
         self.store.get_circulating_supply()
     }
 
+    /// Retrieve the circulating supply as of a given virtual DAA score from the utxoindex db.
+    fn get_circulating_supply_at(&self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot> {
+        trace!("[{0}] retrieving circulating supply at DAA score {1}", IDENT, daa_score);
+
+        self.store.get_circulating_supply_at(daa_score)
+    }
+
+    /// Audits the incrementally maintained circulating supply against a full recount of the indexed utxos.
+    fn audit_circulating_supply(&self) -> UtxoIndexResult<AssetCirculatingSupply> {
+        info!("[{0}] auditing the circulating supply...", IDENT);
+
+        let circulating_supply = self.store.get_circulating_supply()?;
+        let recounted_supply = self.store.recount_circulating_supply()?;
+        if circulating_supply != recounted_supply {
+            return Err(UtxoIndexError::CirculatingSupplyMismatch(circulating_supply, recounted_supply));
+        }
+
+        info!("[{0}] circulating supply audit passed: {1:?}", IDENT, circulating_supply);
+        Ok(circulating_supply)
+    }
+
     /// Retrieve utxos by script public keys from the utxoindex db.
     fn get_utxos_by_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey> {
         trace!("[{0}] retrieving utxos from {1} script public keys", IDENT, script_public_keys.len());
@@ -73,9 +101,15 @@ impl UtxoIndexApi for UtxoIndex {
     }
 
     /// Updates the [UtxoIndex] via the virtual state supplied:
-    /// 1) Saves updated utxo differences, virtual parent hashes and circulating supply to the database.
+    /// 1) Saves updated utxo differences, virtual parent hashes, circulating supply and a snapshot of it at the virtual DAA score to the database.
     /// 2) returns an event about utxoindex changes.
-    fn update(&mut self, utxo_diff: Arc<UtxoDiff>, tips: Arc<Vec<Hash>>) -> UtxoIndexResult<UtxoChanges> {
+    fn update(
+        &mut self,
+        utxo_diff: Arc<UtxoDiff>,
+        tips: Arc<Vec<Hash>>,
+        virtual_daa_score: u64,
+        reorg_daa_score: Option<u64>,
+    ) -> UtxoIndexResult<UtxoChanges> {
         trace!("[{0}] updating...", IDENT);
         trace!("[{0}] adding {1} utxos", IDENT, utxo_diff.add.len());
         trace!("[{0}] removing {1} utxos", IDENT, utxo_diff.remove.len());
@@ -88,7 +122,17 @@ impl UtxoIndexApi for UtxoIndex {
         // Commit changed utxo state to db
         self.store.update_utxo_state(&utxoindex_changes.utxo_changes.added, &utxoindex_changes.utxo_changes.removed, false)?;
 
-        let _circulating_supply = self.store.update_circulating_supply(utxoindex_changes.supply_change, false)?;
+        let circulating_supply = self.store.update_circulating_supply(utxoindex_changes.supply_change, false)?;
+        // Snapshots taken on a reorged chain are discarded. Note that the virtual DAA score does not necessarily decrease
+        // on a reorg, so snapshots are discarded from the fork point on
+        if let Some(reorg_daa_score) = reorg_daa_score {
+            self.store.delete_circulating_supply_snapshots_above(reorg_daa_score, false)?;
+        }
+        self.store.insert_circulating_supply_snapshot(virtual_daa_score, circulating_supply, false)?;
+        if virtual_daa_score >= self.snapshots_pruning_daa_score + SUPPLY_SNAPSHOTS_PRUNING_INTERVAL {
+            self.store.prune_circulating_supply_snapshots(virtual_daa_score.saturating_sub(SUPPLY_SNAPSHOTS_RETENTION))?;
+            self.snapshots_pruning_daa_score = virtual_daa_score;
+        }
 
         // Commit new consensus virtual tips.
         self.store.set_tips(utxoindex_changes.tips, false)?; //we expect new tips with every virtual!
@@ -129,14 +173,16 @@ impl UtxoIndexApi for UtxoIndex {
     /// **Notes:**
     /// 1) There is an implicit expectation that the consensus store must have [VirtualParent] tips. i.e. consensus database must be initiated.
     /// 2) resyncing while consensus notifies of utxo differences, may result in a corrupted db.
+    /// 3) the circulating supply history is kept, up to the current virtual DAA score.
     fn resync(&mut self) -> UtxoIndexResult<()> {
         info!("Resyncing the utxoindex...");
 
-        self.store.delete_all()?;
+        self.store.delete_utxo_state()?;
         let consensus = self.consensus_manager.consensus();
         let session = futures::executor::block_on(consensus.session_blocking());
 
         let consensus_tips = session.get_virtual_parents();
+        let virtual_daa_score = session.get_virtual_daa_score();
         let mut circulating_supply = AssetCirculatingSupply::default();
 
         //Initial batch is without specified seek and none-skipping.
@@ -164,6 +210,8 @@ impl UtxoIndexApi for UtxoIndex {
         }
 
         self.store.insert_circulating_supply(circulating_supply, true)?;
+        self.store.delete_circulating_supply_snapshots_above(virtual_daa_score, true)?;
+        self.store.insert_circulating_supply_snapshot(virtual_daa_score, circulating_supply, true)?;
 
         trace!("[{0}] committing consensus tips {consensus_tips:?} from consensus db", IDENT);
         self.store.set_tips(consensus_tips, true)?;
@@ -203,7 +251,7 @@ impl ConsensusResetHandler for UtxoIndexConsensusResetHandler {
 
 #[cfg(test)]
 mod tests {
-    use crate::model::AssetCirculatingSupply;
+    use crate::model::{AssetCirculatingSupply, CirculatingSupplySnapshot};
     use crate::{api::UtxoIndexApi, testutils::virtual_change_emulator::VirtualChangeEmulator, UtxoIndex};
     use kash_consensus::{
         config::Config,
@@ -273,11 +321,7 @@ mod tests {
         let mut consensus_supply: AssetCirculatingSupply = AssetCirculatingSupply::default();
         let consensus_utxo_set_size = consensus_utxos.len();
         for (tx_outpoint, utxo_entry) in consensus_utxos.into_iter() {
-            match utxo_entry.asset_type {
-                AssetType::KSH => consensus_supply.ksh_supply += utxo_entry.amount,
-                AssetType::KUSD => consensus_supply.kusd_supply += utxo_entry.amount,
-                AssetType::KRV => consensus_supply.kusd_supply += utxo_entry.amount,
-            }
+            consensus_supply.add_amount(utxo_entry.asset_type, utxo_entry.amount);
 
             let indexed_utxos = utxoindex
                 .read()
@@ -295,16 +339,28 @@ mod tests {
 
         assert_eq!(i, consensus_utxo_set_size);
         assert_eq!(utxoindex.read().get_circulating_supply().expect("expected circulating supply"), consensus_supply);
+        assert!([AssetType::KSH, AssetType::KUSD, AssetType::KRV].into_iter().all(|asset_type| consensus_supply.get(asset_type) > 0));
+        assert_eq!(
+            utxoindex.read().get_circulating_supply_at(u64::MAX).expect("expected circulating supply snapshot"),
+            CirculatingSupplySnapshot::new(0, consensus_supply)
+        );
+        assert_eq!(utxoindex.read().audit_circulating_supply().expect("expected a consistent circulating supply"), consensus_supply);
         assert_eq!(*utxoindex.read().get_utxo_index_tips().expect("expected circulating supply"), tc.get_virtual_parents());
 
         // Test update: Change and signal new virtual state.
         virtual_change_emulator.clear_virtual_state();
         virtual_change_emulator.change_virtual_state(update_utxo_collection_size, update_utxo_collection_size, 1);
 
+        let update_daa_score = 1_000;
         let now = Instant::now();
         let utxo_changes = utxoindex
             .write()
-            .update(virtual_change_emulator.accumulated_utxo_diff.clone(), virtual_change_emulator.virtual_parents)
+            .update(
+                virtual_change_emulator.accumulated_utxo_diff.clone(),
+                virtual_change_emulator.virtual_parents.clone(),
+                update_daa_score,
+                None,
+            )
             .expect("expected utxoindex utxo changes");
         let bench_time = now.elapsed().as_millis();
         // TODO: move over to proper benching eventually.
@@ -345,9 +401,60 @@ mod tests {
             utxoindex.read().get_circulating_supply().expect("expected circulating supply"),
             virtual_change_emulator.circulating_supply
         );
+        assert_eq!(
+            utxoindex.read().audit_circulating_supply().expect("expected a consistent circulating supply"),
+            virtual_change_emulator.circulating_supply
+        );
         assert_eq!(*utxoindex.read().get_utxo_index_tips().expect("expected circulating supply"), virtual_change_emulator.tips);
 
-        //test if resync clears db.
+        // Test historical supply: each DAA score maps to the most recent snapshot at or below it
+        let snapshot_at =
+            |daa_score| utxoindex.read().get_circulating_supply_at(daa_score).expect("expected circulating supply snapshot");
+        assert_eq!(
+            snapshot_at(u64::MAX),
+            CirculatingSupplySnapshot::new(update_daa_score, virtual_change_emulator.circulating_supply)
+        );
+        assert_eq!(
+            snapshot_at(update_daa_score),
+            CirculatingSupplySnapshot::new(update_daa_score, virtual_change_emulator.circulating_supply)
+        );
+        assert_eq!(snapshot_at(update_daa_score - 1), CirculatingSupplySnapshot::new(0, consensus_supply));
+
+        // Test that an update without a reorg keeps the previous snapshots, even at a lower virtual DAA score
+        let update = |virtual_daa_score, reorg_daa_score| {
+            utxoindex
+                .write()
+                .update(
+                    Arc::new(UtxoDiff::default()),
+                    virtual_change_emulator.virtual_parents.clone(),
+                    virtual_daa_score,
+                    reorg_daa_score,
+                )
+                .expect("expected utxoindex utxo changes");
+        };
+        update(update_daa_score - 1, None);
+        assert_eq!(
+            snapshot_at(u64::MAX),
+            CirculatingSupplySnapshot::new(update_daa_score, virtual_change_emulator.circulating_supply)
+        );
+
+        // Test that a virtual reorg discards the snapshots of the abandoned chain from the fork point on,
+        // although the virtual DAA score increased
+        let reorg_daa_score = update_daa_score + 10;
+        update(reorg_daa_score, Some(update_daa_score / 2));
+        assert_eq!(snapshot_at(u64::MAX), CirculatingSupplySnapshot::new(reorg_daa_score, virtual_change_emulator.circulating_supply));
+        assert_eq!(snapshot_at(reorg_daa_score - 1), CirculatingSupplySnapshot::new(0, consensus_supply));
+
+        // Test that expired snapshots are pruned, except for the one still covering the retention period
+        let pruning_daa_score = SUPPLY_SNAPSHOTS_RETENTION + SUPPLY_SNAPSHOTS_PRUNING_INTERVAL;
+        update(pruning_daa_score, None);
+        assert_eq!(
+            snapshot_at(SUPPLY_SNAPSHOTS_PRUNING_INTERVAL),
+            CirculatingSupplySnapshot::new(reorg_daa_score, virtual_change_emulator.circulating_supply)
+        );
+        assert!(utxoindex.read().get_circulating_supply_at(reorg_daa_score - 1).is_err());
+
+        //test if resync clears db, while keeping the supply history up to the consensus virtual DAA score.
 
         utxoindex.write().resync().expect("expected resync");
 
@@ -373,6 +480,8 @@ mod tests {
         }
         assert_eq!(i, consensus_utxo_set_size);
         assert_eq!(*utxoindex.read().get_utxo_index_tips().expect("expected circulating supply"), tc.get_virtual_parents());
+        assert_eq!(utxoindex.read().get_circulating_supply().expect("expected circulating supply"), consensus_supply);
+        assert_eq!(snapshot_at(u64::MAX), CirculatingSupplySnapshot::new(0, consensus_supply));
 
         // Deconstruct
         drop(utxoindex);
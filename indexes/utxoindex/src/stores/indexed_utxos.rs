@@ -1,13 +1,13 @@
-use crate::core::model::{CompactUtxoCollection, CompactUtxoEntry, UtxoSetByScriptPublicKey};
+use crate::core::model::{AssetCirculatingSupply, CompactUtxoCollection, CompactUtxoEntry, UtxoSetByScriptPublicKey};
 
 use kash_consensus_core::tx::{
     ScriptPublicKey, ScriptPublicKeyVersion, ScriptPublicKeys, ScriptVec, TransactionIndexType, TransactionOutpoint,
 };
 use kash_core::debug;
-use kash_database::prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreResult, DB};
+use kash_database::prelude::{CachePolicy, CachedDbAccess, DirectDbWriter, StoreError, StoreResult, DB};
 use kash_database::registry::DatabaseStorePrefixes;
 use kash_hashes::Hash;
-use kash_index_core::indexed_utxos::BalanceByScriptPublicKey;
+use kash_index_core::indexed_utxos::{AssetBalance, BalanceByScriptPublicKey};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fmt::Display;
@@ -127,6 +127,8 @@ pub trait UtxoSetByScriptPublicKeyStoreReader {
     fn get_utxos_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<UtxoSetByScriptPublicKey>;
     fn get_balance_from_script_public_keys(&self, script_public_keys: ScriptPublicKeys) -> StoreResult<BalanceByScriptPublicKey>;
     fn get_all_outpoints(&self) -> StoreResult<HashSet<TransactionOutpoint>>; // This can have a big memory footprint, so it should be used only for tests.
+    /// Sums the amounts of all indexed utxos, per asset type. This iterates over the whole indexed utxo set.
+    fn recount_circulating_supply(&self) -> StoreResult<AssetCirculatingSupply>;
 }
 
 pub trait UtxoSetByScriptPublicKeyStore: UtxoSetByScriptPublicKeyStoreReader {
@@ -183,15 +185,12 @@ impl UtxoSetByScriptPublicKeyStoreReader for DbUtxoSetByScriptPublicKeyStore {
         let mut balance_by_script_public_keys = BalanceByScriptPublicKey::new();
         for script_public_key in script_public_keys.into_iter() {
             let script_public_key_bucket = ScriptPublicKeyBucket::from(&script_public_key);
-            let balance: u64 = self
-                .access
-                .seek_iterator(Some(script_public_key_bucket.as_ref()), None, usize::MAX, false)
-                .map(|res| {
-                    entries_count += 1;
-                    let (_, entry) = res.unwrap();
-                    entry.amount
-                })
-                .sum();
+            let mut balance = AssetBalance::default();
+            for res in self.access.seek_iterator(Some(script_public_key_bucket.as_ref()), None, usize::MAX, false) {
+                entries_count += 1;
+                let (_, entry) = res.unwrap();
+                balance.add_amount(entry.asset_type, entry.amount);
+            }
             balance_by_script_public_keys.insert(script_public_key, balance);
         }
         debug!("IDXPRC, Executed a query for the balance of {} script public keys involving {} entries", script_count, entries_count);
@@ -204,6 +203,15 @@ impl UtxoSetByScriptPublicKeyStoreReader for DbUtxoSetByScriptPublicKeyStore {
             self.access.iterator().map(|res| UtxoEntryFullAccessKey(Arc::new(res.unwrap().0.to_vec())).extract_outpoint()),
         ))
     }
+
+    fn recount_circulating_supply(&self) -> StoreResult<AssetCirculatingSupply> {
+        let mut circulating_supply = AssetCirculatingSupply::default();
+        for res in self.access.iterator() {
+            let (_, entry) = res.map_err(|err| StoreError::DataInconsistency(err.to_string()))?;
+            circulating_supply.add_amount(entry.asset_type, entry.amount);
+        }
+        Ok(circulating_supply)
+    }
 }
 
 impl UtxoSetByScriptPublicKeyStore for DbUtxoSetByScriptPublicKeyStore {
@@ -257,3 +265,54 @@ impl UtxoSetByScriptPublicKeyStore for DbUtxoSetByScriptPublicKeyStore {
         self.access.delete_all(DirectDbWriter::new(&self.db))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kash_consensus_core::asset_type::AssetType;
+    use kash_database::create_temp_db;
+    use kash_database::prelude::ConnBuilder;
+
+    #[test]
+    fn test_balance_by_asset_type() {
+        let (_db_lifetime, db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
+        let mut store = DbUtxoSetByScriptPublicKeyStore::new(db, CachePolicy::Empty);
+
+        let script_public_key = ScriptPublicKey::from_vec(0, vec![1; 34]);
+        let other_script_public_key = ScriptPublicKey::from_vec(0, vec![2; 34]);
+        let empty_script_public_key = ScriptPublicKey::from_vec(0, vec![3; 34]);
+        let entries = [(AssetType::KSH, 100), (AssetType::KSH, 50), (AssetType::KUSD, 7), (AssetType::KRV, 3), (AssetType::KRV, 4)];
+        let utxos = CompactUtxoCollection::from_iter(entries.iter().enumerate().map(|(index, (asset_type, amount))| {
+            (TransactionOutpoint::new(Hash::from_u64_word(1), index as u32), CompactUtxoEntry::new(*amount, 0, false, *asset_type))
+        }));
+        let other_utxos = CompactUtxoCollection::from_iter([(
+            TransactionOutpoint::new(Hash::from_u64_word(2), 0),
+            CompactUtxoEntry::new(1000, 0, false, AssetType::KUSD),
+        )]);
+        store
+            .add_utxo_entries(&UtxoSetByScriptPublicKey::from_iter([
+                (script_public_key.clone(), utxos),
+                (other_script_public_key.clone(), other_utxos.clone()),
+            ]))
+            .unwrap();
+
+        let balances = store
+            .get_balance_from_script_public_keys(ScriptPublicKeys::from_iter([
+                script_public_key.clone(),
+                other_script_public_key.clone(),
+                empty_script_public_key.clone(),
+            ]))
+            .unwrap();
+        assert_eq!(balances[&script_public_key], AssetBalance::new(150, 7, 7));
+        assert_eq!(balances[&other_script_public_key], AssetBalance::new(0, 1000, 0));
+        assert_eq!(balances[&empty_script_public_key], AssetBalance::default());
+        assert_eq!(balances[&script_public_key].get(AssetType::KRV), 7);
+        assert_eq!(balances[&script_public_key].total(), 164);
+
+        // Removed entries no longer count towards the balance
+        store.remove_utxo_entries(&UtxoSetByScriptPublicKey::from_iter([(other_script_public_key.clone(), other_utxos)])).unwrap();
+        let balances =
+            store.get_balance_from_script_public_keys(ScriptPublicKeys::from_iter([other_script_public_key.clone()])).unwrap();
+        assert_eq!(balances[&other_script_public_key], AssetBalance::default());
+    }
+}
@@ -1,4 +1,5 @@
 mod indexed_utxos;
 pub mod store_manager;
 mod supply;
+mod supply_snapshots;
 mod tips;
@@ -8,12 +8,15 @@ use kash_core::trace;
 use kash_database::prelude::{CachePolicy, StoreResult, DB};
 use kash_index_core::indexed_utxos::BalanceByScriptPublicKey;
 
-use crate::model::{AssetCirculatingSupply, AssetCirculatingSupplyDiffs};
+use crate::model::{AssetCirculatingSupply, AssetCirculatingSupplyDiffs, CirculatingSupplySnapshot};
 use crate::{
     model::UtxoSetByScriptPublicKey,
     stores::{
         indexed_utxos::{DbUtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStore, UtxoSetByScriptPublicKeyStoreReader},
         supply::{CirculatingSupplyStore, CirculatingSupplyStoreReader, DbCirculatingSupplyStore},
+        supply_snapshots::{
+            CirculatingSupplySnapshotsStore, CirculatingSupplySnapshotsStoreReader, DbCirculatingSupplySnapshotsStore,
+        },
         tips::{DbUtxoIndexTipsStore, UtxoIndexTipsStore, UtxoIndexTipsStoreReader},
     },
     IDENT,
@@ -23,6 +26,7 @@ use crate::{
 pub struct Store {
     utxoindex_tips_store: DbUtxoIndexTipsStore,
     circulating_supply_store: DbCirculatingSupplyStore,
+    circulating_supply_snapshots_store: DbCirculatingSupplySnapshotsStore,
     utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore,
 }
 
@@ -31,6 +35,7 @@ impl Store {
         Self {
             utxoindex_tips_store: DbUtxoIndexTipsStore::new(db.clone()),
             circulating_supply_store: DbCirculatingSupplyStore::new(db.clone()),
+            circulating_supply_snapshots_store: DbCirculatingSupplySnapshotsStore::new(db.clone()),
             utxos_by_script_public_key_store: DbUtxoSetByScriptPublicKeyStore::new(db, CachePolicy::Empty),
         }
     }
@@ -43,6 +48,11 @@ impl Store {
         self.utxos_by_script_public_key_store.get_balance_from_script_public_keys(script_public_keys)
     }
 
+    /// Recounts the circulating supply from all indexed utxos.
+    pub fn recount_circulating_supply(&self) -> StoreResult<AssetCirculatingSupply> {
+        self.utxos_by_script_public_key_store.recount_circulating_supply()
+    }
+
     // This can have a big memory footprint, so it should be used only for tests.
     pub fn get_all_outpoints(&self) -> StoreResult<HashSet<TransactionOutpoint>> {
         self.utxos_by_script_public_key_store.get_all_outpoints()
@@ -100,6 +110,35 @@ impl Store {
         res
     }
 
+    pub fn get_circulating_supply_at(&self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot> {
+        self.circulating_supply_snapshots_store.get_at(daa_score)
+    }
+
+    pub fn insert_circulating_supply_snapshot(
+        &mut self,
+        daa_score: u64,
+        circulating_supply: AssetCirculatingSupply,
+        try_reset_on_err: bool,
+    ) -> StoreResult<()> {
+        let res = self.circulating_supply_snapshots_store.insert(daa_score, circulating_supply);
+        if try_reset_on_err && res.is_err() {
+            self.delete_all()?;
+        }
+        res
+    }
+
+    pub fn delete_circulating_supply_snapshots_above(&mut self, daa_score: u64, try_reset_on_err: bool) -> StoreResult<()> {
+        let res = self.circulating_supply_snapshots_store.delete_above(daa_score);
+        if try_reset_on_err && res.is_err() {
+            self.delete_all()?;
+        }
+        res
+    }
+
+    pub fn prune_circulating_supply_snapshots(&mut self, daa_score: u64) -> StoreResult<()> {
+        self.circulating_supply_snapshots_store.prune_below(daa_score)
+    }
+
     pub fn get_tips(&self) -> StoreResult<Arc<BlockHashSet>> {
         self.utxoindex_tips_store.get()
     }
@@ -114,6 +153,12 @@ impl Store {
 
     /// Resets the utxoindex database:
     pub fn delete_all(&mut self) -> StoreResult<()> {
+        self.delete_utxo_state()?;
+        self.circulating_supply_snapshots_store.delete_all()
+    }
+
+    /// Resets the utxoindex database, except for the circulating supply history.
+    pub fn delete_utxo_state(&mut self) -> StoreResult<()> {
         // TODO: explore possibility of deleting and replacing whole db, currently there is an issue because of file lock and db being in an arc.
         trace!("[{0}] attempting to clear utxoindex database...", IDENT);
 
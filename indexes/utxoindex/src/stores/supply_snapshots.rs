@@ -0,0 +1,122 @@
+use std::{mem::size_of, sync::Arc};
+
+use kash_database::{
+    prelude::{DbKey, DbWriter, DirectDbWriter, StoreError, StoreResult, DB},
+    registry::DatabaseStorePrefixes,
+};
+use rocksdb::{Direction, IterateBounds, IteratorMode, ReadOptions};
+
+use crate::model::{AssetCirculatingSupply, CirculatingSupplySnapshot};
+
+/// Size of the [`DaaScoreKey`] in bytes.
+const DAA_SCORE_KEY_SIZE: usize = size_of::<u64>();
+
+/// Consists of 8 bytes of big endian DAA score, so that the DB ordering of the keys matches the ordering of the DAA scores.
+#[derive(Eq, Hash, PartialEq, Debug, Copy, Clone)]
+struct DaaScoreKey([u8; DAA_SCORE_KEY_SIZE]);
+
+impl From<u64> for DaaScoreKey {
+    fn from(daa_score: u64) -> Self {
+        Self(daa_score.to_be_bytes())
+    }
+}
+
+impl From<DaaScoreKey> for u64 {
+    fn from(key: DaaScoreKey) -> Self {
+        u64::from_be_bytes(key.0)
+    }
+}
+
+impl AsRef<[u8]> for DaaScoreKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Reader API for `CirculatingSupplySnapshotsStore`.
+pub trait CirculatingSupplySnapshotsStoreReader {
+    /// Returns the most recent snapshot taken at a virtual DAA score lower or equal to `daa_score`.
+    fn get_at(&self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot>;
+}
+
+pub trait CirculatingSupplySnapshotsStore: CirculatingSupplySnapshotsStoreReader {
+    /// Inserts a snapshot of the circulating supply at virtual DAA score `daa_score`, replacing any snapshot at the same score.
+    fn insert(&mut self, daa_score: u64, supply: AssetCirculatingSupply) -> StoreResult<()>;
+    /// Deletes the snapshots taken at a DAA score higher than `daa_score`, i.e., those of a reorged virtual chain.
+    fn delete_above(&mut self, daa_score: u64) -> StoreResult<()>;
+    /// Deletes the snapshots taken before `daa_score`, except for the most recent of them, so that lookups at
+    /// `daa_score` and above keep returning the same results.
+    fn prune_below(&mut self, daa_score: u64) -> StoreResult<()>;
+    fn delete_all(&mut self) -> StoreResult<()>;
+}
+
+/// A DB implementation of `CirculatingSupplySnapshotsStore` trait, keyed by virtual DAA score
+#[derive(Clone)]
+pub struct DbCirculatingSupplySnapshotsStore {
+    db: Arc<DB>,
+    prefix: Vec<u8>,
+}
+
+impl DbCirculatingSupplySnapshotsStore {
+    pub fn new(db: Arc<DB>) -> Self {
+        Self { db, prefix: DatabaseStorePrefixes::CirculatingSupplySnapshots.into() }
+    }
+}
+
+impl CirculatingSupplySnapshotsStoreReader for DbCirculatingSupplySnapshotsStore {
+    fn get_at(&self, daa_score: u64) -> StoreResult<CirculatingSupplySnapshot> {
+        let db_key = DbKey::new(&self.prefix, DaaScoreKey::from(daa_score));
+        let mut read_opts = ReadOptions::default();
+        read_opts.set_iterate_range(rocksdb::PrefixRange(DbKey::prefix_only(&self.prefix).as_ref()));
+
+        // Seeking backwards lands on the highest key which is lower or equal to the seek key
+        match self.db.iterator_opt(IteratorMode::From(db_key.as_ref(), Direction::Reverse), read_opts).next() {
+            Some(Ok((key_bytes, value_bytes))) => {
+                let key = DaaScoreKey(<[u8; DAA_SCORE_KEY_SIZE]>::try_from(&key_bytes[db_key.prefix_len()..]).unwrap());
+                Ok(CirculatingSupplySnapshot::new(key.into(), bincode::deserialize(value_bytes.as_ref())?))
+            }
+            Some(Err(err)) => Err(err.into()),
+            None => Err(StoreError::KeyNotFound(db_key)),
+        }
+    }
+}
+
+impl CirculatingSupplySnapshotsStore for DbCirculatingSupplySnapshotsStore {
+    fn insert(&mut self, daa_score: u64, supply: AssetCirculatingSupply) -> StoreResult<()> {
+        let mut writer = DirectDbWriter::new(&self.db);
+        writer.put(DbKey::new(&self.prefix, DaaScoreKey::from(daa_score)), bincode::serialize(&supply)?)?;
+        Ok(())
+    }
+
+    fn delete_above(&mut self, daa_score: u64) -> StoreResult<()> {
+        let Some(from_daa_score) = daa_score.checked_add(1) else {
+            return Ok(());
+        };
+        let mut writer = DirectDbWriter::new(&self.db);
+        let from = DbKey::new(&self.prefix, DaaScoreKey::from(from_daa_score));
+        let (_, to) = rocksdb::PrefixRange(DbKey::prefix_only(&self.prefix).as_ref()).into_bounds();
+        writer.delete_range(from.as_ref().to_vec(), to.unwrap())?;
+        Ok(())
+    }
+
+    fn prune_below(&mut self, daa_score: u64) -> StoreResult<()> {
+        // The most recent snapshot below `daa_score` still answers lookups from `daa_score` on, so it is kept
+        let to_daa_score = match self.get_at(daa_score.saturating_sub(1)) {
+            Ok(snapshot) => snapshot.daa_score,
+            Err(StoreError::KeyNotFound(_)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let mut writer = DirectDbWriter::new(&self.db);
+        let (from, _) = rocksdb::PrefixRange(DbKey::prefix_only(&self.prefix).as_ref()).into_bounds();
+        let to = DbKey::new(&self.prefix, DaaScoreKey::from(to_daa_score));
+        writer.delete_range(from.unwrap(), to.as_ref().to_vec())?;
+        Ok(())
+    }
+
+    fn delete_all(&mut self) -> StoreResult<()> {
+        let mut writer = DirectDbWriter::new(&self.db);
+        let (from, to) = rocksdb::PrefixRange(DbKey::prefix_only(&self.prefix).as_ref()).into_bounds();
+        writer.delete_range(from.unwrap(), to.unwrap())?;
+        Ok(())
+    }
+}
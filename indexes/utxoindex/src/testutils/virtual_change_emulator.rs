@@ -1,6 +1,5 @@
 use crate::model::{AssetCirculatingSupply, AssetCirculatingSupplyDiffs};
 use kash_consensus::test_helpers::*;
-use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::{
     tx::ScriptPublicKey,
     utxo::{utxo_collection::UtxoCollection, utxo_diff::UtxoDiff},
@@ -41,12 +40,8 @@ impl VirtualChangeEmulator {
         let rng = &mut SmallRng::seed_from_u64(43);
         self.script_public_key_pool.extend((0..script_public_key_pool_size).map(|_| generate_random_p2pk_script_public_key(rng)));
         self.utxo_collection = generate_random_utxos_from_script_public_key_pool(rng, amount, &self.script_public_key_pool);
-        for (_, utxo_entry) in self.utxo_collection.clone() {
-            match utxo_entry.asset_type {
-                AssetType::KSH => self.circulating_supply.ksh_supply += utxo_entry.amount,
-                AssetType::KUSD => self.circulating_supply.kusd_supply += utxo_entry.amount,
-                AssetType::KRV => self.circulating_supply.krv_supply += utxo_entry.amount,
-            }
+        for utxo_entry in self.utxo_collection.values() {
+            self.circulating_supply.add_amount(utxo_entry.asset_type, utxo_entry.amount);
         }
         self.tips = BlockHashSet::from_iter(generate_random_hashes(rng, 1));
     }
@@ -59,21 +54,13 @@ impl VirtualChangeEmulator {
             UtxoCollection::from_iter(
                 generate_random_utxos_from_script_public_key_pool(rng, add_amount, &self.script_public_key_pool).into_iter().map(
                     |(k, v)| {
-                        match v.asset_type {
-                            AssetType::KSH => new_circulating_supply_diff.ksh_supply_diff += v.amount as i64,
-                            AssetType::KUSD => new_circulating_supply_diff.kusd_supply_diff += v.amount as i64,
-                            AssetType::KRV => new_circulating_supply_diff.krv_supply_diff += v.amount as i64,
-                        }
+                        new_circulating_supply_diff.add_utxo_entry(&v);
                         (k, v)
                     },
                 ),
             ),
             UtxoCollection::from_iter(self.utxo_collection.iter().take(remove_amount).map(|(k, v)| {
-                match v.asset_type {
-                    AssetType::KSH => new_circulating_supply_diff.ksh_supply_diff -= v.amount as i64,
-                    AssetType::KUSD => new_circulating_supply_diff.kusd_supply_diff -= v.amount as i64,
-                    AssetType::KRV => new_circulating_supply_diff.krv_supply_diff -= v.amount as i64,
-                }
+                new_circulating_supply_diff.remove_utxo_entry(v);
                 (*k, v.clone())
             })),
         ));
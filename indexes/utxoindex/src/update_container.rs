@@ -1,4 +1,3 @@
-use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::{
     tx::{TransactionOutpoint, UtxoEntry},
     utxo::utxo_diff::UtxoDiff,
@@ -35,11 +34,7 @@ impl UtxoIndexChanges {
                 continue;
             }
 
-            match utxo_entry.asset_type {
-                AssetType::KSH => self.supply_change.ksh_supply_diff += utxo_entry.amount as i64,
-                AssetType::KUSD => self.supply_change.kusd_supply_diff += utxo_entry.amount as i64,
-                AssetType::KRV => self.supply_change.krv_supply_diff += utxo_entry.amount as i64,
-            }; // TODO: Using `virtual_state.mergeset_rewards` might be a better way to extract this.
+            self.supply_change.add_utxo_entry(&utxo_entry); // TODO: Using `virtual_state.mergeset_rewards` might be a better way to extract this.
 
             self.utxo_changes.added.insert_into_nested(
                 utxo_entry.script_public_key,
@@ -49,11 +44,7 @@ impl UtxoIndexChanges {
         }
 
         for (transaction_outpoint, utxo_entry) in to_remove.into_iter() {
-            match utxo_entry.asset_type {
-                AssetType::KSH => self.supply_change.ksh_supply_diff -= utxo_entry.amount as i64,
-                AssetType::KUSD => self.supply_change.kusd_supply_diff -= utxo_entry.amount as i64,
-                AssetType::KRV => self.supply_change.krv_supply_diff -= utxo_entry.amount as i64,
-            } // TODO: Using `virtual_state.mergeset_rewards` might be a better way to extract this.
+            self.supply_change.remove_utxo_entry(&utxo_entry); // TODO: Using `virtual_state.mergeset_rewards` might be a better way to extract this.
 
             self.utxo_changes.removed.insert_into_nested(
                 utxo_entry.script_public_key,
@@ -68,11 +59,7 @@ impl UtxoIndexChanges {
     /// Note: This is meant to be used when resyncing.
     pub fn add_utxos_from_vector(&mut self, utxo_vector: Vec<(TransactionOutpoint, UtxoEntry)>) {
         for (transaction_outpoint, utxo_entry) in utxo_vector.into_iter() {
-            match utxo_entry.asset_type {
-                AssetType::KSH => self.supply_change.ksh_supply_diff += utxo_entry.amount as i64,
-                AssetType::KUSD => self.supply_change.kusd_supply_diff += utxo_entry.amount as i64,
-                AssetType::KRV => self.supply_change.krv_supply_diff += utxo_entry.amount as i64,
-            }
+            self.supply_change.add_utxo_entry(&utxo_entry);
 
             self.utxo_changes.added.insert_into_nested(
                 utxo_entry.script_public_key,
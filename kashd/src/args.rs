@@ -13,8 +13,10 @@ use std::ffi::OsString;
 use std::sync::Arc;
 
 use kash_consensus_core::{
+    asset_type::AssetType,
     config::Config,
     network::{NetworkId, NetworkType},
+    tx::TransactionAction,
 };
 
 // The import of `KSH` is used within `#[cfg(feature = "devnet-prealloc")]` blocks.
@@ -26,7 +28,9 @@ use kash_consensus_core::asset_type::AssetType::KSH;
 
 use kash_core::kashd_env::version;
 
-use kash_utils::networking::ContextualNetAddress;
+use kash_mining::mempool::config::StandardPolicy;
+
+use kash_utils::{hex::FromHex, networking::ContextualNetAddress};
 use kash_wrpc_server::address::WrpcNetAddress;
 
 #[derive(Debug, Clone)]
@@ -38,6 +42,7 @@ pub struct Args {
     pub rpclisten: Option<ContextualNetAddress>,
     pub rpclisten_borsh: Option<WrpcNetAddress>,
     pub rpclisten_json: Option<WrpcNetAddress>,
+    pub rpclisten_http: Option<ContextualNetAddress>,
     pub unsafe_rpc: bool,
     pub wrpc_verbose: bool,
     pub log_level: String,
@@ -47,10 +52,12 @@ pub struct Args {
     pub listen: Option<ContextualNetAddress>,
     pub user_agent_comments: Vec<String>,
     pub utxoindex: bool,
+    pub txindex: bool,
     pub reset_db: bool,
     pub outbound_target: usize,
     pub inbound_limit: usize,
     pub rpc_max_clients: usize,
+    pub rpc_auth_config: Option<String>,
     pub enable_unsynced_mining: bool,
     pub enable_mainnet_mining: bool,
     pub testnet: bool,
@@ -75,6 +82,21 @@ pub struct Args {
     pub disable_upnp: bool,
     pub disable_dns_seeding: bool,
     pub ram_scale: f64,
+
+    /// Per-asset minimum relay fee overrides, in sompi/kg, indexed by the numeric value of the asset type
+    pub min_relay_tx_fees: [Option<u64>; AssetType::ALL.len()],
+    /// Per-asset dust relay fee overrides, in sompi/kg, indexed by the numeric value of the asset type
+    pub dust_relay_fees: [Option<u64>; AssetType::ALL.len()],
+    /// Per-action minimum relay fee overrides, in sompi/kg of the source asset, indexed by the numeric value of the action
+    pub action_min_relay_tx_fees: [Option<u64>; TransactionAction::ALL.len()],
+    /// Per-action dust relay fee overrides, in sompi/kg of the destination asset, indexed by the numeric value of the action
+    pub action_dust_relay_fees: [Option<u64>; TransactionAction::ALL.len()],
+    pub max_mint_txs_per_block: Option<u64>,
+    pub max_redeem_txs_per_block: Option<u64>,
+
+    /// X-only public keys of the price oracles trusted by the network (devnet and simnet only)
+    pub price_oracle_public_keys: Vec<[u8; 32]>,
+    pub price_oracle_activation_daa_score: Option<u64>,
 }
 
 impl Default for Args {
@@ -84,13 +106,16 @@ impl Default for Args {
             no_log_files: false,
             rpclisten_borsh: None,
             rpclisten_json: None,
+            rpclisten_http: None,
             unsafe_rpc: false,
             async_threads: num_cpus::get(),
             utxoindex: false,
+            txindex: false,
             reset_db: false,
             outbound_target: 8,
             inbound_limit: 128,
             rpc_max_clients: 128,
+            rpc_auth_config: None,
             enable_unsynced_mining: false,
             enable_mainnet_mining: false,
             testnet: false,
@@ -123,6 +148,14 @@ impl Default for Args {
             disable_upnp: false,
             disable_dns_seeding: false,
             ram_scale: 1.0,
+            min_relay_tx_fees: Default::default(),
+            dust_relay_fees: Default::default(),
+            action_min_relay_tx_fees: Default::default(),
+            action_dust_relay_fees: Default::default(),
+            max_mint_txs_per_block: None,
+            max_redeem_txs_per_block: None,
+            price_oracle_public_keys: vec![],
+            price_oracle_activation_daa_score: None,
         }
     }
 }
@@ -130,6 +163,7 @@ impl Default for Args {
 impl Args {
     pub fn apply_to_config(&self, config: &mut Config) {
         config.utxoindex = self.utxoindex;
+        config.txindex = self.txindex;
         config.disable_upnp = self.disable_upnp;
         config.unsafe_rpc = self.unsafe_rpc;
         config.enable_unsynced_mining = self.enable_unsynced_mining;
@@ -143,6 +177,14 @@ impl Args {
         config.externalip = self.externalip.map(|v| v.normalize(config.default_p2p_port()));
         config.ram_scale = self.ram_scale;
 
+        if !self.price_oracle_public_keys.is_empty() {
+            // Params hold static data, so the keys are leaked once for the lifetime of the process
+            config.params.price_oracle_public_keys = Box::leak(self.price_oracle_public_keys.clone().into_boxed_slice());
+        }
+        if let Some(price_oracle_activation_daa_score) = self.price_oracle_activation_daa_score {
+            config.params.price_oracle_activation_daa_score = price_oracle_activation_daa_score;
+        }
+
         #[cfg(feature = "devnet-prealloc")]
         if let Some(num_prealloc_utxos) = self.num_prealloc_utxos {
             config.initial_utxo_set = Arc::new(self.generate_prealloc_utxos(num_prealloc_utxos));
@@ -169,6 +211,32 @@ impl Args {
             .collect()
     }
 
+    /// Builds the mempool standardness policy, applying any overrides given by the user to the defaults
+    pub fn standard_policy(&self) -> StandardPolicy {
+        let mut standard_policy = StandardPolicy::default();
+        for asset_type in AssetType::ALL {
+            let asset_policy = standard_policy.asset_policy_mut(asset_type);
+            if let Some(fee) = self.min_relay_tx_fees[asset_type as usize] {
+                asset_policy.minimum_relay_transaction_fee = fee;
+            }
+            if let Some(fee) = self.dust_relay_fees[asset_type as usize] {
+                asset_policy.dust_relay_transaction_fee = fee;
+            }
+        }
+        for action in TransactionAction::ALL {
+            let action_policy = standard_policy.action_policy_mut(action);
+            action_policy.minimum_relay_transaction_fee = self.action_min_relay_tx_fees[u32::from(action) as usize];
+            action_policy.dust_relay_transaction_fee = self.action_dust_relay_fees[u32::from(action) as usize];
+        }
+        if let Some(max_mint_txs_per_block) = self.max_mint_txs_per_block {
+            standard_policy.maximum_mint_transactions_per_block = max_mint_txs_per_block;
+        }
+        if let Some(max_redeem_txs_per_block) = self.max_redeem_txs_per_block {
+            standard_policy.maximum_redeem_transactions_per_block = max_redeem_txs_per_block;
+        }
+        standard_policy
+    }
+
     pub fn network(&self) -> NetworkId {
         match (self.testnet, self.devnet, self.simnet) {
             (false, false, false) => NetworkId::new(NetworkType::Mainnet),
@@ -238,6 +306,16 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(WrpcNetAddress))
                 .help("Interface:port to listen for wRPC JSON connections (default port: 18110, testnet: 18210)."),
         )
+        .arg(
+            Arg::new("rpclisten-http")
+                .long("rpclisten-http")
+                .value_name("IP[:PORT]")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("127.0.0.1")
+                .value_parser(clap::value_parser!(ContextualNetAddress))
+                .help("Interface:port to listen for JSON-RPC 2.0 over HTTP requests (default port: 19110, testnet: 19210)."),
+        )
         .arg(arg!(--unsaferpc "Enable RPC commands which affect the state of the node"))
         .arg(
             Arg::new("connect-peers")
@@ -289,6 +367,13 @@ pub fn cli() -> Command {
                 .value_parser(clap::value_parser!(usize))
                 .help("Max number of RPC clients for standard connections (default: 128)."),
         )
+        .arg(
+            Arg::new("rpcauthconfig")
+                .long("rpcauthconfig")
+                .value_name("FILE")
+                .require_equals(true)
+                .help("JSON file defining the credentials, per-method ACLs and rate limits of the gRPC and wRPC servers."),
+        )
         .arg(arg!(--"reset-db" "Reset database before starting node. It's needed when switching between subnetworks."))
         .arg(arg!(--"enable-unsynced-mining" "Allow the node to accept blocks from RPC while not synced (this flag is mainly used for testing)"))
         .arg(
@@ -299,6 +384,7 @@ pub fn cli() -> Command {
                 .help("Allow mainnet mining (do not use unless you know what you are doing)"),
         )
         .arg(arg!(--utxoindex "Enable the UTXO index"))
+        .arg(arg!(--txindex "Enable the transaction index"))
         .arg(arg!(--testnet "Use the test network"))
         .arg(
             Arg::new("netsuffix")
@@ -347,6 +433,74 @@ pub fn cli() -> Command {
                 .help("Apply a scale factor to memory allocation bounds. Nodes with limited RAM (~4-8GB) should set this to ~0.3-0.5 respectively. Nodes with 
 a large RAM (~64GB) can set this value to ~3.0-4.0 and gain superior performance especially for syncing peers faster"),
         )
+        .args(AssetType::ALL.map(|asset_type| {
+            Arg::new(min_relay_tx_fee_arg_id(asset_type))
+                .long(min_relay_tx_fee_arg_id(asset_type))
+                .value_name("SOMPI_PER_KG")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!("Minimum relay fee rate in {asset_type} sompi/kg for transactions paying their fee in {asset_type} (default: 1000)."))
+        }))
+        .args(AssetType::ALL.map(|asset_type| {
+            Arg::new(dust_relay_fee_arg_id(asset_type))
+                .long(dust_relay_fee_arg_id(asset_type))
+                .value_name("SOMPI_PER_KG")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!("Fee rate in {asset_type} sompi/kg used to define {asset_type} outputs as dust (default: 1000)."))
+        }))
+        .args(CONVERSION_ACTIONS.map(|action| {
+            let (source, _) = action.asset_transfer_types();
+            Arg::new(action_min_relay_tx_fee_arg_id(action))
+                .long(action_min_relay_tx_fee_arg_id(action))
+                .value_name("SOMPI_PER_KG")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "Minimum relay fee rate in {source} sompi/kg for {action:?} transactions (default: the {source} minimum relay fee rate)."
+                ))
+        }))
+        .args(CONVERSION_ACTIONS.map(|action| {
+            let (_, destination) = action.asset_transfer_types();
+            Arg::new(action_dust_relay_fee_arg_id(action))
+                .long(action_dust_relay_fee_arg_id(action))
+                .value_name("SOMPI_PER_KG")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help(format!(
+                    "Fee rate in {destination} sompi/kg used to define the {destination} outputs of {action:?} transactions as dust (default: the {destination} dust relay fee rate)."
+                ))
+        }))
+        .arg(
+            Arg::new("max-mint-txs-per-block")
+                .long("max-mint-txs-per-block")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Maximum number of KUSD mint transactions included in a block template (default: unlimited)."),
+        )
+        .arg(
+            Arg::new("max-redeem-txs-per-block")
+                .long("max-redeem-txs-per-block")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("Maximum number of KSH redeem transactions included in a block template (default: unlimited)."),
+        )
+        .arg(
+            Arg::new("price-oracle-key")
+                .long("price-oracle-key")
+                .value_name("HEX")
+                .require_equals(true)
+                .action(ArgAction::Append)
+                .value_parser(|key: &str| <[u8; 32]>::from_hex(key).map_err(|err| err.to_string()))
+                .help("X-only public key of a trusted price oracle, may be repeated (devnet and simnet only)."),
+        )
+        .arg(
+            Arg::new("price-oracle-activation-daa-score")
+                .long("price-oracle-activation-daa-score")
+                .require_equals(true)
+                .value_parser(clap::value_parser!(u64))
+                .help("DAA score from which coinbases must commit to a signed price report (devnet and simnet only)."),
+        )
         ;
 
     #[cfg(feature = "devnet-prealloc")]
@@ -358,6 +512,48 @@ a large RAM (~64GB) can set this value to ~3.0-4.0 and gain superior performance
     cmd
 }
 
+fn min_relay_tx_fee_arg_id(asset_type: AssetType) -> &'static str {
+    match asset_type {
+        AssetType::KSH => "min-relay-tx-fee-ksh",
+        AssetType::KUSD => "min-relay-tx-fee-kusd",
+        AssetType::KRV => "min-relay-tx-fee-krv",
+    }
+}
+
+fn dust_relay_fee_arg_id(asset_type: AssetType) -> &'static str {
+    match asset_type {
+        AssetType::KSH => "dust-relay-fee-ksh",
+        AssetType::KUSD => "dust-relay-fee-kusd",
+        AssetType::KRV => "dust-relay-fee-krv",
+    }
+}
+
+/// Asset conversion actions whose fee and dust rates can be configured separately from the asset defaults
+const CONVERSION_ACTIONS: [TransactionAction; 3] =
+    [TransactionAction::MintKUSD, TransactionAction::StakeKSH, TransactionAction::RedeemKSH];
+
+fn action_min_relay_tx_fee_arg_id(action: TransactionAction) -> &'static str {
+    match action {
+        TransactionAction::MintKUSD => "min-relay-tx-fee-mint-kusd",
+        TransactionAction::StakeKSH => "min-relay-tx-fee-stake-ksh",
+        TransactionAction::RedeemKSH => "min-relay-tx-fee-redeem-ksh",
+        TransactionAction::TransferKSH | TransactionAction::TransferKUSD | TransactionAction::TransferKRV => {
+            unreachable!("transfer fee rates are configured per asset")
+        }
+    }
+}
+
+fn action_dust_relay_fee_arg_id(action: TransactionAction) -> &'static str {
+    match action {
+        TransactionAction::MintKUSD => "dust-relay-fee-mint-kusd",
+        TransactionAction::StakeKSH => "dust-relay-fee-stake-ksh",
+        TransactionAction::RedeemKSH => "dust-relay-fee-redeem-ksh",
+        TransactionAction::TransferKSH | TransactionAction::TransferKUSD | TransactionAction::TransferKRV => {
+            unreachable!("transfer dust rates are configured per asset")
+        }
+    }
+}
+
 pub fn parse_args() -> Args {
     match Args::parse(std::env::args_os()) {
         Ok(args) => args,
@@ -384,6 +580,7 @@ impl Args {
             rpclisten: m.get_one::<ContextualNetAddress>("rpclisten").cloned(),
             rpclisten_borsh: m.get_one::<WrpcNetAddress>("rpclisten-borsh").cloned(),
             rpclisten_json: m.get_one::<WrpcNetAddress>("rpclisten-json").cloned(),
+            rpclisten_http: m.get_one::<ContextualNetAddress>("rpclisten-http").cloned(),
             unsafe_rpc: m.get_one::<bool>("unsaferpc").cloned().unwrap_or(defaults.unsafe_rpc),
             wrpc_verbose: false,
             log_level: m.get_one::<String>("log_level").cloned().unwrap(),
@@ -394,10 +591,12 @@ impl Args {
             outbound_target: m.get_one::<usize>("outpeers").cloned().unwrap_or(defaults.outbound_target),
             inbound_limit: m.get_one::<usize>("maxinpeers").cloned().unwrap_or(defaults.inbound_limit),
             rpc_max_clients: m.get_one::<usize>("rpcmaxclients").cloned().unwrap_or(defaults.rpc_max_clients),
+            rpc_auth_config: m.get_one::<String>("rpcauthconfig").cloned(),
             reset_db: m.get_one::<bool>("reset-db").cloned().unwrap_or(defaults.reset_db),
             enable_unsynced_mining: m.get_one::<bool>("enable-unsynced-mining").cloned().unwrap_or(defaults.enable_unsynced_mining),
             enable_mainnet_mining: m.get_one::<bool>("enable-mainnet-mining").cloned().unwrap_or(defaults.enable_mainnet_mining),
             utxoindex: m.get_one::<bool>("utxoindex").cloned().unwrap_or(defaults.utxoindex),
+            txindex: m.get_one::<bool>("txindex").cloned().unwrap_or(defaults.txindex),
             testnet: m.get_one::<bool>("testnet").cloned().unwrap_or(defaults.testnet),
             testnet_suffix: m.get_one::<u32>("netsuffix").cloned().unwrap_or(defaults.testnet_suffix),
             devnet: m.get_one::<bool>("devnet").cloned().unwrap_or(defaults.devnet),
@@ -417,6 +616,26 @@ impl Args {
             disable_upnp: m.get_one::<bool>("disable-upnp").cloned().unwrap_or(defaults.disable_upnp),
             disable_dns_seeding: m.get_one::<bool>("nodnsseed").cloned().unwrap_or(defaults.disable_dns_seeding),
             ram_scale: m.get_one::<f64>("ram-scale").cloned().unwrap_or(defaults.ram_scale),
+            min_relay_tx_fees: AssetType::ALL.map(|asset_type| m.get_one::<u64>(min_relay_tx_fee_arg_id(asset_type)).cloned()),
+            dust_relay_fees: AssetType::ALL.map(|asset_type| m.get_one::<u64>(dust_relay_fee_arg_id(asset_type)).cloned()),
+            action_min_relay_tx_fees: TransactionAction::ALL.map(|action| {
+                if CONVERSION_ACTIONS.contains(&action) {
+                    m.get_one::<u64>(action_min_relay_tx_fee_arg_id(action)).cloned()
+                } else {
+                    None
+                }
+            }),
+            action_dust_relay_fees: TransactionAction::ALL.map(|action| {
+                if CONVERSION_ACTIONS.contains(&action) {
+                    m.get_one::<u64>(action_dust_relay_fee_arg_id(action)).cloned()
+                } else {
+                    None
+                }
+            }),
+            max_mint_txs_per_block: m.get_one::<u64>("max-mint-txs-per-block").cloned(),
+            max_redeem_txs_per_block: m.get_one::<u64>("max-redeem-txs-per-block").cloned(),
+            price_oracle_public_keys: m.get_many::<[u8; 32]>("price-oracle-key").unwrap_or_default().copied().collect(),
+            price_oracle_activation_daa_score: m.get_one::<u64>("price-oracle-activation-daa-score").cloned(),
 
             #[cfg(feature = "devnet-prealloc")]
             num_prealloc_utxos: m.get_one::<u64>("num-prealloc-utxos").cloned(),
@@ -500,6 +719,7 @@ impl Args {
       --maxutxocachesize=                   Max size of loaded UTXO into ram from the disk in bytes (default:
                                             5000000000)
       --utxoindex                           Enable the UTXO index
+      --txindex                             Enable the transaction index
       --archival                            Run as an archival node: don't delete old block data when moving the
                                             pruning point (Warning: heavy disk usage)'
       --protocol-version=                   Use non default p2p protocol version (default: 5)
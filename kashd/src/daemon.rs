@@ -1,4 +1,10 @@
-use std::{fs, path::PathBuf, process::exit, sync::Arc, time::Duration};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+    sync::Arc,
+    time::Duration,
+};
 
 use async_channel::unbounded;
 use kash_consensus_core::{
@@ -10,7 +16,11 @@ use kash_core::{core::Core, info, trace};
 use kash_core::{kashd_env::version, task::tick::TickService};
 use kash_database::prelude::CachePolicy;
 use kash_grpc_server::service::GrpcService;
-use kash_rpc_service::service::RpcCoreService;
+use kash_jsonrpc_server::service::JsonRpcService;
+use kash_rpc_service::{
+    auth::{config::AuthConfig, guard::RpcGuard},
+    service::RpcCoreService,
+};
 use kash_txscript::caches::TxScriptCacheCounters;
 use kash_utils::networking::ContextualNetAddress;
 use kash_utils_tower::counters::TowerConnectionCounters;
@@ -31,6 +41,7 @@ use kash_mining::{
 use kash_p2p_flows::{flow_context::FlowContext, service::P2pService};
 
 use kash_perf_monitor::{builder::Builder as PerfMonitorBuilder, counters::CountersSnapshot};
+use kash_txindex::{api::TxIndexProxy, TxIndex};
 use kash_utxoindex::{api::UtxoIndexProxy, UtxoIndex};
 use kash_wrpc_server::service::{Options as WrpcServerOptions, WebSocketCounters as WrpcServerCounters, WrpcEncoding, WrpcService};
 
@@ -48,6 +59,7 @@ use crate::args::Args;
 const DEFAULT_DATA_DIR: &str = "datadir";
 const CONSENSUS_DB: &str = "consensus";
 const UTXOINDEX_DB: &str = "utxoindex";
+const TXINDEX_DB: &str = "txindex";
 const META_DB: &str = "meta";
 const META_DB_FILE_LIMIT: i32 = 5;
 const DEFAULT_LOG_DIR: &str = "logs";
@@ -91,6 +103,13 @@ pub fn validate_args(args: &Args) -> ConfigResult<()> {
     if args.ram_scale > 10.0 {
         return Err(ConfigError::RamScaleTooHigh);
     }
+    if (!args.price_oracle_public_keys.is_empty() || args.price_oracle_activation_daa_score.is_some()) && !(args.devnet || args.simnet)
+    {
+        return Err(ConfigError::PriceOracleOnNonDevnet);
+    }
+    if args.price_oracle_activation_daa_score.is_some() && args.price_oracle_public_keys.is_empty() {
+        return Err(ConfigError::MissingPriceOracleKeys);
+    }
     Ok(())
 }
 
@@ -203,12 +222,31 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
     } else {
         0
     };
+    let tx_files_limit = if args.txindex {
+        let tx_files_limit = fd_remaining * 10 / 100;
+        fd_remaining -= tx_files_limit;
+        tx_files_limit
+    } else {
+        0
+    };
     // Make sure args forms a valid set of properties
     if let Err(err) = validate_args(args) {
         println!("{}", err);
         exit(1);
     }
 
+    // Without an auth config, the RPC servers accept every client and call
+    let rpc_guard = match args.rpc_auth_config.as_ref() {
+        Some(path) => match AuthConfig::load(Path::new(path)).and_then(|config| RpcGuard::from_config(&config)) {
+            Ok(guard) => Arc::new(guard),
+            Err(err) => {
+                println!("{}", err);
+                exit(1);
+            }
+        },
+        None => Default::default(),
+    };
+
     let config = Arc::new(
         ConfigBuilder::new(network.into())
             .adjust_perf_params_to_consensus_params()
@@ -238,6 +276,7 @@ pub fn create_core_with_runtime(runtime: &Runtime, args: &Args, fd_total_budget:
 
     let consensus_db_dir = db_dir.join(CONSENSUS_DB);
     let utxoindex_db_dir = db_dir.join(UTXOINDEX_DB);
+    let txindex_db_dir = db_dir.join(TXINDEX_DB);
     let meta_db_dir = db_dir.join(META_DB);
 
     let mut is_db_reset_needed = args.reset_db;
@@ -257,6 +296,10 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
         info!("Utxoindex Data directory {}", utxoindex_db_dir.display());
         fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
     }
+    if args.txindex {
+        info!("Txindex Data directory {}", txindex_db_dir.display());
+        fs::create_dir_all(txindex_db_dir.as_path()).unwrap();
+    }
 
     // DB used for addresses store and for multi-consensus management
     let mut meta_db = kash_database::prelude::ConnBuilder::default()
@@ -329,6 +372,10 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
             fs::create_dir_all(utxoindex_db_dir.as_path()).unwrap();
         }
 
+        if args.txindex {
+            fs::create_dir_all(txindex_db_dir.as_path()).unwrap();
+        }
+
         // Reopen the DB
         meta_db = kash_database::prelude::ConnBuilder::default()
             .with_db_path(meta_db_dir)
@@ -396,15 +443,25 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
     };
 
     let notify_service = Arc::new(NotifyService::new(notification_root.clone(), notification_recv));
-    let index_service: Option<Arc<IndexService>> = if args.utxoindex {
+    let index_service: Option<Arc<IndexService>> = if args.utxoindex || args.txindex {
         // Use only a single thread for none-consensus databases
-        let utxoindex_db = kash_database::prelude::ConnBuilder::default()
-            .with_db_path(utxoindex_db_dir)
-            .with_files_limit(utxo_files_limit)
-            .build()
-            .unwrap();
-        let utxoindex = UtxoIndexProxy::new(UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap());
-        let index_service = Arc::new(IndexService::new(&notify_service.notifier(), Some(utxoindex)));
+        let utxoindex = args.utxoindex.then(|| {
+            let utxoindex_db = kash_database::prelude::ConnBuilder::default()
+                .with_db_path(utxoindex_db_dir)
+                .with_files_limit(utxo_files_limit)
+                .build()
+                .unwrap();
+            UtxoIndexProxy::new(UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap())
+        });
+        let txindex = args.txindex.then(|| {
+            let txindex_db = kash_database::prelude::ConnBuilder::default()
+                .with_db_path(txindex_db_dir)
+                .with_files_limit(tx_files_limit)
+                .build()
+                .unwrap();
+            TxIndexProxy::new(TxIndex::new(consensus_manager.clone(), txindex_db).unwrap())
+        });
+        let index_service = Arc::new(IndexService::new(&notify_service.notifier(), utxoindex, txindex));
         Some(index_service)
     } else {
         None
@@ -418,8 +475,10 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
         false,
         config.max_block_mass,
         config.ram_scale,
+        args.standard_policy(),
         config.block_template_cache_lifetime,
         mining_counters,
+        Some(notification_root.clone()),
     )));
 
     let flow_context = Arc::new(FlowContext::new(
@@ -448,7 +507,8 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
         index_service.as_ref().map(|x| x.notifier()),
         mining_manager,
         flow_context,
-        index_service.as_ref().map(|x| x.utxoindex().unwrap()),
+        index_service.as_ref().and_then(|x| x.utxoindex()),
+        index_service.as_ref().and_then(|x| x.txindex()),
         config.clone(),
         core.clone(),
         processing_counters,
@@ -457,7 +517,11 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
         perf_monitor.clone(),
         p2p_tower_counters.clone(),
         grpc_tower_counters.clone(),
+        rpc_guard,
     ));
+    let jsonrpc_service = args.rpclisten_http.map(|address| {
+        Arc::new(JsonRpcService::new(address.normalize(network.network_type.default_http_rpc_port()), rpc_core_service.clone()))
+    });
     let grpc_service =
         Arc::new(GrpcService::new(grpc_server_addr, config, rpc_core_service.clone(), args.rpc_max_clients, grpc_tower_counters));
 
@@ -473,6 +537,9 @@ do you confirm? (answer y/n or pass --yes to the Kashd command line to confirm a
     };
     async_runtime.register(rpc_core_service.clone());
     async_runtime.register(grpc_service);
+    if let Some(jsonrpc_service) = jsonrpc_service {
+        async_runtime.register(jsonrpc_service)
+    };
     async_runtime.register(p2p_service);
     async_runtime.register(consensus_monitor);
     async_runtime.register(mining_monitor);
@@ -1,4 +1,5 @@
 use kash_consensus_core::{
+    asset_type::AssetType,
     errors::tx::TxRuleError,
     tx::{TransactionId, TransactionOutpoint},
 };
@@ -11,6 +12,7 @@ pub enum RuleError {
     /// Note that following variants are converted:
     ///
     /// - TxRuleError::ImmatureCoinbaseSpend => RuleError::RejectImmatureSpend
+    /// - TxRuleError::ImmatureKrvRedeem => RuleError::RejectImmatureSpend
     /// - TxRuleError::MissingTxOutpoints => RuleError::RejectMissingOutpoint
     #[error(transparent)]
     RejectTxRule(TxRuleError),
@@ -87,6 +89,7 @@ impl From<TxRuleError> for RuleError {
     fn from(item: TxRuleError) -> Self {
         match item {
             TxRuleError::ImmatureCoinbaseSpend(_, _, _, _, _) => RuleError::RejectImmatureSpend(item),
+            TxRuleError::ImmatureKrvRedeem(_, _, _, _, _) => RuleError::RejectImmatureSpend(item),
             TxRuleError::MissingTxOutpoints => RuleError::RejectMissingOutpoint,
             _ => RuleError::RejectTxRule(item),
         }
@@ -115,14 +118,14 @@ pub enum NonStandardError {
     #[error("transaction output #{1}: non-standard script form")]
     RejectOutputScriptClass(TransactionId, usize),
 
-    #[error("transaction output #{1}: payment of {2} is dust")]
-    RejectDust(TransactionId, usize, u64),
+    #[error("transaction output #{1}: payment of {2} {3} is dust")]
+    RejectDust(TransactionId, usize, u64, AssetType),
 
     #[error("transaction input {1}: non-standard script form")]
     RejectInputScriptClass(TransactionId, usize),
 
-    #[error("transaction has {1} fees which is under the required amount of {2}")]
-    RejectInsufficientFee(TransactionId, u64, u64),
+    #[error("transaction has {1} {3} fees which is under the required amount of {2} {3}")]
+    RejectInsufficientFee(TransactionId, u64, u64, AssetType),
 
     #[error("transaction input #{1} has {2} signature operations which is more than the allowed max amount of {3}")]
     RejectSignatureCount(TransactionId, usize, u8, u8),
@@ -137,9 +140,9 @@ impl NonStandardError {
             NonStandardError::RejectSignatureScriptSize(id, _, _, _) => id,
             NonStandardError::RejectScriptPublicKeyVersion(id, _) => id,
             NonStandardError::RejectOutputScriptClass(id, _) => id,
-            NonStandardError::RejectDust(id, _, _) => id,
+            NonStandardError::RejectDust(id, _, _, _) => id,
             NonStandardError::RejectInputScriptClass(id, _) => id,
-            NonStandardError::RejectInsufficientFee(id, _, _) => id,
+            NonStandardError::RejectInsufficientFee(id, _, _, _) => id,
             NonStandardError::RejectSignatureCount(id, _, _, _) => id,
         }
     }
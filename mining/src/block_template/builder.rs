@@ -1,5 +1,7 @@
 use super::{errors::BuilderResult, policy::Policy};
-use crate::{block_template::selector::TransactionsSelector, model::candidate_tx::CandidateTransaction};
+use crate::{
+    block_template::selector::TransactionsSelector, mempool::config::StandardPolicy, model::candidate_tx::CandidateTransaction,
+};
 use kash_consensus_core::{
     api::ConsensusApi,
     block::{BlockTemplate, TemplateBuildMode},
@@ -17,8 +19,8 @@ pub(crate) struct BlockTemplateBuilder {
 }
 
 impl BlockTemplateBuilder {
-    pub(crate) fn new(max_block_mass: u64) -> Self {
-        let policy = Policy::new(max_block_mass);
+    pub(crate) fn new(max_block_mass: u64, standard_policy: StandardPolicy) -> Self {
+        let policy = Policy::new(max_block_mass, standard_policy);
         Self { policy }
     }
 
@@ -60,7 +62,8 @@ impl BlockTemplateBuilder {
     /// transactions until the block size reaches that minimum size.
     ///
     /// Any transactions which would cause the block to exceed the BlockMaxMass
-    /// policy setting, exceed the maximum allowed signature operations per block, or
+    /// policy setting, exceed the maximum allowed signature operations per block,
+    /// exceed the maximum number of mint/redeem transactions per block, or
     /// otherwise cause the block to be invalid are skipped.
     ///
     /// Given the above, a block generated by this function is of the following form:
@@ -107,12 +110,14 @@ impl BlockTemplateBuilder {
         let mut block_template = block_template_to_modify.clone();
 
         // The first transaction is always the coinbase transaction
+        let daa_score = block_template.block.header.daa_score;
         let coinbase_tx = &mut block_template.block.transactions[COINBASE_TRANSACTION_INDEX];
-        let new_payload = consensus.modify_coinbase_payload(coinbase_tx.payload.clone(), new_miner_data)?;
+        let new_payload = consensus.modify_coinbase_payload(coinbase_tx.payload.clone(), new_miner_data, daa_score)?;
         coinbase_tx.payload = new_payload;
-        if block_template.coinbase_has_red_reward {
-            // The last output is always the coinbase red blocks reward
-            coinbase_tx.outputs.last_mut().unwrap().script_public_key = new_miner_data.script_public_key.clone();
+        // The last outputs are always the coinbase red blocks rewards, one per rewarded asset
+        let red_reward_outputs_start = coinbase_tx.outputs.len() - block_template.coinbase_red_reward_outputs;
+        for output in coinbase_tx.outputs[red_reward_outputs_start..].iter_mut() {
+            output.script_public_key = new_miner_data.script_public_key.clone();
         }
         // Update the hash merkle root according to the modified transactions
         block_template.block.header.hash_merkle_root = calc_hash_merkle_root(block_template.block.transactions.iter());
@@ -1,3 +1,5 @@
+use crate::mempool::config::StandardPolicy;
+
 /// Policy houses the policy (configuration parameters) which is used to control
 /// the generation of block templates. See the documentation for
 /// NewBlockTemplate for more details on each of these parameters are used.
@@ -5,10 +7,14 @@
 pub(crate) struct Policy {
     /// max_block_mass is the maximum block mass to be used when generating a block template.
     pub(crate) max_block_mass: u64,
+
+    /// standard_policy holds the caps on the number of transactions per transaction action
+    /// to be used when generating a block template.
+    pub(crate) standard_policy: StandardPolicy,
 }
 
 impl Policy {
-    pub(crate) fn new(max_block_mass: u64) -> Self {
-        Self { max_block_mass }
+    pub(crate) fn new(max_block_mass: u64, standard_policy: StandardPolicy) -> Self {
+        Self { max_block_mass, standard_policy }
     }
 }
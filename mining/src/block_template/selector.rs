@@ -5,13 +5,14 @@ use std::collections::HashMap;
 use crate::model::candidate_tx::CandidateTransaction;
 
 use super::{
-    model::tx::{CandidateList, SelectableTransaction, SelectableTransactions, TransactionIndex},
+    model::tx::{Candidate, CandidateList, SelectableTransaction, SelectableTransactions, TransactionIndex},
     policy::Policy,
 };
 use kash_consensus_core::{
     block::TemplateTransactionSelector,
+    coinbase::AssetFees,
     subnets::SubnetworkId,
-    tx::{Transaction, TransactionId},
+    tx::{Transaction, TransactionAction, TransactionId},
 };
 
 /// ALPHA is a coefficient that defines how uniform the distribution of
@@ -42,14 +43,20 @@ pub(crate) struct TransactionsSelector {
     /// to the index of the tx in the `transactions` vec
     selected_txs_map: Option<HashMap<TransactionId, TransactionIndex>>,
 
+    /// Candidates skipped during the current selection since their action reached its cap per block. They are
+    /// restored as candidates once the selection completes, since a later rejection might free a slot for them
+    capped_txs: Vec<TransactionIndex>,
+
     // Inner state of the selection process
     candidate_list: CandidateList,
     overall_rejections: usize,
     used_count: usize,
     used_p: f64,
     total_mass: u64,
-    total_fees: u64,
+    /// Fees of the selected transactions, per asset the fees are paid in
+    total_fees: AssetFees,
     gas_usage_map: HashMap<SubnetworkId, u64>,
+    action_counts_map: HashMap<TransactionAction, u64>,
 }
 
 impl TransactionsSelector {
@@ -65,13 +72,15 @@ impl TransactionsSelector {
             selectable_txs: Default::default(),
             selected_txs: Default::default(),
             selected_txs_map: None,
+            capped_txs: Default::default(),
             candidate_list: Default::default(),
             overall_rejections: 0,
             used_count: 0,
             used_p: 0.0,
             total_mass: 0,
-            total_fees: 0,
+            total_fees: Default::default(),
             gas_usage_map: Default::default(),
+            action_counts_map: Default::default(),
         };
 
         // Create the selectable transactions
@@ -142,6 +151,26 @@ impl TransactionsSelector {
                 break;
             }
 
+            // Enforce maximum transactions per action per block (applies to asset conversions such as mint and redeem).
+            let max_action_count = self.policy.standard_policy.maximum_transactions_per_block(selected_tx.tx.action);
+            if let Some(max_action_count) = max_action_count {
+                if self.action_counts_map.get(&selected_tx.tx.action).copied().unwrap_or_default() >= max_action_count {
+                    trace!(
+                        "Tx {0} would exceed the maximum of {1} {2:?} transactions per block. As such, skipping it.",
+                        selected_tx.tx.id(),
+                        max_action_count,
+                        selected_tx.tx.action
+                    );
+
+                    // Skip for the current selection only
+                    selected_candidate.is_marked_for_deletion = true;
+                    self.used_count += 1;
+                    self.used_p += self.selectable_txs[selected_candidate.index].p;
+                    self.capped_txs.push(selected_candidate.index);
+                    continue;
+                }
+            }
+
             // Enforce maximum gas per subnetwork per block.
             // Also check for overflow.
             if !selected_tx.tx.subnetwork_id.is_builtin_or_native() {
@@ -180,7 +209,10 @@ impl TransactionsSelector {
             // result.
             self.selected_txs.push(selected_candidate.index);
             self.total_mass += selected_tx.calculated_mass;
-            self.total_fees += selected_tx.calculated_fee;
+            self.total_fees.add(selected_tx.tx.action.asset_transfer_types().0, selected_tx.calculated_fee);
+            if max_action_count.is_some() {
+                *self.action_counts_map.entry(selected_tx.tx.action).or_insert(0) += 1;
+            }
 
             trace!(
                 "Adding tx {0} (fee per megagram: {1})",
@@ -195,10 +227,21 @@ impl TransactionsSelector {
         }
 
         self.selected_txs.sort();
+        self.restore_capped_candidates();
 
         self.get_transactions()
     }
 
+    /// Appends the candidates skipped by the current selection due to action caps back to the candidate list
+    fn restore_capped_candidates(&mut self) {
+        for index in self.capped_txs.drain(..) {
+            let p = self.selectable_txs[index].p;
+            let total_p = self.candidate_list.total_p;
+            self.candidate_list.candidates.push(Candidate::new(index, total_p, total_p + p));
+            self.candidate_list.total_p += p;
+        }
+    }
+
     fn get_transactions(&self) -> Vec<Transaction> {
         // These transactions leave the selector so we clone
         self.selected_txs.iter().map(|x| self.transactions[*x].tx.as_ref().clone()).collect()
@@ -215,10 +258,15 @@ impl TransactionsSelector {
     /// calc_tx_value calculates a value to be used in transaction selection.
     /// The higher the number the more likely it is that the transaction will be
     /// included in the block.
+    ///
+    /// Since fees are denominated in the source asset of the transaction action, they are
+    /// not comparable across assets as raw amounts. The fee is hence expressed relative to
+    /// the minimum relay fee rate configured for the action.
     fn calc_tx_value(&self, transaction: &CandidateTransaction) -> f64 {
         let mass_limit = self.policy.max_block_mass as f64;
         let mass = transaction.calculated_mass as f64;
-        let fee = transaction.calculated_fee as f64;
+        let minimum_relay_transaction_fee = self.policy.standard_policy.minimum_relay_transaction_fee(transaction.tx.action).max(1);
+        let fee = transaction.calculated_fee as f64 / minimum_relay_transaction_fee as f64;
         if transaction.tx.subnetwork_id.is_builtin_or_native() {
             fee / mass / mass_limit
         } else {
@@ -242,10 +290,13 @@ impl TemplateTransactionSelector for TransactionsSelector {
         let tx_index = selected_txs_map.remove(&tx_id).expect("only previously selected txs can be rejected (and only once)");
         let tx = &self.transactions[tx_index];
         self.total_mass -= tx.calculated_mass;
-        self.total_fees -= tx.calculated_fee;
+        self.total_fees.sub(tx.tx.action.asset_transfer_types().0, tx.calculated_fee);
         if !tx.tx.subnetwork_id.is_builtin_or_native() {
             *self.gas_usage_map.get_mut(&tx.tx.subnetwork_id).expect("previously selected txs have an entry") -= tx.tx.gas;
         }
+        if let Some(action_count) = self.action_counts_map.get_mut(&tx.tx.action) {
+            *action_count -= 1;
+        }
         self.overall_rejections += 1;
     }
 
@@ -264,7 +315,7 @@ impl TemplateTransactionSelector for TransactionsSelector {
 mod tests {
     use super::*;
     use itertools::Itertools;
-    use kash_consensus_core::tx::TransactionAction::TransferKSH;
+    use kash_consensus_core::tx::TransactionAction::{MintKUSD, RedeemKSH, TransferKSH};
     use kash_consensus_core::{
         asset_type::AssetType::KSH,
         constants::{MAX_TX_IN_SEQUENCE_NUM, SOMPI_PER_KASH, TX_VERSION},
@@ -275,7 +326,10 @@ mod tests {
     use kash_txscript::{pay_to_script_hash_signature_script, test_helpers::op_true_script};
     use std::{collections::HashSet, sync::Arc};
 
-    use crate::{mempool::config::DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE, model::candidate_tx::CandidateTransaction};
+    use crate::{
+        mempool::config::{StandardPolicy, DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE},
+        model::candidate_tx::CandidateTransaction,
+    };
 
     #[test]
     fn test_reject_transaction() {
@@ -283,7 +337,7 @@ mod tests {
 
         // Create a vector of transactions differing by output value so they have unique ids
         let transactions = (0..TX_INITIAL_COUNT).map(|i| create_transaction(SOMPI_PER_KASH * (i + 1) as u64)).collect_vec();
-        let policy = Policy::new(100_000);
+        let policy = Policy::new(100_000, StandardPolicy::default());
         let mut selector = TransactionsSelector::new(policy, transactions);
         let (mut kept, mut rejected) = (HashSet::new(), HashSet::new());
         let mut reject_count = 32;
@@ -309,14 +363,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_maximum_transactions_per_action() {
+        const TX_COUNT_PER_ACTION: usize = 20;
+        const MAX_MINT_TRANSACTIONS: u64 = 5;
+        const MAX_REDEEM_TRANSACTIONS: u64 = 3;
+
+        // Transactions differ by output value so they have unique ids (the action is not committed to by TX_VERSION ids)
+        let transactions = [TransferKSH, MintKUSD, RedeemKSH]
+            .into_iter()
+            .enumerate()
+            .flat_map(|(j, action)| {
+                (0..TX_COUNT_PER_ACTION)
+                    .map(move |i| create_transaction_with_action(SOMPI_PER_KASH * (j * TX_COUNT_PER_ACTION + i + 1) as u64, action))
+            })
+            .collect_vec();
+        let standard_policy = StandardPolicy {
+            maximum_mint_transactions_per_block: MAX_MINT_TRANSACTIONS,
+            maximum_redeem_transactions_per_block: MAX_REDEEM_TRANSACTIONS,
+            ..Default::default()
+        };
+        let mut selector = TransactionsSelector::new(Policy::new(1_000_000, standard_policy), transactions);
+        let count_action = |txs: &[Transaction], action: TransactionAction| txs.iter().filter(|tx| tx.action == action).count();
+
+        let selected_txs = selector.select_transactions();
+        assert_eq!(count_action(&selected_txs, TransferKSH), TX_COUNT_PER_ACTION, "transfers are not capped");
+        assert_eq!(count_action(&selected_txs, MintKUSD), MAX_MINT_TRANSACTIONS as usize);
+        assert_eq!(count_action(&selected_txs, RedeemKSH), MAX_REDEEM_TRANSACTIONS as usize);
+
+        // A rejected mint transaction frees a slot, which is refilled by one of the mint candidates skipped so far
+        let rejected_mint = selected_txs.iter().find(|tx| tx.action == MintKUSD).unwrap().id();
+        selector.reject_selection(rejected_mint);
+        assert_eq!(selector.action_counts_map[&MintKUSD], MAX_MINT_TRANSACTIONS - 1);
+        let refilled_txs = selector.select_transactions();
+        assert_eq!(refilled_txs.len(), 1);
+        assert_eq!(refilled_txs[0].action, MintKUSD);
+        assert_ne!(refilled_txs[0].id(), rejected_mint);
+        assert!(!selected_txs.iter().any(|tx| tx.id() == refilled_txs[0].id()));
+        assert_eq!(selector.action_counts_map[&MintKUSD], MAX_MINT_TRANSACTIONS);
+
+        // Once the cap is reached again, no further candidates are selected
+        assert!(selector.select_transactions().is_empty());
+    }
+
+    #[test]
+    fn test_fee_value_relative_to_asset_policy() {
+        // Two transactions paying the same raw fee amount, in assets with a tenfold difference in minimum relay fee rates
+        let transfer = create_transaction(SOMPI_PER_KASH);
+        let mut mint = create_transaction_with_action(SOMPI_PER_KASH, MintKUSD);
+        mint.calculated_mass = transfer.calculated_mass;
+        let mut standard_policy = StandardPolicy::default();
+        standard_policy.action_policy_mut(MintKUSD).minimum_relay_transaction_fee = Some(DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE * 10);
+
+        let selector = TransactionsSelector::new(Policy::new(1_000_000, standard_policy), vec![transfer.clone(), mint.clone()]);
+        assert!(selector.calc_tx_value(&transfer) > selector.calc_tx_value(&mint));
+    }
+
     fn create_transaction(value: u64) -> CandidateTransaction {
+        create_transaction_with_action(value, TransferKSH)
+    }
+
+    fn create_transaction_with_action(value: u64, action: TransactionAction) -> CandidateTransaction {
         let previous_outpoint = TransactionOutpoint::new(TransactionId::default(), 0);
         let (script_public_key, redeem_script) = op_true_script();
         let signature_script = pay_to_script_hash_signature_script(redeem_script, vec![]).expect("the redeem script is canonical");
 
         let input = TransactionInput::new(previous_outpoint, signature_script, MAX_TX_IN_SEQUENCE_NUM, 1);
         let output = TransactionOutput::new(value - DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE, script_public_key, KSH);
-        let tx = Arc::new(Transaction::new(TX_VERSION, vec![input], vec![output], TransferKSH, 0, SUBNETWORK_ID_NATIVE, 0, vec![]));
+        let tx = Arc::new(Transaction::new(TX_VERSION, vec![input], vec![output], action, 0, SUBNETWORK_ID_NATIVE, 0, vec![]));
         let calculated_mass = transaction_estimated_serialized_size(&tx);
         let calculated_fee = DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE;
 
@@ -3,7 +3,7 @@ use crate::{
     cache::BlockTemplateCache,
     errors::MiningManagerResult,
     mempool::{
-        config::Config,
+        config::{Config, StandardPolicy},
         model::tx::{MempoolTransaction, TxRemovalReason},
         populate_entries_and_try_validate::{
             populate_mempool_transactions_in_parallel, validate_mempool_transaction, validate_mempool_transactions_in_parallel,
@@ -25,8 +25,9 @@ use kash_consensus_core::{
     block::{BlockTemplate, TemplateBuildMode},
     coinbase::MinerData,
     errors::{block::RuleError as BlockRuleError, tx::TxRuleError},
-    tx::{MutableTransaction, Transaction, TransactionId, TransactionOutput},
+    tx::{MutableTransaction, Transaction, TransactionAction, TransactionId, TransactionOutput},
 };
+use kash_consensus_notify::{notification::MempoolChangeReason, root::ConsensusNotificationRoot};
 use kash_consensusmanager::{spawn_blocking, ConsensusProxy};
 use kash_core::{debug, error, info, time::Stopwatch, warn};
 use kash_mining_errors::{manager::MiningManagerError, mempool::RuleError};
@@ -50,7 +51,7 @@ impl MiningManager {
         counters: Arc<MiningCounters>,
     ) -> Self {
         let config = Config::build_default(target_time_per_block, relay_non_std_transactions, max_block_mass);
-        Self::with_config(config, cache_lifetime, counters)
+        Self::with_config(config, cache_lifetime, counters, None)
     }
 
     pub fn new_with_extended_config(
@@ -58,17 +59,25 @@ impl MiningManager {
         relay_non_std_transactions: bool,
         max_block_mass: u64,
         ram_scale: f64,
+        standard_policy: StandardPolicy,
         cache_lifetime: Option<u64>,
         counters: Arc<MiningCounters>,
+        notification_root: Option<Arc<ConsensusNotificationRoot>>,
     ) -> Self {
-        let config =
-            Config::build_default(target_time_per_block, relay_non_std_transactions, max_block_mass).apply_ram_scale(ram_scale);
-        Self::with_config(config, cache_lifetime, counters)
+        let config = Config::build_default(target_time_per_block, relay_non_std_transactions, max_block_mass)
+            .apply_ram_scale(ram_scale)
+            .apply_standard_policy(standard_policy);
+        Self::with_config(config, cache_lifetime, counters, notification_root)
     }
 
-    pub(crate) fn with_config(config: Config, cache_lifetime: Option<u64>, counters: Arc<MiningCounters>) -> Self {
+    pub(crate) fn with_config(
+        config: Config,
+        cache_lifetime: Option<u64>,
+        counters: Arc<MiningCounters>,
+        notification_root: Option<Arc<ConsensusNotificationRoot>>,
+    ) -> Self {
         let config = Arc::new(config);
-        let mempool = RwLock::new(Mempool::new(config.clone(), counters.clone()));
+        let mempool = RwLock::new(Mempool::new(config.clone(), counters.clone(), notification_root));
         let block_template_cache = BlockTemplateCache::new(cache_lifetime);
         Self { config, block_template_cache, mempool, counters }
     }
@@ -78,8 +87,12 @@ impl MiningManager {
         let mut cache_lock = self.block_template_cache.lock(virtual_state_approx_id);
         let immutable_template = cache_lock.get_immutable_cached_template();
 
-        // We first try and use a cached template if not expired
-        if let Some(immutable_template) = immutable_template {
+        // We first try and use a cached template if not expired. Note that a template committing to a price report
+        // is never modified into one without a report, since reports are mandatory from the price oracle activation on.
+        // A new template is built instead, which fails if a report is required
+        if let Some(immutable_template) =
+            immutable_template.filter(|template| template.miner_data.price_report.is_none() || miner_data.price_report.is_some())
+        {
             drop(cache_lock);
             if immutable_template.miner_data == *miner_data {
                 return Ok(immutable_template.as_ref().clone());
@@ -104,7 +117,8 @@ impl MiningManager {
             attempts += 1;
 
             let transactions = self.block_candidate_transactions();
-            let block_template_builder = BlockTemplateBuilder::new(self.config.maximum_mass_per_block);
+            let block_template_builder =
+                BlockTemplateBuilder::new(self.config.maximum_mass_per_block, self.config.standard_policy.clone());
             let build_mode = if attempts < self.config.maximum_build_block_template_attempts {
                 TemplateBuildMode::Standard
             } else {
@@ -205,7 +219,7 @@ impl MiningManager {
 
     #[cfg(test)]
     pub(crate) fn block_template_builder(&self) -> BlockTemplateBuilder {
-        BlockTemplateBuilder::new(self.config.maximum_mass_per_block)
+        BlockTemplateBuilder::new(self.config.maximum_mass_per_block, self.config.standard_policy.clone())
     }
 
     /// validate_and_insert_transaction validates the given transaction, and
@@ -237,9 +251,14 @@ impl MiningManager {
         let validation_result = validate_mempool_transaction(consensus, &mut transaction);
         // write lock on mempool
         let mut mempool = self.mempool.write();
-        if let Some(accepted_transaction) =
-            mempool.post_validate_and_insert_transaction(consensus, validation_result, transaction, priority, orphan)?
-        {
+        if let Some(accepted_transaction) = mempool.post_validate_and_insert_transaction(
+            consensus,
+            validation_result,
+            transaction,
+            priority,
+            orphan,
+            MempoolChangeReason::Accepted,
+        )? {
             let unorphaned_transactions = mempool.get_unorphaned_transactions_after_accepted_transaction(&accepted_transaction);
             drop(mempool);
 
@@ -297,6 +316,7 @@ impl MiningManager {
                         transaction,
                         priority,
                         Orphan::Forbidden,
+                        MempoolChangeReason::OrphanResolved,
                     ) {
                         Ok(Some(accepted_transaction)) => {
                             accepted_transactions.push(accepted_transaction.clone());
@@ -386,7 +406,14 @@ impl MiningManager {
             let mut mempool = self.mempool.write();
             let txs = chunk.flat_map(|(transaction, validation_result)| {
                 let transaction_id = transaction.id();
-                match mempool.post_validate_and_insert_transaction(consensus, validation_result, transaction, priority, orphan) {
+                match mempool.post_validate_and_insert_transaction(
+                    consensus,
+                    validation_result,
+                    transaction,
+                    priority,
+                    orphan,
+                    MempoolChangeReason::Accepted,
+                ) {
                     Ok(Some(accepted_transaction)) => {
                         insert_results.push(Ok(accepted_transaction.clone()));
                         self.counters.increase_tx_counts(1, priority);
@@ -517,7 +544,7 @@ impl MiningManager {
         for chunk in &expired_low_priority_transactions.iter().chunks(24) {
             let mut mempool = self.mempool.write();
             chunk.into_iter().for_each(|tx| {
-                if let Err(err) = mempool.remove_transaction(tx, true, TxRemovalReason::Muted, "") {
+                if let Err(err) = mempool.remove_transaction(tx, true, TxRemovalReason::MutedExpired, "") {
                     warn!("Failed to remove transaction {} from mempool: {}", tx, err);
                 }
             });
@@ -718,14 +745,19 @@ impl MiningManager {
     }
 
     /// is_transaction_output_dust returns whether or not the passed transaction output
-    /// amount is considered dust or not based on the configured minimum transaction
-    /// relay fee.
+    /// amount is considered dust or not based on the dust relay fee configured for
+    /// the asset of the output, possibly overridden for the action of the transaction.
     ///
-    /// Dust is defined in terms of the minimum transaction relay fee. In particular,
-    /// if the cost to the network to spend coins is more than 1/3 of the minimum
-    /// transaction relay fee, it is considered dust.
-    pub fn is_transaction_output_dust(&self, transaction_output: &TransactionOutput) -> bool {
-        self.mempool.read().is_transaction_output_dust(transaction_output)
+    /// Dust is defined in terms of the dust relay fee. In particular, if the cost
+    /// to the network to spend coins is more than 1/3 of the dust relay fee, it is
+    /// considered dust.
+    pub fn is_transaction_output_dust(&self, transaction_output: &TransactionOutput, action: TransactionAction) -> bool {
+        self.mempool.read().is_transaction_output_dust(transaction_output, action)
+    }
+
+    /// Returns the per-asset and per-action standardness policy applied by the mempool and block template builder
+    pub fn standard_policy(&self) -> &StandardPolicy {
+        &self.config.standard_policy
     }
 
     pub fn has_accepted_transaction(&self, transaction_id: &TransactionId) -> bool {
@@ -871,4 +903,8 @@ impl MiningManagerProxy {
     pub fn snapshot(&self) -> MempoolCountersSnapshot {
         self.inner.counters.snapshot()
     }
+
+    pub fn standard_policy(&self) -> StandardPolicy {
+        self.inner.standard_policy().clone()
+    }
 }
@@ -29,11 +29,17 @@ mod tests {
             TransactionOutput, UtxoEntry,
         },
     };
+    use kash_consensus_notify::{
+        notification::{MempoolChangeReason, Notification},
+        root::ConsensusNotificationRoot,
+    };
     use kash_hashes::Hash;
+    use kash_notify::{scope::MempoolChangedScope, subscriber::SubscriptionManager};
     use kash_txscript::{
         pay_to_address_script, pay_to_script_hash_signature_script,
         test_helpers::{create_transaction, op_true_script},
     };
+    use kash_utils::channel::Channel;
     use std::sync::Arc;
     use tokio::sync::mpsc::{error::TryRecvError, unbounded_channel};
 
@@ -296,6 +302,59 @@ mod tests {
         }
     }
 
+    // test_mempool_changed_notifications verifies that transactions entering and leaving the mempool are notified when subscribed.
+    #[tokio::test]
+    async fn test_mempool_changed_notifications() {
+        let consensus = Arc::new(ConsensusMock::new());
+        let counters = Arc::new(MiningCounters::default());
+        let channel = Channel::<Notification>::default();
+        let notification_root = Arc::new(ConsensusNotificationRoot::new(channel.sender()));
+        let config = Config::build_default(TARGET_TIME_PER_BLOCK, false, MAX_BLOCK_MASS);
+        let mining_manager = MiningManager::with_config(config, None, counters, Some(notification_root.clone()));
+
+        // Nothing is notified without a subscription
+        let transaction = create_transaction_with_utxo_entry(0, 0);
+        let result = mining_manager.validate_and_insert_transaction(
+            consensus.as_ref(),
+            transaction.tx.as_ref().clone(),
+            Priority::Low,
+            Orphan::Allowed,
+        );
+        assert!(result.is_ok(), "the insertion of a new valid transaction in the mempool failed");
+        assert!(channel.try_recv().is_err(), "no notification should be sent without a subscription");
+
+        notification_root.start_notify(0, MempoolChangedScope {}.into()).await.expect("subscribing to MempoolChanged should succeed");
+
+        let transaction = create_transaction_with_utxo_entry(1, 0);
+        let result = mining_manager.validate_and_insert_transaction(
+            consensus.as_ref(),
+            transaction.tx.as_ref().clone(),
+            Priority::Low,
+            Orphan::Allowed,
+        );
+        assert!(result.is_ok(), "the insertion of a new valid transaction in the mempool failed");
+        match channel.try_recv() {
+            Ok(Notification::MempoolChanged(notification)) => {
+                assert_eq!(notification.transaction_id, transaction.id());
+                assert_eq!(notification.reason, MempoolChangeReason::Accepted);
+                assert_eq!(notification.script_public_keys.as_slice(), &[transaction.tx.outputs[0].script_public_key.clone()]);
+            }
+            other => panic!("expected a MempoolChanged notification but got {other:?}"),
+        }
+
+        let block = build_block_transactions(std::iter::once(transaction.tx.as_ref()));
+        let result = mining_manager.handle_new_block_transactions(consensus.as_ref(), 2, &block);
+        assert!(result.is_ok(), "the handling by the mempool of the transactions of a block should succeed but returned {result:?}");
+        match channel.try_recv() {
+            Ok(Notification::MempoolChanged(notification)) => {
+                assert_eq!(notification.transaction_id, transaction.id());
+                assert_eq!(notification.reason, MempoolChangeReason::IncludedInBlock);
+            }
+            other => panic!("expected a MempoolChanged notification but got {other:?}"),
+        }
+        assert!(channel.try_recv().is_err(), "no further notification is expected");
+    }
+
     #[test]
     // test_double_spend_with_block verifies that any transactions which are now double spends as a result of the block's new transactions
     // will be removed from the mempool.
@@ -587,7 +646,7 @@ mod tests {
         // Limit the orphan pool to 2 transactions
         config.maximum_orphan_transaction_count = 2;
         let counters = Arc::new(MiningCounters::default());
-        let mining_manager = MiningManager::with_config(config.clone(), None, counters);
+        let mining_manager = MiningManager::with_config(config.clone(), None, counters, None);
 
         // Create pairs of transaction parent-and-child pairs according to the test vector
         let (parent_txs, child_txs) = create_arrays_of_parent_and_children_transactions(&consensus, tests.len());
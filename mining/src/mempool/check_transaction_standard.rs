@@ -5,7 +5,7 @@ use crate::mempool::{
 use kash_consensus_core::{
     constants::{MAX_SCRIPT_PUBLIC_KEY_VERSION, MAX_SOMPI},
     mass,
-    tx::{MutableTransaction, PopulatedTransaction, TransactionOutput},
+    tx::{MutableTransaction, PopulatedTransaction, TransactionAction, TransactionOutput},
 };
 use kash_txscript::{get_sig_op_count, is_unspendable, script_class::ScriptClass};
 
@@ -95,8 +95,8 @@ impl Mempool {
                 return Err(NonStandardError::RejectOutputScriptClass(transaction_id, i));
             }
 
-            if self.is_transaction_output_dust(output) {
-                return Err(NonStandardError::RejectDust(transaction_id, i, output.value));
+            if self.is_transaction_output_dust(output, transaction.tx.action) {
+                return Err(NonStandardError::RejectDust(transaction_id, i, output.value, output.asset_type));
             }
         }
 
@@ -104,15 +104,16 @@ impl Mempool {
     }
 
     /// is_transaction_output_dust returns whether or not the passed transaction output
-    /// amount is considered dust or not based on the configured minimum transaction
-    /// relay fee.
+    /// amount is considered dust or not based on the dust relay fee configured for
+    /// the asset of the output, possibly overridden for the action of the transaction.
     ///
-    /// Dust is defined in terms of the minimum transaction relay fee. In particular,
-    /// if the cost to the network to spend coins is more than 1/3 of the minimum
-    /// transaction relay fee, it is considered dust.
+    /// Dust is defined in terms of the dust relay fee. In particular, if the cost to
+    /// the network to spend coins is more than 1/3 of the dust relay fee, it is
+    /// considered dust. Since the value of an output is denominated in its own asset,
+    /// each asset has a separately configured dust relay fee.
     ///
     /// It is exposed by [MiningManager] for use by transaction generators and wallets.
-    pub(crate) fn is_transaction_output_dust(&self, transaction_output: &TransactionOutput) -> bool {
+    pub(crate) fn is_transaction_output_dust(&self, transaction_output: &TransactionOutput, action: TransactionAction) -> bool {
         // Unspendable outputs are considered dust.
         if is_unspendable::<PopulatedTransaction>(transaction_output.script_public_key.script()) {
             return true;
@@ -138,25 +139,24 @@ impl Mempool {
         let total_serialized_size = mass::transaction_output_estimated_serialized_size(transaction_output) + 148;
 
         // The output is considered dust if the cost to the network to spend the
-        // coins is more than 1/3 of the minimum free transaction relay fee.
-        // mp.config.MinimumRelayTransactionFee is in sompi/KB, so multiply
-        // by 1000 to convert to bytes.
+        // coins is more than 1/3 of the dust relay fee of the output asset.
+        // The dust relay fee is in sompi/KB, so multiply by 1000 to convert
+        // to bytes.
         //
         // Using the typical values for a pay-to-pubkey transaction from
-        // the breakdown above and the default minimum free transaction relay
-        // fee of 1000, this equates to values less than 546 sompi being
-        // considered dust.
+        // the breakdown above and the default dust relay fee of 1000,
+        // this equates to values less than 546 sompi being considered dust.
         //
         // The following is equivalent to (value/total_serialized_size) * (1/3) * 1000
         // without needing to do floating point math.
         //
         // Since the multiplication may overflow a u64, 2 separate calculation paths
         // are considered to avoid overflowing.
+        let dust_relay_transaction_fee = self.config.standard_policy.dust_relay_transaction_fee(action, transaction_output.asset_type);
         match transaction_output.value.checked_mul(1000) {
-            Some(value_1000) => value_1000 / (3 * total_serialized_size) < self.config.minimum_relay_transaction_fee,
+            Some(value_1000) => value_1000 / (3 * total_serialized_size) < dust_relay_transaction_fee,
             None => {
-                (transaction_output.value as u128 * 1000 / (3 * total_serialized_size as u128))
-                    < self.config.minimum_relay_transaction_fee as u128
+                (transaction_output.value as u128 * 1000 / (3 * total_serialized_size as u128)) < dust_relay_transaction_fee as u128
             }
         }
     }
@@ -167,7 +167,8 @@ impl Mempool {
     /// standard form and, for pay-to-script-hash, does not have more than
     /// maxStandardP2SHSigOps signature operations.
     /// In addition, makes sure that the transaction's fee is above the minimum for acceptance
-    /// into the mempool and relay.
+    /// into the mempool and relay, as configured for the asset the fee is paid in (which is
+    /// the source asset of the transaction action).
     pub(crate) fn check_transaction_standard_in_context(&self, transaction: &MutableTransaction) -> NonStandardResult<()> {
         let transaction_id = transaction.id();
         let contextual_mass = transaction.tx.mass();
@@ -197,9 +198,15 @@ impl Mempool {
             }
 
             // TODO: For now, until wallets adapt, we don't require fee as function of full contextual_mass (but the fee/mass ratio will affect tx selection to block template)
-            let minimum_fee = self.minimum_required_transaction_relay_fee(transaction.calculated_compute_mass.unwrap());
+            let minimum_fee =
+                self.minimum_required_transaction_relay_fee(transaction.calculated_compute_mass.unwrap(), transaction.tx.action);
             if transaction.calculated_fee.unwrap() < minimum_fee {
-                return Err(NonStandardError::RejectInsufficientFee(transaction_id, transaction.calculated_fee.unwrap(), minimum_fee));
+                return Err(NonStandardError::RejectInsufficientFee(
+                    transaction_id,
+                    transaction.calculated_fee.unwrap(),
+                    minimum_fee,
+                    transaction.tx.action.asset_transfer_types().0,
+                ));
             }
         }
 
@@ -207,16 +214,19 @@ impl Mempool {
     }
 
     /// minimum_required_transaction_relay_fee returns the minimum transaction fee required
-    /// for a transaction with the passed mass to be accepted into the mempool and relayed.
-    fn minimum_required_transaction_relay_fee(&self, mass: u64) -> u64 {
+    /// for a transaction with the passed mass and action to be accepted into the mempool and
+    /// relayed. The fee is denominated in the source asset of the action.
+    fn minimum_required_transaction_relay_fee(&self, mass: u64, action: TransactionAction) -> u64 {
+        let minimum_relay_transaction_fee = self.config.standard_policy.minimum_relay_transaction_fee(action);
+
         // Calculate the minimum fee for a transaction to be allowed into the
         // mempool and relayed by scaling the base fee. MinimumRelayTransactionFee is in
         // sompi/kg so multiply by mass (which is in grams) and divide by 1000 to get
         // minimum sompis.
-        let mut minimum_fee = (mass * self.config.minimum_relay_transaction_fee) / 1000;
+        let mut minimum_fee = (mass * minimum_relay_transaction_fee) / 1000;
 
         if minimum_fee == 0 {
-            minimum_fee = self.config.minimum_relay_transaction_fee;
+            minimum_fee = minimum_relay_transaction_fee;
         }
 
         // Set the minimum fee to the maximum possible value if the calculated
@@ -235,11 +245,11 @@ mod tests {
         MiningCounters,
     };
     use kash_addresses::{Address, Prefix, Version};
-    use kash_consensus_core::asset_type::AssetType::KSH;
-    use kash_consensus_core::tx::TransactionAction::TransferKSH;
+    use kash_consensus_core::asset_type::{AssetType, AssetType::KSH};
+    use kash_consensus_core::tx::TransactionAction::{MintKUSD, TransferKRV, TransferKSH, TransferKUSD};
     use kash_consensus_core::{
         config::params::Params,
-        constants::{MAX_TX_IN_SEQUENCE_NUM, SOMPI_PER_KASH, TX_VERSION},
+        constants::{MAX_TX_IN_SEQUENCE_NUM, SOMPI_PER_KASH, TX_VERSION, TX_VERSION_ASSET_COMMITMENT},
         network::NetworkType,
         subnets::SUBNETWORK_ID_NATIVE,
         tx::{ScriptPublicKey, ScriptVec, Transaction, TransactionInput, TransactionOutpoint, TransactionOutput},
@@ -290,19 +300,39 @@ mod tests {
 
         for test in tests.iter() {
             for net in NetworkType::iter() {
-                let params: Params = net.into();
-                let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
-                config.minimum_relay_transaction_fee = test.minimum_relay_transaction_fee;
-                let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
+                for asset_type in AssetType::ALL {
+                    let params: Params = net.into();
+                    let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
+                    config.standard_policy.asset_policy_mut(asset_type).minimum_relay_transaction_fee =
+                        test.minimum_relay_transaction_fee;
+                    let counters = Arc::new(MiningCounters::default());
+                    let mempool = Mempool::new(Arc::new(config), counters, None);
+
+                    let got = mempool.minimum_required_transaction_relay_fee(test.size, transfer_action(asset_type));
+                    if got != test.want {
+                        println!(
+                            "test_calc_min_required_tx_relay_fee test '{}' failed for {}: got {}, want {}",
+                            test.name, asset_type, got, test.want
+                        );
+                    }
+                    assert_eq!(test.want, got);
 
-                let got = mempool.minimum_required_transaction_relay_fee(test.size);
-                if got != test.want {
-                    println!("test_calc_min_required_tx_relay_fee test '{}' failed: got {}, want {}", test.name, got, test.want);
+                    // The relay fees of other assets are left untouched
+                    for other_asset_type in AssetType::ALL.into_iter().filter(|&other| other != asset_type) {
+                        let other_fee = mempool.config.standard_policy.asset_policy(other_asset_type).minimum_relay_transaction_fee;
+                        assert_eq!(DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE, other_fee);
+                    }
                 }
-                assert_eq!(test.want, got);
             }
         }
+
+        // An action policy overrides the policy of the asset the fee is paid in, for this action only
+        let params: Params = NetworkType::Mainnet.into();
+        let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
+        config.standard_policy.action_policy_mut(MintKUSD).minimum_relay_transaction_fee = Some(5000);
+        let mempool = Mempool::new(Arc::new(config), Arc::new(MiningCounters::default()), None);
+        assert_eq!(mempool.minimum_required_transaction_relay_fee(1500, MintKUSD), 7500);
+        assert_eq!(mempool.minimum_required_transaction_relay_fee(1500, TransferKSH), 1500);
     }
 
     #[test]
@@ -319,76 +349,102 @@ mod tests {
         struct Test {
             name: &'static str,
             tx_out: TransactionOutput,
-            minimum_relay_transaction_fee: u64,
+            dust_relay_transaction_fee: u64,
             is_dust: bool,
         }
 
         let tests = vec![
             // Any value is allowed with a zero relay fee.
             Test {
-                name: "zero value with zero relay fee",
+                name: "zero value with zero dust relay fee",
                 tx_out: TransactionOutput::new(0, script_public_key.clone(), KSH),
-                minimum_relay_transaction_fee: 0,
+                dust_relay_transaction_fee: 0,
                 is_dust: false,
             },
-            // Zero value is dust with any relay fee"
+            // Zero value is dust with any dust relay fee"
             Test {
                 name: "zero value with very small tx fee",
                 tx_out: TransactionOutput::new(0, script_public_key.clone(), KSH),
-                minimum_relay_transaction_fee: 1,
+                dust_relay_transaction_fee: 1,
                 is_dust: true,
             },
             Test {
                 name: "36 byte public key script with value 605",
                 tx_out: TransactionOutput::new(605, script_public_key.clone(), KSH),
-                minimum_relay_transaction_fee: 1000,
+                dust_relay_transaction_fee: 1000,
                 is_dust: true,
             },
             Test {
                 name: "36 byte public key script with value 606",
                 tx_out: TransactionOutput::new(606, script_public_key.clone(), KSH),
-                minimum_relay_transaction_fee: 1000,
+                dust_relay_transaction_fee: 1000,
                 is_dust: false,
             },
             // Maximum allowed value is never dust.
             Test {
                 name: "max sompi amount is never dust",
                 tx_out: TransactionOutput::new(MAX_SOMPI, script_public_key.clone(), KSH),
-                minimum_relay_transaction_fee: 1000,
+                dust_relay_transaction_fee: 1000,
                 is_dust: false,
             },
             // Maximum uint64 value causes NO overflow.
             // Rust rewrite: caution, this differs from the golang version
             Test {
                 name: "maximum uint64 value",
-                tx_out: TransactionOutput::new(u64::MAX, script_public_key, KSH),
-                minimum_relay_transaction_fee: u64::MAX,
+                tx_out: TransactionOutput::new(u64::MAX, script_public_key.clone(), KSH),
+                dust_relay_transaction_fee: u64::MAX,
                 is_dust: false,
             },
             // Unspendable script_public_key due to an invalid public key script.
             Test {
                 name: "unspendable script_public_key",
                 tx_out: TransactionOutput::new(5000, invalid_script_public_key, KSH),
-                minimum_relay_transaction_fee: 0,
+                dust_relay_transaction_fee: 0,
                 is_dust: true,
             },
         ];
         for test in tests {
             for net in NetworkType::iter() {
-                let params: Params = net.into();
-                let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
-                config.minimum_relay_transaction_fee = test.minimum_relay_transaction_fee;
-                let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
-
-                println!("test_is_transaction_output_dust test '{}' ", test.name);
-                let res = mempool.is_transaction_output_dust(&test.tx_out);
-                if res != test.is_dust {
-                    println!("test_is_transaction_output_dust test '{}' failed: got {}, want {}", test.name, res, test.is_dust);
+                for asset_type in AssetType::ALL {
+                    let params: Params = net.into();
+                    let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
+                    config.standard_policy.asset_policy_mut(asset_type).dust_relay_transaction_fee = test.dust_relay_transaction_fee;
+                    let counters = Arc::new(MiningCounters::default());
+                    let mempool = Mempool::new(Arc::new(config), counters, None);
+
+                    println!("test_is_transaction_output_dust test '{}' for {}", test.name, asset_type);
+                    let mut tx_out = test.tx_out.clone();
+                    tx_out.asset_type = asset_type;
+                    let res = mempool.is_transaction_output_dust(&tx_out, transfer_action(asset_type));
+                    if res != test.is_dust {
+                        println!(
+                            "test_is_transaction_output_dust test '{}' failed for {}: got {}, want {}",
+                            test.name, asset_type, res, test.is_dust
+                        );
+                    }
+                    assert_eq!(test.is_dust, res);
                 }
-                assert_eq!(test.is_dust, res);
             }
         }
+
+        // An action policy overrides the dust relay fee of the destination asset of the action only
+        let params: Params = NetworkType::Mainnet.into();
+        let mut config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
+        config.standard_policy.action_policy_mut(MintKUSD).dust_relay_transaction_fee = Some(0);
+        let mempool = Mempool::new(Arc::new(config), Arc::new(MiningCounters::default()), None);
+        let kusd_output = TransactionOutput::new(605, script_public_key.clone(), AssetType::KUSD);
+        let ksh_output = TransactionOutput::new(605, script_public_key, KSH);
+        assert!(!mempool.is_transaction_output_dust(&kusd_output, MintKUSD));
+        assert!(mempool.is_transaction_output_dust(&kusd_output, TransferKUSD));
+        assert!(mempool.is_transaction_output_dust(&ksh_output, MintKUSD));
+    }
+
+    fn transfer_action(asset_type: AssetType) -> TransactionAction {
+        match asset_type {
+            AssetType::KSH => TransferKSH,
+            AssetType::KUSD => TransferKUSD,
+            AssetType::KRV => TransferKRV,
+        }
     }
 
     #[test]
@@ -437,7 +493,7 @@ mod tests {
                 name: "Transaction version too high",
                 mtx: new_mtx(
                     Transaction::new(
-                        TX_VERSION + 1,
+                        TX_VERSION_ASSET_COMMITMENT + 1,
                         vec![dummy_tx_input.clone()],
                         vec![dummy_tx_out.clone()],
                         TransferKSH,
@@ -568,7 +624,7 @@ mod tests {
                 let params: Params = net.into();
                 let config = Config::build_default(params.target_time_per_block, false, params.max_block_mass);
                 let counters = Arc::new(MiningCounters::default());
-                let mempool = Mempool::new(Arc::new(config), counters);
+                let mempool = Mempool::new(Arc::new(config), counters, None);
 
                 // Ensure standard-ness is as expected.
                 println!("test_check_transaction_standard_in_isolation test '{}' ", test.name);
@@ -1,4 +1,8 @@
-use kash_consensus_core::constants::TX_VERSION;
+use kash_consensus_core::{
+    asset_type::AssetType,
+    constants::{TX_VERSION, TX_VERSION_ASSET_COMMITMENT},
+    tx::TransactionAction,
+};
 
 pub(crate) const DEFAULT_MAXIMUM_TRANSACTION_COUNT: u64 = 1_000_000;
 pub(crate) const DEFAULT_MAXIMUM_READY_TRANSACTION_COUNT: u64 = 50_000;
@@ -20,12 +24,132 @@ pub(crate) const DEFAULT_MAXIMUM_ORPHAN_TRANSACTION_COUNT: u64 = 50;
 /// the mempool and relayed. It is specified in sompi per 1kg (or 1000 grams) of transaction mass.
 pub(crate) const DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE: u64 = 1000;
 
+/// DEFAULT_MAXIMUM_CONVERSION_TRANSACTIONS_PER_BLOCK is the default cap on the number of mint or redeem
+/// transactions selected into a single block template. By default these are not capped.
+pub(crate) const DEFAULT_MAXIMUM_CONVERSION_TRANSACTIONS_PER_BLOCK: u64 = u64::MAX;
+
 /// Standard transaction version range might be different from what consensus accepts, therefore
 /// we define separate values in mempool.
-/// However, currently there's exactly one transaction version, so mempool accepts the same version
-/// as consensus.
+/// However, currently mempool accepts the same version range as consensus, which in turn enforces the
+/// exact version expected at the virtual DAA score (see the asset commitment hardfork).
 pub(crate) const DEFAULT_MINIMUM_STANDARD_TRANSACTION_VERSION: u16 = TX_VERSION;
-pub(crate) const DEFAULT_MAXIMUM_STANDARD_TRANSACTION_VERSION: u16 = TX_VERSION;
+pub(crate) const DEFAULT_MAXIMUM_STANDARD_TRANSACTION_VERSION: u16 = TX_VERSION_ASSET_COMMITMENT;
+
+/// Standardness rules for the outputs and fees denominated in a single [`AssetType`].
+///
+/// Both rates are expressed in sompi of the asset per 1kg (or 1000 grams) of transaction mass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetPolicy {
+    /// Minimum fee rate for a transaction paying its fee in this asset to be accepted to the mempool and relayed.
+    pub minimum_relay_transaction_fee: u64,
+    /// Fee rate used to define dust outputs of this asset. See `Mempool::is_transaction_output_dust`.
+    pub dust_relay_transaction_fee: u64,
+}
+
+impl AssetPolicy {
+    pub const fn new(minimum_relay_transaction_fee: u64, dust_relay_transaction_fee: u64) -> Self {
+        Self { minimum_relay_transaction_fee, dust_relay_transaction_fee }
+    }
+}
+
+impl Default for AssetPolicy {
+    fn default() -> Self {
+        DEFAULT_ASSET_POLICY
+    }
+}
+
+pub(crate) const DEFAULT_ASSET_POLICY: AssetPolicy =
+    AssetPolicy::new(DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE, DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE);
+
+/// Overrides of the [`AssetPolicy`] rates for the transactions of a single [`TransactionAction`].
+///
+/// The minimum relay fee is expressed in sompi of the source asset of the action (the asset the fee is paid in),
+/// and the dust relay fee applies to the outputs in the destination asset of the action, both per 1kg of mass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActionPolicy {
+    pub minimum_relay_transaction_fee: Option<u64>,
+    pub dust_relay_transaction_fee: Option<u64>,
+}
+
+impl ActionPolicy {
+    pub const fn new(minimum_relay_transaction_fee: Option<u64>, dust_relay_transaction_fee: Option<u64>) -> Self {
+        Self { minimum_relay_transaction_fee, dust_relay_transaction_fee }
+    }
+}
+
+/// The standardness policy which is configurable by the node operator: per-asset fee and dust rates,
+/// and caps on the number of asset conversion transactions per block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StandardPolicy {
+    /// Per-asset policies, indexed by the numeric value of the [`AssetType`]
+    pub asset_policies: [AssetPolicy; AssetType::ALL.len()],
+    /// Per-action overrides of the asset policies, indexed by the numeric value of the [`TransactionAction`]
+    pub action_policies: [ActionPolicy; TransactionAction::ALL.len()],
+    /// Maximum number of `MintKUSD` transactions selected into a single block template
+    pub maximum_mint_transactions_per_block: u64,
+    /// Maximum number of `RedeemKSH` transactions selected into a single block template
+    pub maximum_redeem_transactions_per_block: u64,
+}
+
+impl StandardPolicy {
+    pub fn asset_policy(&self, asset_type: AssetType) -> &AssetPolicy {
+        &self.asset_policies[asset_type as usize]
+    }
+
+    pub fn asset_policy_mut(&mut self, asset_type: AssetType) -> &mut AssetPolicy {
+        &mut self.asset_policies[asset_type as usize]
+    }
+
+    pub fn action_policy(&self, action: TransactionAction) -> &ActionPolicy {
+        &self.action_policies[u32::from(action) as usize]
+    }
+
+    pub fn action_policy_mut(&mut self, action: TransactionAction) -> &mut ActionPolicy {
+        &mut self.action_policies[u32::from(action) as usize]
+    }
+
+    /// Returns the minimum relay fee rate of transactions with `action`, in sompi of the source asset of
+    /// the action per 1kg of mass.
+    pub fn minimum_relay_transaction_fee(&self, action: TransactionAction) -> u64 {
+        self.action_policy(action)
+            .minimum_relay_transaction_fee
+            .unwrap_or_else(|| self.asset_policy(action.asset_transfer_types().0).minimum_relay_transaction_fee)
+    }
+
+    /// Returns the dust relay fee rate of the outputs in `asset_type` of transactions with `action`.
+    pub fn dust_relay_transaction_fee(&self, action: TransactionAction, asset_type: AssetType) -> u64 {
+        match self.action_policy(action).dust_relay_transaction_fee {
+            Some(dust_relay_transaction_fee) if action.asset_transfer_types().1 == asset_type => dust_relay_transaction_fee,
+            _ => self.asset_policy(asset_type).dust_relay_transaction_fee,
+        }
+    }
+
+    /// Returns the maximum number of transactions with `action` which can be selected into a single block
+    /// template, or `None` if transactions with this action are not capped.
+    pub fn maximum_transactions_per_block(&self, action: TransactionAction) -> Option<u64> {
+        match action {
+            TransactionAction::MintKUSD => Some(self.maximum_mint_transactions_per_block),
+            TransactionAction::RedeemKSH => Some(self.maximum_redeem_transactions_per_block),
+            TransactionAction::TransferKSH
+            | TransactionAction::TransferKUSD
+            | TransactionAction::TransferKRV
+            | TransactionAction::StakeKSH => None,
+        }
+    }
+}
+
+impl Default for StandardPolicy {
+    fn default() -> Self {
+        DEFAULT_STANDARD_POLICY
+    }
+}
+
+pub(crate) const DEFAULT_STANDARD_POLICY: StandardPolicy = StandardPolicy {
+    asset_policies: [DEFAULT_ASSET_POLICY; AssetType::ALL.len()],
+    action_policies: [ActionPolicy::new(None, None); TransactionAction::ALL.len()],
+    maximum_mint_transactions_per_block: DEFAULT_MAXIMUM_CONVERSION_TRANSACTIONS_PER_BLOCK,
+    maximum_redeem_transactions_per_block: DEFAULT_MAXIMUM_CONVERSION_TRANSACTIONS_PER_BLOCK,
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -44,7 +168,7 @@ pub struct Config {
     pub maximum_orphan_transaction_count: u64,
     pub accept_non_standard: bool,
     pub maximum_mass_per_block: u64,
-    pub minimum_relay_transaction_fee: u64,
+    pub standard_policy: StandardPolicy,
     pub minimum_standard_transaction_version: u16,
     pub maximum_standard_transaction_version: u16,
 }
@@ -67,7 +191,7 @@ impl Config {
         maximum_orphan_transaction_count: u64,
         accept_non_standard: bool,
         maximum_mass_per_block: u64,
-        minimum_relay_transaction_fee: u64,
+        standard_policy: StandardPolicy,
         minimum_standard_transaction_version: u16,
         maximum_standard_transaction_version: u16,
     ) -> Self {
@@ -87,7 +211,7 @@ impl Config {
             maximum_orphan_transaction_count,
             accept_non_standard,
             maximum_mass_per_block,
-            minimum_relay_transaction_fee,
+            standard_policy,
             minimum_standard_transaction_version,
             maximum_standard_transaction_version,
         }
@@ -115,12 +239,17 @@ impl Config {
             maximum_orphan_transaction_count: DEFAULT_MAXIMUM_ORPHAN_TRANSACTION_COUNT,
             accept_non_standard: relay_non_std_transactions,
             maximum_mass_per_block: max_block_mass,
-            minimum_relay_transaction_fee: DEFAULT_MINIMUM_RELAY_TRANSACTION_FEE,
+            standard_policy: DEFAULT_STANDARD_POLICY,
             minimum_standard_transaction_version: DEFAULT_MINIMUM_STANDARD_TRANSACTION_VERSION,
             maximum_standard_transaction_version: DEFAULT_MAXIMUM_STANDARD_TRANSACTION_VERSION,
         }
     }
 
+    pub fn apply_standard_policy(mut self, standard_policy: StandardPolicy) -> Self {
+        self.standard_policy = standard_policy;
+        self
+    }
+
     pub fn apply_ram_scale(mut self, ram_scale: f64) -> Self {
         self.maximum_transaction_count = (self.maximum_transaction_count as f64 * ram_scale.min(1.0)) as u64; // Allow only scaling down
         self
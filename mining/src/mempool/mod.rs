@@ -12,8 +12,14 @@ use self::{
     model::{accepted_transactions::AcceptedTransactions, orphan_pool::OrphanPool, pool::Pool, transactions_pool::TransactionsPool},
     tx::Priority,
 };
+use itertools::Itertools;
 use kash_consensus_core::tx::{MutableTransaction, TransactionId};
-use kash_core::time::Stopwatch;
+use kash_consensus_notify::{
+    notification::{MempoolChangeReason, MempoolChangedNotification, Notification},
+    root::ConsensusNotificationRoot,
+};
+use kash_core::{debug, time::Stopwatch};
+use kash_notify::{events::EventType, notifier::Notify};
 use std::sync::Arc;
 
 pub(crate) mod check_transaction_standard;
@@ -41,20 +47,27 @@ pub(crate) mod validate_and_insert_transaction;
 ///   rebroadcasts them once in a while.
 /// - Transactions received through P2P have **low-priority**. They expire after
 ///   60 seconds and are removed if not inserted in a block for mining.
+/// - Transactions entering or leaving the transaction pool are notified through
+///   the notification root, if any. Orphans are only notified once resolved.
 pub(crate) struct Mempool {
     config: Arc<Config>,
     transaction_pool: TransactionsPool,
     orphan_pool: OrphanPool,
     accepted_transactions: AcceptedTransactions,
     counters: Arc<MiningCounters>,
+    notification_root: Option<Arc<ConsensusNotificationRoot>>,
 }
 
 impl Mempool {
-    pub(crate) fn new(config: Arc<Config>, counters: Arc<MiningCounters>) -> Self {
+    pub(crate) fn new(
+        config: Arc<Config>,
+        counters: Arc<MiningCounters>,
+        notification_root: Option<Arc<ConsensusNotificationRoot>>,
+    ) -> Self {
         let transaction_pool = TransactionsPool::new(config.clone());
         let orphan_pool = OrphanPool::new(config.clone());
         let accepted_transactions = AcceptedTransactions::new(config.clone());
-        Self { config, transaction_pool, orphan_pool, accepted_transactions, counters }
+        Self { config, transaction_pool, orphan_pool, accepted_transactions, counters, notification_root }
     }
 
     pub(crate) fn get_transaction(&self, transaction_id: &TransactionId, query: TransactionQuery) -> Option<MutableTransaction> {
@@ -144,6 +157,30 @@ impl Mempool {
             .filter(|transaction_id| !(self.transaction_pool.has(transaction_id) || self.orphan_pool.has(transaction_id)));
         self.accepted_transactions.unaccepted(&mut not_in_pools_txs)
     }
+
+    /// Notifies `transaction` entering or leaving the transaction pool, if the event is subscribed to.
+    ///
+    /// The notification carries the script public keys of the transaction outputs and of its populated inputs.
+    fn notify_mempool_changed(&self, transaction: &MutableTransaction, reason: MempoolChangeReason) {
+        let Some(ref notification_root) = self.notification_root else { return };
+        if !notification_root.has_subscription(EventType::MempoolChanged) {
+            return;
+        }
+        let script_public_keys = transaction
+            .tx
+            .outputs
+            .iter()
+            .map(|output| &output.script_public_key)
+            .chain(transaction.entries.iter().flatten().map(|entry| &entry.script_public_key))
+            .unique()
+            .cloned()
+            .collect_vec();
+        let notification =
+            Notification::MempoolChanged(MempoolChangedNotification::new(transaction.id(), reason, Arc::new(script_public_keys)));
+        if let Err(err) = notification_root.notify(notification) {
+            debug!("Failed to notify the mempool change ({}) of transaction {}: {}", reason, transaction.id(), err);
+        }
+    }
 }
 
 pub mod tx {
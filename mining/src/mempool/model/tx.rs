@@ -1,5 +1,6 @@
 use crate::mempool::tx::Priority;
 use kash_consensus_core::{tx::MutableTransaction, tx::TransactionId};
+use kash_consensus_notify::notification::MempoolChangeReason;
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter},
@@ -56,6 +57,8 @@ impl PartialEq for MempoolTransaction {
 #[derive(PartialEq, Eq)]
 pub(crate) enum TxRemovalReason {
     Muted,
+    /// Expired, with the removals being logged as a summary by the caller
+    MutedExpired,
     Accepted,
     MakingRoom,
     Unorphaned,
@@ -69,6 +72,7 @@ impl TxRemovalReason {
     pub(crate) fn as_str(&self) -> &'static str {
         match self {
             TxRemovalReason::Muted => "",
+            TxRemovalReason::MutedExpired => "",
             TxRemovalReason::Accepted => "accepted",
             TxRemovalReason::MakingRoom => "making room",
             TxRemovalReason::Unorphaned => "unorphaned",
@@ -80,7 +84,23 @@ impl TxRemovalReason {
     }
 
     pub(crate) fn verbose(&self) -> bool {
-        !matches!(self, TxRemovalReason::Muted)
+        !matches!(self, TxRemovalReason::Muted | TxRemovalReason::MutedExpired)
+    }
+
+    /// Reason notified to the subscribers of the mempool changes, if any
+    pub(crate) fn mempool_change_reason(&self) -> Option<MempoolChangeReason> {
+        match self {
+            TxRemovalReason::Muted => None,
+            TxRemovalReason::Accepted => Some(MempoolChangeReason::IncludedInBlock),
+            TxRemovalReason::MakingRoom => Some(MempoolChangeReason::LowFee),
+            // Only applies to orphans which get notified when entering the transaction pool
+            TxRemovalReason::Unorphaned => None,
+            TxRemovalReason::Expired | TxRemovalReason::MutedExpired => Some(MempoolChangeReason::Expired),
+            TxRemovalReason::DoubleSpend => Some(MempoolChangeReason::DoubleSpentInBlock),
+            TxRemovalReason::InvalidInBlockTemplate | TxRemovalReason::RevalidationWithMissingOutpoints => {
+                Some(MempoolChangeReason::Invalidated)
+            }
+        }
     }
 }
 
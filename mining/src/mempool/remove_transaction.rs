@@ -29,6 +29,7 @@ impl Mempool {
             removed_transactions.extend(self.transaction_pool.get_redeemer_ids_in_pool(transaction_id));
         }
 
+        let change_reason = reason.mempool_change_reason();
         let mut removed_orphans: Vec<TransactionId> = vec![];
         for tx_id in removed_transactions.iter() {
             // Remove the tx from the transaction pool and the UTXO set (handled within the pool)
@@ -36,11 +37,14 @@ impl Mempool {
             // Update/remove descendent orphan txs (depending on `remove_redeemers`)
             let txs = self.orphan_pool.update_orphans_after_transaction_removed(&tx, remove_redeemers)?;
             removed_orphans.extend(txs.into_iter().map(|x| x.id()));
+            if let Some(change_reason) = change_reason {
+                self.notify_mempool_changed(&tx.mtx, change_reason);
+            }
         }
         removed_transactions.extend(removed_orphans);
 
         match reason {
-            TxRemovalReason::Muted => {}
+            TxRemovalReason::Muted | TxRemovalReason::MutedExpired => {}
             TxRemovalReason::DoubleSpend => match removed_transactions.len() {
                 0 => {}
                 1 => warn!("Removed transaction ({}) {}{}", reason, removed_transactions[0], extra_info),
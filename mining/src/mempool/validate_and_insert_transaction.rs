@@ -12,6 +12,7 @@ use kash_consensus_core::{
     constants::UNACCEPTED_DAA_SCORE,
     tx::{MutableTransaction, Transaction, TransactionId, TransactionOutpoint, UtxoEntry},
 };
+use kash_consensus_notify::notification::MempoolChangeReason;
 use kash_core::{debug, info};
 use std::sync::Arc;
 
@@ -37,6 +38,7 @@ impl Mempool {
         transaction: MutableTransaction,
         priority: Priority,
         orphan: Orphan,
+        acceptance_reason: MempoolChangeReason,
     ) -> RuleResult<Option<Arc<Transaction>>> {
         let transaction_id = transaction.id();
 
@@ -78,6 +80,9 @@ impl Mempool {
         // Add the transaction to the mempool as a MempoolTransaction and return a clone of the embedded Arc<Transaction>
         let accepted_transaction =
             self.transaction_pool.add_transaction(transaction, consensus.get_virtual_daa_score(), priority)?.mtx.tx.clone();
+        if let Some(transaction) = self.transaction_pool.get(&transaction_id) {
+            self.notify_mempool_changed(&transaction.mtx, acceptance_reason);
+        }
         Ok(Some(accepted_transaction))
     }
 
@@ -26,7 +26,7 @@ impl CoinbaseManagerMock {
 
         CoinbaseTransactionTemplate {
             tx: Transaction::new(TX_VERSION, vec![], vec![output], TransferKSH, 0, SUBNETWORK_ID_COINBASE, 0, payload),
-            has_red_reward: false,
+            red_reward_outputs: 0,
         }
     }
 
@@ -2,6 +2,7 @@ use super::events::EventType;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use derive_more::Display;
 use kash_addresses::Address;
+use kash_consensus_core::{asset_type::AssetType, Hash};
 use serde::{Deserialize, Serialize};
 
 macro_rules! scope_enum {
@@ -43,6 +44,7 @@ pub enum Scope {
     VirtualDaaScoreChanged,
     PruningPointUtxoSetOverride,
     NewBlockTemplate,
+    MempoolChanged,
 }
 }
 
@@ -52,17 +54,36 @@ pub struct BlockAddedScope {}
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 pub struct VirtualChainChangedScope {
     pub include_accepted_transaction_ids: bool,
+    /// If set, the changes of the virtual chain since this chain block are replayed
+    /// before live notifications are relayed.
+    ///
+    /// Replays are handled by the RPC servers on a per-connection basis and are
+    /// never part of a compounded subscription.
+    #[serde(default)]
+    pub start_hash: Option<Hash>,
 }
 
 impl VirtualChainChangedScope {
     pub fn new(include_accepted_transaction_ids: bool) -> Self {
-        Self { include_accepted_transaction_ids }
+        Self { include_accepted_transaction_ids, start_hash: None }
+    }
+
+    pub fn with_start_hash(include_accepted_transaction_ids: bool, start_hash: Option<Hash>) -> Self {
+        Self { include_accepted_transaction_ids, start_hash }
     }
 }
 
 impl std::fmt::Display for VirtualChainChangedScope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "VirtualChainChangedScope{}", if self.include_accepted_transaction_ids { " with accepted transactions" } else { "" })
+        write!(
+            f,
+            "VirtualChainChangedScope{}",
+            if self.include_accepted_transaction_ids { " with accepted transactions" } else { "" }
+        )?;
+        if let Some(start_hash) = self.start_hash {
+            write!(f, " replayed from {}", start_hash)?;
+        }
+        Ok(())
     }
 }
 
@@ -75,6 +96,12 @@ pub struct FinalityConflictResolvedScope {}
 #[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 pub struct UtxosChangedScope {
     pub addresses: Vec<Address>,
+    /// If set, only UTXOs of this asset type are notified
+    #[serde(default)]
+    pub asset_type: Option<AssetType>,
+    /// Only UTXOs holding at least this amount are notified
+    #[serde(default)]
+    pub min_amount: u64,
 }
 
 impl std::fmt::Display for UtxosChangedScope {
@@ -84,13 +111,23 @@ impl std::fmt::Display for UtxosChangedScope {
             1 => format!("{}", self.addresses[0]),
             n => format!("{} addresses", n),
         };
-        write!(f, "UtxosChangedScope ({})", addresses)
+        write!(f, "UtxosChangedScope ({})", addresses)?;
+        if let Some(asset_type) = self.asset_type {
+            write!(f, " of {}", asset_type)?;
+        }
+        if self.min_amount > 0 {
+            write!(f, " from {} sompi", self.min_amount)?;
+        }
+        Ok(())
     }
 }
 
 impl PartialEq for UtxosChangedScope {
     fn eq(&self, other: &Self) -> bool {
-        self.addresses.len() == other.addresses.len() && self.addresses.iter().all(|x| other.addresses.contains(x))
+        self.asset_type == other.asset_type
+            && self.min_amount == other.min_amount
+            && self.addresses.len() == other.addresses.len()
+            && self.addresses.iter().all(|x| other.addresses.contains(x))
     }
 }
 
@@ -98,7 +135,42 @@ impl Eq for UtxosChangedScope {}
 
 impl UtxosChangedScope {
     pub fn new(addresses: Vec<Address>) -> Self {
-        Self { addresses }
+        Self { addresses, asset_type: None, min_amount: 0 }
+    }
+
+    /// Creates a scope only covering UTXOs of `asset_type` (if some) holding at least `min_amount`
+    pub fn with_filter(addresses: Vec<Address>, asset_type: Option<AssetType>, min_amount: u64) -> Self {
+        Self { addresses, asset_type, min_amount }
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.filter().is_filtered()
+    }
+
+    pub fn filter(&self) -> UtxosChangedFilter {
+        UtxosChangedFilter::new(self.asset_type, self.min_amount)
+    }
+}
+
+/// Asset type and amount filter applied to the UTXOs notified for an address
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UtxosChangedFilter {
+    pub asset_type: Option<AssetType>,
+    pub min_amount: u64,
+}
+
+impl UtxosChangedFilter {
+    pub fn new(asset_type: Option<AssetType>, min_amount: u64) -> Self {
+        Self { asset_type, min_amount }
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.asset_type.is_some() || self.min_amount > 0
+    }
+
+    /// Returns true if a UTXO of `asset_type` holding `amount` passes the filter
+    pub fn matches(&self, asset_type: AssetType, amount: u64) -> bool {
+        self.asset_type.map_or(true, |x| x == asset_type) && amount >= self.min_amount
     }
 }
 
@@ -113,3 +185,6 @@ pub struct PruningPointUtxoSetOverrideScope {}
 
 #[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 pub struct NewBlockTemplateScope {}
+
+#[derive(Clone, Display, Debug, Default, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+pub struct MempoolChangedScope {}
@@ -93,15 +93,20 @@ impl Compounded for VirtualChainChangedSubscription {
                     if all {
                         // Add All
                         *self.all_mut() += 1;
-                        if self.all() == 1 {
-                            return Some(mutation);
-                        }
                     } else {
                         // Add Reduced
                         *self.reduced_mut() += 1;
-                        if self.reduced() == 1 && self.all() == 0 {
-                            return Some(mutation);
-                        }
+                    }
+                    if let Some(start_hash) = scope.start_hash {
+                        // A replay is served for the request only so it cannot be compounded and
+                        // always gets forwarded, keeping the accepted transaction ids if any listener wants them
+                        return Some(Mutation::new(
+                            Command::Start,
+                            Scope::VirtualChainChanged(VirtualChainChangedScope::with_start_hash(self.all() > 0, Some(start_hash))),
+                        ));
+                    }
+                    if (all && self.all() == 1) || (!all && self.reduced() == 1 && self.all() == 0) {
+                        return Some(mutation);
                     }
                 }
                 Command::Stop => {
@@ -303,13 +308,20 @@ mod tests {
     #[allow(clippy::redundant_clone)]
     fn test_virtual_chain_changed_compounding() {
         fn m(command: Command, include_accepted_transaction_ids: bool) -> Mutation {
-            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }) }
+            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)) }
         }
         let none = Box::<VirtualChainChangedSubscription>::default;
         let add_all = || m(Command::Start, true);
         let add_reduced = || m(Command::Start, false);
         let remove_reduced = || m(Command::Stop, false);
         let remove_all = || m(Command::Stop, true);
+        let replay = |include_accepted_transaction_ids: bool| Mutation {
+            command: Command::Start,
+            scope: Scope::VirtualChainChanged(VirtualChainChangedScope::with_start_hash(
+                include_accepted_transaction_ids,
+                Some(1.into()),
+            )),
+        };
         let test = Test {
             name: "VirtualChainChanged",
             initial_state: none(),
@@ -329,6 +341,15 @@ mod tests {
                 Step { name: "add all 1, masking reduced", mutation: add_all(), result: Some(add_all()) },
                 Step { name: "remove reduced 1, masked by all", mutation: remove_reduced(), result: None },
                 Step { name: "remove all 1", mutation: remove_all(), result: Some(remove_all()) },
+                // Replays are always forwarded
+                Step { name: "replay reduced 1", mutation: replay(false), result: Some(replay(false)) },
+                Step { name: "replay reduced 2", mutation: replay(false), result: Some(replay(false)) },
+                Step { name: "replay all 1", mutation: replay(true), result: Some(replay(true)) },
+                Step { name: "replay reduced 3, upgraded by all", mutation: replay(false), result: Some(replay(true)) },
+                Step { name: "remove reduced 3", mutation: remove_reduced(), result: None },
+                Step { name: "remove reduced 2", mutation: remove_reduced(), result: None },
+                Step { name: "remove all 1, revealing reduced", mutation: remove_all(), result: Some(add_reduced()) },
+                Step { name: "remove reduced 1", mutation: remove_reduced(), result: Some(remove_reduced()) },
             ],
             final_state: none(),
         };
@@ -348,7 +369,7 @@ mod tests {
 
         let a = |indexes: &[usize]| indexes.iter().map(|idx| (a_stock[*idx]).clone()).collect::<Vec<_>>();
         let m = |command: Command, indexes: &[usize]| -> Mutation {
-            Mutation { command, scope: Scope::UtxosChanged(UtxosChangedScope { addresses: a(indexes) }) }
+            Mutation { command, scope: Scope::UtxosChanged(UtxosChangedScope::new(a(indexes))) }
         };
         let none = Box::<UtxosChangedSubscription>::default;
 
@@ -74,6 +74,11 @@ pub trait Subscription {
     fn event_type(&self) -> EventType;
     fn active(&self) -> bool;
     fn scope(&self) -> Scope;
+
+    /// Returns the scopes needed to fully restore the subscription, which may not fit a single [`Scope`]
+    fn scopes(&self) -> Vec<Scope> {
+        vec![self.scope()]
+    }
 }
 
 pub trait Compounded: Subscription + AsAny + DynEq + CompoundedClone + Debug + Send + Sync {
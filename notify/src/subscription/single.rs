@@ -2,11 +2,11 @@ use super::{Mutation, Single, Subscription};
 use crate::{
     address::UtxoAddress,
     events::EventType,
-    scope::{Scope, UtxosChangedScope, VirtualChainChangedScope},
+    scope::{Scope, UtxosChangedFilter, UtxosChangedScope, VirtualChainChangedScope},
     subscription::Command,
 };
 use kash_addresses::Address;
-use kash_consensus_core::tx::ScriptPublicKey;
+use kash_consensus_core::{asset_type::AssetType, tx::ScriptPublicKey};
 use kash_txscript::pay_to_address_script;
 use std::{
     collections::HashMap,
@@ -152,15 +152,49 @@ impl Subscription for VirtualChainChangedSubscription {
 pub struct UtxosChangedSubscription {
     active: bool,
     addresses: HashMap<ScriptPublicKey, UtxoAddress>,
+    /// Filters of the selected addresses, only holding the addresses actually filtered
+    filters: HashMap<ScriptPublicKey, UtxosChangedFilter>,
+    /// Filter applied when the subscription covers all addresses
+    all_filter: UtxosChangedFilter,
 }
 
 impl UtxosChangedSubscription {
     pub fn new(active: bool, addresses: Vec<Address>) -> Self {
-        let mut subscription = Self { active, addresses: HashMap::default() };
-        subscription.set_addresses(addresses);
+        Self::with_filter(active, addresses, None, 0)
+    }
+
+    /// Creates a subscription applying the same asset and amount filter to all of its `addresses`
+    pub fn with_filter(active: bool, addresses: Vec<Address>, asset_type: Option<AssetType>, min_amount: u64) -> Self {
+        let mut subscription = Self { active, ..Default::default() };
+        let filter = UtxosChangedFilter::new(asset_type, min_amount);
+        if addresses.is_empty() {
+            subscription.all_filter = filter;
+        } else {
+            addresses.iter().for_each(|x| {
+                subscription.insert_address(x);
+                subscription.set_address_filter(x, filter);
+            });
+        }
         subscription
     }
 
+    /// Sets the filter of an already inserted `address`, returning true if it changed
+    fn set_address_filter(&mut self, address: &Address, filter: UtxosChangedFilter) -> bool {
+        let script_public_key = pay_to_address_script(address);
+        let previous = match filter.is_filtered() {
+            true => self.filters.insert(script_public_key, filter),
+            false => self.filters.remove(&script_public_key),
+        };
+        previous.unwrap_or_default() != filter
+    }
+
+    /// Sets the filter applying to all addresses, returning true if it changed
+    fn set_all_filter(&mut self, filter: UtxosChangedFilter) -> bool {
+        let changed = self.all_filter != filter;
+        self.all_filter = filter;
+        changed
+    }
+
     fn set_addresses(&mut self, addresses: Vec<Address>) -> &mut Self {
         self.addresses = addresses
             .into_iter()
@@ -182,7 +216,15 @@ impl UtxosChangedSubscription {
     }
 
     pub fn remove_address(&mut self, address: &Address) -> bool {
-        self.addresses.remove(&pay_to_address_script(address)).is_some()
+        let script_public_key = pay_to_address_script(address);
+        self.filters.remove(&script_public_key);
+        self.addresses.remove(&script_public_key).is_some()
+    }
+
+    fn clear(&mut self) {
+        self.addresses.clear();
+        self.filters.clear();
+        self.all_filter = Default::default();
     }
 
     pub fn addresses(&self) -> &HashMap<ScriptPublicKey, UtxoAddress> {
@@ -192,11 +234,48 @@ impl UtxosChangedSubscription {
     pub fn to_all(&self) -> bool {
         self.addresses.is_empty()
     }
+
+    /// Returns the asset and amount filter applying to the UTXOs of `script_public_key`
+    pub fn filter(&self, script_public_key: &ScriptPublicKey) -> UtxosChangedFilter {
+        match self.to_all() {
+            true => self.all_filter,
+            false => self.filters.get(script_public_key).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Returns true if notifications of at least one address are filtered by asset type or amount
+    pub fn is_filtered(&self) -> bool {
+        self.all_filter.is_filtered() || !self.filters.is_empty()
+    }
+
+    /// Returns true if a UTXO of `script_public_key` and `asset_type` holding `amount` passes the filter of its address
+    pub fn matches_entry(&self, script_public_key: &ScriptPublicKey, asset_type: AssetType, amount: u64) -> bool {
+        self.filter(script_public_key).matches(asset_type, amount)
+    }
+
+    /// Returns the scopes covering the subscription, one per distinct filter
+    pub fn filtered_scopes(&self) -> Vec<UtxosChangedScope> {
+        if self.to_all() {
+            return vec![UtxosChangedScope::with_filter(vec![], self.all_filter.asset_type, self.all_filter.min_amount)];
+        }
+        let mut scopes: HashMap<UtxosChangedFilter, Vec<Address>> = HashMap::default();
+        self.addresses.iter().for_each(|(script_public_key, address)| {
+            scopes.entry(self.filter(script_public_key)).or_default().push((**address).clone());
+        });
+        scopes
+            .into_iter()
+            .map(|(filter, addresses)| UtxosChangedScope::with_filter(addresses, filter.asset_type, filter.min_amount))
+            .collect()
+    }
 }
 
 impl PartialEq for UtxosChangedSubscription {
     fn eq(&self, other: &Self) -> bool {
-        if self.active == other.active && self.addresses.len() == other.addresses.len() {
+        if self.active == other.active
+            && self.all_filter == other.all_filter
+            && self.filters == other.filters
+            && self.addresses.len() == other.addresses.len()
+        {
             // HashMaps are considered equal if they contain the same keys
             return self.addresses.keys().all(|x| other.addresses.contains_key(x));
         }
@@ -208,11 +287,13 @@ impl Eq for UtxosChangedSubscription {}
 impl Hash for UtxosChangedSubscription {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.active.hash(state);
+        self.all_filter.hash(state);
 
         // Since item order in hash set is undefined, build a sorted vector
         // so that hashing is determinist.
-        let mut items: Vec<&Address> = self.addresses.values().map(|x| &**x).collect::<Vec<_>>();
-        items.sort();
+        let mut items: Vec<(&Address, UtxosChangedFilter)> =
+            self.addresses.iter().map(|(script_public_key, x)| (&**x, self.filter(script_public_key))).collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.cmp(b.0));
         items.hash(state);
     }
 }
@@ -222,6 +303,9 @@ impl Single for UtxosChangedSubscription {
         if let Scope::UtxosChanged(ref scope) = mutation.scope {
             // Here we want the code to (almost) match a double entry table structure
             // by subscription state and by mutation
+            //
+            // Filters are kept per address (or for the whole All state), so a mutation
+            // only ever changes the filter of the addresses it targets
             #[allow(clippy::collapsible_else_if)]
             if !self.active {
                 // State None
@@ -233,7 +317,15 @@ impl Single for UtxosChangedSubscription {
                     // Here is an exception to the aforementioned goal
                     // Mutations Add(A) && All
                     self.active = true;
+                    self.clear();
                     self.set_addresses(scope.addresses.clone());
+                    if scope.addresses.is_empty() {
+                        self.set_all_filter(scope.filter());
+                    } else {
+                        scope.addresses.iter().for_each(|x| {
+                            self.set_address_filter(x, scope.filter());
+                        });
+                    }
                     Some(vec![mutation])
                 }
             } else if !self.addresses.is_empty() {
@@ -243,12 +335,14 @@ impl Single for UtxosChangedSubscription {
                         // Mutation None
                         self.active = false;
                         let removed = self.addresses.drain().map(|(_, x)| x.into()).collect();
+                        self.clear();
                         Some(vec![Mutation::new(Command::Stop, Scope::UtxosChanged(UtxosChangedScope::new(removed)))])
                     } else {
                         // Mutation Remove(R)
                         let removed: Vec<Address> = scope.addresses.iter().filter(|x| self.remove_address(x)).cloned().collect();
                         if self.addresses.is_empty() {
                             self.active = false;
+                            self.clear();
                         }
                         match removed.is_empty() {
                             false => Some(vec![Mutation::new(Command::Stop, Scope::UtxosChanged(UtxosChangedScope::new(removed)))]),
@@ -259,13 +353,18 @@ impl Single for UtxosChangedSubscription {
                     if !scope.addresses.is_empty() {
                         // Mutation Add(A)
                         let added = scope.addresses.iter().filter(|x| self.insert_address(x)).cloned().collect::<Vec<_>>();
+                        let filter_changed =
+                            scope.addresses.iter().fold(false, |changed, x| self.set_address_filter(x, scope.filter()) || changed);
                         match added.is_empty() {
                             false => Some(vec![Mutation::new(Command::Start, Scope::UtxosChanged(UtxosChangedScope::new(added)))]),
-                            true => None,
+                            // A filter change only affects this subscription, so there is nothing to propagate
+                            true => filter_changed.then(Vec::new),
                         }
                     } else {
                         // Mutation All
                         let removed: Vec<Address> = self.addresses.drain().map(|(_, x)| x.into()).collect();
+                        self.clear();
+                        self.set_all_filter(scope.filter());
                         Some(vec![
                             Mutation::new(Command::Stop, Scope::UtxosChanged(UtxosChangedScope::new(removed))),
                             Mutation::new(Command::Start, Scope::UtxosChanged(UtxosChangedScope::default())),
@@ -278,6 +377,7 @@ impl Single for UtxosChangedSubscription {
                     if scope.addresses.is_empty() {
                         // Mutation None
                         self.active = false;
+                        self.clear();
                         Some(vec![Mutation::new(Command::Stop, Scope::UtxosChanged(UtxosChangedScope::default()))])
                     } else {
                         // Mutation Remove(R)
@@ -286,13 +386,15 @@ impl Single for UtxosChangedSubscription {
                 } else {
                     if !scope.addresses.is_empty() {
                         // Mutation Add(A)
+                        self.clear();
                         scope.addresses.iter().for_each(|x| {
                             self.insert_address(x);
+                            self.set_address_filter(x, scope.filter());
                         });
                         Some(vec![mutation, Mutation::new(Command::Stop, Scope::UtxosChanged(UtxosChangedScope::default()))])
                     } else {
                         // Mutation All
-                        None
+                        self.set_all_filter(scope.filter()).then(Vec::new)
                     }
                 }
             }
@@ -311,8 +413,20 @@ impl Subscription for UtxosChangedSubscription {
         self.active
     }
 
+    /// Returns a scope covering all the addresses of the subscription.
+    ///
+    /// The scope only carries a filter when it is shared by all addresses.
+    /// Use [`Subscription::scopes`] to get the filters of every address.
     fn scope(&self) -> Scope {
-        Scope::UtxosChanged(UtxosChangedScope::new(self.addresses.values().map(|x| &**x).cloned().collect()))
+        let mut scopes = self.filtered_scopes();
+        match scopes.len() {
+            1 => Scope::UtxosChanged(scopes.pop().unwrap()),
+            _ => Scope::UtxosChanged(UtxosChangedScope::new(self.addresses.values().map(|x| &**x).cloned().collect())),
+        }
+    }
+
+    fn scopes(&self) -> Vec<Scope> {
+        self.filtered_scopes().into_iter().map(Scope::UtxosChanged).collect()
     }
 }
 
@@ -529,7 +643,7 @@ mod tests {
             Box::new(VirtualChainChangedSubscription { active, include_accepted_transaction_ids })
         }
         fn m(command: Command, include_accepted_transaction_ids: bool) -> Mutation {
-            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }) }
+            Mutation { command, scope: Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)) }
         }
 
         // Subscriptions
@@ -641,7 +755,7 @@ mod tests {
         let ah = |indexes: &[usize]| indexes.iter().map(|idx| (a_stock[*idx]).clone()).collect::<Vec<_>>();
         let s = |active: bool, indexes: &[usize]| Box::new(UtxosChangedSubscription::new(active, ah(indexes))) as SingleSubscription;
         let m = |command: Command, indexes: &[usize]| -> Mutation {
-            Mutation { command, scope: Scope::UtxosChanged(UtxosChangedScope { addresses: av(indexes) }) }
+            Mutation { command, scope: Scope::UtxosChanged(UtxosChangedScope::new(av(indexes))) }
         };
 
         // Subscriptions
@@ -781,4 +895,124 @@ mod tests {
         ]);
         tests.run()
     }
+
+    #[test]
+    fn test_utxos_changed_filter_mutation() {
+        let a_stock = get_3_addresses(true);
+
+        let av = |indexes: &[usize]| indexes.iter().map(|idx| (a_stock[*idx]).clone()).collect::<Vec<_>>();
+        let s = |active: bool, indexes: &[usize], asset_type: Option<AssetType>, min_amount: u64| {
+            Box::new(UtxosChangedSubscription::with_filter(active, av(indexes), asset_type, min_amount)) as SingleSubscription
+        };
+        // Subscription selecting `indexes` unfiltered and `filtered` with the given filter
+        let sm = |indexes: &[usize], filtered: &[usize], asset_type: Option<AssetType>, min_amount: u64| {
+            let mut subscription = UtxosChangedSubscription::new(true, av(indexes));
+            av(filtered).iter().for_each(|x| {
+                subscription.insert_address(x);
+                subscription.set_address_filter(x, UtxosChangedFilter::new(asset_type, min_amount));
+            });
+            Box::new(subscription) as SingleSubscription
+        };
+        let m = |command: Command, indexes: &[usize], asset_type: Option<AssetType>, min_amount: u64| -> Mutation {
+            Mutation { command, scope: Scope::UtxosChanged(UtxosChangedScope::with_filter(av(indexes), asset_type, min_amount)) }
+        };
+
+        let kusd = Some(AssetType::KUSD);
+
+        // Tests
+        let tests = MutationTests::new(vec![
+            MutationTest {
+                name: "UtxosChangedSubscription None to filtered Selected 0 (add set)",
+                state: s(false, &[], None, 0),
+                mutation: m(Command::Start, &[0], kusd, 100),
+                new_state: s(true, &[0], kusd, 100),
+                result: Some(vec![m(Command::Start, &[0], kusd, 100)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription Selected 01 to Selected 0 and filtered Selected 1 (add set with total intersection)",
+                state: s(true, &[0, 1], None, 0),
+                mutation: m(Command::Start, &[1], kusd, 100),
+                new_state: sm(&[0], &[1], kusd, 100),
+                result: Some(vec![]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered Selected 01 to filtered Selected 01 and Selected 2 (add set with no intersection)",
+                state: s(true, &[0, 1], kusd, 100),
+                mutation: m(Command::Start, &[2], None, 0),
+                new_state: sm(&[2], &[0, 1], kusd, 100),
+                result: Some(vec![m(Command::Start, &[2], None, 0)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered Selected 01 to Selected 0 and filtered Selected 1 (add set with total intersection)",
+                state: s(true, &[0, 1], kusd, 100),
+                mutation: m(Command::Start, &[0], None, 0),
+                new_state: sm(&[0], &[1], kusd, 100),
+                result: Some(vec![]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription Selected 0 and filtered Selected 1 to Selected 0 (remove set)",
+                state: sm(&[0], &[1], kusd, 100),
+                mutation: m(Command::Stop, &[1], None, 0),
+                new_state: s(true, &[0], None, 0),
+                result: Some(vec![m(Command::Stop, &[1], None, 0)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered Selected 0 to filtered Selected 01 (add set with partial intersection)",
+                state: s(true, &[0], kusd, 100),
+                mutation: m(Command::Start, &[0, 1], kusd, 100),
+                new_state: s(true, &[0, 1], kusd, 100),
+                result: Some(vec![m(Command::Start, &[1], None, 0)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered Selected 01 to filtered Selected 1 (remove set)",
+                state: s(true, &[0, 1], kusd, 100),
+                mutation: m(Command::Stop, &[0], None, 0),
+                new_state: s(true, &[1], kusd, 100),
+                result: Some(vec![m(Command::Stop, &[0], None, 0)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription All to filtered All (add all)",
+                state: s(true, &[], None, 0),
+                mutation: m(Command::Start, &[], kusd, 0),
+                new_state: s(true, &[], kusd, 0),
+                result: Some(vec![]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered All to filtered All (add all)",
+                state: s(true, &[], kusd, 0),
+                mutation: m(Command::Start, &[], kusd, 0),
+                new_state: s(true, &[], kusd, 0),
+                result: None,
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered All to Selected 0 (add set)",
+                state: s(true, &[], kusd, 0),
+                mutation: m(Command::Start, &[0], None, 0),
+                new_state: s(true, &[0], None, 0),
+                result: Some(vec![m(Command::Start, &[0], None, 0), m(Command::Stop, &[], None, 0)]),
+            },
+            MutationTest {
+                name: "UtxosChangedSubscription filtered All to None (remove all)",
+                state: s(true, &[], kusd, 0),
+                mutation: m(Command::Stop, &[], None, 0),
+                new_state: s(false, &[], None, 0),
+                result: Some(vec![m(Command::Stop, &[], None, 0)]),
+            },
+        ]);
+        tests.run();
+
+        // Filters apply per address and are all restored by the subscription scopes
+        let subscription = sm(&[0], &[1], kusd, 100);
+        let subscription = (*subscription).as_any().downcast_ref::<UtxosChangedSubscription>().unwrap();
+        let (spk_0, spk_1) = (pay_to_address_script(&a_stock[0]), pay_to_address_script(&a_stock[1]));
+        assert!(subscription.matches_entry(&spk_0, AssetType::KSH, 1));
+        assert!(!subscription.matches_entry(&spk_1, AssetType::KSH, 1000));
+        assert!(!subscription.matches_entry(&spk_1, AssetType::KUSD, 99));
+        assert!(subscription.matches_entry(&spk_1, AssetType::KUSD, 100));
+        let scopes = subscription.scopes();
+        assert_eq!(scopes.len(), 2);
+        assert!(scopes.contains(&Scope::UtxosChanged(UtxosChangedScope::new(av(&[0])))));
+        assert!(scopes.contains(&Scope::UtxosChanged(UtxosChangedScope::with_filter(av(&[1]), kusd, 100))));
+        assert_eq!(subscription.scope(), Scope::UtxosChanged(UtxosChangedScope::new(av(&[0, 1]))));
+    }
 }
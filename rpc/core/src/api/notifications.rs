@@ -43,6 +43,9 @@ pub enum Notification {
 
     #[display(fmt = "NewBlockTemplate notification")]
     NewBlockTemplate(NewBlockTemplateNotification),
+
+    #[display(fmt = "MempoolChanged notification: transaction {} {:?}", "_0.transaction_id", "_0.reason")]
+    MempoolChanged(MempoolChangedNotification),
 }
 }
 
@@ -59,6 +62,7 @@ impl Notification {
             Notification::VirtualDaaScoreChanged(v) => to_value(&v),
             Notification::SinkBlueScoreChanged(v) => to_value(&v),
             Notification::VirtualChainChanged(v) => to_value(&v),
+            Notification::MempoolChanged(v) => to_value(&v),
         }
     }
 }
@@ -13,7 +13,7 @@ use workflow_core::enums::Describe;
 /// or using Serde attributes. This applies only to RPC infrastructure that uses internal
 /// data structures and does not affect gRPC. gRPC should issue and handle its
 /// own versioning.
-pub const RPC_API_VERSION: [u16; 4] = [0, 1, 0, 0];
+pub const RPC_API_VERSION: [u16; 4] = [0, 1, 1, 0];
 
 #[derive(Describe, Clone, Copy, Debug, PartialEq, Eq, Hash, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -114,6 +114,22 @@ pub enum RpcApiOps {
     VirtualDaaScoreChangedNotification,
     PruningPointUtxoSetOverrideNotification,
     NewBlockTemplateNotification,
+
+    // Ops added after the initial release. Borsh clients identify ops by
+    // their discriminant, so new ops must always be appended below.
+    /// Get the reference KSH/USD price of the virtual and of recent chain blocks
+    GetPrice,
+    /// Get an accepted transaction by id (requires the transaction index)
+    GetTransaction,
+    /// Get the accepted transactions involving an address (requires the transaction index)
+    GetTransactionsByAddress,
+    /// Get a chunk of the virtual or pruning point UTXO set
+    GetUtxoSet,
+    /// Start or stop mempool change notifications
+    NotifyMempoolChanged,
+    MempoolChangedNotification,
+    /// Authenticates a wRPC connection
+    Authenticate,
 }
 
 impl RpcApiOps {
@@ -129,10 +145,27 @@ impl RpcApiOps {
                 | RpcApiOps::NotifyFinalityConflictResolved
                 | RpcApiOps::NotifySinkBlueScoreChanged
                 | RpcApiOps::NotifyVirtualDaaScoreChanged
+                | RpcApiOps::NotifyMempoolChanged
                 | RpcApiOps::Subscribe
                 | RpcApiOps::Unsubscribe
         )
     }
+
+    /// The op subscribing to notifications of `event`
+    pub fn subscription(event: EventType) -> Self {
+        match event {
+            EventType::BlockAdded => RpcApiOps::NotifyBlockAdded,
+            EventType::VirtualChainChanged => RpcApiOps::NotifyVirtualChainChanged,
+            EventType::FinalityConflict => RpcApiOps::NotifyFinalityConflict,
+            EventType::FinalityConflictResolved => RpcApiOps::NotifyFinalityConflictResolved,
+            EventType::UtxosChanged => RpcApiOps::NotifyUtxosChanged,
+            EventType::SinkBlueScoreChanged => RpcApiOps::NotifySinkBlueScoreChanged,
+            EventType::VirtualDaaScoreChanged => RpcApiOps::NotifyVirtualDaaScoreChanged,
+            EventType::PruningPointUtxoSetOverride => RpcApiOps::NotifyPruningPointUtxoSetOverride,
+            EventType::NewBlockTemplate => RpcApiOps::NotifyNewBlockTemplate,
+            EventType::MempoolChanged => RpcApiOps::NotifyMempoolChanged,
+        }
+    }
 }
 
 impl From<RpcApiOps> for u32 {
@@ -155,6 +188,7 @@ impl From<EventType> for RpcApiOps {
             EventType::VirtualDaaScoreChanged => RpcApiOps::VirtualDaaScoreChangedNotification,
             EventType::PruningPointUtxoSetOverride => RpcApiOps::PruningPointUtxoSetOverrideNotification,
             EventType::NewBlockTemplate => RpcApiOps::NewBlockTemplateNotification,
+            EventType::MempoolChanged => RpcApiOps::MempoolChangedNotification,
         }
     }
 }
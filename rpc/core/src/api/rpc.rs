@@ -11,6 +11,10 @@ use kash_notify::{listener::ListenerId, scope::Scope, subscription::Command};
 use std::sync::Arc;
 
 pub const MAX_SAFE_WINDOW_SIZE: u32 = 10_000;
+pub const MAX_SAFE_PRICE_HISTORY_LENGTH: u32 = 10_000;
+pub const MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT: u32 = 10_000;
+pub const MAX_SAFE_HEADERS_LIMIT: u64 = 10_000;
+pub const MAX_SAFE_UTXO_SET_LIMIT: u32 = 10_000;
 
 /// Client RPC Api
 ///
@@ -183,7 +187,8 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn get_block_dag_info_call(&self, request: GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse>;
 
-    ///
+    /// Resolves a finality conflict by using the specified block as the finality point of this node.
+    /// Requires the node to run with unsafe RPC enabled.
     async fn resolve_finality_conflict(&self, finality_block_hash: RpcHash) -> RpcResult<()> {
         self.resolve_finality_conflict_call(ResolveFinalityConflictRequest::new(finality_block_hash)).await?;
         Ok(())
@@ -200,7 +205,14 @@ pub trait RpcApi: Sync + Send + AnySync {
     }
     async fn shutdown_call(&self, request: ShutdownRequest) -> RpcResult<ShutdownResponse>;
 
-    /// Requests headers between the given `start_hash` and the current virtual, up to the given limit.
+    /// Requests up to `limit` headers paged from `start_hash`, which is itself excluded.
+    ///
+    /// Ascending pages hold the blocks merged by the selected chain from `start_hash` up to the sink, in consensus order.
+    /// Descending pages hold the blocks merged by the selected chain of `start_hash` (including the mergeset of `start_hash`
+    /// itself), in reverse consensus order down to the pruning point (or genesis on archival nodes). Both directions thus
+    /// return the same blocks between two chain blocks. Pages end on a chain block and hold whole mergesets, hence a page
+    /// exceeds `limit` only if its first mergeset does. In both directions, the hash of the last returned header can be
+    /// used as `start_hash` of the next page. A zero `limit` requests the default of [`MAX_SAFE_HEADERS_LIMIT`] headers.
     async fn get_headers(&self, start_hash: RpcHash, limit: u64, is_ascending: bool) -> RpcResult<Vec<RpcHeader>> {
         Ok(self.get_headers_call(GetHeadersRequest::new(start_hash, limit, is_ascending)).await?.headers)
     }
@@ -227,7 +239,7 @@ pub trait RpcApi: Sync + Send + AnySync {
     ///
     /// This call is only available when this node was started with `--utxoindex`.
     async fn get_utxos_by_addresses(&self, addresses: Vec<RpcAddress>) -> RpcResult<Vec<RpcUtxosByAddressesEntry>> {
-        Ok(self.get_utxos_by_addresses_call(GetUtxosByAddressesRequest::new(addresses)).await?.entries)
+        Ok(self.get_utxos_by_addresses_call(GetUtxosByAddressesRequest::new(addresses, None, 0)).await?.entries)
     }
     async fn get_utxos_by_addresses_call(&self, request: GetUtxosByAddressesRequest) -> RpcResult<GetUtxosByAddressesResponse>;
 
@@ -292,7 +304,12 @@ pub trait RpcApi: Sync + Send + AnySync {
 
     ///
     async fn get_coin_supply(&self) -> RpcResult<GetCoinSupplyResponse> {
-        self.get_coin_supply_call(GetCoinSupplyRequest {}).await
+        self.get_coin_supply_call(GetCoinSupplyRequest::new(None, false)).await
+    }
+
+    /// Retrieves the circulating supply as of virtual DAA score `daa_score`.
+    async fn get_coin_supply_at(&self, daa_score: u64) -> RpcResult<GetCoinSupplyResponse> {
+        self.get_coin_supply_call(GetCoinSupplyRequest::new(Some(daa_score), false)).await
     }
     async fn get_coin_supply_call(&self, request: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse>;
 
@@ -304,6 +321,58 @@ pub trait RpcApi: Sync + Send + AnySync {
         request: GetDaaScoreTimestampEstimateRequest,
     ) -> RpcResult<GetDaaScoreTimestampEstimateResponse>;
 
+    /// Retrieves the reference KSH/USD price of the virtual block along with up to `history_length` prices of recent chain blocks.
+    async fn get_price(&self, history_length: u32) -> RpcResult<GetPriceResponse> {
+        self.get_price_call(GetPriceRequest { history_length }).await
+    }
+    async fn get_price_call(&self, request: GetPriceRequest) -> RpcResult<GetPriceResponse>;
+
+    /// Retrieves a transaction accepted by the virtual selected chain, along with the blocks including and accepting it.
+    /// Requires the node to run with the transaction index.
+    async fn get_transaction(&self, transaction_id: RpcTransactionId) -> RpcResult<GetTransactionResponse> {
+        self.get_transaction_call(GetTransactionRequest { transaction_id }).await
+    }
+    async fn get_transaction_call(&self, request: GetTransactionRequest) -> RpcResult<GetTransactionResponse>;
+
+    /// Retrieves up to `limit` accepted transactions involving `address`, ordered by the blue score of their accepting
+    /// chain block and starting at `start_blue_score`, right after `start_transaction_id` if set. Requires the node to run
+    /// with the transaction index.
+    async fn get_transactions_by_address(
+        &self,
+        address: RpcAddress,
+        start_blue_score: u64,
+        start_transaction_id: Option<RpcTransactionId>,
+        limit: u32,
+    ) -> RpcResult<Vec<RpcAddressTransaction>> {
+        Ok(self
+            .get_transactions_by_address_call(GetTransactionsByAddressRequest::new(
+                address,
+                start_blue_score,
+                start_transaction_id,
+                limit,
+            ))
+            .await?
+            .entries)
+    }
+    async fn get_transactions_by_address_call(
+        &self,
+        request: GetTransactionsByAddressRequest,
+    ) -> RpcResult<GetTransactionsByAddressResponse>;
+
+    /// Retrieves a chunk of up to `limit` entries of the virtual or pruning point UTXO set, starting right after
+    /// `from_outpoint`. Exporting the whole set is done by passing back the cursor of each response to
+    /// [`Self::get_utxo_set_call`] until `last_outpoint` is `None`, which the virtual UTXO set requires since resuming
+    /// it takes its `utxo_commitment`. See [`GetUtxoSetRequest`] for details.
+    async fn get_utxo_set(
+        &self,
+        source: RpcUtxoSetSource,
+        from_outpoint: Option<RpcTransactionOutpoint>,
+        limit: u32,
+    ) -> RpcResult<GetUtxoSetResponse> {
+        self.get_utxo_set_call(GetUtxoSetRequest::new(source, None, from_outpoint, limit, None, None, None)).await
+    }
+    async fn get_utxo_set_call(&self, request: GetUtxoSetRequest) -> RpcResult<GetUtxoSetResponse>;
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
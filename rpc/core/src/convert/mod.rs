@@ -1,5 +1,6 @@
 pub mod block;
 pub mod notification;
+pub mod price;
 pub mod scope;
 pub mod tx;
 pub mod utxo;
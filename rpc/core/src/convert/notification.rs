@@ -1,7 +1,8 @@
 use crate::{
     convert::utxo::utxo_set_into_rpc, BlockAddedNotification, FinalityConflictNotification, FinalityConflictResolvedNotification,
-    NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification, RpcAcceptedTransactionIds,
-    SinkBlueScoreChangedNotification, UtxosChangedNotification, VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
+    MempoolChangedNotification, NewBlockTemplateNotification, Notification, PruningPointUtxoSetOverrideNotification,
+    RpcAcceptedTransactionIds, RpcMempoolChangeReason, SinkBlueScoreChangedNotification, UtxosChangedNotification,
+    VirtualChainChangedNotification, VirtualDaaScoreChangedNotification,
 };
 use kash_consensus_notify::notification as consensus_notify;
 use kash_index_core::notification as index_notify;
@@ -29,6 +30,7 @@ impl From<&consensus_notify::Notification> for Notification {
             consensus_notify::Notification::VirtualDaaScoreChanged(msg) => Notification::VirtualDaaScoreChanged(msg.into()),
             consensus_notify::Notification::PruningPointUtxoSetOverride(msg) => Notification::PruningPointUtxoSetOverride(msg.into()),
             consensus_notify::Notification::NewBlockTemplate(msg) => Notification::NewBlockTemplate(msg.into()),
+            consensus_notify::Notification::MempoolChanged(msg) => Notification::MempoolChanged(msg.into()),
         }
     }
 }
@@ -110,6 +112,28 @@ impl From<&consensus_notify::NewBlockTemplateNotification> for NewBlockTemplateN
     }
 }
 
+impl From<consensus_notify::MempoolChangeReason> for RpcMempoolChangeReason {
+    fn from(item: consensus_notify::MempoolChangeReason) -> Self {
+        match item {
+            consensus_notify::MempoolChangeReason::Accepted => RpcMempoolChangeReason::Accepted,
+            consensus_notify::MempoolChangeReason::OrphanResolved => RpcMempoolChangeReason::OrphanResolved,
+            consensus_notify::MempoolChangeReason::IncludedInBlock => RpcMempoolChangeReason::IncludedInBlock,
+            consensus_notify::MempoolChangeReason::DoubleSpentInBlock => RpcMempoolChangeReason::DoubleSpentInBlock,
+            consensus_notify::MempoolChangeReason::Expired => RpcMempoolChangeReason::Expired,
+            consensus_notify::MempoolChangeReason::LowFee => RpcMempoolChangeReason::LowFee,
+            consensus_notify::MempoolChangeReason::Invalidated => RpcMempoolChangeReason::Invalidated,
+        }
+    }
+}
+
+impl From<&consensus_notify::MempoolChangedNotification> for MempoolChangedNotification {
+    // The addresses are left empty because no address prefix is available.
+    // Use kash_rpc_service::converter::consensus::ConsensusConverter instead.
+    fn from(item: &consensus_notify::MempoolChangedNotification) -> Self {
+        Self { transaction_id: item.transaction_id, reason: item.reason.into(), addresses: vec![] }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // index to rpc_core
 // ----------------------------------------------------------------------------
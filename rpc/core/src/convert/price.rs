@@ -0,0 +1,12 @@
+use crate::RpcBlockPrice;
+use kash_consensus_core::price::BlockPrice;
+
+// ----------------------------------------------------------------------------
+// consensus_core to rpc_core
+// ----------------------------------------------------------------------------
+
+impl From<BlockPrice> for RpcBlockPrice {
+    fn from(item: BlockPrice) -> Self {
+        Self { block_hash: item.hash, daa_score: item.daa_score, price: item.price }
+    }
+}
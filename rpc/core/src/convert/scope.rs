@@ -1,7 +1,7 @@
 use crate::{
-    NotifyBlockAddedRequest, NotifyFinalityConflictRequest, NotifyNewBlockTemplateRequest, NotifyPruningPointUtxoSetOverrideRequest,
-    NotifySinkBlueScoreChangedRequest, NotifyUtxosChangedRequest, NotifyVirtualChainChangedRequest,
-    NotifyVirtualDaaScoreChangedRequest,
+    NotifyBlockAddedRequest, NotifyFinalityConflictRequest, NotifyMempoolChangedRequest, NotifyNewBlockTemplateRequest,
+    NotifyPruningPointUtxoSetOverrideRequest, NotifySinkBlueScoreChangedRequest, NotifyUtxosChangedRequest,
+    NotifyVirtualChainChangedRequest, NotifyVirtualDaaScoreChangedRequest,
 };
 use kash_notify::scope::*;
 
@@ -45,7 +45,7 @@ macro_rules! from {
 
 from!(BlockAdded);
 from!(item: VirtualChainChanged, {
-    Self::new(item.include_accepted_transaction_ids)
+    Self::with_start_hash(item.include_accepted_transaction_ids, item.start_hash)
 });
 from!(FinalityConflict);
 impl From<&NotifyFinalityConflictRequest> for FinalityConflictResolvedScope {
@@ -54,9 +54,10 @@ impl From<&NotifyFinalityConflictRequest> for FinalityConflictResolvedScope {
     }
 }
 from!(item: UtxosChanged, {
-    Self::new(item.addresses.clone())
+    Self::with_filter(item.addresses, item.asset_type, item.min_amount)
 });
 from!(SinkBlueScoreChanged);
 from!(VirtualDaaScoreChanged);
 from!(PruningPointUtxoSetOverride);
 from!(NewBlockTemplate);
+from!(MempoolChanged);
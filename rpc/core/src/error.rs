@@ -44,6 +44,9 @@ pub enum RpcError {
     #[error("Coinbase payload is above max length ({0}). Try to shorten the extra data.")]
     CoinbasePayloadLengthAboveMax(usize),
 
+    #[error("Price report signature length {0} is invalid, expected {1} bytes.")]
+    InvalidPriceReportSignatureLength(usize, usize),
+
     #[error("Rejected transaction {0}: {1}")]
     RejectedTransaction(RpcTransactionId, String),
 
@@ -59,6 +62,9 @@ pub enum RpcError {
     #[error("Method unavailable. Run the node with the --utxoindex argument.")]
     NoUtxoIndex,
 
+    #[error("Method unavailable. Run the node with the --txindex argument.")]
+    NoTxIndex,
+
     #[error("Method unavailable. No connection manager is currently available.")]
     NoConnectionManager,
 
@@ -68,9 +74,30 @@ pub enum RpcError {
     #[error("Requested window size {0} is larger than pruning point depth {1}.")]
     WindowSizeExceedingPruningDepth(u32, u64),
 
+    #[error("Requested price history length {0} is larger than max {1} allowed in RPC safe mode.")]
+    PriceHistoryLengthExceedingMaximum(u32, u32),
+
+    #[error("Requested transactions limit {0} is larger than max {1} allowed in RPC safe mode.")]
+    TransactionsLimitExceedingMaximum(u32, u32),
+
+    #[error("Requested headers limit {0} is larger than max {1} allowed in RPC safe mode.")]
+    HeadersLimitExceedingMaximum(u64, u64),
+
+    #[error("Requested UTXO set limit {0} is larger than max {1} allowed in RPC safe mode.")]
+    UtxoSetLimitExceedingMaximum(u32, u32),
+
+    #[error("Invalid UTXO set multiset.")]
+    InvalidUtxoSetMultiset,
+
+    #[error("The virtual UTXO set changed since the previous chunk was read.")]
+    VirtualUtxoSetChanged,
+
     #[error("Method unavailable in safe mode. Run the node with --unsafe argument.")]
     UnavailableInSafeMode,
 
+    #[error("No circulating supply was recorded at or below DAA score {0}.")]
+    NoCoinSupplyAtDaaScore(u64),
+
     #[error("Cannot ban IP {0} because it has some permanent connection.")]
     IpHasPermanentConnection(IpAddress),
 
@@ -115,6 +142,18 @@ pub enum RpcError {
 
     #[error("transaction query must either not filter transactions or include orphans")]
     InconsistentMempoolTxQuery,
+
+    #[error("Authentication required. Provide a bearer token or an API key.")]
+    Unauthenticated,
+
+    #[error("Invalid credentials: {0}")]
+    InvalidCredentials(String),
+
+    #[error("Method {0} is not allowed for this client.")]
+    MethodNotAllowed(String),
+
+    #[error("Rate limit exceeded, retry later.")]
+    RateLimited,
 }
 
 impl From<String> for RpcError {
@@ -1,4 +1,4 @@
-use crate::{RpcTransactionOutpoint, RpcUtxoEntry};
+use crate::{RpcHash, RpcTransactionId, RpcTransactionOutpoint, RpcUtxoEntry};
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +19,22 @@ pub struct RpcUtxosByAddressesEntry {
 pub struct RpcBalancesByAddressesEntry {
     pub address: RpcAddress,
 
-    /// Balance of `address` if available
+    /// Total balance of all asset types of `address` if available
     pub balance: Option<u64>,
+
+    /// Balance of `address` broken down by asset type if available
+    #[serde(default)]
+    pub asset_balance: Option<RpcAssetBalance>,
+}
+
+/// A balance broken down by asset type
+pub type RpcAssetBalance = kash_index_core::indexed_utxos::AssetBalance;
+
+/// A transaction accepted by the virtual selected chain which spends from or pays to an address
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcAddressTransaction {
+    pub transaction_id: RpcTransactionId,
+    pub accepting_block_hash: RpcHash,
+    pub accepting_block_blue_score: u64,
 }
@@ -1,6 +1,7 @@
 use super::RpcAddress;
 use super::RpcTransaction;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use kash_consensus_core::asset_type::AssetType;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
@@ -28,3 +29,40 @@ impl RpcMempoolEntryByAddress {
         Self { address, sending, receiving }
     }
 }
+
+/// Mempool standardness rules applied to a single asset. Fee rates are in sompi of the asset per 1kg of mass.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcMempoolAssetPolicy {
+    pub asset_type: AssetType,
+    /// Minimum fee rate for transactions paying their fee in this asset
+    pub minimum_relay_transaction_fee: u64,
+    /// Fee rate used to define dust outputs of this asset
+    pub dust_relay_transaction_fee: u64,
+}
+
+impl RpcMempoolAssetPolicy {
+    pub fn new(asset_type: AssetType, minimum_relay_transaction_fee: u64, dust_relay_transaction_fee: u64) -> Self {
+        Self { asset_type, minimum_relay_transaction_fee, dust_relay_transaction_fee }
+    }
+}
+
+/// Reason of a transaction entering or leaving the mempool
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcMempoolChangeReason {
+    /// The transaction was accepted into the mempool
+    Accepted,
+    /// The transaction was accepted into the mempool after all its missing outpoints were resolved
+    OrphanResolved,
+    /// The transaction was removed from the mempool after having been included in a block
+    IncludedInBlock,
+    /// The transaction was removed from the mempool because a block transaction spends some of its outpoints
+    DoubleSpentInBlock,
+    /// The low priority transaction was removed from the mempool after having not been included in a block for too long
+    Expired,
+    /// The transaction was evicted from a full mempool, making room for a transaction paying a higher fee rate
+    LowFee,
+    /// The transaction was removed from the mempool after having been found invalid
+    Invalidated,
+}
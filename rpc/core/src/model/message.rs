@@ -1,6 +1,6 @@
 use crate::model::*;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use kash_consensus_core::block_count::BlockCount;
+use kash_consensus_core::{asset_type::AssetType, block_count::BlockCount};
 use kash_core::debug;
 use kash_notify::subscription::{single::UtxosChangedSubscription, Command};
 use serde::{Deserialize, Serialize};
@@ -79,10 +79,16 @@ pub struct GetBlockTemplateRequest {
     /// Which kash address should the coinbase block reward transaction pay into
     pub pay_address: RpcAddress,
     pub extra_data: RpcExtraData,
+    /// Signed KSH/USD price report committed to by the coinbase payload. Required from the price oracle activation on
+    pub price_report: Option<RpcPriceReport>,
 }
 impl GetBlockTemplateRequest {
     pub fn new(pay_address: RpcAddress, extra_data: RpcExtraData) -> Self {
-        Self { pay_address, extra_data }
+        Self { pay_address, extra_data, price_report: None }
+    }
+
+    pub fn with_price_report(self, price_report: Option<RpcPriceReport>) -> Self {
+        Self { price_report, ..self }
     }
 }
 
@@ -135,6 +141,12 @@ pub struct GetInfoResponse {
     pub is_synced: bool,
     pub has_notify_command: bool,
     pub has_message_id: bool,
+    /// Mempool standardness rules of each asset
+    pub mempool_asset_policies: Vec<RpcMempoolAssetPolicy>,
+    /// Maximum number of `MintKUSD` transactions included by this node in a block template
+    pub maximum_mint_transactions_per_block: u64,
+    /// Maximum number of `RedeemKSH` transactions included by this node in a block template
+    pub maximum_redeem_transactions_per_block: u64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
@@ -460,6 +472,7 @@ pub struct ShutdownResponse {}
 #[serde(rename_all = "camelCase")]
 pub struct GetHeadersRequest {
     pub start_hash: RpcHash,
+    /// Maximum number of headers per page, where zero requests the server default
     pub limit: u64,
     pub is_ascending: bool,
 }
@@ -497,12 +510,16 @@ impl GetBalanceByAddressRequest {
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GetBalanceByAddressResponse {
+    /// Total balance of all asset types
     pub balance: u64,
+    /// Balance broken down by asset type, if provided by the node
+    #[serde(default)]
+    pub asset_balance: Option<RpcAssetBalance>,
 }
 
 impl GetBalanceByAddressResponse {
-    pub fn new(balance: u64) -> Self {
-        Self { balance }
+    pub fn new(asset_balance: RpcAssetBalance) -> Self {
+        Self { balance: asset_balance.total(), asset_balance: Some(asset_balance) }
     }
 }
 
@@ -550,11 +567,17 @@ impl GetSinkBlueScoreResponse {
 #[serde(rename_all = "camelCase")]
 pub struct GetUtxosByAddressesRequest {
     pub addresses: Vec<RpcAddress>,
+    /// If set, only UTXOs of this asset type are returned
+    #[serde(default)]
+    pub asset_type: Option<AssetType>,
+    /// Only UTXOs holding at least this amount are returned
+    #[serde(default)]
+    pub min_amount: u64,
 }
 
 impl GetUtxosByAddressesRequest {
-    pub fn new(addresses: Vec<RpcAddress>) -> Self {
-        Self { addresses }
+    pub fn new(addresses: Vec<RpcAddress>, asset_type: Option<AssetType>, min_amount: u64) -> Self {
+        Self { addresses, asset_type, min_amount }
     }
 }
 
@@ -656,7 +679,19 @@ impl GetMempoolEntriesByAddressesResponse {
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct GetCoinSupplyRequest {}
+pub struct GetCoinSupplyRequest {
+    /// If set, the circulating supply as of this virtual DAA score is returned instead of the current one
+    pub daa_score: Option<u64>,
+    /// If set, the node first audits its current circulating supply against a full recount of the indexed utxos,
+    /// failing the request on mismatch. The audit is expensive and thus only available in unsafe RPC mode
+    pub audit: bool,
+}
+
+impl GetCoinSupplyRequest {
+    pub fn new(daa_score: Option<u64>, audit: bool) -> Self {
+        Self { daa_score, audit }
+    }
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
@@ -665,15 +700,24 @@ pub struct GetCoinSupplyResponse {
     pub ksh_circulating_sompi: u64,
     pub kusd_circulating_sompi: u64,
     pub krv_circulating_sompi: u64,
+    /// The virtual DAA score at which the returned circulating supply was recorded
+    pub daa_score: u64,
 }
 
 impl GetCoinSupplyResponse {
-    pub fn new(max_sompi: u64, circulating_ksh_sompi: u64, circulating_kusd_sompi: u64, circulating_krv_sompi: u64) -> Self {
+    pub fn new(
+        max_sompi: u64,
+        circulating_ksh_sompi: u64,
+        circulating_kusd_sompi: u64,
+        circulating_krv_sompi: u64,
+        daa_score: u64,
+    ) -> Self {
         Self {
             max_sompi,
             ksh_circulating_sompi: circulating_ksh_sompi,
             kusd_circulating_sompi: circulating_kusd_sompi,
             krv_circulating_sompi: circulating_krv_sompi,
+            daa_score,
         }
     }
 }
@@ -722,6 +766,10 @@ pub struct ConnectionMetrics {
     pub json_handshake_failures: u64,
 
     pub active_peers: u32,
+
+    pub rpc_authentication_failures: u64,
+    pub rpc_denied_calls: u64,
+    pub rpc_rate_limited_calls: u64,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
@@ -830,6 +878,187 @@ impl GetDaaScoreTimestampEstimateResponse {
     }
 }
 
+/// GetPriceRequest requests the reference KSH/USD price of the virtual block, along with the
+/// reference prices of the `history_length` most recent selected chain blocks
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPriceRequest {
+    pub history_length: u32,
+}
+
+impl GetPriceRequest {
+    pub fn new(history_length: u32) -> Self {
+        Self { history_length }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPriceResponse {
+    /// Reference price of the virtual block, in KUSD sompi per one KSH, if any was reported
+    pub price: Option<u64>,
+    /// Reference prices of the most recent chain blocks, ordered from the sink backwards
+    pub history: Vec<RpcBlockPrice>,
+}
+
+impl GetPriceResponse {
+    pub fn new(price: Option<u64>, history: Vec<RpcBlockPrice>) -> Self {
+        Self { price, history }
+    }
+}
+
+/// GetTransactionRequest requests a transaction accepted by the virtual selected chain.
+/// Requires the node to run with the transaction index
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionRequest {
+    pub transaction_id: RpcTransactionId,
+}
+
+impl GetTransactionRequest {
+    pub fn new(transaction_id: RpcTransactionId) -> Self {
+        Self { transaction_id }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionResponse {
+    pub transaction: RpcTransaction,
+    /// Hash of the block including the transaction
+    pub block_hash: RpcHash,
+    /// Hash of the chain block whose mergeset accepted the transaction
+    pub accepting_block_hash: RpcHash,
+    pub accepting_block_blue_score: u64,
+    /// Blue score distance from the accepting block to the sink, plus one
+    pub confirmations: u64,
+}
+
+impl GetTransactionResponse {
+    pub fn new(
+        transaction: RpcTransaction,
+        block_hash: RpcHash,
+        accepting_block_hash: RpcHash,
+        accepting_block_blue_score: u64,
+        confirmations: u64,
+    ) -> Self {
+        Self { transaction, block_hash, accepting_block_hash, accepting_block_blue_score, confirmations }
+    }
+}
+
+/// GetTransactionsByAddressRequest requests up to `limit` accepted transactions spending from or paying to `address`,
+/// ordered by the blue score of their accepting chain block and by transaction id, and starting at `start_blue_score`.
+///
+/// If `start_transaction_id` is set, the scan resumes right after the transaction `start_transaction_id` accepted at
+/// `start_blue_score`. Passing back the blue score and id of the last entry of a response hence fetches the next page.
+/// Requires the node to run with the transaction index
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionsByAddressRequest {
+    pub address: RpcAddress,
+    pub start_blue_score: u64,
+    pub start_transaction_id: Option<RpcTransactionId>,
+    pub limit: u32,
+}
+
+impl GetTransactionsByAddressRequest {
+    pub fn new(address: RpcAddress, start_blue_score: u64, start_transaction_id: Option<RpcTransactionId>, limit: u32) -> Self {
+        Self { address, start_blue_score, start_transaction_id, limit }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionsByAddressResponse {
+    pub entries: Vec<RpcAddressTransaction>,
+}
+
+impl GetTransactionsByAddressResponse {
+    pub fn new(entries: Vec<RpcAddressTransaction>) -> Self {
+        Self { entries }
+    }
+}
+
+/// The UTXO set iterated by a [`GetUtxoSetRequest`]
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcUtxoSetSource {
+    /// The UTXO set of the virtual. It keeps changing while being exported, hence paging fails once it changes.
+    Virtual,
+    /// The UTXO set of the pruning point. Chunks are consistent as long as the pruning point does not move.
+    PruningPoint,
+}
+
+/// GetUtxoSetRequest requests a chunk of the UTXO set of `source`, ordered by outpoint.
+///
+/// The scan starts right after `from_outpoint` (or at the beginning of the set if `None`) and covers up to `limit` entries,
+/// of which only those of `asset_type` are returned if it is set. Each response carries a cursor made of `last_outpoint`
+/// and `multiset`, which should be passed back as `from_outpoint` and `multiset` for fetching the next chunk. When exporting
+/// the pruning point UTXO set, `pruning_point_hash` should be passed back as well so the request fails if the pruning point moves.
+/// Likewise, when exporting the virtual UTXO set, `utxo_commitment` must be passed back so the request fails with
+/// [`RpcError::VirtualUtxoSetChanged`](crate::RpcError::VirtualUtxoSetChanged) once a block changes the virtual, in which
+/// case the export has to be restarted.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxoSetRequest {
+    pub source: RpcUtxoSetSource,
+    /// Expected pruning point, defaults to the current one. Ignored for the virtual UTXO set.
+    pub pruning_point_hash: Option<RpcHash>,
+    pub from_outpoint: Option<RpcTransactionOutpoint>,
+    pub limit: u32,
+    pub asset_type: Option<AssetType>,
+    /// Serialized MuHash of all previously returned entries
+    pub multiset: Option<Vec<u8>>,
+    /// Expected UTXO commitment of the virtual UTXO set, required when resuming from `from_outpoint`. Ignored for the
+    /// pruning point UTXO set.
+    #[serde(default)]
+    pub utxo_commitment: Option<RpcHash>,
+}
+
+impl GetUtxoSetRequest {
+    pub fn new(
+        source: RpcUtxoSetSource,
+        pruning_point_hash: Option<RpcHash>,
+        from_outpoint: Option<RpcTransactionOutpoint>,
+        limit: u32,
+        asset_type: Option<AssetType>,
+        multiset: Option<Vec<u8>>,
+        utxo_commitment: Option<RpcHash>,
+    ) -> Self {
+        Self { source, pruning_point_hash, from_outpoint, limit, asset_type, multiset, utxo_commitment }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxoSetResponse {
+    pub entries: Vec<RpcUtxosByAddressesEntry>,
+    /// The pruning point the chunk was read at, `None` for the virtual UTXO set
+    pub pruning_point_hash: Option<RpcHash>,
+    /// The last scanned outpoint, `None` once the end of the set was reached
+    pub last_outpoint: Option<RpcTransactionOutpoint>,
+    /// Serialized MuHash of all entries returned so far, including this chunk
+    pub multiset: Vec<u8>,
+    /// Finalized hash of `multiset`
+    pub multiset_hash: RpcHash,
+    /// The UTXO commitment of the pruning point or of the virtual the chunk was read at. Once the whole set was exported
+    /// without an asset filter, `multiset_hash` is expected to match it.
+    pub utxo_commitment: Option<RpcHash>,
+}
+
+impl GetUtxoSetResponse {
+    pub fn new(
+        entries: Vec<RpcUtxosByAddressesEntry>,
+        pruning_point_hash: Option<RpcHash>,
+        last_outpoint: Option<RpcTransactionOutpoint>,
+        multiset: Vec<u8>,
+        multiset_hash: RpcHash,
+        utxo_commitment: Option<RpcHash>,
+    ) -> Self {
+        Self { entries, pruning_point_hash, last_outpoint, multiset, multiset_hash, utxo_commitment }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Subscriptions & notifications
 // ----------------------------------------------------------------------------
@@ -877,11 +1106,18 @@ pub struct BlockAddedNotification {
 pub struct NotifyVirtualChainChangedRequest {
     pub include_accepted_transaction_ids: bool,
     pub command: Command,
+    /// If set, the changes of the virtual chain since this chain block are replayed before live notifications
+    #[serde(default)]
+    pub start_hash: Option<RpcHash>,
 }
 
 impl NotifyVirtualChainChangedRequest {
     pub fn new(include_accepted_transaction_ids: bool, command: Command) -> Self {
-        Self { include_accepted_transaction_ids, command }
+        Self { include_accepted_transaction_ids, command, start_hash: None }
+    }
+
+    pub fn with_start_hash(include_accepted_transaction_ids: bool, command: Command, start_hash: Option<RpcHash>) -> Self {
+        Self { include_accepted_transaction_ids, command, start_hash }
     }
 }
 
@@ -968,11 +1204,21 @@ pub struct FinalityConflictResolvedNotification {
 pub struct NotifyUtxosChangedRequest {
     pub addresses: Vec<RpcAddress>,
     pub command: Command,
+    /// If set, only UTXOs of this asset type are notified
+    #[serde(default)]
+    pub asset_type: Option<AssetType>,
+    /// Only UTXOs holding at least this amount are notified
+    #[serde(default)]
+    pub min_amount: u64,
 }
 
 impl NotifyUtxosChangedRequest {
     pub fn new(addresses: Vec<RpcAddress>, command: Command) -> Self {
-        Self { addresses, command }
+        Self { addresses, command, asset_type: None, min_amount: 0 }
+    }
+
+    pub fn with_filter(addresses: Vec<RpcAddress>, command: Command, asset_type: Option<AssetType>, min_amount: u64) -> Self {
+        Self { addresses, command, asset_type, min_amount }
     }
 }
 
@@ -992,7 +1238,7 @@ pub struct UtxosChangedNotification {
 
 impl UtxosChangedNotification {
     pub(crate) fn apply_utxos_changed_subscription(&self, subscription: &UtxosChangedSubscription) -> Option<Self> {
-        if subscription.to_all() {
+        if subscription.to_all() && !subscription.is_filtered() {
             Some(self.clone())
         } else {
             let added = Self::filter_utxos(&self.added, subscription);
@@ -1007,7 +1253,14 @@ impl UtxosChangedNotification {
     }
 
     fn filter_utxos(utxo_set: &[RpcUtxosByAddressesEntry], subscription: &UtxosChangedSubscription) -> Vec<RpcUtxosByAddressesEntry> {
-        utxo_set.iter().filter(|x| subscription.addresses().contains_key(&x.utxo_entry.script_public_key)).cloned().collect()
+        utxo_set
+            .iter()
+            .filter(|x| {
+                (subscription.to_all() || subscription.addresses().contains_key(&x.utxo_entry.script_public_key))
+                    && subscription.matches_entry(&x.utxo_entry.script_public_key, x.utxo_entry.asset_type, x.utxo_entry.amount)
+            })
+            .cloned()
+            .collect()
     }
 }
 
@@ -1129,6 +1382,42 @@ pub struct NotifyNewBlockTemplateResponse {}
 #[serde(rename_all = "camelCase")]
 pub struct NewBlockTemplateNotification {}
 
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~
+// MempoolChangedNotification
+
+/// NotifyMempoolChangedRequest registers this connection for mempoolChanged notifications.
+///
+/// See: MempoolChangedNotification
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMempoolChangedRequest {
+    pub command: Command,
+}
+
+impl NotifyMempoolChangedRequest {
+    pub fn new(command: Command) -> Self {
+        Self { command }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotifyMempoolChangedResponse {}
+
+/// MempoolChangedNotification is sent whenever a transaction enters or leaves the mempool.
+/// Orphan transactions are only notified once resolved.
+///
+/// `addresses` holds the addresses of the transaction outputs and of its populated inputs.
+///
+/// See: NotifyMempoolChangedRequest
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolChangedNotification {
+    pub transaction_id: RpcTransactionId,
+    pub reason: RpcMempoolChangeReason,
+    pub addresses: Vec<RpcAddress>,
+}
+
 ///
 ///  wRPC response for RpcApiOps::Subscribe request
 ///
@@ -1150,3 +1439,36 @@ impl SubscribeResponse {
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UnsubscribeResponse {}
+
+///
+///  wRPC request for RpcApiOps::Authenticate, granting the connection the rights of the presented credentials
+///
+/// The `authorization` field holds either `Bearer <token>` or `HMAC <keyId>:<unixMillis>:<hexSignature>`,
+/// the API key signature covering the `WRPC:authenticate` request with an empty body.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticateRequest {
+    pub authorization: String,
+}
+
+impl AuthenticateRequest {
+    pub fn new(authorization: String) -> Self {
+        Self { authorization }
+    }
+}
+
+///
+///  wRPC response for RpcApiOps::Authenticate request
+///
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticateResponse {
+    /// Name of the principal the connection is now authenticated as
+    pub principal: String,
+}
+
+impl AuthenticateResponse {
+    pub fn new(principal: String) -> Self {
+        Self { principal }
+    }
+}
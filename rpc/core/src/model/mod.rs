@@ -8,6 +8,7 @@ pub mod mempool;
 pub mod message;
 pub mod network;
 pub mod peer;
+pub mod price;
 pub mod script_class;
 pub mod subnets;
 pub mod tx;
@@ -22,5 +23,6 @@ pub use mempool::*;
 pub use message::*;
 pub use network::*;
 pub use peer::*;
+pub use price::*;
 pub use subnets::*;
 pub use tx::*;
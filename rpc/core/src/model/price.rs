@@ -0,0 +1,42 @@
+use crate::{RpcError, RpcHash};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use kash_consensus_core::price::{PriceReport, PRICE_REPORT_SIGNATURE_LEN};
+use serde::{Deserialize, Serialize};
+
+/// Represents the reference KSH/USD price of a chain block, expressed in KUSD sompi per one KSH
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcBlockPrice {
+    pub block_hash: RpcHash,
+    pub daa_score: u64,
+    pub price: u64,
+}
+
+/// Represents a KSH/USD price report signed by one of the consensus price oracles, expressed in KUSD sompi per one KSH
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, BorshSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcPriceReport {
+    pub price: u64,
+    /// Unix time (in milliseconds) at which the report was signed
+    pub timestamp: u64,
+    pub oracle_index: u8,
+    pub signature: Vec<u8>,
+}
+
+impl From<&PriceReport> for RpcPriceReport {
+    fn from(item: &PriceReport) -> Self {
+        Self { price: item.price, timestamp: item.timestamp, oracle_index: item.oracle_index, signature: item.signature.to_vec() }
+    }
+}
+
+impl TryFrom<&RpcPriceReport> for PriceReport {
+    type Error = RpcError;
+    fn try_from(item: &RpcPriceReport) -> Result<Self, Self::Error> {
+        let signature = item
+            .signature
+            .as_slice()
+            .try_into()
+            .map_err(|_| RpcError::InvalidPriceReportSignatureLength(item.signature.len(), PRICE_REPORT_SIGNATURE_LEN))?;
+        Ok(Self { price: item.price, timestamp: item.timestamp, oracle_index: item.oracle_index, signature })
+    }
+}
@@ -225,6 +225,10 @@ impl RpcApi for GrpcClient {
     route!(get_mempool_entries_by_addresses_call, GetMempoolEntriesByAddresses);
     route!(get_coin_supply_call, GetCoinSupply);
     route!(get_daa_score_timestamp_estimate_call, GetDaaScoreTimestampEstimate);
+    route!(get_price_call, GetPrice);
+    route!(get_transaction_call, GetTransaction);
+    route!(get_transactions_by_address_call, GetTransactionsByAddress);
+    route!(get_utxo_set_call, GetUtxoSet);
 
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
@@ -549,7 +553,9 @@ impl Inner {
             let subscriptions = subscriptions.lock().await;
             for event in EVENT_TYPE_ARRAY {
                 if subscriptions[event].active() {
-                    self.clone().start_notify_to_client(subscriptions[event].scope()).await?;
+                    for scope in subscriptions[event].scopes() {
+                        self.clone().start_notify_to_client(scope).await?;
+                    }
                 }
             }
         }
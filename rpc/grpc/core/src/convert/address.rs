@@ -1,20 +1,54 @@
 use crate::protowire;
 use crate::{from, try_from};
-use kash_rpc_core::RpcError;
+use kash_rpc_core::{RpcError, RpcHash, RpcTransactionId};
+use std::str::FromStr;
 
 // ----------------------------------------------------------------------------
 // rpc_core to protowire
 // ----------------------------------------------------------------------------
 
+from!(item: &kash_rpc_core::RpcAssetBalance, protowire::RpcAssetBalance, {
+    Self { ksh: item.ksh, kusd: item.kusd, krv: item.krv }
+});
+
 from!(item: &kash_rpc_core::RpcBalancesByAddressesEntry, protowire::RpcBalancesByAddressesEntry, {
-    Self { address: (&item.address).into(), balance: item.balance.unwrap_or_default(), error: None }
+    Self {
+        address: (&item.address).into(),
+        balance: item.balance.unwrap_or_default(),
+        asset_balance: item.asset_balance.as_ref().map(|x| x.into()),
+        error: None,
+    }
+});
+
+from!(item: &kash_rpc_core::RpcAddressTransaction, protowire::RpcAddressTransaction, {
+    Self {
+        transaction_id: item.transaction_id.to_string(),
+        accepting_block_hash: item.accepting_block_hash.to_string(),
+        accepting_block_blue_score: item.accepting_block_blue_score,
+    }
 });
 
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
 
+try_from!(item: &protowire::RpcAssetBalance, kash_rpc_core::RpcAssetBalance, {
+    Self { ksh: item.ksh, kusd: item.kusd, krv: item.krv }
+});
+
 try_from!(item: &protowire::RpcBalancesByAddressesEntry, kash_rpc_core::RpcBalancesByAddressesEntry, {
-    let balance = if item.error.is_some() { None } else { Some(item.balance) };
-    Self { address: item.address.as_str().try_into()?, balance }
+    let (balance, asset_balance) = if item.error.is_some() {
+        (None, None)
+    } else {
+        (Some(item.balance), item.asset_balance.as_ref().map(|x| x.try_into()).transpose()?)
+    };
+    Self { address: item.address.as_str().try_into()?, balance, asset_balance }
+});
+
+try_from!(item: &protowire::RpcAddressTransaction, kash_rpc_core::RpcAddressTransaction, {
+    Self {
+        transaction_id: RpcTransactionId::from_str(&item.transaction_id)?,
+        accepting_block_hash: RpcHash::from_str(&item.accepting_block_hash)?,
+        accepting_block_blue_score: item.accepting_block_blue_score,
+    }
 });
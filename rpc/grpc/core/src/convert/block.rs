@@ -1,6 +1,6 @@
 use crate::protowire;
 use crate::{from, try_from};
-use kash_rpc_core::{RpcError, RpcHash};
+use kash_rpc_core::{FromRpcHex, RpcError, RpcHash, ToRpcHex};
 use std::str::FromStr;
 
 // ----------------------------------------------------------------------------
@@ -30,6 +30,19 @@ from!(item: &kash_rpc_core::RpcBlockVerboseData, protowire::RpcBlockVerboseData,
     }
 });
 
+from!(item: &kash_rpc_core::RpcBlockPrice, protowire::RpcBlockPrice, {
+    Self { block_hash: item.block_hash.to_string(), daa_score: item.daa_score, price: item.price }
+});
+
+from!(item: &kash_rpc_core::RpcPriceReport, protowire::RpcPriceReport, {
+    Self {
+        price: item.price,
+        timestamp: item.timestamp,
+        oracle_index: item.oracle_index as u32,
+        signature: item.signature.to_rpc_hex(),
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -76,3 +89,16 @@ try_from!(item: &protowire::RpcBlockVerboseData, kash_rpc_core::RpcBlockVerboseD
         is_chain_block: item.is_chain_block,
     }
 });
+
+try_from!(item: &protowire::RpcBlockPrice, kash_rpc_core::RpcBlockPrice, {
+    Self { block_hash: RpcHash::from_str(&item.block_hash)?, daa_score: item.daa_score, price: item.price }
+});
+
+try_from!(item: &protowire::RpcPriceReport, kash_rpc_core::RpcPriceReport, {
+    Self {
+        price: item.price,
+        timestamp: item.timestamp,
+        oracle_index: item.oracle_index.try_into()?,
+        signature: Vec::from_rpc_hex(&item.signature)?,
+    }
+});
@@ -57,6 +57,10 @@ pub mod kashd_request_convert {
     impl_into_kashd_request!(GetServerInfo);
     impl_into_kashd_request!(GetSyncStatus);
     impl_into_kashd_request!(GetDaaScoreTimestampEstimate);
+    impl_into_kashd_request!(GetPrice);
+    impl_into_kashd_request!(GetTransaction);
+    impl_into_kashd_request!(GetTransactionsByAddress);
+    impl_into_kashd_request!(GetUtxoSet);
 
     impl_into_kashd_request!(NotifyBlockAdded);
     impl_into_kashd_request!(NotifyNewBlockTemplate);
@@ -66,6 +70,7 @@ pub mod kashd_request_convert {
     impl_into_kashd_request!(NotifyVirtualDaaScoreChanged);
     impl_into_kashd_request!(NotifyVirtualChainChanged);
     impl_into_kashd_request!(NotifySinkBlueScoreChanged);
+    impl_into_kashd_request!(NotifyMempoolChanged);
 
     macro_rules! impl_into_kashd_request {
         ($name:tt) => {
@@ -188,6 +193,10 @@ pub mod kashd_response_convert {
     impl_into_kashd_response!(GetServerInfo);
     impl_into_kashd_response!(GetSyncStatus);
     impl_into_kashd_response!(GetDaaScoreTimestampEstimate);
+    impl_into_kashd_response!(GetPrice);
+    impl_into_kashd_response!(GetTransaction);
+    impl_into_kashd_response!(GetTransactionsByAddress);
+    impl_into_kashd_response!(GetUtxoSet);
 
     impl_into_kashd_notify_response!(NotifyBlockAdded);
     impl_into_kashd_notify_response!(NotifyNewBlockTemplate);
@@ -197,6 +206,7 @@ pub mod kashd_response_convert {
     impl_into_kashd_notify_response!(NotifyVirtualDaaScoreChanged);
     impl_into_kashd_notify_response!(NotifyVirtualChainChanged);
     impl_into_kashd_notify_response!(NotifySinkBlueScoreChanged);
+    impl_into_kashd_notify_response!(NotifyMempoolChanged);
 
     impl_into_kashd_notify_response!(NotifyUtxosChanged, StopNotifyingUtxosChanged);
     impl_into_kashd_notify_response!(NotifyPruningPointUtxoSetOverride, StopNotifyingPruningPointUtxoSetOverride);
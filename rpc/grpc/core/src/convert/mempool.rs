@@ -1,5 +1,6 @@
 use crate::protowire;
 use crate::{from, try_from};
+use kash_consensus_core::asset_type::AssetType;
 use kash_rpc_core::RpcError;
 
 // ----------------------------------------------------------------------------
@@ -18,6 +19,14 @@ from!(item: &kash_rpc_core::RpcMempoolEntryByAddress, protowire::RpcMempoolEntry
     }
 });
 
+from!(item: &kash_rpc_core::RpcMempoolAssetPolicy, protowire::RpcMempoolAssetPolicy, {
+    Self {
+        asset_type: item.asset_type.into(),
+        minimum_relay_transaction_fee: item.minimum_relay_transaction_fee,
+        dust_relay_transaction_fee: item.dust_relay_transaction_fee,
+    }
+});
+
 // ----------------------------------------------------------------------------
 // protowire to rpc_core
 // ----------------------------------------------------------------------------
@@ -40,3 +49,7 @@ try_from!(item: &protowire::RpcMempoolEntryByAddress, kash_rpc_core::RpcMempoolE
         item.receiving.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
     )
 });
+
+try_from!(item: &protowire::RpcMempoolAssetPolicy, kash_rpc_core::RpcMempoolAssetPolicy, {
+    Self::new(AssetType::from(item.asset_type), item.minimum_relay_transaction_fee, item.dust_relay_transaction_fee)
+});
@@ -23,8 +23,8 @@ use kash_consensus_core::network::NetworkId;
 use kash_core::debug;
 use kash_notify::subscription::Command;
 use kash_rpc_core::{
-    RpcContextualPeerAddress, RpcError, RpcExtraData, RpcHash, RpcIpAddress, RpcNetworkType, RpcPeerAddress, RpcResult,
-    SubmitBlockRejectReason, SubmitBlockReport,
+    FromRpcHex, RpcContextualPeerAddress, RpcError, RpcExtraData, RpcHash, RpcIpAddress, RpcNetworkType, RpcPeerAddress, RpcResult,
+    RpcUtxoSetSource, SubmitBlockRejectReason, SubmitBlockReport, ToRpcHex,
 };
 use std::str::FromStr;
 
@@ -155,6 +155,7 @@ from!(item: &kash_rpc_core::GetBlockTemplateRequest, protowire::GetBlockTemplate
     Self {
         pay_address: (&item.pay_address).into(),
         extra_data: String::from_utf8(item.extra_data.clone()).expect("extra data has to be valid UTF-8"),
+        price_report: item.price_report.as_ref().map(|x| x.into()),
     }
 });
 from!(item: RpcResult<&kash_rpc_core::GetBlockTemplateResponse>, protowire::GetBlockTemplateResponseMessage, {
@@ -183,6 +184,9 @@ from!(item: RpcResult<&kash_rpc_core::GetInfoResponse>, protowire::GetInfoRespon
         is_synced: item.is_synced,
         has_notify_command: item.has_notify_command,
         has_message_id: item.has_message_id,
+        mempool_asset_policies: item.mempool_asset_policies.iter().map(|x| x.into()).collect(),
+        maximum_mint_transactions_per_block: item.maximum_mint_transactions_per_block,
+        maximum_redeem_transactions_per_block: item.maximum_redeem_transactions_per_block,
         error: None,
     }
 });
@@ -192,6 +196,11 @@ from!(item: &kash_rpc_core::NotifyNewBlockTemplateRequest, protowire::NotifyNewB
 });
 from!(RpcResult<&kash_rpc_core::NotifyNewBlockTemplateResponse>, protowire::NotifyNewBlockTemplateResponseMessage);
 
+from!(item: &kash_rpc_core::NotifyMempoolChangedRequest, protowire::NotifyMempoolChangedRequestMessage, {
+    Self { command: item.command.into() }
+});
+from!(RpcResult<&kash_rpc_core::NotifyMempoolChangedResponse>, protowire::NotifyMempoolChangedResponseMessage);
+
 // ~~~
 
 from!(&kash_rpc_core::GetCurrentNetworkRequest, protowire::GetCurrentNetworkRequestMessage);
@@ -320,11 +329,16 @@ from!(item: &kash_rpc_core::GetHeadersRequest, protowire::GetHeadersRequestMessa
     Self { start_hash: item.start_hash.to_string(), limit: item.limit, is_ascending: item.is_ascending }
 });
 from!(item: RpcResult<&kash_rpc_core::GetHeadersResponse>, protowire::GetHeadersResponseMessage, {
-    Self { headers: item.headers.iter().map(|x| x.hash.to_string()).collect(), error: None }
+    Self { headers: item.headers.iter().map(|x| x.into()).collect(), error: None }
 });
 
 from!(item: &kash_rpc_core::GetUtxosByAddressesRequest, protowire::GetUtxosByAddressesRequestMessage, {
-    Self { addresses: item.addresses.iter().map(|x| x.into()).collect() }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        filter_by_asset_type: item.asset_type.is_some(),
+        asset_type: item.asset_type.map_or(Default::default(), |x| x.into()),
+        min_amount: item.min_amount,
+    }
 });
 from!(item: RpcResult<&kash_rpc_core::GetUtxosByAddressesResponse>, protowire::GetUtxosByAddressesResponseMessage, {
     debug!("GRPC, Creating GetUtxosByAddresses message with {} entries", item.entries.len());
@@ -336,7 +350,7 @@ from!(item: &kash_rpc_core::GetBalanceByAddressRequest, protowire::GetBalanceByA
 });
 from!(item: RpcResult<&kash_rpc_core::GetBalanceByAddressResponse>, protowire::GetBalanceByAddressResponseMessage, {
     debug!("GRPC, Creating GetBalanceByAddress messages");
-    Self { balance: item.balance, error: None }
+    Self { balance: item.balance, asset_balance: item.asset_balance.as_ref().map(|x| x.into()), error: None }
 });
 
 from!(item: &kash_rpc_core::GetBalancesByAddressesRequest, protowire::GetBalancesByAddressesRequestMessage, {
@@ -380,13 +394,16 @@ from!(
     { Self { entries: item.entries.iter().map(|x| x.into()).collect(), error: None } }
 );
 
-from!(&kash_rpc_core::GetCoinSupplyRequest, protowire::GetCoinSupplyRequestMessage);
+from!(item: &kash_rpc_core::GetCoinSupplyRequest, protowire::GetCoinSupplyRequestMessage, {
+    Self { historical: item.daa_score.is_some(), daa_score: item.daa_score.unwrap_or_default(), audit: item.audit }
+});
 from!(item: RpcResult<&kash_rpc_core::GetCoinSupplyResponse>, protowire::GetCoinSupplyResponseMessage, {
     Self {
         max_sompi: item.max_sompi,
         ksh_circulating_sompi: item.ksh_circulating_sompi,
         kusd_circulating_sompi: item.kusd_circulating_sompi,
         krv_circulating_sompi: item.krv_circulating_sompi,
+        daa_score: item.daa_score,
         error: None
     }
 });
@@ -400,6 +417,72 @@ from!(item: RpcResult<&kash_rpc_core::GetDaaScoreTimestampEstimateResponse>, pro
     Self { timestamps: item.timestamps.clone(), error: None }
 });
 
+from!(item: &kash_rpc_core::GetPriceRequest, protowire::GetPriceRequestMessage, { Self { history_length: item.history_length } });
+from!(item: RpcResult<&kash_rpc_core::GetPriceResponse>, protowire::GetPriceResponseMessage, {
+    Self {
+        price: item.price.unwrap_or_default(),
+        history: item.history.iter().map(|x| x.into()).collect(),
+        error: None,
+    }
+});
+
+from!(item: &kash_rpc_core::GetTransactionRequest, protowire::GetTransactionRequestMessage, {
+    Self { transaction_id: item.transaction_id.to_string() }
+});
+from!(item: RpcResult<&kash_rpc_core::GetTransactionResponse>, protowire::GetTransactionResponseMessage, {
+    Self {
+        transaction: Some((&item.transaction).into()),
+        block_hash: item.block_hash.to_string(),
+        accepting_block_hash: item.accepting_block_hash.to_string(),
+        accepting_block_blue_score: item.accepting_block_blue_score,
+        confirmations: item.confirmations,
+        error: None,
+    }
+});
+
+from!(item: &kash_rpc_core::GetTransactionsByAddressRequest, protowire::GetTransactionsByAddressRequestMessage, {
+    Self {
+        address: (&item.address).into(),
+        start_blue_score: item.start_blue_score,
+        limit: item.limit,
+        start_transaction_id: item.start_transaction_id.map_or(Default::default(), |x| x.to_string()),
+    }
+});
+from!(item: RpcResult<&kash_rpc_core::GetTransactionsByAddressResponse>, protowire::GetTransactionsByAddressResponseMessage, {
+    Self { entries: item.entries.iter().map(|x| x.into()).collect(), error: None }
+});
+
+from!(item: &RpcUtxoSetSource, protowire::RpcUtxoSetSource, {
+    match item {
+        RpcUtxoSetSource::Virtual => protowire::RpcUtxoSetSource::Virtual,
+        RpcUtxoSetSource::PruningPoint => protowire::RpcUtxoSetSource::PruningPoint,
+    }
+});
+
+from!(item: &kash_rpc_core::GetUtxoSetRequest, protowire::GetUtxoSetRequestMessage, {
+    Self {
+        source: protowire::RpcUtxoSetSource::from(&item.source) as i32,
+        pruning_point_hash: item.pruning_point_hash.map_or(Default::default(), |x| x.to_string()),
+        from_outpoint: item.from_outpoint.as_ref().map(|x| x.into()),
+        limit: item.limit,
+        filter_by_asset_type: item.asset_type.is_some(),
+        asset_type: item.asset_type.map_or(Default::default(), |x| x.into()),
+        multiset: item.multiset.as_ref().map_or(Default::default(), |x| x.to_rpc_hex()),
+        utxo_commitment: item.utxo_commitment.map_or(Default::default(), |x| x.to_string()),
+    }
+});
+from!(item: RpcResult<&kash_rpc_core::GetUtxoSetResponse>, protowire::GetUtxoSetResponseMessage, {
+    Self {
+        entries: item.entries.iter().map(|x| x.into()).collect(),
+        pruning_point_hash: item.pruning_point_hash.map_or(Default::default(), |x| x.to_string()),
+        last_outpoint: item.last_outpoint.as_ref().map(|x| x.into()),
+        multiset: item.multiset.to_rpc_hex(),
+        multiset_hash: item.multiset_hash.to_string(),
+        utxo_commitment: item.utxo_commitment.map_or(Default::default(), |x| x.to_string()),
+        error: None,
+    }
+});
+
 from!(&kash_rpc_core::PingRequest, protowire::PingRequestMessage);
 from!(RpcResult<&kash_rpc_core::PingResponse>, protowire::PingResponseMessage);
 
@@ -443,7 +526,13 @@ from!(item: RpcResult<&kash_rpc_core::GetSyncStatusResponse>, protowire::GetSync
 });
 
 from!(item: &kash_rpc_core::NotifyUtxosChangedRequest, protowire::NotifyUtxosChangedRequestMessage, {
-    Self { addresses: item.addresses.iter().map(|x| x.into()).collect(), command: item.command.into() }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+        filter_by_asset_type: item.asset_type.is_some(),
+        asset_type: item.asset_type.map_or(Default::default(), |x| x.into()),
+        min_amount: item.min_amount,
+        command: item.command.into(),
+    }
 });
 from!(item: &kash_rpc_core::NotifyUtxosChangedRequest, protowire::StopNotifyingUtxosChangedRequestMessage, {
     Self { addresses: item.addresses.iter().map(|x| x.into()).collect() }
@@ -475,7 +564,11 @@ from!(item: &kash_rpc_core::NotifyVirtualDaaScoreChangedRequest, protowire::Noti
 from!(RpcResult<&kash_rpc_core::NotifyVirtualDaaScoreChangedResponse>, protowire::NotifyVirtualDaaScoreChangedResponseMessage);
 
 from!(item: &kash_rpc_core::NotifyVirtualChainChangedRequest, protowire::NotifyVirtualChainChangedRequestMessage, {
-    Self { include_accepted_transaction_ids: item.include_accepted_transaction_ids, command: item.command.into() }
+    Self {
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        start_hash: item.start_hash.map_or(Default::default(), |x| x.to_string()),
+        command: item.command.into(),
+    }
 });
 from!(RpcResult<&kash_rpc_core::NotifyVirtualChainChangedResponse>, protowire::NotifyVirtualChainChangedResponseMessage);
 
@@ -533,7 +626,11 @@ impl TryFrom<&protowire::SubmitBlockResponseMessage> for kash_rpc_core::SubmitBl
 }
 
 try_from!(item: &protowire::GetBlockTemplateRequestMessage, kash_rpc_core::GetBlockTemplateRequest, {
-    Self { pay_address: item.pay_address.clone().try_into()?, extra_data: RpcExtraData::from_iter(item.extra_data.bytes()) }
+    Self {
+        pay_address: item.pay_address.clone().try_into()?,
+        extra_data: RpcExtraData::from_iter(item.extra_data.bytes()),
+        price_report: item.price_report.as_ref().map(kash_rpc_core::RpcPriceReport::try_from).transpose()?,
+    }
 });
 try_from!(item: &protowire::GetBlockTemplateResponseMessage, RpcResult<kash_rpc_core::GetBlockTemplateResponse>, {
     Self {
@@ -574,6 +671,9 @@ try_from!(item: &protowire::GetInfoResponseMessage, RpcResult<kash_rpc_core::Get
         is_synced: item.is_synced,
         has_notify_command: item.has_notify_command,
         has_message_id: item.has_message_id,
+        mempool_asset_policies: item.mempool_asset_policies.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()?,
+        maximum_mint_transactions_per_block: item.maximum_mint_transactions_per_block,
+        maximum_redeem_transactions_per_block: item.maximum_redeem_transactions_per_block,
     }
 });
 
@@ -582,6 +682,11 @@ try_from!(item: &protowire::NotifyNewBlockTemplateRequestMessage, kash_rpc_core:
 });
 try_from!(&protowire::NotifyNewBlockTemplateResponseMessage, RpcResult<kash_rpc_core::NotifyNewBlockTemplateResponse>);
 
+try_from!(item: &protowire::NotifyMempoolChangedRequestMessage, kash_rpc_core::NotifyMempoolChangedRequest, {
+    Self { command: item.command.into() }
+});
+try_from!(&protowire::NotifyMempoolChangedResponseMessage, RpcResult<kash_rpc_core::NotifyMempoolChangedResponse>);
+
 // ~~~
 
 try_from!(&protowire::GetCurrentNetworkRequestMessage, kash_rpc_core::GetCurrentNetworkRequest);
@@ -722,12 +827,15 @@ try_from!(item: &protowire::GetHeadersRequestMessage, kash_rpc_core::GetHeadersR
     Self { start_hash: RpcHash::from_str(&item.start_hash)?, limit: item.limit, is_ascending: item.is_ascending }
 });
 try_from!(item: &protowire::GetHeadersResponseMessage, RpcResult<kash_rpc_core::GetHeadersResponse>, {
-    // TODO
-    Self { headers: vec![] }
+    Self { headers: item.headers.iter().map(kash_rpc_core::RpcHeader::try_from).collect::<Result<Vec<_>, _>>()? }
 });
 
 try_from!(item: &protowire::GetUtxosByAddressesRequestMessage, kash_rpc_core::GetUtxosByAddressesRequest, {
-    Self { addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()? }
+    Self {
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+        asset_type: if item.filter_by_asset_type { Some(item.asset_type.into()) } else { None },
+        min_amount: item.min_amount,
+    }
 });
 try_from!(item: &protowire::GetUtxosByAddressesResponseMessage, RpcResult<kash_rpc_core::GetUtxosByAddressesResponse>, {
     Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? }
@@ -737,7 +845,7 @@ try_from!(item: &protowire::GetBalanceByAddressRequestMessage, kash_rpc_core::Ge
     Self { address: item.address.as_str().try_into()? }
 });
 try_from!(item: &protowire::GetBalanceByAddressResponseMessage, RpcResult<kash_rpc_core::GetBalanceByAddressResponse>, {
-    Self { balance: item.balance }
+    Self { balance: item.balance, asset_balance: item.asset_balance.as_ref().map(|x| x.try_into()).transpose()? }
 });
 
 try_from!(item: &protowire::GetBalancesByAddressesRequestMessage, kash_rpc_core::GetBalancesByAddressesRequest, {
@@ -783,9 +891,11 @@ try_from!(
     { Self { entries: item.entries.iter().map(|x| x.try_into()).collect::<Result<Vec<_>, _>>()? } }
 );
 
-try_from!(&protowire::GetCoinSupplyRequestMessage, kash_rpc_core::GetCoinSupplyRequest);
+try_from!(item: &protowire::GetCoinSupplyRequestMessage, kash_rpc_core::GetCoinSupplyRequest, {
+    Self { daa_score: if item.historical { Some(item.daa_score) } else { None }, audit: item.audit }
+});
 try_from!(item: &protowire::GetCoinSupplyResponseMessage, RpcResult<kash_rpc_core::GetCoinSupplyResponse>, {
-    Self { max_sompi: item.max_sompi, ksh_circulating_sompi: item.ksh_circulating_sompi, kusd_circulating_sompi: item.kusd_circulating_sompi, krv_circulating_sompi: item.krv_circulating_sompi }
+    Self { max_sompi: item.max_sompi, ksh_circulating_sompi: item.ksh_circulating_sompi, kusd_circulating_sompi: item.kusd_circulating_sompi, krv_circulating_sompi: item.krv_circulating_sompi, daa_score: item.daa_score }
 });
 
 try_from!(item: &protowire::GetDaaScoreTimestampEstimateRequestMessage, kash_rpc_core::GetDaaScoreTimestampEstimateRequest , {
@@ -797,6 +907,77 @@ try_from!(item: &protowire::GetDaaScoreTimestampEstimateResponseMessage, RpcResu
     Self { timestamps: item.timestamps.clone() }
 });
 
+try_from!(item: &protowire::GetPriceRequestMessage, kash_rpc_core::GetPriceRequest, { Self { history_length: item.history_length } });
+try_from!(item: &protowire::GetPriceResponseMessage, RpcResult<kash_rpc_core::GetPriceResponse>, {
+    Self {
+        // A reported price can never be zero, so zero stands for no price
+        price: Some(item.price).filter(|&price| price != 0),
+        history: item.history.iter().map(kash_rpc_core::RpcBlockPrice::try_from).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
+try_from!(item: &protowire::GetTransactionRequestMessage, kash_rpc_core::GetTransactionRequest, {
+    Self { transaction_id: kash_rpc_core::RpcTransactionId::from_str(&item.transaction_id)? }
+});
+try_from!(item: &protowire::GetTransactionResponseMessage, RpcResult<kash_rpc_core::GetTransactionResponse>, {
+    Self {
+        transaction: item
+            .transaction
+            .as_ref()
+            .ok_or_else(|| RpcError::MissingRpcFieldError("GetTransactionResponseMessage".to_string(), "transaction".to_string()))?
+            .try_into()?,
+        block_hash: RpcHash::from_str(&item.block_hash)?,
+        accepting_block_hash: RpcHash::from_str(&item.accepting_block_hash)?,
+        accepting_block_blue_score: item.accepting_block_blue_score,
+        confirmations: item.confirmations,
+    }
+});
+
+try_from!(item: &protowire::GetTransactionsByAddressRequestMessage, kash_rpc_core::GetTransactionsByAddressRequest, {
+    Self {
+        address: item.address.as_str().try_into()?,
+        start_blue_score: item.start_blue_score,
+        start_transaction_id: if item.start_transaction_id.is_empty() {
+            None
+        } else {
+            Some(kash_rpc_core::RpcTransactionId::from_str(&item.start_transaction_id)?)
+        },
+        limit: item.limit,
+    }
+});
+try_from!(item: &protowire::GetTransactionsByAddressResponseMessage, RpcResult<kash_rpc_core::GetTransactionsByAddressResponse>, {
+    Self { entries: item.entries.iter().map(kash_rpc_core::RpcAddressTransaction::try_from).collect::<Result<Vec<_>, _>>()? }
+});
+
+from!(item: protowire::RpcUtxoSetSource, RpcUtxoSetSource, {
+    match item {
+        protowire::RpcUtxoSetSource::Virtual => RpcUtxoSetSource::Virtual,
+        protowire::RpcUtxoSetSource::PruningPoint => RpcUtxoSetSource::PruningPoint,
+    }
+});
+
+try_from!(item: &protowire::GetUtxoSetRequestMessage, kash_rpc_core::GetUtxoSetRequest, {
+    Self {
+        source: protowire::RpcUtxoSetSource::try_from(item.source).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+        pruning_point_hash: if item.pruning_point_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.pruning_point_hash)?) },
+        from_outpoint: item.from_outpoint.as_ref().map(kash_rpc_core::RpcTransactionOutpoint::try_from).transpose()?,
+        limit: item.limit,
+        asset_type: if item.filter_by_asset_type { Some(item.asset_type.into()) } else { None },
+        multiset: if item.multiset.is_empty() { None } else { Some(Vec::from_rpc_hex(&item.multiset)?) },
+        utxo_commitment: if item.utxo_commitment.is_empty() { None } else { Some(RpcHash::from_str(&item.utxo_commitment)?) },
+    }
+});
+try_from!(item: &protowire::GetUtxoSetResponseMessage, RpcResult<kash_rpc_core::GetUtxoSetResponse>, {
+    Self {
+        entries: item.entries.iter().map(kash_rpc_core::RpcUtxosByAddressesEntry::try_from).collect::<Result<Vec<_>, _>>()?,
+        pruning_point_hash: if item.pruning_point_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.pruning_point_hash)?) },
+        last_outpoint: item.last_outpoint.as_ref().map(kash_rpc_core::RpcTransactionOutpoint::try_from).transpose()?,
+        multiset: Vec::from_rpc_hex(&item.multiset)?,
+        multiset_hash: RpcHash::from_str(&item.multiset_hash)?,
+        utxo_commitment: if item.utxo_commitment.is_empty() { None } else { Some(RpcHash::from_str(&item.utxo_commitment)?) },
+    }
+});
+
 try_from!(&protowire::PingRequestMessage, kash_rpc_core::PingRequest);
 try_from!(&protowire::PingResponseMessage, RpcResult<kash_rpc_core::PingResponse>);
 
@@ -836,13 +1017,12 @@ try_from!(item: &protowire::NotifyUtxosChangedRequestMessage, kash_rpc_core::Not
     Self {
         addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
         command: item.command.into(),
+        asset_type: if item.filter_by_asset_type { Some(item.asset_type.into()) } else { None },
+        min_amount: item.min_amount,
     }
 });
 try_from!(item: &protowire::StopNotifyingUtxosChangedRequestMessage, kash_rpc_core::NotifyUtxosChangedRequest, {
-    Self {
-        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
-        command: Command::Stop,
-    }
+    Self::new(item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?, Command::Stop)
 });
 try_from!(&protowire::NotifyUtxosChangedResponseMessage, RpcResult<kash_rpc_core::NotifyUtxosChangedResponse>);
 try_from!(&protowire::StopNotifyingUtxosChangedResponseMessage, RpcResult<kash_rpc_core::NotifyUtxosChangedResponse>);
@@ -877,7 +1057,11 @@ try_from!(item: &protowire::NotifyVirtualDaaScoreChangedRequestMessage, kash_rpc
 try_from!(&protowire::NotifyVirtualDaaScoreChangedResponseMessage, RpcResult<kash_rpc_core::NotifyVirtualDaaScoreChangedResponse>);
 
 try_from!(item: &protowire::NotifyVirtualChainChangedRequestMessage, kash_rpc_core::NotifyVirtualChainChangedRequest, {
-    Self { include_accepted_transaction_ids: item.include_accepted_transaction_ids, command: item.command.into() }
+    Self {
+        include_accepted_transaction_ids: item.include_accepted_transaction_ids,
+        start_hash: if item.start_hash.is_empty() { None } else { Some(RpcHash::from_str(&item.start_hash)?) },
+        command: item.command.into(),
+    }
 });
 try_from!(&protowire::NotifyVirtualChainChangedResponseMessage, RpcResult<kash_rpc_core::NotifyVirtualChainChangedResponse>);
 
@@ -29,6 +29,9 @@ from!(item: &kash_rpc_core::ConnectionMetrics, protowire::ConnectionMetrics, {
         json_connection_attempts: item.json_connection_attempts,
         json_handshake_failures: item.json_handshake_failures,
         active_peers: item.active_peers,
+        rpc_authentication_failures: item.rpc_authentication_failures,
+        rpc_denied_calls: item.rpc_denied_calls,
+        rpc_rate_limited_calls: item.rpc_rate_limited_calls,
     }
 });
 
@@ -93,6 +96,9 @@ try_from!(item: &protowire::ConnectionMetrics, kash_rpc_core::ConnectionMetrics,
         json_connection_attempts: item.json_connection_attempts,
         json_handshake_failures: item.json_handshake_failures,
         active_peers: item.active_peers,
+        rpc_authentication_failures: item.rpc_authentication_failures,
+        rpc_denied_calls: item.rpc_denied_calls,
+        rpc_rate_limited_calls: item.rpc_rate_limited_calls,
     }
 });
 
@@ -1,5 +1,6 @@
 use crate::protowire::{
-    kashd_response::Payload, BlockAddedNotificationMessage, KashdResponse, NewBlockTemplateNotificationMessage, RpcNotifyCommand,
+    self, kashd_response::Payload, BlockAddedNotificationMessage, KashdResponse, MempoolChangedNotificationMessage,
+    NewBlockTemplateNotificationMessage, RpcNotifyCommand,
 };
 use crate::protowire::{
     FinalityConflictNotificationMessage, FinalityConflictResolvedNotificationMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
@@ -11,7 +12,7 @@ use crate::protowire::{
 };
 use crate::{from, try_from};
 use kash_notify::subscription::Command;
-use kash_rpc_core::{Notification, RpcError, RpcHash};
+use kash_rpc_core::{Notification, RpcError, RpcHash, RpcMempoolChangeReason, RpcTransactionId};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -34,6 +35,7 @@ from!(item: &kash_rpc_core::Notification, Payload, {
         Notification::PruningPointUtxoSetOverride(ref notification) => {
             Payload::PruningPointUtxoSetOverrideNotification(notification.into())
         }
+        Notification::MempoolChanged(ref notification) => Payload::MempoolChangedNotification(notification.into()),
     }
 });
 
@@ -74,6 +76,26 @@ from!(item: &kash_rpc_core::VirtualDaaScoreChangedNotification, VirtualDaaScoreC
 
 from!(&kash_rpc_core::PruningPointUtxoSetOverrideNotification, PruningPointUtxoSetOverrideNotificationMessage);
 
+from!(item: &RpcMempoolChangeReason, protowire::RpcMempoolChangeReason, {
+    match item {
+        RpcMempoolChangeReason::Accepted => protowire::RpcMempoolChangeReason::Accepted,
+        RpcMempoolChangeReason::OrphanResolved => protowire::RpcMempoolChangeReason::OrphanResolved,
+        RpcMempoolChangeReason::IncludedInBlock => protowire::RpcMempoolChangeReason::IncludedInBlock,
+        RpcMempoolChangeReason::DoubleSpentInBlock => protowire::RpcMempoolChangeReason::DoubleSpentInBlock,
+        RpcMempoolChangeReason::Expired => protowire::RpcMempoolChangeReason::Expired,
+        RpcMempoolChangeReason::LowFee => protowire::RpcMempoolChangeReason::LowFee,
+        RpcMempoolChangeReason::Invalidated => protowire::RpcMempoolChangeReason::Invalidated,
+    }
+});
+
+from!(item: &kash_rpc_core::MempoolChangedNotification, MempoolChangedNotificationMessage, {
+    Self {
+        transaction_id: item.transaction_id.to_string(),
+        reason: protowire::RpcMempoolChangeReason::from(&item.reason) as i32,
+        addresses: item.addresses.iter().map(|x| x.into()).collect(),
+    }
+});
+
 from!(item: Command, RpcNotifyCommand, {
     match item {
         Command::Start => RpcNotifyCommand::NotifyStart,
@@ -117,6 +139,7 @@ try_from!(item: &Payload, kash_rpc_core::Notification, {
         Payload::PruningPointUtxoSetOverrideNotification(ref notification) => {
             Notification::PruningPointUtxoSetOverride(notification.try_into()?)
         }
+        Payload::MempoolChangedNotification(ref notification) => Notification::MempoolChanged(notification.try_into()?),
         _ => Err(RpcError::UnsupportedFeature)?,
     }
 });
@@ -171,6 +194,26 @@ try_from!(item: &VirtualDaaScoreChangedNotificationMessage, kash_rpc_core::Virtu
 
 try_from!(&PruningPointUtxoSetOverrideNotificationMessage, kash_rpc_core::PruningPointUtxoSetOverrideNotification);
 
+from!(item: protowire::RpcMempoolChangeReason, RpcMempoolChangeReason, {
+    match item {
+        protowire::RpcMempoolChangeReason::Accepted => RpcMempoolChangeReason::Accepted,
+        protowire::RpcMempoolChangeReason::OrphanResolved => RpcMempoolChangeReason::OrphanResolved,
+        protowire::RpcMempoolChangeReason::IncludedInBlock => RpcMempoolChangeReason::IncludedInBlock,
+        protowire::RpcMempoolChangeReason::DoubleSpentInBlock => RpcMempoolChangeReason::DoubleSpentInBlock,
+        protowire::RpcMempoolChangeReason::Expired => RpcMempoolChangeReason::Expired,
+        protowire::RpcMempoolChangeReason::LowFee => RpcMempoolChangeReason::LowFee,
+        protowire::RpcMempoolChangeReason::Invalidated => RpcMempoolChangeReason::Invalidated,
+    }
+});
+
+try_from!(item: &MempoolChangedNotificationMessage, kash_rpc_core::MempoolChangedNotification, {
+    Self {
+        transaction_id: RpcTransactionId::from_str(&item.transaction_id)?,
+        reason: protowire::RpcMempoolChangeReason::try_from(item.reason).map_err(|_| RpcError::PrimitiveToEnumConversionError)?.into(),
+        addresses: item.addresses.iter().map(|x| x.as_str().try_into()).collect::<Result<Vec<_>, _>>()?,
+    }
+});
+
 from!(item: RpcNotifyCommand, Command, {
     match item {
         RpcNotifyCommand::NotifyStart => Command::Start,
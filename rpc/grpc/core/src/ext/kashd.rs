@@ -2,8 +2,9 @@ use kash_notify::{scope::Scope, subscription::Command};
 
 use crate::protowire::{
     kashd_request, kashd_response, KashdRequest, KashdResponse, NotifyBlockAddedRequestMessage, NotifyFinalityConflictRequestMessage,
-    NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage, NotifySinkBlueScoreChangedRequestMessage,
-    NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage, NotifyVirtualDaaScoreChangedRequestMessage,
+    NotifyMempoolChangedRequestMessage, NotifyNewBlockTemplateRequestMessage, NotifyPruningPointUtxoSetOverrideRequestMessage,
+    NotifySinkBlueScoreChangedRequestMessage, NotifyUtxosChangedRequestMessage, NotifyVirtualChainChangedRequestMessage,
+    NotifyVirtualDaaScoreChangedRequestMessage,
 };
 
 impl KashdRequest {
@@ -30,6 +31,7 @@ impl kashd_request::Payload {
                 kashd_request::Payload::NotifyVirtualChainChangedRequest(NotifyVirtualChainChangedRequestMessage {
                     command: command.into(),
                     include_accepted_transaction_ids: scope.include_accepted_transaction_ids,
+                    start_hash: scope.start_hash.map_or(Default::default(), |x| x.to_string()),
                 })
             }
             Scope::FinalityConflict(_) => {
@@ -40,6 +42,9 @@ impl kashd_request::Payload {
             }
             Scope::UtxosChanged(ref scope) => kashd_request::Payload::NotifyUtxosChangedRequest(NotifyUtxosChangedRequestMessage {
                 addresses: scope.addresses.iter().map(|x| x.into()).collect::<Vec<String>>(),
+                filter_by_asset_type: scope.asset_type.is_some(),
+                asset_type: scope.asset_type.map_or(Default::default(), |x| x.into()),
+                min_amount: scope.min_amount,
                 command: command.into(),
             }),
             Scope::SinkBlueScoreChanged(_) => {
@@ -57,6 +62,9 @@ impl kashd_request::Payload {
                     command: command.into(),
                 })
             }
+            Scope::MempoolChanged(_) => {
+                kashd_request::Payload::NotifyMempoolChangedRequest(NotifyMempoolChangedRequestMessage { command: command.into() })
+            }
         }
     }
 
@@ -72,6 +80,7 @@ impl kashd_request::Payload {
                 | Payload::NotifyVirtualDaaScoreChangedRequest(_)
                 | Payload::NotifyPruningPointUtxoSetOverrideRequest(_)
                 | Payload::NotifyNewBlockTemplateRequest(_)
+                | Payload::NotifyMempoolChangedRequest(_)
                 | Payload::StopNotifyingUtxosChangedRequest(_)
                 | Payload::StopNotifyingPruningPointUtxoSetOverrideRequest(_)
         )
@@ -101,6 +110,7 @@ impl kashd_response::Payload {
             Payload::VirtualDaaScoreChangedNotification(_) => true,
             Payload::PruningPointUtxoSetOverrideNotification(_) => true,
             Payload::NewBlockTemplateNotification(_) => true,
+            Payload::MempoolChangedNotification(_) => true,
             _ => false,
         }
     }
@@ -1,6 +1,6 @@
 use crate::protowire::{kashd_request::Payload as RequestPayload, kashd_response::Payload as ResponsePayload, *};
-use kash_rpc_core::RpcError;
-use workflow_core::enums::Describe;
+use kash_rpc_core::{api::ops::RpcApiOps, RpcError};
+use workflow_core::enums::{Describe, EnumTrait};
 
 macro_rules! payload_type_enum {
     ($(#[$meta:meta])* $vis:vis enum $name:ident {
@@ -81,6 +81,10 @@ pub enum KashdPayloadOps {
     GetServerInfo,
     GetSyncStatus,
     GetDaaScoreTimestampEstimate,
+    GetPrice,
+    GetTransaction,
+    GetTransactionsByAddress,
+    GetUtxoSet,
 
     // Subscription commands for starting/stopping notifications
     NotifyBlockAdded,
@@ -91,6 +95,7 @@ pub enum KashdPayloadOps {
     NotifyPruningPointUtxoSetOverride,
     NotifyVirtualDaaScoreChanged,
     NotifyVirtualChainChanged,
+    NotifyMempoolChanged,
 
     // Legacy stop subscription commands
     StopNotifyingUtxosChanged,
@@ -101,3 +106,28 @@ pub enum KashdPayloadOps {
     // The conversion from a notification ResponsePayload into KashdPayloadOps fails.
 }
 }
+
+impl KashdPayloadOps {
+    /// The [`RpcApiOps`] counterpart of this op, legacy stop subscription commands being mapped to their subscription
+    pub fn rpc_api_op(&self) -> Option<RpcApiOps> {
+        match self {
+            KashdPayloadOps::StopNotifyingUtxosChanged => Some(RpcApiOps::NotifyUtxosChanged),
+            KashdPayloadOps::StopNotifyingPruningPointUtxoSetOverride => Some(RpcApiOps::NotifyPruningPointUtxoSetOverride),
+            op => RpcApiOps::from_str(op.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_api_op() {
+        for op in KashdPayloadOps::list() {
+            assert!(op.rpc_api_op().is_some(), "{op:?} has no RpcApiOps counterpart");
+        }
+        assert_eq!(KashdPayloadOps::GetBlock.rpc_api_op(), Some(RpcApiOps::GetBlock));
+        assert_eq!(KashdPayloadOps::StopNotifyingUtxosChanged.rpc_api_op(), Some(RpcApiOps::NotifyUtxosChanged));
+    }
+}
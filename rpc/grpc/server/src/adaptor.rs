@@ -2,6 +2,7 @@ use crate::{connection_handler::ConnectionHandler, manager::Manager};
 use kash_core::debug;
 use kash_notify::notifier::Notifier;
 use kash_rpc_core::{api::rpc::DynRpcService, notify::connection::ChannelConnection, Notification, RpcResult};
+use kash_rpc_service::auth::guard::RpcGuard;
 use kash_utils::networking::NetAddress;
 use kash_utils_tower::counters::TowerConnectionCounters;
 use std::{ops::Deref, sync::Arc};
@@ -38,9 +39,11 @@ impl Adaptor {
         core_service: DynRpcService,
         core_notifier: Arc<Notifier<Notification, ChannelConnection>>,
         counters: Arc<TowerConnectionCounters>,
+        guard: Arc<RpcGuard>,
     ) -> Arc<Self> {
         let (manager_sender, manager_receiver) = mpsc_channel(Self::manager_channel_size());
-        let connection_handler = ConnectionHandler::new(network_bps, manager_sender, core_service.clone(), core_notifier, counters);
+        let connection_handler =
+            ConnectionHandler::new(network_bps, manager_sender, core_service.clone(), core_notifier, counters, guard);
         let server_termination = connection_handler.serve(serve_address);
         let adaptor = Arc::new(Adaptor::new(Some(server_termination), connection_handler, manager, serve_address));
         adaptor.manager.clone().start_event_loop(manager_receiver);
@@ -19,6 +19,7 @@ use kash_notify::{
     connection::Connection as ConnectionT, error::Error as NotificationError, listener::ListenerId, notifier::Notifier,
 };
 use kash_rpc_core::Notification;
+use kash_rpc_service::{auth::guard::Session, replay::VirtualChainReplay};
 use parking_lot::Mutex;
 use std::{
     collections::{hash_map::Entry, HashMap},
@@ -51,6 +52,9 @@ struct InnerMutableState {
     ///
     /// Registered when handling the first subscription to any notifications
     listener_id: Option<ListenerId>,
+
+    /// Replay of the virtual chain changes, if requested by the client
+    virtual_chain_replay: Option<VirtualChainReplay>,
 }
 
 impl InnerMutableState {
@@ -76,6 +80,9 @@ struct Inner {
     /// The server RPC core service and notifier
     server_context: ServerContext,
 
+    /// The authorization state of this client
+    session: Session,
+
     /// Used for managing connection mutable state
     mutable_state: Mutex<InnerMutableState>,
 
@@ -172,7 +179,13 @@ impl Router {
             debug!("GRPC, Route to handler got empty payload, client: {}", connection);
             return Err(GrpcServerError::InvalidRequestPayload);
         }
-        let rpc_op = request.payload.as_ref().unwrap().into();
+        let rpc_op: KashdPayloadOps = request.payload.as_ref().unwrap().into();
+        if let Err(err) = rpc_op.rpc_api_op().map_or(Ok(()), |op| connection.session().check(op)) {
+            debug!("GRPC, {:?} refused for client {} - {}", rpc_op, connection, err);
+            let response = KashdResponse { id: request.id, payload: Some(rpc_op.to_error_response(err)) };
+            connection.enqueue(response).await?;
+            return Ok(());
+        }
         let route = self.get_or_subscribe(connection, rpc_op);
         match route.policy {
             RoutingPolicy::Enqueue => match route.send(request).await {
@@ -217,6 +230,7 @@ impl Connection {
         manager_sender: MpscSender<ManagerEvent>,
         mut incoming_stream: Streaming<KashdRequest>,
         outgoing_route: GrpcSender,
+        session: Session,
     ) -> Self {
         let (shutdown_sender, mut shutdown_receiver) = oneshot_channel();
         let mut router = Router::new(server_context.clone(), interface.clone());
@@ -227,6 +241,7 @@ impl Connection {
                 outgoing_route,
                 manager_sender,
                 server_context,
+                session,
                 mutable_state: Mutex::new(InnerMutableState::new(Some(shutdown_sender))),
                 is_closed: AtomicBool::new(false),
             }),
@@ -274,6 +289,7 @@ impl Connection {
             }
             // Unregister from notifier
             connection.unregister_listener();
+            connection.set_virtual_chain_replay(None);
 
             // Drop all routes, triggering the drop of all handlers
             router.unsubscribe_all();
@@ -311,6 +327,10 @@ impl Connection {
         self.inner.connection_id
     }
 
+    pub fn session(&self) -> &Session {
+        &self.inner.session
+    }
+
     pub fn notifier(&self) -> Arc<GrpcNotifier> {
         self.inner.server_context.notifier.clone()
     }
@@ -334,6 +354,11 @@ impl Connection {
         }
     }
 
+    /// Sets the replay of the virtual chain changes relayed to this connection, stopping the previous one if any.
+    pub fn set_virtual_chain_replay(&self, replay: Option<VirtualChainReplay>) {
+        self.inner.mutable_state.lock().virtual_chain_replay = replay;
+    }
+
     pub fn request_channel_size() -> usize {
         256
     }
@@ -19,6 +19,10 @@ use kash_rpc_core::{
     notify::{channel::NotificationChannel, connection::ChannelConnection},
     Notification, RpcResult,
 };
+use kash_rpc_service::auth::{
+    credentials::{Credentials, SignedRequest},
+    guard::RpcGuard,
+};
 use kash_utils::networking::NetAddress;
 use kash_utils_tower::{
     counters::TowerConnectionCounters,
@@ -69,10 +73,14 @@ pub struct ConnectionHandler {
     interface: Arc<Interface>,
     running: Arc<AtomicBool>,
     counters: Arc<TowerConnectionCounters>,
+    guard: Arc<RpcGuard>,
 }
 
 const GRPC_SERVER: &str = "grpc-server";
 
+/// Metadata key carrying the credentials of a client, formatted as a [`Credentials`] string
+pub const AUTHORIZATION_METADATA: &str = "authorization";
+
 impl ConnectionHandler {
     pub(crate) fn new(
         network_bps: u64,
@@ -80,6 +88,7 @@ impl ConnectionHandler {
         core_service: DynRpcService,
         core_notifier: Arc<Notifier<Notification, ChannelConnection>>,
         counters: Arc<TowerConnectionCounters>,
+        guard: Arc<RpcGuard>,
     ) -> Self {
         // Prepare core objects
         let core_channel = NotificationChannel::default();
@@ -97,7 +106,7 @@ impl ConnectionHandler {
         let interface = Arc::new(Factory::new_interface(server_context.clone(), network_bps));
         let running = Default::default();
 
-        Self { manager_sender, server_context, interface, running, counters }
+        Self { manager_sender, server_context, interface, running, counters, guard }
     }
 
     /// Launches a gRPC server listener loop
@@ -233,6 +242,22 @@ impl Rpc for ConnectionHandler {
 
         debug!("GRPC, Incoming message stream from {:?}", remote_address);
 
+        // Authenticate the client with the credentials of the `authorization` metadata, if any
+        let credentials = match request.metadata().get(AUTHORIZATION_METADATA) {
+            Some(value) => Some(
+                value
+                    .to_str()
+                    .map_err(|_| tonic::Status::unauthenticated("The authorization metadata is not a valid string"))?
+                    .parse::<Credentials>()
+                    .map_err(|err| tonic::Status::unauthenticated(err.to_string()))?,
+            ),
+            None => None,
+        };
+        let session = self.guard.open_session(remote_address.ip(), credentials.as_ref(), &SignedRequest::GRPC).map_err(|err| {
+            debug!("GRPC, Refusing incoming message stream from {:?} - {}", remote_address, err);
+            tonic::Status::unauthenticated(err.to_string())
+        })?;
+
         // Build the in/out pipes
         let (outgoing_route, outgoing_receiver) = mpsc_channel(Self::outgoing_route_channel_size());
         let incoming_stream = request.into_inner();
@@ -245,6 +270,7 @@ impl Rpc for ConnectionHandler {
             self.manager_sender(),
             incoming_stream,
             outgoing_route,
+            session,
         );
 
         // Try to get the connection registered into the central Manager
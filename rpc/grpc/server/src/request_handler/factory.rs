@@ -13,9 +13,14 @@ use crate::{
 };
 use kash_grpc_core::protowire::{kashd_request::Payload, *};
 use kash_grpc_core::{ops::KashdPayloadOps, protowire::NotifyFinalityConflictResponseMessage};
-use kash_notify::{scope::FinalityConflictResolvedScope, subscriber::SubscriptionManager};
-use kash_rpc_core::{SubmitBlockRejectReason, SubmitBlockReport, SubmitBlockResponse};
+use kash_notify::{
+    scope::{FinalityConflictResolvedScope, VirtualChainChangedScope},
+    subscriber::SubscriptionManager,
+    subscription::Command,
+};
+use kash_rpc_core::{RpcError, SubmitBlockRejectReason, SubmitBlockReport, SubmitBlockResponse};
 use kash_rpc_macros::build_grpc_server_interface;
+use kash_rpc_service::replay::VirtualChainReplay;
 
 pub struct Factory {}
 
@@ -75,6 +80,10 @@ impl Factory {
                 GetServerInfo,
                 GetSyncStatus,
                 GetDaaScoreTimestampEstimate,
+                GetPrice,
+                GetTransaction,
+                GetTransactionsByAddress,
+                GetUtxoSet,
                 NotifyBlockAdded,
                 NotifyNewBlockTemplate,
                 NotifyFinalityConflict,
@@ -83,6 +92,7 @@ impl Factory {
                 NotifyPruningPointUtxoSetOverride,
                 NotifyVirtualDaaScoreChanged,
                 NotifyVirtualChainChanged,
+                NotifyMempoolChanged,
                 StopNotifyingUtxosChanged,
                 StopNotifyingPruningPointUtxoSetOverride,
             ]
@@ -129,6 +139,67 @@ impl Factory {
         });
         interface.replace_method(KashdPayloadOps::NotifyFinalityConflict, method);
 
+        // Manually reimplementing the NotifyVirtualChainChangedRequest method so a subscription
+        // with a start hash gets served by a replay of the virtual chain changes since this block.
+        let method: KashdMethod = Method::new(|server_ctx: ServerContext, connection: Connection, request: KashdRequest| {
+            Box::pin(async move {
+                let mut response: KashdResponse = match request.payload {
+                    Some(Payload::NotifyVirtualChainChangedRequest(ref request)) => {
+                        match kash_rpc_core::NotifyVirtualChainChangedRequest::try_from(request) {
+                            Ok(request) => {
+                                let listener_id = connection.get_or_register_listener_id()?;
+                                // Any running replay is superseded by the new command
+                                connection.set_virtual_chain_replay(None);
+                                let result = match (request.command, request.start_hash) {
+                                    (Command::Start, Some(start_hash)) => {
+                                        // The replay relays the live changes by itself
+                                        let stop_result = server_ctx
+                                            .notifier
+                                            .clone()
+                                            .execute_subscribe_command(
+                                                listener_id,
+                                                VirtualChainChangedScope::default().into(),
+                                                Command::Stop,
+                                            )
+                                            .await;
+                                        match stop_result {
+                                            Ok(()) => VirtualChainReplay::start(
+                                                server_ctx.core_service.clone(),
+                                                connection.clone(),
+                                                start_hash,
+                                                request.include_accepted_transaction_ids,
+                                            )
+                                            .await
+                                            .map(|replay| connection.set_virtual_chain_replay(Some(replay))),
+                                            Err(err) => Err(err.into()),
+                                        }
+                                    }
+                                    (command, _) => server_ctx
+                                        .notifier
+                                        .clone()
+                                        .execute_subscribe_command(
+                                            listener_id,
+                                            VirtualChainChangedScope::new(request.include_accepted_transaction_ids).into(),
+                                            command,
+                                        )
+                                        .await
+                                        .map_err(RpcError::from),
+                                };
+                                NotifyVirtualChainChangedResponseMessage::from(result).into()
+                            }
+                            Err(err) => NotifyVirtualChainChangedResponseMessage::from(err).into(),
+                        }
+                    }
+                    _ => {
+                        return Err(GrpcServerError::InvalidRequestPayload);
+                    }
+                };
+                response.id = request.id;
+                Ok(response)
+            })
+        });
+        interface.replace_method(KashdPayloadOps::NotifyVirtualChainChanged, method);
+
         // Methods with special properties
         let network_bps = network_bps as usize;
         interface.set_method_properties(
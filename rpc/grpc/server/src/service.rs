@@ -52,6 +52,7 @@ impl AsyncService for GrpcService {
             self.core_service.clone(),
             self.core_service.notifier(),
             self.counters.clone(),
+            self.core_service.guard(),
         );
 
         // Launch the service and wait for a shutdown signal
@@ -2,10 +2,11 @@ use super::rpc_core_mock::RpcCoreMock;
 use crate::{adaptor::Adaptor, manager::Manager};
 use kash_core::info;
 use kash_grpc_client::GrpcClient;
-use kash_notify::scope::{NewBlockTemplateScope, Scope};
-use kash_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode};
+use kash_notify::scope::{NewBlockTemplateScope, Scope, VirtualChainChangedScope};
+use kash_rpc_core::{api::rpc::RpcApi, notify::mode::NotificationMode, Notification, RpcHash};
+use kash_rpc_service::replay::VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE;
 use kash_utils::networking::{ContextualNetAddress, NetAddress};
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 #[tokio::test]
 async fn test_client_server_sanity_check() {
@@ -187,9 +188,85 @@ async fn test_client_server_notifications() {
     tokio::time::sleep(std::time::Duration::from_millis(50)).await;
 }
 
+#[tokio::test]
+async fn test_client_server_virtual_chain_replay() {
+    kash_core::log::try_init_logger("info, kash_grpc_core=trace, kash_grpc_server=trace, kash_grpc_client=trace");
+
+    // Create and start a fake core service with a virtual chain long enough to be replayed in several chunks
+    let rpc_core_service = Arc::new(RpcCoreMock::new());
+    rpc_core_service.start();
+    let chain_length = 2 * VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE as u64 + 500;
+    let mut virtual_chain: Vec<RpcHash> = (0..chain_length).map(RpcHash::from_u64_word).collect();
+    rpc_core_service.set_virtual_chain(virtual_chain.clone());
+
+    // Create and start the server
+    let server = create_server(rpc_core_service.clone());
+    let client = create_client(server.serve_address()).await;
+
+    // Subscribe with a replay starting from a chain block
+    let start_height = 10;
+    let start_hash = virtual_chain[start_height];
+    let scope = Scope::VirtualChainChanged(VirtualChainChangedScope::with_start_hash(true, Some(start_hash)));
+    assert!(client.start_notify(0, scope).await.is_ok());
+
+    // Reorg the tip of the chain while the replay is being relayed, then extend the chain
+    let fork_height = virtual_chain.len() - 6;
+    virtual_chain = rpc_core_service.reorg_virtual_chain(fork_height, (0..7).map(|i| RpcHash::from_u64_word(1_000_000 + i)).collect());
+    assert!(rpc_core_service.notify_virtual_chain_changed().is_ok());
+    rpc_core_service.notify_complete().await;
+    virtual_chain =
+        rpc_core_service.reorg_virtual_chain(virtual_chain.len() - 1, (0..3).map(|i| RpcHash::from_u64_word(2_000_000 + i)).collect());
+    assert!(rpc_core_service.notify_virtual_chain_changed().is_ok());
+    rpc_core_service.notify_complete().await;
+
+    // Rebuild the virtual chain from the notifications, which must neither skip nor repeat any chain block
+    let mut local_chain = virtual_chain[..=start_height].to_vec();
+    let mut local_chain_set: HashSet<RpcHash> = local_chain.iter().copied().collect();
+    let receiver = client.notification_channel_receiver();
+    while local_chain != virtual_chain {
+        let notification = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .expect("the replay should relay all the virtual chain changes")
+            .unwrap();
+        let Notification::VirtualChainChanged(notification) = notification else {
+            panic!("unexpected notification {notification:?}");
+        };
+        assert!(notification.added_chain_block_hashes.len() <= VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE, "the replay must be chunked");
+        assert_eq!(notification.added_chain_block_hashes.len(), notification.accepted_transaction_ids.len());
+        for removed in notification.removed_chain_block_hashes.iter() {
+            assert_eq!(local_chain.pop(), Some(*removed), "removed chain blocks must be the tip of the relayed chain");
+            local_chain_set.remove(removed);
+        }
+        for added in notification.added_chain_block_hashes.iter() {
+            assert!(local_chain_set.insert(*added), "chain block {added} was relayed twice");
+            local_chain.push(*added);
+        }
+    }
+    assert!(receiver.is_empty(), "no notification is expected once the relayed chain is up to date");
+
+    assert!(client.disconnect().await.is_ok(), "client failed to disconnect");
+    drop(client);
+
+    // Stop the fake service
+    rpc_core_service.join().await;
+
+    // Stop the server
+    assert!(server.stop().await.is_ok(), "error stopping the server");
+    drop(server);
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+}
+
 fn create_server(core_service: Arc<RpcCoreMock>) -> Arc<Adaptor> {
     let manager = Manager::new(128);
-    Adaptor::server(get_free_net_address(), 1, manager, core_service.clone(), core_service.core_notifier(), Default::default())
+    Adaptor::server(
+        get_free_net_address(),
+        1,
+        manager,
+        core_service.clone(),
+        core_service.core_notifier(),
+        Default::default(),
+        Default::default(),
+    )
 }
 
 async fn create_client(server_address: NetAddress) -> GrpcClient {
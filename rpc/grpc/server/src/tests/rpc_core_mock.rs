@@ -6,6 +6,8 @@ use kash_notify::notifier::{Notifier, Notify};
 use kash_notify::scope::Scope;
 use kash_rpc_core::{api::rpc::RpcApi, *};
 use kash_rpc_core::{notify::connection::ChannelConnection, RpcResult};
+use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub(super) type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
@@ -13,6 +15,14 @@ pub(super) type RpcCoreNotifier = Notifier<Notification, ChannelConnection>;
 pub(super) struct RpcCoreMock {
     core_notifier: Arc<RpcCoreNotifier>,
     _sync_receiver: Receiver<()>,
+    virtual_chain: Mutex<VirtualChainMock>,
+}
+
+/// A virtual selected chain, remembering the selected parent of the blocks it lost to reorgs
+#[derive(Default)]
+struct VirtualChainMock {
+    chain: Vec<RpcHash>,
+    removed_selected_parents: HashMap<RpcHash, RpcHash>,
 }
 
 impl RpcCoreMock {
@@ -30,6 +40,36 @@ impl RpcCoreMock {
         self.core_notifier.notify(notification)
     }
 
+    #[allow(dead_code)]
+    pub(super) fn set_virtual_chain(&self, chain: Vec<RpcHash>) {
+        *self.virtual_chain.lock() = VirtualChainMock { chain, removed_selected_parents: HashMap::new() };
+    }
+
+    /// Replaces the chain blocks above `fork_height` by `added` and returns the resulting virtual chain
+    #[allow(dead_code)]
+    pub(super) fn reorg_virtual_chain(&self, fork_height: usize, added: Vec<RpcHash>) -> Vec<RpcHash> {
+        let mut virtual_chain = self.virtual_chain.lock();
+        let removed = virtual_chain.chain.split_off(fork_height + 1);
+        let mut selected_parent = virtual_chain.chain[fork_height];
+        for hash in removed {
+            virtual_chain.removed_selected_parents.insert(hash, selected_parent);
+            selected_parent = hash;
+        }
+        virtual_chain.chain.extend(added);
+        virtual_chain.chain.clone()
+    }
+
+    #[allow(dead_code)]
+    pub(super) fn notify_virtual_chain_changed(&self) -> kash_notify::error::Result<()> {
+        // The content does not matter to the virtual chain replay which reads the changes from the cursor
+        let notification = Notification::VirtualChainChanged(VirtualChainChangedNotification {
+            removed_chain_block_hashes: Default::default(),
+            added_chain_block_hashes: Default::default(),
+            accepted_transaction_ids: Default::default(),
+        });
+        self.core_notifier.notify(notification)
+    }
+
     #[allow(dead_code)]
     pub(super) async fn notify_complete(&self) {
         assert!(self._sync_receiver.recv().await.is_ok(), "the notifier sync channel is unexpectedly empty and closed");
@@ -49,7 +89,7 @@ impl Default for RpcCoreMock {
         let (sync_sender, sync_receiver) = unbounded();
         let core_notifier: Arc<RpcCoreNotifier> =
             Arc::new(Notifier::with_sync("rpc-core", EVENT_TYPE_ARRAY[..].into(), vec![], vec![], 10, Some(sync_sender)));
-        Self { core_notifier, _sync_receiver: sync_receiver }
+        Self { core_notifier, _sync_receiver: sync_receiver, virtual_chain: Default::default() }
     }
 }
 
@@ -65,6 +105,9 @@ impl RpcApi for RpcCoreMock {
             is_synced: false,
             has_notify_command: false,
             has_message_id: false,
+            mempool_asset_policies: vec![],
+            maximum_mint_transactions_per_block: u64::MAX,
+            maximum_redeem_transactions_per_block: u64::MAX,
         })
     }
 
@@ -134,9 +177,27 @@ impl RpcApi for RpcCoreMock {
 
     async fn get_virtual_chain_from_block_call(
         &self,
-        _request: GetVirtualChainFromBlockRequest,
+        request: GetVirtualChainFromBlockRequest,
     ) -> RpcResult<GetVirtualChainFromBlockResponse> {
-        Err(RpcError::NotImplemented)
+        let virtual_chain = self.virtual_chain.lock();
+        let mut removed_chain_block_hashes = vec![];
+        let mut hash = request.start_hash;
+        let fork = loop {
+            if let Some(position) = virtual_chain.chain.iter().position(|x| *x == hash) {
+                break position;
+            }
+            removed_chain_block_hashes.push(hash);
+            hash = *virtual_chain.removed_selected_parents.get(&hash).ok_or(RpcError::General("unknown block".to_string()))?;
+        };
+        let added_chain_block_hashes = virtual_chain.chain[fork + 1..].to_vec();
+        let accepted_transaction_ids = match request.include_accepted_transaction_ids {
+            true => added_chain_block_hashes
+                .iter()
+                .map(|x| RpcAcceptedTransactionIds { accepting_block_hash: *x, accepted_transaction_ids: vec![] })
+                .collect(),
+            false => vec![],
+        };
+        Ok(GetVirtualChainFromBlockResponse::new(removed_chain_block_hashes, added_chain_block_hashes, accepted_transaction_ids))
     }
 
     async fn get_blocks_call(&self, _request: GetBlocksRequest) -> RpcResult<GetBlocksResponse> {
@@ -218,6 +279,25 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_price_call(&self, _request: GetPriceRequest) -> RpcResult<GetPriceResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transaction_call(&self, _request: GetTransactionRequest) -> RpcResult<GetTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transactions_by_address_call(
+        &self,
+        _request: GetTransactionsByAddressRequest,
+    ) -> RpcResult<GetTransactionsByAddressResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxo_set_call(&self, _request: GetUtxoSetRequest) -> RpcResult<GetUtxoSetResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
@@ -0,0 +1,15 @@
+//! Kash JSON-RPC 2.0 over HTTP server (AsyncService) module
+//!
+//! A stateless HTTP listener mapping JSON-RPC 2.0 calls, single or batched, onto the
+//! [`RpcApi`](kash_rpc_core::api::rpc::RpcApi). Method names are the camelCase names of
+//! [`RpcApiOps`](kash_rpc_core::api::ops::RpcApiOps) and params are the JSON encoding of
+//! the matching `rpc/core` request. Subscriptions are not available since no connection is kept.
+pub mod error;
+pub mod model;
+pub mod result;
+pub mod router;
+pub mod schema;
+pub mod service;
+
+#[cfg(test)]
+mod tests;
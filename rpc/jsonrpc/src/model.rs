@@ -0,0 +1,158 @@
+use kash_rpc_core::RpcError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Method returning the schema of all the methods served by the endpoint (see [OpenRPC](https://spec.open-rpc.org/#service-discovery-method))
+pub const DISCOVER_METHOD: &str = "rpc.discover";
+
+/// A single JSON-RPC 2.0 call
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonRpcRequest {
+    pub method: String,
+    /// Named params, `{}` when omitted by the caller
+    pub params: Value,
+    /// The request id, `None` for a notification which expects no response
+    pub id: Option<Value>,
+}
+
+impl JsonRpcRequest {
+    /// Validates a call object.
+    ///
+    /// On failure, the returned error carries the id of the call if it could be read, `Null` otherwise.
+    pub fn try_from_value(value: Value) -> Result<Self, (Value, JsonRpcError)> {
+        let Value::Object(mut object) = value else {
+            return Err((Value::Null, JsonRpcError::invalid_request("a call must be an object")));
+        };
+
+        // A call without an `id` member is a notification, whereas an explicit `null` id is still answered
+        let id = object.remove("id");
+        let error_id = id.clone().unwrap_or(Value::Null);
+        if !matches!(id, None | Some(Value::Null) | Some(Value::String(_)) | Some(Value::Number(_))) {
+            return Err((Value::Null, JsonRpcError::invalid_request("the id must be a string, a number or null")));
+        }
+        if object.remove("jsonrpc").as_ref().and_then(Value::as_str) != Some(JSONRPC_VERSION) {
+            return Err((error_id, JsonRpcError::invalid_request("the jsonrpc member must be exactly \"2.0\"")));
+        }
+        let Some(Value::String(method)) = object.remove("method") else {
+            return Err((error_id, JsonRpcError::invalid_request("the method must be a string")));
+        };
+        let params = match object.remove("params") {
+            None | Some(Value::Null) => Value::Object(Map::new()),
+            Some(params @ Value::Object(_)) => params,
+            // Positional params are accepted when they wrap the request object
+            Some(Value::Array(mut params)) if params.len() == 1 && params[0].is_object() => params.remove(0),
+            Some(_) => {
+                return Err((error_id, JsonRpcError::invalid_params("params must be an object holding the request fields")));
+            }
+        };
+
+        Ok(Self { method, params, id })
+    }
+}
+
+/// Result of a single JSON-RPC 2.0 call
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    pub jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+    pub id: Value,
+}
+
+impl JsonRpcResponse {
+    pub fn new(id: Value, result: Result<Value, JsonRpcError>) -> Self {
+        match result {
+            Ok(result) => Self { jsonrpc: JSONRPC_VERSION.to_string(), result: Some(result), error: None, id },
+            Err(error) => Self::error(id, error),
+        }
+    }
+
+    pub fn error(id: Value, error: JsonRpcError) -> Self {
+        Self { jsonrpc: JSONRPC_VERSION.to_string(), result: None, error: Some(error), id }
+    }
+}
+
+/// Error object of the JSON-RPC 2.0 specification
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcError {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// Code of the errors returned by the [`RpcApi`](kash_rpc_core::api::rpc::RpcApi) calls
+    pub const RPC_ERROR: i64 = -32000;
+
+    fn new(code: i64, message: &str, details: impl ToString) -> Self {
+        Self { code, message: message.to_string(), data: Some(Value::String(details.to_string())) }
+    }
+
+    pub fn parse_error(details: impl ToString) -> Self {
+        Self::new(Self::PARSE_ERROR, "Parse error", details)
+    }
+
+    pub fn invalid_request(details: impl ToString) -> Self {
+        Self::new(Self::INVALID_REQUEST, "Invalid Request", details)
+    }
+
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(Self::METHOD_NOT_FOUND, "Method not found", format!("unknown method `{method}`"))
+    }
+
+    pub fn invalid_params(details: impl ToString) -> Self {
+        Self::new(Self::INVALID_PARAMS, "Invalid params", details)
+    }
+
+    pub fn internal_error(details: impl ToString) -> Self {
+        Self::new(Self::INTERNAL_ERROR, "Internal error", details)
+    }
+}
+
+impl From<RpcError> for JsonRpcError {
+    fn from(err: RpcError) -> Self {
+        Self { code: Self::RPC_ERROR, message: err.to_string(), data: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_validation() {
+        let request = JsonRpcRequest::try_from_value(json!({"jsonrpc": "2.0", "method": "getInfo", "id": 1})).unwrap();
+        assert_eq!(request, JsonRpcRequest { method: "getInfo".to_string(), params: json!({}), id: Some(json!(1)) });
+
+        let request =
+            JsonRpcRequest::try_from_value(json!({"jsonrpc": "2.0", "method": "getBlock", "params": [{"hash": "00"}]})).unwrap();
+        assert_eq!(request, JsonRpcRequest { method: "getBlock".to_string(), params: json!({"hash": "00"}), id: None });
+
+        let request = JsonRpcRequest::try_from_value(json!({"jsonrpc": "2.0", "method": "ping", "id": null})).unwrap();
+        assert_eq!(request.id, Some(Value::Null));
+
+        let tests = [
+            (json!(1), Value::Null, JsonRpcError::INVALID_REQUEST),
+            (json!({"jsonrpc": "1.0", "method": "ping", "id": 2}), json!(2), JsonRpcError::INVALID_REQUEST),
+            (json!({"jsonrpc": "2.0", "method": 3, "id": "a"}), json!("a"), JsonRpcError::INVALID_REQUEST),
+            (json!({"jsonrpc": "2.0", "method": "ping", "id": {}}), Value::Null, JsonRpcError::INVALID_REQUEST),
+            (json!({"jsonrpc": "2.0", "method": "ping", "params": 1, "id": 4}), json!(4), JsonRpcError::INVALID_PARAMS),
+        ];
+        for (value, expected_id, expected_code) in tests {
+            let (id, error) = JsonRpcRequest::try_from_value(value.clone()).unwrap_err();
+            assert_eq!(id, expected_id, "wrong error id for {value}");
+            assert_eq!(error.code, expected_code, "wrong error code for {value}");
+        }
+    }
+}
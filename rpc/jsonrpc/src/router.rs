@@ -0,0 +1,281 @@
+use crate::{
+    model::{JsonRpcError, JsonRpcRequest, JsonRpcResponse, DISCOVER_METHOD},
+    schema::{method_name, SchemaBuilder},
+};
+use borsh::BorshSchema;
+use futures::{stream, StreamExt};
+use kash_core::trace;
+use kash_rpc_core::{
+    api::{ops::RpcApiOps, rpc::DynRpcService},
+    prelude::*,
+};
+use kash_rpc_service::auth::guard::Session;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Maximum number of calls accepted in a single batch
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// Maximum number of calls of a batch being processed concurrently
+pub const MAX_BATCH_CONCURRENCY: usize = 8;
+
+// The following macro iterates the supplied enum variants taking the variant
+// name and building both the dispatch of a call to the matching RpcApi method
+// and the schema of its request and response types.
+// ... `GetInfo` yields: get_info_call() + GetInfoRequest + GetInfoResponse
+macro_rules! build_jsonrpc_methods {
+    ([$($op:ident),* $(,)?]) => {
+        paste::paste! {
+            /// The operations exposed as JSON-RPC methods
+            pub const METHODS: &[RpcApiOps] = &[$(RpcApiOps::$op),*];
+
+            async fn dispatch(service: &DynRpcService, op: RpcApiOps, params: Value) -> Result<Value, JsonRpcError> {
+                match op {
+                    $(
+                        RpcApiOps::$op => {
+                            let request: [<$op Request>] = serde_json::from_value(params).map_err(JsonRpcError::invalid_params)?;
+                            let response: [<$op Response>] = service.[<$op:snake _call>](request).await?;
+                            serde_json::to_value(response).map_err(JsonRpcError::internal_error)
+                        }
+                    )*
+                    _ => Err(JsonRpcError::method_not_found(&method_name(op))),
+                }
+            }
+
+            /// Builds the schema of all the [`METHODS`]
+            pub fn schema() -> Value {
+                let mut builder = SchemaBuilder::default();
+                $(
+                    builder.method(RpcApiOps::$op, [<$op Request>]::schema_container(), [<$op Response>]::schema_container());
+                )*
+                builder.build()
+            }
+        }
+    };
+}
+
+// Subscriptions are left out since the endpoint is stateless
+build_jsonrpc_methods!([
+    AddPeer,
+    Ban,
+    EstimateNetworkHashesPerSecond,
+    GetBalanceByAddress,
+    GetBalancesByAddresses,
+    GetBlock,
+    GetBlockCount,
+    GetBlockDagInfo,
+    GetBlocks,
+    GetBlockTemplate,
+    GetCoinSupply,
+    GetConnectedPeerInfo,
+    GetDaaScoreTimestampEstimate,
+    GetPrice,
+    GetTransaction,
+    GetTransactionsByAddress,
+    GetUtxoSet,
+    GetServerInfo,
+    GetCurrentNetwork,
+    GetHeaders,
+    GetInfo,
+    GetMempoolEntries,
+    GetMempoolEntriesByAddresses,
+    GetMempoolEntry,
+    GetPeerAddresses,
+    GetMetrics,
+    GetSink,
+    GetSubnetwork,
+    GetSyncStatus,
+    GetUtxosByAddresses,
+    GetSinkBlueScore,
+    GetVirtualChainFromBlock,
+    Ping,
+    ResolveFinalityConflict,
+    Shutdown,
+    SubmitBlock,
+    SubmitTransaction,
+    Unban,
+]);
+
+/// Maps JSON-RPC 2.0 payloads, single calls or batches, onto an [`RpcApi`](kash_rpc_core::api::rpc::RpcApi) service
+pub struct Router {
+    service: DynRpcService,
+    schema: Arc<Value>,
+}
+
+impl Router {
+    pub fn new(service: DynRpcService) -> Self {
+        Self { service, schema: Arc::new(schema()) }
+    }
+
+    pub fn schema(&self) -> Arc<Value> {
+        self.schema.clone()
+    }
+
+    /// Processes a request body on behalf of `session`, returning the response payload or `None` if the body only holds notifications
+    pub async fn handle(&self, body: &[u8], session: &Session) -> Option<Value> {
+        let payload = match serde_json::from_slice::<Value>(body) {
+            Ok(payload) => payload,
+            Err(err) => return Some(Self::to_value(JsonRpcResponse::error(Value::Null, JsonRpcError::parse_error(err)))),
+        };
+        match payload {
+            Value::Array(calls) if calls.is_empty() => {
+                Some(Self::to_value(JsonRpcResponse::error(Value::Null, JsonRpcError::invalid_request("empty batch"))))
+            }
+            Value::Array(calls) if calls.len() > MAX_BATCH_SIZE => Some(Self::to_value(JsonRpcResponse::error(
+                Value::Null,
+                JsonRpcError::invalid_request(format!("batch of {} calls exceeds the maximum of {}", calls.len(), MAX_BATCH_SIZE)),
+            ))),
+            Value::Array(calls) => {
+                let responses = stream::iter(calls.into_iter().map(|call| self.handle_call(call, session)))
+                    .buffered(MAX_BATCH_CONCURRENCY)
+                    .filter_map(|response| async move { response })
+                    .collect::<Vec<_>>()
+                    .await;
+                (!responses.is_empty()).then(|| Value::Array(responses.into_iter().map(Self::to_value).collect()))
+            }
+            call => self.handle_call(call, session).await.map(Self::to_value),
+        }
+    }
+
+    async fn handle_call(&self, call: Value, session: &Session) -> Option<JsonRpcResponse> {
+        let request = match JsonRpcRequest::try_from_value(call) {
+            Ok(request) => request,
+            Err((id, err)) => return Some(JsonRpcResponse::error(id, err)),
+        };
+        trace!("[JSON-RPC] call {} {:?}", request.method, request.id);
+        let result = self.call(&request.method, request.params, session).await;
+        // Notifications get no response, even on failure
+        request.id.map(|id| JsonRpcResponse::new(id, result))
+    }
+
+    async fn call(&self, method: &str, params: Value, session: &Session) -> Result<Value, JsonRpcError> {
+        if method == DISCOVER_METHOD {
+            return Ok(self.schema.as_ref().clone());
+        }
+        match serde_json::from_value::<RpcApiOps>(Value::String(method.to_string())) {
+            Ok(op) if METHODS.contains(&op) => {
+                session.check(op)?;
+                dispatch(&self.service, op, params).await
+            }
+            _ => Err(JsonRpcError::method_not_found(method)),
+        }
+    }
+
+    fn to_value(response: JsonRpcResponse) -> Value {
+        serde_json::to_value(response).expect("a JSON-RPC response is serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::rpc_core_mock::RpcCoreMock;
+    use kash_rpc_service::auth::{credentials::SignedRequest, guard::RpcGuard};
+    use serde_json::json;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn router_and_session() -> (Router, Session) {
+        let session =
+            Arc::new(RpcGuard::open()).open_session(IpAddr::V4(Ipv4Addr::LOCALHOST), None, &SignedRequest::default()).unwrap();
+        (Router::new(Arc::new(RpcCoreMock)), session)
+    }
+
+    async fn handle(router: &Router, session: &Session, payload: Value) -> Option<Value> {
+        router.handle(&serde_json::to_vec(&payload).unwrap(), session).await
+    }
+
+    #[test]
+    fn test_schema() {
+        let schema = schema();
+        let methods = schema["methods"].as_array().unwrap();
+        assert_eq!(methods.len(), METHODS.len());
+        for (method, op) in methods.iter().zip(METHODS.iter()) {
+            assert_eq!(method["name"], serde_json::to_value(op).unwrap(), "method names must match the serde encoding of RpcApiOps");
+            assert_eq!(method["name"], method_name(*op));
+        }
+
+        let get_block = methods.iter().find(|x| x["name"] == "getBlock").unwrap();
+        let request = &schema["components"]["schemas"]["GetBlockRequest"];
+        assert_eq!(get_block["params"][0]["schema"]["$ref"], "#/components/schemas/GetBlockRequest");
+        assert_eq!(request["properties"]["hash"]["type"], "string");
+        assert_eq!(request["properties"]["includeTransactions"]["type"], "boolean");
+        assert_eq!(request["required"], serde_json::json!(["hash", "includeTransactions"]));
+
+        // Every referenced schema is defined
+        let schemas = schema["components"]["schemas"].as_object().unwrap();
+        assert!(schemas.values().all(|x| !x.is_null()));
+    }
+
+    #[tokio::test]
+    async fn test_calls() {
+        let (router, session) = router_and_session();
+
+        let response =
+            handle(&router, &session, json!({ "jsonrpc": "2.0", "id": 1, "method": "getInfo", "params": {} })).await.unwrap();
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["p2pId"], "p2p-mock");
+
+        let response =
+            handle(&router, &session, json!({ "jsonrpc": "2.0", "id": 2, "method": "getFoo", "params": {} })).await.unwrap();
+        assert_eq!(response["id"], 2);
+        assert_eq!(response["error"]["code"], JsonRpcError::METHOD_NOT_FOUND);
+
+        let params = json!({ "hash": 42, "includeTransactions": "yes" });
+        let response =
+            handle(&router, &session, json!({ "jsonrpc": "2.0", "id": 3, "method": "getBlock", "params": params })).await.unwrap();
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["error"]["code"], JsonRpcError::INVALID_PARAMS);
+
+        let response = router.handle(b"{ not json", &session).await.unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], JsonRpcError::PARSE_ERROR);
+
+        // A notification gets no response
+        assert!(handle(&router, &session, json!({ "jsonrpc": "2.0", "method": "ping", "params": {} })).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_batches() {
+        let (router, session) = router_and_session();
+
+        // Responses keep the order of the calls and notifications are left out
+        let batch = json!([
+            { "jsonrpc": "2.0", "id": "a", "method": "ping", "params": {} },
+            { "jsonrpc": "2.0", "method": "ping", "params": {} },
+            { "jsonrpc": "2.0", "id": "b", "method": "getFoo", "params": {} },
+            { "jsonrpc": "2.0", "id": "c", "method": "getInfo", "params": {} },
+            42,
+        ]);
+        let responses = handle(&router, &session, batch).await.unwrap();
+        let responses = responses.as_array().unwrap();
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["id"], "a");
+        assert_eq!(responses[0]["result"], json!({}));
+        assert_eq!(responses[1]["id"], "b");
+        assert_eq!(responses[1]["error"]["code"], JsonRpcError::METHOD_NOT_FOUND);
+        assert_eq!(responses[2]["id"], "c");
+        assert_eq!(responses[2]["result"]["serverVersion"], "mock");
+        assert_eq!(responses[3]["id"], Value::Null);
+        assert_eq!(responses[3]["error"]["code"], JsonRpcError::INVALID_REQUEST);
+
+        // A batch of notifications only gets no response
+        let batch = json!([
+            { "jsonrpc": "2.0", "method": "ping", "params": {} },
+            { "jsonrpc": "2.0", "method": "getFoo", "params": {} },
+        ]);
+        assert!(handle(&router, &session, batch).await.is_none());
+
+        let response = handle(&router, &session, json!([])).await.unwrap();
+        assert_eq!(response["error"]["code"], JsonRpcError::INVALID_REQUEST);
+
+        // Oversized batches are refused as a whole
+        let batch = (0..=MAX_BATCH_SIZE).map(|id| json!({ "jsonrpc": "2.0", "id": id, "method": "ping", "params": {} })).collect();
+        let response = handle(&router, &session, Value::Array(batch)).await.unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(response["error"]["code"], JsonRpcError::INVALID_REQUEST);
+
+        let batch = (0..MAX_BATCH_SIZE).map(|id| json!({ "jsonrpc": "2.0", "id": id, "method": "ping", "params": {} })).collect();
+        let responses = handle(&router, &session, Value::Array(batch)).await.unwrap();
+        assert_eq!(responses.as_array().unwrap().len(), MAX_BATCH_SIZE);
+    }
+}
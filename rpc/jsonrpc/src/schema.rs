@@ -0,0 +1,198 @@
+//! Machine-readable description of the JSON-RPC endpoint, formatted as an [OpenRPC](https://spec.open-rpc.org) document.
+//!
+//! The request and response schemas are generated from the [`BorshSchema`](borsh::BorshSchema) of the `rpc/core`
+//! models, translated to their serde JSON encoding: field and variant names are camelCased and the types having
+//! a custom serde encoding (hashes, addresses, script public keys...) are described as strings.
+
+use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
+use kash_rpc_core::api::ops::RpcApiOps;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use workflow_core::enums::EnumTrait;
+
+pub const OPENRPC_VERSION: &str = "1.2.6";
+
+/// Types serialized by serde as a single string
+const STRING_ENCODED_TYPES: &[&str] = &["Hash", "Address", "ScriptPublicKey", "NetworkId", "SubnetworkId", "Uint192"];
+
+/// Collects the method descriptions and the schemas of the named types they refer to
+#[derive(Default)]
+pub struct SchemaBuilder {
+    methods: Vec<Value>,
+    schemas: Map<String, Value>,
+}
+
+impl SchemaBuilder {
+    pub fn method(&mut self, op: RpcApiOps, request: BorshSchemaContainer, response: BorshSchemaContainer) {
+        let params_schema = self.schema_of(&request.declaration, &request.definitions);
+        let result_schema = self.schema_of(&response.declaration, &response.definitions);
+        self.methods.push(json!({
+            "name": method_name(op),
+            "summary": op.descr(),
+            "paramStructure": "by-name",
+            "params": [{ "name": "request", "required": false, "schema": params_schema }],
+            "result": { "name": "response", "schema": result_schema },
+        }));
+    }
+
+    pub fn build(self) -> Value {
+        json!({
+            "openrpc": OPENRPC_VERSION,
+            "info": { "title": "Kash JSON-RPC", "version": env!("CARGO_PKG_VERSION") },
+            "methods": self.methods,
+            "components": { "schemas": self.schemas },
+        })
+    }
+
+    /// Returns the schema of `declaration`, registering the named types in the components as a side effect
+    fn schema_of(&mut self, declaration: &Declaration, definitions: &HashMap<Declaration, Definition>) -> Value {
+        if STRING_ENCODED_TYPES.contains(&declaration.as_str()) {
+            return json!({ "type": "string" });
+        }
+        if let Some(schema) = primitive_schema(declaration) {
+            return schema;
+        }
+        let Some(definition) = definitions.get(declaration) else {
+            return json!({});
+        };
+        match definition {
+            Definition::Enum { variants } if declaration.starts_with("Option<") && variants.len() == 2 => {
+                json!({ "oneOf": [{ "type": "null" }, self.schema_of(&variants[1].1, definitions)] })
+            }
+            Definition::Sequence { elements } if declaration.starts_with("HashMap<") => match definitions.get(elements) {
+                Some(Definition::Tuple { elements }) if elements.len() == 2 => {
+                    json!({ "type": "object", "additionalProperties": self.schema_of(&elements[1], definitions) })
+                }
+                _ => json!({ "type": "object" }),
+            },
+            Definition::Sequence { elements } => json!({ "type": "array", "items": self.schema_of(elements, definitions) }),
+            Definition::Array { length, elements } => {
+                json!({ "type": "array", "items": self.schema_of(elements, definitions), "minItems": length, "maxItems": length })
+            }
+            Definition::Tuple { elements } => {
+                let items = elements.iter().map(|x| self.schema_of(x, definitions)).collect::<Vec<_>>();
+                json!({ "type": "array", "prefixItems": items, "minItems": elements.len(), "maxItems": elements.len() })
+            }
+            Definition::Struct { .. } | Definition::Enum { .. } => {
+                // Register a placeholder first so recursive types terminate
+                if !self.schemas.contains_key(declaration) {
+                    self.schemas.insert(declaration.clone(), Value::Null);
+                    let schema = self.named_schema(definition, definitions);
+                    self.schemas.insert(declaration.clone(), schema);
+                }
+                json!({ "$ref": format!("#/components/schemas/{declaration}") })
+            }
+        }
+    }
+
+    fn named_schema(&mut self, definition: &Definition, definitions: &HashMap<Declaration, Definition>) -> Value {
+        match definition {
+            Definition::Struct { fields: Fields::NamedFields(fields) } => self.object_schema(fields, definitions),
+            Definition::Struct { fields: Fields::UnnamedFields(fields) } if fields.len() == 1 => {
+                // Newtypes are serialized as their inner value
+                self.schema_of(&fields[0], definitions)
+            }
+            Definition::Struct { fields: Fields::UnnamedFields(fields) } => {
+                let items = fields.iter().map(|x| self.schema_of(x, definitions)).collect::<Vec<_>>();
+                json!({ "type": "array", "prefixItems": items, "minItems": fields.len(), "maxItems": fields.len() })
+            }
+            Definition::Struct { fields: Fields::Empty } => json!({ "type": "object" }),
+            Definition::Enum { variants } => {
+                let is_unit = |declaration: &Declaration| is_empty_struct(definitions.get(declaration));
+                if variants.iter().all(|(_, declaration)| is_unit(declaration)) {
+                    let names = variants.iter().map(|(name, _)| camel_case(name)).collect::<Vec<_>>();
+                    return json!({ "type": "string", "enum": names });
+                }
+                // Serde externally tags the variants having some content
+                let one_of = variants
+                    .iter()
+                    .map(|(name, declaration)| {
+                        if is_unit(declaration) {
+                            json!({ "const": camel_case(name) })
+                        } else {
+                            let content = match definitions.get(declaration) {
+                                Some(definition) => self.named_schema(definition, definitions),
+                                None => json!({}),
+                            };
+                            json!({ "type": "object", "properties": { camel_case(name): content }, "required": [camel_case(name)] })
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                json!({ "oneOf": one_of })
+            }
+            _ => json!({}),
+        }
+    }
+
+    fn object_schema(&mut self, fields: &[(String, Declaration)], definitions: &HashMap<Declaration, Definition>) -> Value {
+        let mut properties = Map::new();
+        let mut required = vec![];
+        for (name, declaration) in fields.iter() {
+            let name = camel_case(name);
+            if !declaration.starts_with("Option<") {
+                required.push(Value::String(name.clone()));
+            }
+            properties.insert(name, self.schema_of(declaration, definitions));
+        }
+        json!({ "type": "object", "properties": properties, "required": required })
+    }
+}
+
+/// The JSON-RPC method name of an operation, matching its serde encoding
+pub fn method_name(op: RpcApiOps) -> String {
+    camel_case(op.as_str())
+}
+
+fn is_empty_struct(definition: Option<&Definition>) -> bool {
+    match definition {
+        None | Some(Definition::Struct { fields: Fields::Empty }) => true,
+        Some(Definition::Struct { fields: Fields::NamedFields(fields) }) => fields.is_empty(),
+        _ => false,
+    }
+}
+
+fn primitive_schema(declaration: &str) -> Option<Value> {
+    let schema = match declaration {
+        "u8" | "u16" | "u32" | "u64" | "u128" => json!({ "type": "integer", "minimum": 0 }),
+        "i8" | "i16" | "i32" | "i64" | "i128" => json!({ "type": "integer" }),
+        "f32" | "f64" => json!({ "type": "number" }),
+        "bool" => json!({ "type": "boolean" }),
+        "string" => json!({ "type": "string" }),
+        "nil" => json!({ "type": "null" }),
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Converts a snake_case field name or a PascalCase variant name to camelCase, as done by `#[serde(rename_all = "camelCase")]`
+fn camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize = false;
+    for (i, c) in name.chars().enumerate() {
+        if c == '_' {
+            capitalize = i > 0;
+        } else if capitalize {
+            result.extend(c.to_uppercase());
+            capitalize = false;
+        } else if i == 0 {
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("transaction_id"), "transactionId");
+        assert_eq!(camel_case("include_accepted_transaction_ids"), "includeAcceptedTransactionIds");
+        assert_eq!(camel_case("OrphanResolved"), "orphanResolved");
+        assert_eq!(camel_case("GetInfo"), "getInfo");
+        assert_eq!(camel_case("hash"), "hash");
+    }
+}
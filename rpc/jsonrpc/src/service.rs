@@ -0,0 +1,161 @@
+use crate::{error::Error, result::Result, router::Router};
+use hyper::{
+    body::HttpBody,
+    header::{HeaderValue, ALLOW, AUTHORIZATION, CONTENT_TYPE},
+    server::conn::AddrStream,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use kash_core::{
+    info,
+    task::service::{AsyncService, AsyncServiceError, AsyncServiceFuture},
+    trace, warn,
+};
+use kash_rpc_core::RpcError;
+use kash_rpc_service::{
+    auth::{
+        credentials::{Credentials, SignedRequest},
+        guard::{RpcGuard, Session},
+    },
+    service::RpcCoreService,
+};
+use kash_utils::{networking::NetAddress, triggers::SingleTrigger};
+use serde_json::Value;
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+
+const JSONRPC_SERVICE: &str = "jsonrpc-service";
+
+/// Path serving the schema of the endpoint to plain HTTP GET requests
+pub const SCHEMA_PATH: &str = "/schema";
+
+static MAX_REQUEST_BODY_SIZE: u64 = 1024 * 1024 * 32; // 32MB
+
+/// Stateless JSON-RPC 2.0 over HTTP listener
+pub struct JsonRpcService {
+    net_address: NetAddress,
+    router: Arc<Router>,
+    guard: Arc<RpcGuard>,
+    shutdown: SingleTrigger,
+}
+
+impl JsonRpcService {
+    pub fn new(address: NetAddress, core_service: Arc<RpcCoreService>) -> Self {
+        let guard = core_service.guard();
+        Self { net_address: address, router: Arc::new(Router::new(core_service)), guard, shutdown: Default::default() }
+    }
+
+    async fn serve(router: Arc<Router>, session: Arc<Session>, request: Request<Body>) -> Result<Response<Body>> {
+        match (request.method(), request.uri().path()) {
+            (&Method::POST, _) => {}
+            (&Method::GET, SCHEMA_PATH) => return Self::json_response(StatusCode::OK, &router.schema()),
+            _ => {
+                let mut response = Self::status_response(StatusCode::METHOD_NOT_ALLOWED);
+                response.headers_mut().insert(ALLOW, HeaderValue::from_static("POST, GET"));
+                return Ok(response);
+            }
+        }
+
+        let credentials = match request.headers().get(AUTHORIZATION).map(|x| x.to_str().ok().map(str::parse::<Credentials>)) {
+            Some(Some(Ok(credentials))) => Some(credentials),
+            Some(_) => return Ok(Self::status_response(StatusCode::UNAUTHORIZED)),
+            None => None,
+        };
+
+        if request.body().size_hint().lower() > MAX_REQUEST_BODY_SIZE {
+            return Ok(Self::status_response(StatusCode::PAYLOAD_TOO_LARGE));
+        }
+        let (parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        if body.len() as u64 > MAX_REQUEST_BODY_SIZE {
+            return Ok(Self::status_response(StatusCode::PAYLOAD_TOO_LARGE));
+        }
+
+        // Every request is authenticated on its own, with the credentials of its `Authorization` header if any,
+        // while the rate limits of the session apply to all the requests of the connection. API key signatures
+        // cover the method, the path and the body of the request.
+        let signed_request = SignedRequest::http(parts.method.as_str(), parts.uri.path(), &body);
+        match session.reauthenticate(credentials.as_ref(), &signed_request) {
+            Ok(()) => {}
+            Err(RpcError::RateLimited) => return Ok(Self::status_response(StatusCode::TOO_MANY_REQUESTS)),
+            Err(_) => return Ok(Self::status_response(StatusCode::UNAUTHORIZED)),
+        }
+
+        match router.handle(&body, &session).await {
+            Some(payload) => Self::json_response(StatusCode::OK, &payload),
+            // A body holding only notifications gets no content back
+            None => Ok(Self::status_response(StatusCode::NO_CONTENT)),
+        }
+    }
+
+    fn json_response(status: StatusCode, payload: &Value) -> Result<Response<Body>> {
+        let mut response = Response::new(Body::from(serde_json::to_vec(payload)?));
+        *response.status_mut() = status;
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Ok(response)
+    }
+
+    fn status_response(status: StatusCode) -> Response<Body> {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = status;
+        response
+    }
+}
+
+impl AsyncService for JsonRpcService {
+    fn ident(self: Arc<Self>) -> &'static str {
+        JSONRPC_SERVICE
+    }
+
+    fn start(self: Arc<Self>) -> AsyncServiceFuture {
+        trace!("{} starting", JSONRPC_SERVICE);
+
+        // Prepare a shutdown signal receiver
+        let shutdown_signal = self.shutdown.listener.clone();
+
+        let address: SocketAddr = self.net_address.into();
+        let router = self.router.clone();
+        let guard = self.guard.clone();
+
+        Box::pin(async move {
+            let make_service = make_service_fn(move |connection: &AddrStream| {
+                let router = router.clone();
+                let session = Arc::new(guard.open_pending_session(connection.remote_addr().ip()));
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |request| {
+                        let router = router.clone();
+                        let session = session.clone();
+                        async move {
+                            Ok::<_, Infallible>(Self::serve(router, session, request).await.unwrap_or_else(|err: Error| {
+                                warn!("JSON-RPC server error: {}", err);
+                                Self::status_response(StatusCode::INTERNAL_SERVER_ERROR)
+                            }))
+                        }
+                    }))
+                }
+            });
+
+            let server = Server::try_bind(&address)
+                .map_err(|err| AsyncServiceError::Service(format!("{JSONRPC_SERVICE} cannot listen on {address}: {err}")))?;
+            info!("JSON-RPC Server starting on: {}", address);
+
+            // Keep the HTTP server running until a service shutdown signal is received
+            server
+                .serve(make_service)
+                .with_graceful_shutdown(shutdown_signal)
+                .await
+                .map_err(|err| AsyncServiceError::Service(format!("{JSONRPC_SERVICE} error: {err}")))
+        })
+    }
+
+    fn signal_exit(self: Arc<Self>) {
+        trace!("sending an exit signal to {}", JSONRPC_SERVICE);
+        self.shutdown.trigger.trigger();
+    }
+
+    fn stop(self: Arc<Self>) -> AsyncServiceFuture {
+        Box::pin(async move {
+            trace!("{} stopped", JSONRPC_SERVICE);
+            Ok(())
+        })
+    }
+}
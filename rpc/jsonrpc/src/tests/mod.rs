@@ -0,0 +1 @@
+pub(crate) mod rpc_core_mock;
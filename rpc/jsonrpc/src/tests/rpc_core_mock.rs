@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use kash_notify::{listener::ListenerId, scope::Scope};
+use kash_rpc_core::{api::rpc::RpcApi, notify::connection::ChannelConnection, *};
+
+/// A node answering `getInfo` and `ping` only, enough to exercise the JSON-RPC routing
+#[derive(Default)]
+pub(crate) struct RpcCoreMock;
+
+#[async_trait]
+impl RpcApi for RpcCoreMock {
+    async fn get_info_call(&self, _request: GetInfoRequest) -> RpcResult<GetInfoResponse> {
+        Ok(GetInfoResponse {
+            p2p_id: "p2p-mock".to_string(),
+            mempool_size: 1234,
+            server_version: "mock".to_string(),
+            is_utxo_indexed: false,
+            is_synced: false,
+            has_notify_command: false,
+            has_message_id: false,
+            mempool_asset_policies: vec![],
+            maximum_mint_transactions_per_block: u64::MAX,
+            maximum_redeem_transactions_per_block: u64::MAX,
+        })
+    }
+
+    async fn ping_call(&self, _request: PingRequest) -> RpcResult<PingResponse> {
+        Ok(PingResponse {})
+    }
+
+    async fn get_metrics_call(&self, _request: GetMetricsRequest) -> RpcResult<GetMetricsResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_server_info_call(&self, _request: GetServerInfoRequest) -> RpcResult<GetServerInfoResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_sync_status_call(&self, _request: GetSyncStatusRequest) -> RpcResult<GetSyncStatusResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_current_network_call(&self, _request: GetCurrentNetworkRequest) -> RpcResult<GetCurrentNetworkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn submit_block_call(&self, _request: SubmitBlockRequest) -> RpcResult<SubmitBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_template_call(&self, _request: GetBlockTemplateRequest) -> RpcResult<GetBlockTemplateResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_peer_addresses_call(&self, _request: GetPeerAddressesRequest) -> RpcResult<GetPeerAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_sink_call(&self, _request: GetSinkRequest) -> RpcResult<GetSinkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_mempool_entry_call(&self, _request: GetMempoolEntryRequest) -> RpcResult<GetMempoolEntryResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_mempool_entries_call(&self, _request: GetMempoolEntriesRequest) -> RpcResult<GetMempoolEntriesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_connected_peer_info_call(&self, _request: GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn add_peer_call(&self, _request: AddPeerRequest) -> RpcResult<AddPeerResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn submit_transaction_call(&self, _request: SubmitTransactionRequest) -> RpcResult<SubmitTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_call(&self, _request: GetBlockRequest) -> RpcResult<GetBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_subnetwork_call(&self, _request: GetSubnetworkRequest) -> RpcResult<GetSubnetworkResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_virtual_chain_from_block_call(
+        &self,
+        _request: GetVirtualChainFromBlockRequest,
+    ) -> RpcResult<GetVirtualChainFromBlockResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_blocks_call(&self, _request: GetBlocksRequest) -> RpcResult<GetBlocksResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_count_call(&self, _request: GetBlockCountRequest) -> RpcResult<GetBlockCountResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_block_dag_info_call(&self, _request: GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn resolve_finality_conflict_call(
+        &self,
+        _request: ResolveFinalityConflictRequest,
+    ) -> RpcResult<ResolveFinalityConflictResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn shutdown_call(&self, _request: ShutdownRequest) -> RpcResult<ShutdownResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_headers_call(&self, _request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_balance_by_address_call(&self, _request: GetBalanceByAddressRequest) -> RpcResult<GetBalanceByAddressResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_balances_by_addresses_call(
+        &self,
+        _request: GetBalancesByAddressesRequest,
+    ) -> RpcResult<GetBalancesByAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxos_by_addresses_call(&self, _request: GetUtxosByAddressesRequest) -> RpcResult<GetUtxosByAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_sink_blue_score_call(&self, _request: GetSinkBlueScoreRequest) -> RpcResult<GetSinkBlueScoreResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn ban_call(&self, _request: BanRequest) -> RpcResult<BanResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn unban_call(&self, _request: UnbanRequest) -> RpcResult<UnbanResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn estimate_network_hashes_per_second_call(
+        &self,
+        _request: EstimateNetworkHashesPerSecondRequest,
+    ) -> RpcResult<EstimateNetworkHashesPerSecondResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_mempool_entries_by_addresses_call(
+        &self,
+        _request: GetMempoolEntriesByAddressesRequest,
+    ) -> RpcResult<GetMempoolEntriesByAddressesResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_coin_supply_call(&self, _request: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_daa_score_timestamp_estimate_call(
+        &self,
+        _request: GetDaaScoreTimestampEstimateRequest,
+    ) -> RpcResult<GetDaaScoreTimestampEstimateResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_price_call(&self, _request: GetPriceRequest) -> RpcResult<GetPriceResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transaction_call(&self, _request: GetTransactionRequest) -> RpcResult<GetTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transactions_by_address_call(
+        &self,
+        _request: GetTransactionsByAddressRequest,
+    ) -> RpcResult<GetTransactionsByAddressResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxo_set_call(&self, _request: GetUtxoSetRequest) -> RpcResult<GetUtxoSetResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+    // Notification API
+
+    fn register_new_listener(&self, _connection: ChannelConnection) -> ListenerId {
+        unimplemented!("the JSON-RPC endpoint does not subscribe")
+    }
+
+    async fn unregister_listener(&self, _id: ListenerId) -> RpcResult<()> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn start_notify(&self, _id: ListenerId, _scope: Scope) -> RpcResult<()> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn stop_notify(&self, _id: ListenerId, _scope: Scope) -> RpcResult<()> {
+        Err(RpcError::NotImplemented)
+    }
+}
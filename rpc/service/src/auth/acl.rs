@@ -0,0 +1,94 @@
+use super::config::RoleConfig;
+use kash_rpc_core::api::ops::RpcApiOps;
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Wildcard matching every method in an allow or deny list
+pub const ANY_METHOD: &str = "*";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MethodSet {
+    All,
+    Some(HashSet<RpcApiOps>),
+}
+
+impl MethodSet {
+    fn parse(methods: &[String]) -> Result<Self, String> {
+        if methods.iter().any(|x| x == ANY_METHOD) {
+            return Ok(MethodSet::All);
+        }
+        let ops = methods
+            .iter()
+            .map(|method| {
+                serde_json::from_value::<RpcApiOps>(Value::String(method.clone()))
+                    .map_err(|_| format!("unknown RPC method `{method}`"))
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+        Ok(MethodSet::Some(ops))
+    }
+
+    fn contains(&self, op: RpcApiOps) -> bool {
+        match self {
+            MethodSet::All => true,
+            MethodSet::Some(ops) => ops.contains(&op),
+        }
+    }
+}
+
+/// Methods a principal may call, a denied method being refused even if allowed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acl {
+    allow: MethodSet,
+    deny: MethodSet,
+}
+
+impl Acl {
+    /// An ACL granting every method
+    pub fn allow_all() -> Self {
+        Self { allow: MethodSet::All, deny: MethodSet::Some(HashSet::new()) }
+    }
+
+    pub fn from_config(config: &RoleConfig) -> Result<Self, String> {
+        Ok(Self { allow: MethodSet::parse(&config.allow)?, deny: MethodSet::parse(&config.deny)? })
+    }
+
+    pub fn is_allowed(&self, op: RpcApiOps) -> bool {
+        // Authenticating is always possible so a client can upgrade its role
+        op == RpcApiOps::Authenticate || (self.allow.contains(op) && !self.deny.contains(op))
+    }
+}
+
+impl Default for Acl {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role(allow: &[&str], deny: &[&str]) -> RoleConfig {
+        RoleConfig { allow: allow.iter().map(|x| x.to_string()).collect(), deny: deny.iter().map(|x| x.to_string()).collect() }
+    }
+
+    #[test]
+    fn test_acl() {
+        let acl = Acl::from_config(&role(&["getInfo", "notifyBlockAdded"], &[])).unwrap();
+        assert!(acl.is_allowed(RpcApiOps::GetInfo));
+        assert!(acl.is_allowed(RpcApiOps::NotifyBlockAdded));
+        assert!(acl.is_allowed(RpcApiOps::Authenticate));
+        assert!(!acl.is_allowed(RpcApiOps::SubmitBlock));
+
+        let acl = Acl::from_config(&role(&["*"], &["shutdown", "ban"])).unwrap();
+        assert!(acl.is_allowed(RpcApiOps::GetBlock));
+        assert!(!acl.is_allowed(RpcApiOps::Shutdown));
+        assert!(!acl.is_allowed(RpcApiOps::Ban));
+
+        let acl = Acl::from_config(&role(&[], &[])).unwrap();
+        assert!(!acl.is_allowed(RpcApiOps::Ping));
+        assert!(acl.is_allowed(RpcApiOps::Authenticate));
+
+        assert!(Acl::from_config(&role(&["getInfos"], &[])).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use super::{
+    acl::Acl,
+    config::AuthConfig,
+    credentials::{Credentials, SignedRequest, API_KEY_SIGNATURE_MAX_AGE},
+};
+use kash_core::time::unix_now;
+use kash_rpc_core::{RpcError, RpcResult};
+use parking_lot::Mutex;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
+use subtle::ConstantTimeEq;
+
+/// Identity granted to an RPC client together with the methods it may call
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Principal {
+    pub name: String,
+    pub acl: Acl,
+}
+
+impl Principal {
+    pub fn new(name: String, acl: Acl) -> Self {
+        Self { name, acl }
+    }
+
+    pub fn anonymous(acl: Acl) -> Self {
+        Self::new("anonymous".to_string(), acl)
+    }
+}
+
+/// Maps the credentials presented by a client, if any, to a [`Principal`]
+pub trait Authorizer: Send + Sync + Debug {
+    /// Authenticates `credentials`, API key signatures being checked against the `request` carrying them
+    fn authenticate(&self, credentials: Option<&Credentials>, request: &SignedRequest) -> RpcResult<Principal>;
+}
+
+pub type DynAuthorizer = Arc<dyn Authorizer>;
+
+/// Authorizer granting every method to everyone, the behavior of a node having no auth config
+#[derive(Debug, Default)]
+pub struct OpenAuthorizer;
+
+impl Authorizer for OpenAuthorizer {
+    fn authenticate(&self, _credentials: Option<&Credentials>, _request: &SignedRequest) -> RpcResult<Principal> {
+        Ok(Principal::anonymous(Acl::allow_all()))
+    }
+}
+
+#[derive(Debug)]
+struct BearerToken {
+    name: String,
+    token: Vec<u8>,
+    acl: Acl,
+}
+
+#[derive(Debug)]
+struct ApiKey {
+    secret: String,
+    acl: Acl,
+}
+
+/// Authorizer resolving bearer tokens and API keys declared in an [`AuthConfig`]
+#[derive(Debug)]
+pub struct ConfigAuthorizer {
+    anonymous: Option<Acl>,
+    bearer_tokens: Vec<BearerToken>,
+    api_keys: HashMap<String, ApiKey>,
+    // API key signatures accepted within the last `API_KEY_SIGNATURE_MAX_AGE`, which are refused if presented again
+    used_signatures: Mutex<HashSet<(String, u64)>>,
+}
+
+impl ConfigAuthorizer {
+    pub fn new(config: &AuthConfig) -> Result<Self, String> {
+        let roles = config
+            .roles
+            .iter()
+            .map(|(name, role)| Ok((name.clone(), Acl::from_config(role).map_err(|err| format!("role `{name}`: {err}"))?)))
+            .collect::<Result<HashMap<_, _>, String>>()?;
+        let role = |name: &str| roles.get(name).cloned().ok_or_else(|| format!("undefined role `{name}`"));
+
+        let anonymous = config.anonymous_role.as_deref().map(role).transpose()?;
+        let bearer_tokens = config
+            .bearer_tokens
+            .iter()
+            .map(|x| {
+                if x.token.is_empty() {
+                    return Err("bearer tokens cannot be empty".to_string());
+                }
+                let name = x.name.clone().unwrap_or_else(|| x.role.clone());
+                Ok(BearerToken { name, token: x.token.as_bytes().to_vec(), acl: role(&x.role)? })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let mut api_keys = HashMap::with_capacity(config.api_keys.len());
+        for x in config.api_keys.iter() {
+            let key = ApiKey { secret: x.secret.clone(), acl: role(&x.role)? };
+            if api_keys.insert(x.key_id.clone(), key).is_some() {
+                return Err(format!("duplicate API key id `{}`", x.key_id));
+            }
+        }
+        Ok(Self { anonymous, bearer_tokens, api_keys, used_signatures: Default::default() })
+    }
+
+    /// Records the use of the API key signature of `key_id` at `timestamp`, failing if it was already used
+    fn use_signature(&self, key_id: &str, timestamp: u64, now: u64) -> RpcResult<()> {
+        let mut used_signatures = self.used_signatures.lock();
+        // Signatures out of the validity window are refused anyway so they need not be remembered
+        used_signatures.retain(|(_, timestamp)| now.abs_diff(*timestamp) <= API_KEY_SIGNATURE_MAX_AGE);
+        if !used_signatures.insert((key_id.to_string(), timestamp)) {
+            return Err(RpcError::InvalidCredentials("the API key signature was already used".to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl Authorizer for ConfigAuthorizer {
+    fn authenticate(&self, credentials: Option<&Credentials>, request: &SignedRequest) -> RpcResult<Principal> {
+        match credentials {
+            None => self.anonymous.clone().map(Principal::anonymous).ok_or(RpcError::Unauthenticated),
+            Some(Credentials::Bearer(token)) => {
+                // Compare against every token in constant time so timing reveals neither a token nor its position
+                let token = token.as_bytes();
+                let mut found = None;
+                for x in self.bearer_tokens.iter() {
+                    if bool::from(x.token.as_slice().ct_eq(token)) {
+                        found = Some(x);
+                    }
+                }
+                found
+                    .map(|x| Principal::new(x.name.clone(), x.acl.clone()))
+                    .ok_or_else(|| RpcError::InvalidCredentials("unknown bearer token".to_string()))
+            }
+            Some(Credentials::ApiKey { key_id, timestamp, signature }) => {
+                let key = self.api_keys.get(key_id).ok_or_else(|| RpcError::InvalidCredentials("unknown API key".to_string()))?;
+                let now = unix_now();
+                Credentials::verify_api_key(key_id, *timestamp, signature, &key.secret, request, now)?;
+                self.use_signature(key_id, *timestamp, now)?;
+                Ok(Principal::new(key_id.clone(), key.acl.clone()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kash_rpc_core::api::ops::RpcApiOps;
+
+    fn config() -> AuthConfig {
+        serde_json::from_str(
+            r#"{
+                "roles": {
+                    "admin": { "allow": ["*"] },
+                    "public": { "allow": ["getInfo"] }
+                },
+                "anonymousRole": "public",
+                "bearerTokens": [{ "name": "ops", "token": "t0k3n", "role": "admin" }],
+                "apiKeys": [{ "keyId": "indexer", "secret": "s3cr3t", "role": "public" }]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_config_authorizer() {
+        let authorizer = ConfigAuthorizer::new(&config()).unwrap();
+
+        let request = SignedRequest::WRPC;
+        let anonymous = authorizer.authenticate(None, &request).unwrap();
+        assert_eq!(anonymous.name, "anonymous");
+        assert!(anonymous.acl.is_allowed(RpcApiOps::GetInfo));
+        assert!(!anonymous.acl.is_allowed(RpcApiOps::Shutdown));
+
+        let admin = authorizer.authenticate(Some(&"Bearer t0k3n".parse().unwrap()), &request).unwrap();
+        assert_eq!(admin.name, "ops");
+        assert!(admin.acl.is_allowed(RpcApiOps::Shutdown));
+        assert!(authorizer.authenticate(Some(&"Bearer t0k3m".parse().unwrap()), &request).is_err());
+
+        let api_key = |key_id: &str, secret: &str| Credentials::sign_api_key(key_id, secret, &request).parse::<Credentials>().unwrap();
+        let indexer_key = api_key("indexer", "s3cr3t");
+        let indexer = authorizer.authenticate(Some(&indexer_key), &request).unwrap();
+        assert_eq!(indexer.name, "indexer");
+        assert!(authorizer.authenticate(Some(&api_key("indexer", "wrong")), &request).is_err());
+        assert!(authorizer.authenticate(Some(&api_key("unknown", "s3cr3t")), &request).is_err());
+
+        // API key signatures cannot be replayed
+        assert!(matches!(authorizer.authenticate(Some(&indexer_key), &request), Err(RpcError::InvalidCredentials(_))));
+
+        // Without an anonymous role, credentials are mandatory
+        let mut config = config();
+        config.anonymous_role = None;
+        let authorizer = ConfigAuthorizer::new(&config).unwrap();
+        assert!(matches!(authorizer.authenticate(None, &request), Err(RpcError::Unauthenticated)));
+
+        // Roles must be defined
+        config.anonymous_role = Some("guest".to_string());
+        assert!(ConfigAuthorizer::new(&config).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use serde::Deserialize;
+use std::{collections::HashMap, fs, path::Path};
+
+/// Access rules of the RPC servers, usually loaded from a JSON file.
+///
+/// ```json
+/// {
+///     "roles": {
+///         "admin": { "allow": ["*"] },
+///         "public": { "allow": ["getInfo", "getBlock", "notifyBlockAdded"], "deny": ["submitBlock"] }
+///     },
+///     "anonymousRole": "public",
+///     "bearerTokens": [{ "name": "explorer", "token": "s3cr3t", "role": "admin" }],
+///     "apiKeys": [{ "keyId": "indexer", "secret": "an HMAC secret", "role": "public" }],
+///     "rateLimits": {
+///         "perConnection": { "rate": 20.0, "burst": 40 },
+///         "perIp": { "rate": 50.0, "burst": 100 }
+///     }
+/// }
+/// ```
+///
+/// Methods are named after the camelCase [`RpcApiOps`](kash_rpc_core::api::ops::RpcApiOps), `*` standing for all of them.
+/// Clients presenting no credentials get the `anonymousRole` or are refused if there is none.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AuthConfig {
+    #[serde(default)]
+    pub roles: HashMap<String, RoleConfig>,
+    #[serde(default)]
+    pub anonymous_role: Option<String>,
+    #[serde(default)]
+    pub bearer_tokens: Vec<BearerTokenConfig>,
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeyConfig>,
+    #[serde(default)]
+    pub rate_limits: RateLimitsConfig,
+}
+
+impl AuthConfig {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|err| format!("cannot read the RPC auth config {}: {err}", path.display()))?;
+        serde_json::from_str(&content).map_err(|err| format!("invalid RPC auth config {}: {err}", path.display()))
+    }
+}
+
+/// Methods granted to a role, a denied method being refused even if allowed
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RoleConfig {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BearerTokenConfig {
+    /// Name identifying the holder of the token in logs, defaults to the role
+    #[serde(default)]
+    pub name: Option<String>,
+    pub token: String,
+    pub role: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ApiKeyConfig {
+    pub key_id: String,
+    pub secret: String,
+    pub role: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimitsConfig {
+    #[serde(default)]
+    pub per_connection: Option<RateLimit>,
+    #[serde(default)]
+    pub per_ip: Option<RateLimit>,
+}
+
+/// A token bucket refilled with `rate` calls per second and holding up to `burst` calls
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RateLimit {
+    pub rate: f64,
+    pub burst: u32,
+}
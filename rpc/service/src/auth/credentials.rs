@@ -0,0 +1,134 @@
+use hmac::{Hmac, Mac};
+use kash_core::time::unix_now;
+use kash_rpc_core::{RpcError, RpcResult};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Maximum distance in milliseconds between the timestamp of an API key signature and the node clock
+pub const API_KEY_SIGNATURE_MAX_AGE: u64 = 5 * 60 * 1000;
+
+/// Request an API key signature is bound to, so that a signature cannot be used for another request
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SignedRequest<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub body: &'a [u8],
+}
+
+impl SignedRequest<'static> {
+    /// Request of a gRPC client sending its credentials in the `authorization` metadata of its message stream
+    pub const GRPC: Self = Self { method: "GRPC", path: "MessageStream", body: &[] };
+    /// Request of a wRPC client calling the `authenticate` method
+    pub const WRPC: Self = Self { method: "WRPC", path: "authenticate", body: &[] };
+}
+
+impl<'a> SignedRequest<'a> {
+    /// Request of a JSON-RPC client sending its credentials in the `Authorization` header of an HTTP request
+    pub fn http(method: &'a str, path: &'a str, body: &'a [u8]) -> Self {
+        Self { method, path, body }
+    }
+
+    /// The message signed by an API key, `<keyId>:<unixMillis>:<method>:<path>:<hex SHA256 of the body>`
+    fn message(&self, key_id: &str, timestamp: u64) -> String {
+        format!("{key_id}:{timestamp}:{}:{}:{}", self.method, self.path, hex::encode(Sha256::digest(self.body)))
+    }
+}
+
+/// Credentials presented by a client, parsed from an `authorization` value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Credentials {
+    /// `Bearer <token>`
+    Bearer(String),
+    /// `HMAC <keyId>:<unixMillis>:<hexSignature>` where the signature is the HMAC-SHA256 of the [`SignedRequest`] message
+    ApiKey { key_id: String, timestamp: u64, signature: Vec<u8> },
+}
+
+impl Credentials {
+    /// Builds the `authorization` value of an API key signing `request` at the current time
+    pub fn sign_api_key(key_id: &str, secret: &str, request: &SignedRequest) -> String {
+        let timestamp = unix_now();
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+        mac.update(request.message(key_id, timestamp).as_bytes());
+        format!("HMAC {key_id}:{timestamp}:{}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// Verifies the signature of API key credentials for `request` against `secret`, at time `now` (unix millis)
+    pub fn verify_api_key(
+        key_id: &str,
+        timestamp: u64,
+        signature: &[u8],
+        secret: &str,
+        request: &SignedRequest,
+        now: u64,
+    ) -> RpcResult<()> {
+        if now.abs_diff(timestamp) > API_KEY_SIGNATURE_MAX_AGE {
+            return Err(RpcError::InvalidCredentials("the API key signature has expired".to_string()));
+        }
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+        mac.update(request.message(key_id, timestamp).as_bytes());
+        mac.verify_slice(signature).map_err(|_| RpcError::InvalidCredentials("bad API key signature".to_string()))
+    }
+}
+
+impl FromStr for Credentials {
+    type Err = RpcError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || RpcError::InvalidCredentials("expecting `Bearer <token>` or `HMAC <keyId>:<unixMillis>:<signature>`".to_string());
+        let (scheme, value) = s.trim().split_once(' ').ok_or_else(invalid)?;
+        let value = value.trim();
+        if scheme.eq_ignore_ascii_case("Bearer") && !value.is_empty() {
+            return Ok(Credentials::Bearer(value.to_string()));
+        }
+        if scheme.eq_ignore_ascii_case("HMAC") {
+            // The key id may itself hold colons so the value is split from the right
+            let mut parts = value.rsplitn(3, ':');
+            let signature = parts.next().and_then(|x| hex::decode(x).ok()).ok_or_else(invalid)?;
+            let timestamp = parts.next().and_then(|x| x.parse::<u64>().ok()).ok_or_else(invalid)?;
+            let key_id = parts.next().filter(|x| !x.is_empty()).ok_or_else(invalid)?;
+            return Ok(Credentials::ApiKey { key_id: key_id.to_string(), timestamp, signature });
+        }
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credentials_parsing() {
+        assert_eq!("Bearer abc".parse::<Credentials>().unwrap(), Credentials::Bearer("abc".to_string()));
+        assert_eq!(
+            "HMAC svc:1:2:00ff".parse::<Credentials>().unwrap(),
+            Credentials::ApiKey { key_id: "svc:1".to_string(), timestamp: 2, signature: vec![0, 255] }
+        );
+        for invalid in ["", "Bearer", "Bearer ", "Basic abc", "HMAC svc:1", "HMAC :1:00", "HMAC svc:x:00", "HMAC svc:1:zz"] {
+            assert!(invalid.parse::<Credentials>().is_err(), "`{invalid}` should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_api_key_signature() {
+        let request = SignedRequest::http("POST", "/", br#"{"method":"getInfo"}"#);
+        let authorization = Credentials::sign_api_key("svc", "secret", &request);
+        let Credentials::ApiKey { key_id, timestamp, signature } = authorization.parse().unwrap() else {
+            panic!("expecting API key credentials");
+        };
+        let verify = |key_id: &str, secret: &str, request: &SignedRequest, now: u64| {
+            Credentials::verify_api_key(key_id, timestamp, &signature, secret, request, now)
+        };
+        assert!(verify(&key_id, "secret", &request, timestamp + 1000).is_ok());
+        assert!(verify(&key_id, "other secret", &request, timestamp).is_err());
+        assert!(verify("other", "secret", &request, timestamp).is_err());
+        assert!(verify(&key_id, "secret", &request, timestamp + API_KEY_SIGNATURE_MAX_AGE + 1).is_err());
+
+        // The signature holds for the signed request only
+        assert!(verify(&key_id, "secret", &SignedRequest::http("POST", "/", br#"{"method":"shutdown"}"#), timestamp).is_err());
+        assert!(verify(&key_id, "secret", &SignedRequest::http("POST", "/other", request.body), timestamp).is_err());
+        assert!(verify(&key_id, "secret", &SignedRequest::WRPC, timestamp).is_err());
+    }
+}
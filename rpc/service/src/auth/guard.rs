@@ -0,0 +1,311 @@
+use super::{
+    authorizer::{ConfigAuthorizer, DynAuthorizer, OpenAuthorizer, Principal},
+    config::{AuthConfig, RateLimit},
+    credentials::{Credentials, SignedRequest},
+    rate_limit::TokenBucket,
+};
+use kash_core::{debug, trace};
+use kash_rpc_core::{api::ops::RpcApiOps, RpcError, RpcResult};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Number of tracked IP addresses above which the idle ones get dropped
+const IP_BUCKETS_PRUNING_THRESHOLD: usize = 4096;
+
+/// Failed authentications tolerated per IP address, in bursts of 10 then one every 6 seconds,
+/// beyond which the address is refused any authentication attempt
+const AUTHENTICATION_FAILURES_LIMIT: RateLimit = RateLimit { rate: 1.0 / 6.0, burst: 10 };
+
+#[derive(Debug, Default)]
+pub struct RpcGuardCounters {
+    pub authentication_failures: AtomicU64,
+    pub denied_calls: AtomicU64,
+    pub rate_limited_calls: AtomicU64,
+}
+
+/// Gatekeeper shared by the RPC servers, authenticating clients and enforcing per-method ACLs and rate limits
+#[derive(Debug)]
+pub struct RpcGuard {
+    authorizer: DynAuthorizer,
+    per_connection: Option<RateLimit>,
+    per_ip: Option<RateLimit>,
+    ip_buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+    authentication_failures: Mutex<HashMap<IpAddr, TokenBucket>>,
+    counters: Arc<RpcGuardCounters>,
+}
+
+impl RpcGuard {
+    pub fn new(authorizer: DynAuthorizer, per_connection: Option<RateLimit>, per_ip: Option<RateLimit>) -> Self {
+        Self {
+            authorizer,
+            per_connection,
+            per_ip,
+            ip_buckets: Default::default(),
+            authentication_failures: Default::default(),
+            counters: Default::default(),
+        }
+    }
+
+    /// A guard letting every client call every method without limit
+    pub fn open() -> Self {
+        Self::new(Arc::new(OpenAuthorizer), None, None)
+    }
+
+    pub fn from_config(config: &AuthConfig) -> Result<Self, String> {
+        let authorizer = Arc::new(ConfigAuthorizer::new(config)?);
+        Ok(Self::new(authorizer, config.rate_limits.per_connection, config.rate_limits.per_ip))
+    }
+
+    pub fn counters(&self) -> Arc<RpcGuardCounters> {
+        self.counters.clone()
+    }
+
+    /// Opens the session of a new connection from `peer`, authenticating it with `credentials` if provided
+    pub fn open_session(
+        self: &Arc<Self>,
+        peer: IpAddr,
+        credentials: Option<&Credentials>,
+        request: &SignedRequest,
+    ) -> RpcResult<Session> {
+        let principal = self.authenticate(peer, credentials, request)?;
+        trace!("RPC session of {} opened as {}", peer, principal.name);
+        Ok(self.new_session(peer, Some(principal)))
+    }
+
+    /// Opens the session of a new connection expected to authenticate in-band, which is refused every call
+    /// but [`RpcApiOps::Authenticate`] until it does if anonymous clients are not allowed
+    pub fn open_pending_session(self: &Arc<Self>, peer: IpAddr) -> Session {
+        let principal = self.authorizer.authenticate(None, &SignedRequest::default()).ok();
+        trace!("RPC session of {} opened as {}", peer, principal.as_ref().map_or("unauthenticated", |x| x.name.as_str()));
+        self.new_session(peer, principal)
+    }
+
+    fn new_session(self: &Arc<Self>, peer: IpAddr, principal: Option<Principal>) -> Session {
+        Session {
+            guard: self.clone(),
+            peer,
+            principal: RwLock::new(principal),
+            bucket: self.per_connection.map(|limit| Mutex::new(TokenBucket::new(limit))),
+        }
+    }
+
+    fn authenticate(&self, peer: IpAddr, credentials: Option<&Credentials>, request: &SignedRequest) -> RpcResult<Principal> {
+        // Credentials from an address having failed too often are not even checked, bounding brute-force attempts
+        if credentials.is_some() && self.authentication_failures.lock().get_mut(&peer).map_or(false, |bucket| bucket.is_exhausted()) {
+            self.counters.rate_limited_calls.fetch_add(1, Ordering::Relaxed);
+            return Err(RpcError::RateLimited);
+        }
+        self.authorizer.authenticate(credentials, request).map_err(|err| {
+            self.counters.authentication_failures.fetch_add(1, Ordering::Relaxed);
+            if credentials.is_some() {
+                Self::acquire_token(&self.authentication_failures, peer, AUTHENTICATION_FAILURES_LIMIT);
+            }
+            err
+        })
+    }
+
+    fn acquire_ip_token(&self, peer: IpAddr) -> bool {
+        self.per_ip.map_or(true, |limit| Self::acquire_token(&self.ip_buckets, peer, limit))
+    }
+
+    fn acquire_token(buckets: &Mutex<HashMap<IpAddr, TokenBucket>>, peer: IpAddr, limit: RateLimit) -> bool {
+        let mut buckets = buckets.lock();
+        if buckets.len() >= IP_BUCKETS_PRUNING_THRESHOLD && !buckets.contains_key(&peer) {
+            buckets.retain(|_, bucket| !bucket.is_idle());
+        }
+        buckets.entry(peer).or_insert_with(|| TokenBucket::new(limit)).try_acquire()
+    }
+}
+
+impl Default for RpcGuard {
+    fn default() -> Self {
+        Self::open()
+    }
+}
+
+/// Authorization state of a single RPC connection
+#[derive(Debug)]
+pub struct Session {
+    guard: Arc<RpcGuard>,
+    peer: IpAddr,
+    principal: RwLock<Option<Principal>>,
+    bucket: Option<Mutex<TokenBucket>>,
+}
+
+impl Session {
+    /// The name of the session principal, `None` if the session is not authenticated yet
+    pub fn principal(&self) -> Option<String> {
+        self.principal.read().as_ref().map(|x| x.name.clone())
+    }
+
+    /// Replaces the principal of the session by the one identified by `credentials`, returning its name
+    pub fn authenticate(&self, credentials: &Credentials, request: &SignedRequest) -> RpcResult<String> {
+        let principal = self.guard.authenticate(self.peer, Some(credentials), request)?;
+        debug!("RPC session of {} authenticated as {}", self.peer, principal.name);
+        let name = principal.name.clone();
+        *self.principal.write() = Some(principal);
+        Ok(name)
+    }
+
+    /// Replaces the principal of the session by the one identified by `credentials`, or by the anonymous one if `None`,
+    /// keeping the rate limits of the session. The session is left unauthenticated on failure.
+    pub fn reauthenticate(&self, credentials: Option<&Credentials>, request: &SignedRequest) -> RpcResult<()> {
+        match self.guard.authenticate(self.peer, credentials, request) {
+            Ok(principal) => {
+                *self.principal.write() = Some(principal);
+                Ok(())
+            }
+            Err(err) => {
+                *self.principal.write() = None;
+                Err(err)
+            }
+        }
+    }
+
+    /// Checks that the session may call `op` now
+    pub fn check(&self, op: RpcApiOps) -> RpcResult<()> {
+        let denial = match self.principal.read().as_ref() {
+            None if op != RpcApiOps::Authenticate => Some(RpcError::Unauthenticated),
+            Some(principal) if !principal.acl.is_allowed(op) => Some(RpcError::MethodNotAllowed(format!("{op:?}"))),
+            _ => None,
+        };
+        if let Some(err) = denial {
+            self.guard.counters.denied_calls.fetch_add(1, Ordering::Relaxed);
+            return Err(err);
+        }
+        let connection_allowed = self.bucket.as_ref().map_or(true, |bucket| bucket.lock().try_acquire());
+        if !connection_allowed || !self.guard.acquire_ip_token(self.peer) {
+            self.guard.counters.rate_limited_calls.fetch_add(1, Ordering::Relaxed);
+            return Err(RpcError::RateLimited);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_session() {
+        let config: AuthConfig = serde_json::from_str(
+            r#"{
+                "roles": { "admin": { "allow": ["*"] }, "public": { "allow": ["getInfo", "ping"] } },
+                "anonymousRole": "public",
+                "bearerTokens": [{ "token": "t0k3n", "role": "admin" }],
+                "rateLimits": { "perConnection": { "rate": 0.001, "burst": 3 }, "perIp": { "rate": 0.001, "burst": 4 } }
+            }"#,
+        )
+        .unwrap();
+        let guard = Arc::new(RpcGuard::from_config(&config).unwrap());
+        let counters = guard.counters();
+        let peer = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+        let session = guard.open_session(peer, None, &SignedRequest::GRPC).unwrap();
+        assert!(matches!(session.check(RpcApiOps::Shutdown), Err(RpcError::MethodNotAllowed(_))));
+        assert!(session.check(RpcApiOps::GetInfo).is_ok());
+
+        // Upgrading the session grants the methods of the new role
+        assert!(session.authenticate(&"Bearer wrong".parse().unwrap(), &SignedRequest::WRPC).is_err());
+        assert_eq!(session.authenticate(&"Bearer t0k3n".parse().unwrap(), &SignedRequest::WRPC).unwrap(), "admin");
+        assert!(session.check(RpcApiOps::Shutdown).is_ok());
+
+        // The connection burst is exhausted
+        assert!(session.check(RpcApiOps::Ping).is_ok());
+        assert!(matches!(session.check(RpcApiOps::Ping), Err(RpcError::RateLimited)));
+
+        // A second connection from the same address is bounded by the remaining IP budget
+        let other = guard.open_session(peer, None, &SignedRequest::GRPC).unwrap();
+        assert!(other.check(RpcApiOps::Ping).is_ok());
+        assert!(matches!(other.check(RpcApiOps::Ping), Err(RpcError::RateLimited)));
+
+        assert_eq!(counters.authentication_failures.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.denied_calls.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.rate_limited_calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_pending_session() {
+        let config: AuthConfig = serde_json::from_str(
+            r#"{ "roles": { "admin": { "allow": ["*"] } }, "bearerTokens": [{ "token": "t0k3n", "role": "admin" }] }"#,
+        )
+        .unwrap();
+        let guard = Arc::new(RpcGuard::from_config(&config).unwrap());
+        let peer = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+        assert!(matches!(guard.open_session(peer, None, &SignedRequest::GRPC), Err(RpcError::Unauthenticated)));
+        let session = guard.open_pending_session(peer);
+        assert_eq!(session.principal(), None);
+        assert!(matches!(session.check(RpcApiOps::GetInfo), Err(RpcError::Unauthenticated)));
+        assert!(session.check(RpcApiOps::Authenticate).is_ok());
+        session.authenticate(&"Bearer t0k3n".parse().unwrap(), &SignedRequest::WRPC).unwrap();
+        assert!(session.check(RpcApiOps::GetInfo).is_ok());
+    }
+
+    #[test]
+    fn test_open_guard() {
+        let guard = Arc::new(RpcGuard::open());
+        let session = guard.open_session(IpAddr::V4(Ipv4Addr::LOCALHOST), None, &SignedRequest::GRPC).unwrap();
+        assert!((0..1000).all(|_| session.check(RpcApiOps::Shutdown).is_ok()));
+    }
+
+    #[test]
+    fn test_authentication_failures() {
+        let config: AuthConfig = serde_json::from_str(
+            r#"{ "roles": { "admin": { "allow": ["*"] } }, "anonymousRole": "admin", "bearerTokens": [{ "token": "t0k3n", "role": "admin" }] }"#,
+        )
+        .unwrap();
+        let guard = Arc::new(RpcGuard::from_config(&config).unwrap());
+        let peer = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let other_peer = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let wrong = "Bearer wrong".parse().unwrap();
+        let right = "Bearer t0k3n".parse().unwrap();
+
+        let session = guard.open_pending_session(peer);
+        for _ in 0..AUTHENTICATION_FAILURES_LIMIT.burst {
+            assert!(matches!(session.authenticate(&wrong, &SignedRequest::WRPC), Err(RpcError::InvalidCredentials(_))));
+        }
+
+        // The address is now refused even valid credentials, on every session
+        assert!(matches!(session.authenticate(&right, &SignedRequest::WRPC), Err(RpcError::RateLimited)));
+        assert!(matches!(guard.open_session(peer, Some(&right), &SignedRequest::GRPC), Err(RpcError::RateLimited)));
+        assert!(guard.open_session(peer, None, &SignedRequest::GRPC).is_ok());
+        assert!(guard.open_session(other_peer, Some(&right), &SignedRequest::GRPC).is_ok());
+    }
+
+    #[test]
+    fn test_reauthenticate() {
+        let config: AuthConfig = serde_json::from_str(
+            r#"{
+                "roles": { "admin": { "allow": ["*"] }, "public": { "allow": ["getInfo", "ping"] } },
+                "anonymousRole": "public",
+                "bearerTokens": [{ "name": "ops", "token": "t0k3n", "role": "admin" }],
+                "rateLimits": { "perConnection": { "rate": 0.001, "burst": 2 } }
+            }"#,
+        )
+        .unwrap();
+        let guard = Arc::new(RpcGuard::from_config(&config).unwrap());
+        let session = guard.open_pending_session(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        session.reauthenticate(Some(&"Bearer t0k3n".parse().unwrap()), &SignedRequest::default()).unwrap();
+        assert_eq!(session.principal().as_deref(), Some("ops"));
+        assert!(session.check(RpcApiOps::Shutdown).is_ok());
+
+        // Falling back to the anonymous role keeps the rate limit of the session
+        session.reauthenticate(None, &SignedRequest::default()).unwrap();
+        assert!(matches!(session.check(RpcApiOps::Shutdown), Err(RpcError::MethodNotAllowed(_))));
+        assert!(session.check(RpcApiOps::Ping).is_ok());
+        assert!(matches!(session.check(RpcApiOps::Ping), Err(RpcError::RateLimited)));
+
+        assert!(session.reauthenticate(Some(&"Bearer wrong".parse().unwrap()), &SignedRequest::default()).is_err());
+        assert_eq!(session.principal(), None);
+    }
+}
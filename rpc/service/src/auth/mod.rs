@@ -0,0 +1,12 @@
+//! Authentication, per-method access control and rate limiting of the RPC servers.
+//!
+//! Each connection opens a [`Session`](guard::Session) on the shared [`RpcGuard`](guard::RpcGuard), either anonymously
+//! or with the [`Credentials`](credentials::Credentials) it presented, and every call is then checked against the
+//! ACL of its principal and the configured rate limits.
+
+pub mod acl;
+pub mod authorizer;
+pub mod config;
+pub mod credentials;
+pub mod guard;
+pub mod rate_limit;
@@ -0,0 +1,79 @@
+use super::config::RateLimit;
+use std::time::Instant;
+
+/// Token bucket admitting calls at a sustained `rate` per second with bursts of up to `burst` calls
+#[derive(Clone, Debug)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(limit: RateLimit) -> Self {
+        Self::new_at(limit, Instant::now())
+    }
+
+    fn new_at(limit: RateLimit, now: Instant) -> Self {
+        Self { limit, tokens: limit.burst as f64, last_refill: now }
+    }
+
+    /// Takes a token if one is available
+    pub fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns true if no token is available
+    pub fn is_exhausted(&mut self) -> bool {
+        self.refill(Instant::now());
+        self.tokens < 1.0
+    }
+
+    /// Returns true if the bucket is full, in which case it holds no more state than a new one
+    pub fn is_idle(&mut self) -> bool {
+        self.refill(Instant::now());
+        self.tokens >= self.limit.burst as f64
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.rate).min(self.limit.burst as f64);
+        self.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new_at(RateLimit { rate: 2.0, burst: 3 }, start);
+
+        // The burst is available at once
+        assert!((0..3).all(|_| bucket.try_acquire_at(start)));
+        assert!(!bucket.try_acquire_at(start));
+
+        // Tokens come back at the configured rate
+        assert!(!bucket.try_acquire_at(start + Duration::from_millis(400)));
+        assert!(bucket.try_acquire_at(start + Duration::from_millis(500)));
+        assert!(!bucket.try_acquire_at(start + Duration::from_millis(600)));
+
+        // A long pause does not accumulate more than the burst
+        let later = start + Duration::from_secs(60);
+        assert!((0..3).all(|_| bucket.try_acquire_at(later)));
+        assert!(!bucket.try_acquire_at(later));
+    }
+}
@@ -14,9 +14,9 @@ use kash_math::Uint256;
 use kash_mining::model::{owner_txs::OwnerTransactions, TransactionIdSet};
 use kash_notify::converter::Converter;
 use kash_rpc_core::{
-    BlockAddedNotification, Notification, RpcAcceptedTransactionIds, RpcBlock, RpcBlockVerboseData, RpcHash, RpcMempoolEntry,
-    RpcMempoolEntryByAddress, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput, RpcTransactionOutputVerboseData,
-    RpcTransactionVerboseData,
+    BlockAddedNotification, MempoolChangedNotification, Notification, RpcAcceptedTransactionIds, RpcBlock, RpcBlockVerboseData,
+    RpcHash, RpcMempoolEntry, RpcMempoolEntryByAddress, RpcResult, RpcTransaction, RpcTransactionInput, RpcTransactionOutput,
+    RpcTransactionOutputVerboseData, RpcTransactionVerboseData,
 };
 use kash_txscript::{extract_script_pub_key_address, script_class::ScriptClass};
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
@@ -198,6 +198,18 @@ impl Converter for ConsensusConverter {
                 let block = Arc::new(self.get_block(&session, &msg.block, true, true).await.unwrap_or_else(|_| (&msg.block).into()));
                 Notification::BlockAdded(BlockAddedNotification { block })
             }
+            consensus_notify::Notification::MempoolChanged(msg) => {
+                let addresses = msg
+                    .script_public_keys
+                    .iter()
+                    .filter_map(|script_public_key| extract_script_pub_key_address(script_public_key, self.config.prefix()).ok())
+                    .collect();
+                Notification::MempoolChanged(MempoolChangedNotification {
+                    transaction_id: msg.transaction_id,
+                    reason: msg.reason.into(),
+                    addresses,
+                })
+            }
             _ => (&incoming).into(),
         }
     }
@@ -1,3 +1,5 @@
+pub mod auth;
 pub mod collector;
 pub mod converter;
+pub mod replay;
 pub mod service;
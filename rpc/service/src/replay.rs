@@ -0,0 +1,184 @@
+use kash_core::{debug, warn};
+use kash_notify::{connection::Connection, scope::VirtualChainChangedScope};
+use kash_rpc_core::{
+    api::rpc::DynRpcService,
+    notify::connection::{ChannelConnection, ChannelType},
+    GetBlockRequest, GetVirtualChainFromBlockRequest, GetVirtualChainFromBlockResponse, Notification, RpcAcceptedTransactionIds,
+    RpcError, RpcHash, RpcResult, VirtualChainChangedNotification,
+};
+use kash_utils::{channel::Channel, triggers::SingleTrigger};
+use std::{collections::HashSet, sync::Arc};
+
+/// Maximum number of added chain blocks relayed in a single VirtualChainChanged notification
+pub const VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE: usize = 1_000;
+
+/// Relays the changes of the virtual chain to a connection, starting from a given chain block.
+///
+/// The changes since the start block are replayed first. Afterwards, every live VirtualChainChanged
+/// notification triggers the relay of the changes since the last relayed sink, the cursor. Since all
+/// changes are computed from the cursor, the connection gets neither gaps nor duplicates, reorgs included.
+/// A large backlog is relayed in several notifications, see [`VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE`].
+///
+/// The replay stops when dropped, when the connection closes or when the cursor is no longer known by consensus.
+#[derive(Debug)]
+pub struct VirtualChainReplay {
+    shutdown: SingleTrigger,
+}
+
+impl VirtualChainReplay {
+    pub async fn start<C>(
+        rpc_api: DynRpcService,
+        connection: C,
+        start_hash: RpcHash,
+        include_accepted_transaction_ids: bool,
+    ) -> RpcResult<Self>
+    where
+        C: Connection<Notification = Notification>,
+    {
+        // The live subscription is registered before computing the replay so no change can be missed
+        let channel = Channel::<Notification>::default();
+        let listener_id = rpc_api.register_new_listener(ChannelConnection::new(channel.sender(), ChannelType::Closable));
+        let replay = match rpc_api.start_notify(listener_id, VirtualChainChangedScope::new(false).into()).await {
+            Ok(()) => Self::chain_changes(&rpc_api, start_hash, include_accepted_transaction_ids).await,
+            Err(err) => Err(err),
+        };
+        let replay = match replay {
+            Ok(replay) => replay,
+            Err(err) => {
+                let _ = rpc_api.unregister_listener(listener_id).await;
+                return Err(err);
+            }
+        };
+
+        let shutdown = SingleTrigger::new();
+        let shutdown_signal = shutdown.listener.clone();
+        tokio::spawn(async move {
+            debug!("[VirtualChainReplay] replaying virtual chain changes from {} to listener {}", start_hash, listener_id);
+            let mut cursor = start_hash;
+            let mut changes = Some(replay);
+            loop {
+                if let Some(changes) = changes.take() {
+                    if let Err(err) = Self::relay(&rpc_api, &connection, &mut cursor, changes).await {
+                        debug!("[VirtualChainReplay] stopping the relay to listener {}: {}", listener_id, err);
+                        break;
+                    }
+                }
+                tokio::select! {
+                    biased;
+
+                    _ = shutdown_signal.clone() => break,
+
+                    trigger = channel.recv() => {
+                        if trigger.is_err() {
+                            break;
+                        }
+                        // Pending triggers are coalesced since changes are computed from the cursor anyway
+                        while channel.try_recv().is_ok() {}
+                        match Self::chain_changes(&rpc_api, cursor, include_accepted_transaction_ids).await {
+                            Ok(chain_changes) => changes = Some(chain_changes),
+                            Err(err) => {
+                                warn!("[VirtualChainReplay] cannot get the virtual chain from {}: {}", cursor, err);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            let _ = rpc_api.unregister_listener(listener_id).await;
+            debug!("[VirtualChainReplay] replay to listener {} exited", listener_id);
+        });
+
+        Ok(Self { shutdown })
+    }
+
+    pub fn stop(&self) {
+        self.shutdown.trigger.trigger();
+    }
+
+    async fn chain_changes(
+        rpc_api: &DynRpcService,
+        start_hash: RpcHash,
+        include_accepted_transaction_ids: bool,
+    ) -> RpcResult<GetVirtualChainFromBlockResponse> {
+        rpc_api
+            .get_virtual_chain_from_block_call(GetVirtualChainFromBlockRequest::new(start_hash, include_accepted_transaction_ids))
+            .await
+    }
+
+    /// Sends `changes` to `connection` and moves the cursor to the sink they lead to.
+    ///
+    /// The added chain blocks are sent in chunks of at most [`VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE`] blocks,
+    /// the cursor following the last block of every chunk sent.
+    async fn relay<C>(
+        rpc_api: &DynRpcService,
+        connection: &C,
+        cursor: &mut RpcHash,
+        changes: GetVirtualChainFromBlockResponse,
+    ) -> RpcResult<()>
+    where
+        C: Connection<Notification = Notification>,
+    {
+        if connection.is_closed() {
+            return Err(RpcError::General("connection closed".to_string()));
+        }
+        if changes.added_chain_block_hashes.is_empty() {
+            if changes.removed_chain_block_hashes.is_empty() {
+                return Ok(());
+            }
+            // The sink moved back to an ancestor of the cursor, which is the selected parent of the lowest removed block
+            let lowest_removed = *changes.removed_chain_block_hashes.last().unwrap();
+            let block = rpc_api.get_block_call(GetBlockRequest::new(lowest_removed, false)).await?.block;
+            let next_cursor = block
+                .verbose_data
+                .ok_or(RpcError::MissingRpcFieldError("RpcBlock".to_string(), "verbose_data".to_string()))?
+                .selected_parent_hash;
+            Self::send(connection, changes.removed_chain_block_hashes, vec![], vec![]).await?;
+            *cursor = next_cursor;
+            return Ok(());
+        }
+
+        let mut removed_chain_block_hashes = changes.removed_chain_block_hashes;
+        let mut accepted_transaction_ids = changes.accepted_transaction_ids.into_iter().peekable();
+        for chunk in changes.added_chain_block_hashes.chunks(VIRTUAL_CHAIN_REPLAY_CHUNK_SIZE) {
+            if connection.is_closed() {
+                return Err(RpcError::General("connection closed".to_string()));
+            }
+            // Accepted transaction ids are ordered like the added chain blocks
+            let chunk_hashes: HashSet<RpcHash> = chunk.iter().copied().collect();
+            let mut chunk_accepted_transaction_ids = vec![];
+            while let Some(accepted) = accepted_transaction_ids.next_if(|x| chunk_hashes.contains(&x.accepting_block_hash)) {
+                chunk_accepted_transaction_ids.push(accepted);
+            }
+            Self::send(connection, std::mem::take(&mut removed_chain_block_hashes), chunk.to_vec(), chunk_accepted_transaction_ids)
+                .await?;
+            *cursor = *chunk.last().unwrap();
+        }
+        Ok(())
+    }
+
+    async fn send<C>(
+        connection: &C,
+        removed_chain_block_hashes: Vec<RpcHash>,
+        added_chain_block_hashes: Vec<RpcHash>,
+        accepted_transaction_ids: Vec<RpcAcceptedTransactionIds>,
+    ) -> RpcResult<()>
+    where
+        C: Connection<Notification = Notification>,
+    {
+        let notification = Notification::VirtualChainChanged(VirtualChainChangedNotification {
+            removed_chain_block_hashes: Arc::new(removed_chain_block_hashes),
+            added_chain_block_hashes: Arc::new(added_chain_block_hashes),
+            accepted_transaction_ids: Arc::new(accepted_transaction_ids),
+        });
+        connection
+            .send(C::into_message(&notification, &connection.encoding()))
+            .await
+            .map_err(|err| RpcError::NotificationError(err.into()))
+    }
+}
+
+impl Drop for VirtualChainReplay {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -1,17 +1,22 @@
 //! Core server implementation for ClientAPI
 
 use super::collector::{CollectorFromConsensus, CollectorFromIndex};
+use crate::auth::guard::RpcGuard;
 use crate::converter::{consensus::ConsensusConverter, index::IndexConverter, protocol::ProtocolConverter};
 use crate::service::NetworkType::{Mainnet, Testnet};
 use async_trait::async_trait;
 use kash_consensus::pipeline::ProcessingCounters;
 use kash_consensus_core::errors::block::RuleError;
 use kash_consensus_core::{
+    asset_type::AssetType,
     block::Block,
+    blockhash::BlockHashExtensions,
     coinbase::MinerData,
     config::Config,
     constants::MAX_SOMPI,
+    muhash::MuHashExtensions,
     network::NetworkType,
+    price::PriceReport,
     tx::{Transaction, COINBASE_TRANSACTION_INDEX},
 };
 use kash_consensus_notify::{
@@ -29,6 +34,7 @@ use kash_core::{
     task::tick::TickService,
     trace, warn,
 };
+use kash_database::prelude::StoreError;
 use kash_index_core::indexed_utxos::BalanceByScriptPublicKey;
 use kash_index_core::{
     connection::IndexChannelConnection, indexed_utxos::UtxoSetByScriptPublicKey, notification::Notification as IndexNotification,
@@ -36,6 +42,7 @@ use kash_index_core::{
 };
 use kash_mining::model::tx_query::TransactionQuery;
 use kash_mining::{manager::MiningManagerProxy, mempool::tx::Orphan};
+use kash_muhash::MuHash;
 use kash_notify::{
     collector::DynCollector,
     connection::ChannelType,
@@ -51,18 +58,22 @@ use kash_perf_monitor::{counters::CountersSnapshot, Monitor as PerfMonitor};
 use kash_rpc_core::{
     api::{
         ops::RPC_API_VERSION,
-        rpc::{RpcApi, MAX_SAFE_WINDOW_SIZE},
+        rpc::{
+            RpcApi, MAX_SAFE_HEADERS_LIMIT, MAX_SAFE_PRICE_HISTORY_LENGTH, MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT,
+            MAX_SAFE_UTXO_SET_LIMIT, MAX_SAFE_WINDOW_SIZE,
+        },
     },
     model::*,
     notify::connection::ChannelConnection,
     Notification, RpcError, RpcResult,
 };
+use kash_txindex::api::TxIndexProxy;
 use kash_txscript::{extract_script_pub_key_address, pay_to_address_script};
 use kash_utils::{channel::Channel, triggers::SingleTrigger};
 use kash_utils_tower::counters::TowerConnectionCounters;
 use kash_utxoindex::api::UtxoIndexProxy;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     iter::once,
     sync::{atomic::Ordering, Arc},
     vec,
@@ -92,6 +103,7 @@ pub struct RpcCoreService {
     mining_manager: MiningManagerProxy,
     flow_context: Arc<FlowContext>,
     utxoindex: Option<UtxoIndexProxy>,
+    txindex: Option<TxIndexProxy>,
     config: Arc<Config>,
     consensus_converter: Arc<ConsensusConverter>,
     index_converter: Arc<IndexConverter>,
@@ -104,6 +116,7 @@ pub struct RpcCoreService {
     perf_monitor: Arc<PerfMonitor<Arc<TickService>>>,
     p2p_tower_counters: Arc<TowerConnectionCounters>,
     grpc_tower_counters: Arc<TowerConnectionCounters>,
+    guard: Arc<RpcGuard>,
 }
 
 const RPC_CORE: &str = "rpc-core";
@@ -117,6 +130,7 @@ impl RpcCoreService {
         mining_manager: MiningManagerProxy,
         flow_context: Arc<FlowContext>,
         utxoindex: Option<UtxoIndexProxy>,
+        txindex: Option<TxIndexProxy>,
         config: Arc<Config>,
         core: Arc<Core>,
         processing_counters: Arc<ProcessingCounters>,
@@ -125,6 +139,7 @@ impl RpcCoreService {
         perf_monitor: Arc<PerfMonitor<Arc<TickService>>>,
         p2p_tower_counters: Arc<TowerConnectionCounters>,
         grpc_tower_counters: Arc<TowerConnectionCounters>,
+        guard: Arc<RpcGuard>,
     ) -> Self {
         // Prepare consensus-notify objects
         let consensus_notify_channel = Channel::<ConsensusNotification>::default();
@@ -177,6 +192,7 @@ impl RpcCoreService {
             mining_manager,
             flow_context,
             utxoindex,
+            txindex,
             config,
             consensus_converter,
             index_converter,
@@ -189,6 +205,7 @@ impl RpcCoreService {
             perf_monitor,
             p2p_tower_counters,
             grpc_tower_counters,
+            guard,
         }
     }
 
@@ -207,6 +224,12 @@ impl RpcCoreService {
         self.notifier.clone()
     }
 
+    /// The guard that the RPC servers check their connections and calls against
+    #[inline(always)]
+    pub fn guard(&self) -> Arc<RpcGuard> {
+        self.guard.clone()
+    }
+
     async fn get_utxo_set_by_script_public_key<'a>(
         &self,
         addresses: impl Iterator<Item = &'a RpcAddress>,
@@ -320,7 +343,8 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         // Build block template
         let script_public_key = kash_txscript::pay_to_address_script(&request.pay_address);
         let extra_data = version().as_bytes().iter().chain(once(&(b'/'))).chain(&request.extra_data).cloned().collect::<Vec<_>>();
-        let miner_data: MinerData = MinerData::new(script_public_key, extra_data);
+        let price_report = request.price_report.as_ref().map(PriceReport::try_from).transpose()?;
+        let miner_data: MinerData = MinerData::new(script_public_key, extra_data).with_price_report(price_report);
         let session = self.consensus_manager.consensus().unguarded_session();
         let block_template = self.mining_manager.clone().get_block_template(&session, miner_data).await?;
 
@@ -400,6 +424,18 @@ NOTE: This error usually indicates an RPC conversion error between the node and
 
     async fn get_info_call(&self, _request: GetInfoRequest) -> RpcResult<GetInfoResponse> {
         let is_nearly_synced = self.consensus_manager.consensus().unguarded_session().async_is_nearly_synced().await;
+        let standard_policy = self.mining_manager.standard_policy();
+        let mempool_asset_policies = AssetType::ALL
+            .into_iter()
+            .map(|asset_type| {
+                let asset_policy = standard_policy.asset_policy(asset_type);
+                RpcMempoolAssetPolicy::new(
+                    asset_type,
+                    asset_policy.minimum_relay_transaction_fee,
+                    asset_policy.dust_relay_transaction_fee,
+                )
+            })
+            .collect();
         Ok(GetInfoResponse {
             p2p_id: self.flow_context.node_id.to_string(),
             mempool_size: self.mining_manager.clone().transaction_count(TransactionQuery::TransactionsOnly).await as u64,
@@ -408,6 +444,9 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             is_synced: self.has_sufficient_peer_connectivity() && is_nearly_synced,
             has_notify_command: true,
             has_message_id: true,
+            mempool_asset_policies,
+            maximum_mint_transactions_per_block: standard_policy.maximum_mint_transactions_per_block,
+            maximum_redeem_transactions_per_block: standard_policy.maximum_redeem_transactions_per_block,
         })
     }
 
@@ -519,7 +558,14 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         }
         // TODO: discuss if the entry order is part of the method requirements
         //       (the current impl does not retain an entry order matching the request addresses order)
-        let entry_map = self.get_utxo_set_by_script_public_key(request.addresses.iter()).await;
+        let mut entry_map = self.get_utxo_set_by_script_public_key(request.addresses.iter()).await;
+        if request.asset_type.is_some() || request.min_amount > 0 {
+            entry_map.values_mut().for_each(|utxos| {
+                utxos.retain(|_, entry| {
+                    request.asset_type.map_or(true, |asset_type| entry.asset_type == asset_type) && entry.amount >= request.min_amount
+                })
+            });
+        }
         Ok(GetUtxosByAddressesResponse::new(self.index_converter.get_utxos_by_addresses_entries(&entry_map)))
     }
 
@@ -528,7 +574,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             return Err(RpcError::NoUtxoIndex);
         }
         let entry_map = self.get_balance_by_script_public_key(once(&request.address)).await;
-        let balance = entry_map.values().sum();
+        let balance = entry_map.values().next().copied().unwrap_or_default();
         Ok(GetBalanceByAddressResponse::new(balance))
     }
 
@@ -545,24 +591,40 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             .iter()
             .map(|address| {
                 let script_public_key = pay_to_address_script(address);
-                let balance = entry_map.get(&script_public_key).copied();
-                RpcBalancesByAddressesEntry { address: address.to_owned(), balance }
+                let asset_balance = entry_map.get(&script_public_key).copied();
+                RpcBalancesByAddressesEntry {
+                    address: address.to_owned(),
+                    balance: asset_balance.map(|balance| balance.total()),
+                    asset_balance,
+                }
             })
             .collect();
         Ok(GetBalancesByAddressesResponse::new(entries))
     }
 
-    async fn get_coin_supply_call(&self, _: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse> {
+    async fn get_coin_supply_call(&self, request: GetCoinSupplyRequest) -> RpcResult<GetCoinSupplyResponse> {
         if !self.config.utxoindex {
             return Err(RpcError::NoUtxoIndex);
         }
-        let circulating_sompi =
-            self.utxoindex.clone().unwrap().get_circulating_supply().await.map_err(|e| RpcError::General(e.to_string()))?;
+        let utxoindex = self.utxoindex.clone().unwrap();
+        if request.audit {
+            if !self.config.unsafe_rpc {
+                warn!("GetCoinSupply RPC command called with audit while node in safe RPC mode -- ignoring.");
+                return Err(RpcError::UnavailableInSafeMode);
+            }
+            utxoindex.clone().audit_circulating_supply().await.map_err(|e| RpcError::General(e.to_string()))?;
+        }
+        let daa_score = request.daa_score.unwrap_or(u64::MAX);
+        let snapshot = utxoindex.get_circulating_supply_at(daa_score).await.map_err(|e| match e {
+            StoreError::KeyNotFound(_) => RpcError::NoCoinSupplyAtDaaScore(daa_score),
+            e => RpcError::General(e.to_string()),
+        })?;
         Ok(GetCoinSupplyResponse::new(
             MAX_SOMPI,
-            circulating_sompi.ksh_supply,
-            circulating_sompi.kusd_supply,
-            circulating_sompi.krv_supply,
+            snapshot.supply.ksh_supply,
+            snapshot.supply.kusd_supply,
+            snapshot.supply.krv_supply,
+            snapshot.daa_score,
         ))
     }
 
@@ -624,12 +686,223 @@ NOTE: This error usually indicates an RPC conversion error between the node and
         Ok(GetDaaScoreTimestampEstimateResponse::new(timestamps))
     }
 
+    async fn get_price_call(&self, request: GetPriceRequest) -> RpcResult<GetPriceResponse> {
+        if !self.config.unsafe_rpc && request.history_length > MAX_SAFE_PRICE_HISTORY_LENGTH {
+            return Err(RpcError::PriceHistoryLengthExceedingMaximum(request.history_length, MAX_SAFE_PRICE_HISTORY_LENGTH));
+        }
+        let session = self.consensus_manager.consensus().session().await;
+        let price = session.async_get_virtual_reference_price().await;
+        let history = session.async_get_reference_price_history(request.history_length as usize).await;
+        Ok(GetPriceResponse::new(price, history.into_iter().map(RpcBlockPrice::from).collect()))
+    }
+
+    async fn get_transaction_call(&self, request: GetTransactionRequest) -> RpcResult<GetTransactionResponse> {
+        if !self.config.txindex {
+            return Err(RpcError::NoTxIndex);
+        }
+        let acceptance =
+            self.txindex.clone().unwrap().get_transaction_acceptance(request.transaction_id).await.map_err(|e| match e {
+                StoreError::KeyNotFound(_) => RpcError::TransactionNotFound(request.transaction_id),
+                e => RpcError::General(e.to_string()),
+            })?;
+        let session = self.consensus_manager.consensus().session().await;
+        // The body of the including block may have been pruned since the transaction was indexed
+        let block = session
+            .async_get_block(acceptance.including_block_hash)
+            .await
+            .map_err(|_| RpcError::TransactionNotFound(request.transaction_id))?;
+        let transaction = block
+            .transactions
+            .get(acceptance.index_within_block as usize)
+            .ok_or(RpcError::TransactionNotFound(request.transaction_id))?;
+        let sink_blue_score = session.async_get_header(session.async_get_sink().await).await?.blue_score;
+        Ok(GetTransactionResponse::new(
+            self.consensus_converter.get_transaction(&session, transaction, Some(&block.header), true),
+            acceptance.including_block_hash,
+            acceptance.accepting_block_hash,
+            acceptance.accepting_block_blue_score,
+            sink_blue_score.saturating_sub(acceptance.accepting_block_blue_score) + 1,
+        ))
+    }
+
+    async fn get_transactions_by_address_call(
+        &self,
+        request: GetTransactionsByAddressRequest,
+    ) -> RpcResult<GetTransactionsByAddressResponse> {
+        if !self.config.txindex {
+            return Err(RpcError::NoTxIndex);
+        }
+        if !self.config.unsafe_rpc && request.limit > MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT {
+            return Err(RpcError::TransactionsLimitExceedingMaximum(request.limit, MAX_SAFE_TRANSACTIONS_BY_ADDRESS_LIMIT));
+        }
+        let address_transactions = self
+            .txindex
+            .clone()
+            .unwrap()
+            .get_transactions_by_script_public_key(
+                pay_to_address_script(&request.address),
+                request.start_blue_score,
+                request.start_transaction_id,
+                request.limit as usize,
+            )
+            .await
+            .map_err(|e| RpcError::General(e.to_string()))?;
+        let entries = address_transactions
+            .into_iter()
+            .map(|x| RpcAddressTransaction {
+                transaction_id: x.transaction_id,
+                accepting_block_hash: x.accepting_block_hash,
+                accepting_block_blue_score: x.accepting_block_blue_score,
+            })
+            .collect();
+        Ok(GetTransactionsByAddressResponse::new(entries))
+    }
+
+    async fn get_utxo_set_call(&self, request: GetUtxoSetRequest) -> RpcResult<GetUtxoSetResponse> {
+        if !self.config.unsafe_rpc && request.limit > MAX_SAFE_UTXO_SET_LIMIT {
+            return Err(RpcError::UtxoSetLimitExceedingMaximum(request.limit, MAX_SAFE_UTXO_SET_LIMIT));
+        }
+        let mut multiset = match request.multiset {
+            Some(bytes) => MuHash::deserialize(bytes.try_into().map_err(|_| RpcError::InvalidUtxoSetMultiset)?)
+                .map_err(|_| RpcError::InvalidUtxoSetMultiset)?,
+            None => MuHash::new(),
+        };
+
+        // Scanning resumes right after the last outpoint of the previous chunk
+        let skip_first = request.from_outpoint.is_some();
+        let chunk_size = request.limit as usize;
+        let session = self.consensus_manager.consensus().unguarded_session();
+        // The serialization of the entries in the multiset depends on the DAA score of the UTXO set point of view
+        let (utxos, pruning_point_hash, utxo_commitment, daa_score) = match request.source {
+            RpcUtxoSetSource::Virtual => {
+                // Chunks are pinned to the virtual UTXO set of the first one through its commitment, so that an export
+                // never mixes states
+                let (utxos, daa_score, utxo_commitment) =
+                    session.async_get_virtual_utxos_with_commitment(request.from_outpoint, chunk_size, skip_first).await;
+                if skip_first {
+                    let expected = request.utxo_commitment.ok_or_else(|| {
+                        RpcError::MissingRpcFieldError("GetUtxoSetRequest".to_string(), "utxo_commitment".to_string())
+                    })?;
+                    if expected != utxo_commitment {
+                        return Err(RpcError::VirtualUtxoSetChanged);
+                    }
+                }
+                (utxos, None, Some(utxo_commitment), daa_score)
+            }
+            RpcUtxoSetSource::PruningPoint => {
+                let pruning_point = match request.pruning_point_hash {
+                    Some(hash) => hash,
+                    None => session.async_pruning_point().await,
+                };
+                let utxos =
+                    session.async_get_pruning_point_utxos(pruning_point, request.from_outpoint, chunk_size, skip_first).await?;
+                let header = session.async_get_header(pruning_point).await?;
+                (utxos, Some(pruning_point), Some(header.utxo_commitment), header.daa_score)
+            }
+        };
+        let commit_asset_type = self.config.is_asset_commitment_active(daa_score);
+
+        let last_outpoint = if utxos.len() < chunk_size { None } else { utxos.last().map(|(outpoint, _)| *outpoint) };
+        let entries = utxos
+            .into_iter()
+            .filter(|(_, entry)| request.asset_type.map_or(true, |asset_type| entry.asset_type == asset_type))
+            .map(|(outpoint, utxo_entry)| {
+                multiset.add_utxo(&outpoint, &utxo_entry, commit_asset_type);
+                let address = extract_script_pub_key_address(&utxo_entry.script_public_key, self.config.prefix()).ok();
+                RpcUtxosByAddressesEntry { address, outpoint, utxo_entry }
+            })
+            .collect();
+        let multiset_hash = multiset.finalize();
+        Ok(GetUtxoSetResponse::new(
+            entries,
+            pruning_point_hash,
+            last_outpoint,
+            multiset.serialize().to_vec(),
+            multiset_hash,
+            utxo_commitment,
+        ))
+    }
+
     async fn ping_call(&self, _: PingRequest) -> RpcResult<PingResponse> {
         Ok(PingResponse {})
     }
 
-    async fn get_headers_call(&self, _request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
-        Err(RpcError::NotImplemented)
+    async fn get_headers_call(&self, request: GetHeadersRequest) -> RpcResult<GetHeadersResponse> {
+        if !self.config.unsafe_rpc && request.limit > MAX_SAFE_HEADERS_LIMIT {
+            return Err(RpcError::HeadersLimitExceedingMaximum(request.limit, MAX_SAFE_HEADERS_LIMIT));
+        }
+        // A zero limit requests the server default page size
+        let limit = if request.limit == 0 { MAX_SAFE_HEADERS_LIMIT } else { request.limit } as usize;
+        let session = self.consensus_manager.consensus().session().await;
+
+        let hashes = if request.is_ascending {
+            // Blocks merged by the selected chain from the start block up to the sink, in consensus order.
+            // The hashes are fetched with mergeset granularity, hence the lower bound on the number of blocks.
+            let sink = session.async_get_sink().await;
+            let max_blocks = limit.max(self.config.mergeset_size_limit as usize + 1);
+            let (mut hashes, highest_reached) = session.async_get_hashes_between(request.start_hash, sink, max_blocks).await?;
+            if hashes.len() > limit {
+                // Each chain block directly follows its own mergeset, so the page is cut right after the last chain block
+                // fitting within the limit. The page then ends on a chain block, which excludes all of its content from the
+                // next page. The mergeset of the first chain block is kept whole even if it exceeds the limit, so that paging
+                // always makes progress.
+                let page = hashes.iter().copied().collect::<HashSet<_>>();
+                let mut chain_blocks = HashSet::new();
+                let mut current = highest_reached;
+                while page.contains(&current) {
+                    chain_blocks.insert(current);
+                    current = session.async_get_ghostdag_data(current).await?.selected_parent;
+                }
+                let chain_block_positions = hashes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, hash)| chain_blocks.contains(hash))
+                    .map(|(position, _)| position)
+                    .collect::<Vec<_>>();
+                let page_end = chain_block_positions
+                    .iter()
+                    .copied()
+                    .take_while(|&position| position < limit)
+                    .last()
+                    .or(chain_block_positions.first().copied())
+                    .map_or(hashes.len(), |position| position + 1);
+                hashes.truncate(page_end);
+            }
+            hashes
+        } else {
+            // Blocks merged by the selected chain of the start block, in reverse consensus order down to genesis or, unless
+            // the node is archival, the pruning point, below which block data is pruned. The page is cut right after a chain
+            // block, so the next page resumes with the mergeset of that chain block. As for ascending pages, the first mergeset
+            // is kept whole even if it exceeds the limit, so that paging always makes progress.
+            let lowest = if self.config.is_archival { None } else { Some(session.async_pruning_point().await) };
+            let mut hashes = Vec::with_capacity(limit);
+            let mut current = request.start_hash;
+            while Some(current) != lowest {
+                let ghostdag_data = session.async_get_ghostdag_data(current).await?;
+                if ghostdag_data.selected_parent.is_origin() {
+                    break;
+                }
+                let mut merged = Vec::with_capacity(ghostdag_data.mergeset_blues.len() + ghostdag_data.mergeset_reds.len());
+                for &hash in ghostdag_data.mergeset_blues.iter().skip(1).chain(ghostdag_data.mergeset_reds.iter()) {
+                    merged.push((session.async_get_header(hash).await?.blue_work, hash));
+                }
+                if !hashes.is_empty() && hashes.len() + merged.len() + 1 > limit {
+                    break;
+                }
+                // Consensus order sorts the mergeset by blue work and then by hash
+                merged.sort_unstable_by(|a, b| b.cmp(a));
+                hashes.extend(merged.into_iter().map(|(_, hash)| hash));
+                hashes.push(ghostdag_data.selected_parent);
+                current = ghostdag_data.selected_parent;
+            }
+            hashes
+        };
+
+        let mut headers = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            headers.push((*session.async_get_header(hash).await?).clone());
+        }
+        Ok(GetHeadersResponse::new(headers))
     }
 
     async fn get_block_dag_info_call(&self, _: GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse> {
@@ -755,13 +1028,15 @@ NOTE: This error usually indicates an RPC conversion error between the node and
 
     async fn resolve_finality_conflict_call(
         &self,
-        _request: ResolveFinalityConflictRequest,
+        request: ResolveFinalityConflictRequest,
     ) -> RpcResult<ResolveFinalityConflictResponse> {
         if !self.config.unsafe_rpc {
             warn!("ResolveFinalityConflict RPC command called while node in safe RPC mode -- ignoring.");
             return Err(RpcError::UnavailableInSafeMode);
         }
-        Err(RpcError::NotImplemented)
+        let session = self.consensus_manager.consensus().session().await;
+        session.async_resolve_finality_conflict(request.finality_block_hash).await?;
+        Ok(ResolveFinalityConflictResponse {})
     }
 
     async fn get_metrics_call(&self, req: GetMetricsRequest) -> RpcResult<GetMetricsResponse> {
@@ -789,6 +1064,7 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             disk_io_write_per_sec: disk_io_write_per_sec as f32,
         });
 
+        let guard_counters = self.guard.counters();
         let connection_metrics = req.connection_metrics.then_some(ConnectionMetrics {
             borsh_live_connections: self.wrpc_borsh_counters.active_connections.load(Ordering::Relaxed) as u32,
             borsh_connection_attempts: self.wrpc_borsh_counters.total_connections.load(Ordering::Relaxed) as u64,
@@ -797,6 +1073,10 @@ NOTE: This error usually indicates an RPC conversion error between the node and
             json_connection_attempts: self.wrpc_json_counters.total_connections.load(Ordering::Relaxed) as u64,
             json_handshake_failures: self.wrpc_json_counters.handshake_failures.load(Ordering::Relaxed) as u64,
 
+            rpc_authentication_failures: guard_counters.authentication_failures.load(Ordering::Relaxed),
+            rpc_denied_calls: guard_counters.denied_calls.load(Ordering::Relaxed),
+            rpc_rate_limited_calls: guard_counters.rate_limited_calls.load(Ordering::Relaxed),
+
             active_peers: self.flow_context.hub().active_peers_len() as u32,
         });
 
@@ -62,6 +62,7 @@ impl Inner {
             RpcApiOps::VirtualDaaScoreChangedNotification,
             RpcApiOps::PruningPointUtxoSetOverrideNotification,
             RpcApiOps::NewBlockTemplateNotification,
+            RpcApiOps::MempoolChangedNotification,
         ]
         .into_iter()
         .for_each(|notification_op| {
@@ -277,6 +278,20 @@ impl KashRpcClient {
         Ok(self.inner.rpc_client.shutdown().await?)
     }
 
+    /// Authenticates the connection with an `authorization` value (`Bearer <token>` or
+    /// `HMAC <keyId>:<unixMillis>:<hexSignature>` signing the `WRPC:authenticate` request with an empty body),
+    /// returning the granted principal. API key signatures are accepted only once.
+    /// The credentials must be presented again after a reconnection.
+    pub async fn authenticate(&self, authorization: String) -> RpcResult<String> {
+        let response: AuthenticateResponse = self
+            .inner
+            .rpc_client
+            .call(RpcApiOps::Authenticate, AuthenticateRequest::new(authorization))
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(response.principal)
+    }
+
     /// A helper function that is not `async`, allowing connection
     /// process to be initiated from non-async contexts.
     pub fn connect_as_task(&self) -> Result<()> {
@@ -397,6 +412,10 @@ impl RpcApi for KashRpcClient {
             GetCoinSupply,
             GetConnectedPeerInfo,
             GetDaaScoreTimestampEstimate,
+            GetPrice,
+            GetTransaction,
+            GetTransactionsByAddress,
+            GetUtxoSet,
             GetServerInfo,
             GetCurrentNetwork,
             GetHeaders,
@@ -3,11 +3,13 @@ use crate::imports::*;
 use crate::result::Result;
 use js_sys::Array;
 use kash_addresses::{Address, AddressList};
+use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::network::{wasm::Network, NetworkType};
 use kash_consensus_wasm::{SignableTransaction, Transaction};
 use kash_notify::notification::Notification as NotificationT;
 pub use kash_rpc_macros::{build_wrpc_wasm_bindgen_interface, build_wrpc_wasm_bindgen_subscriptions};
 pub use serde_wasm_bindgen::from_value;
+use std::str::FromStr;
 pub use workflow_wasm::serde::to_value;
 
 struct NotificationSink(Function);
@@ -211,14 +213,18 @@ impl RpcClient {
     }
 
     /// Subscription to UTXOs Changed notifications
+    ///
+    /// Notifications can optionally be restricted to UTXOs of `assetType`
+    /// holding at least `minAmount` sompi.
     #[wasm_bindgen(js_name = subscribeUtxosChanged)]
-    pub async fn subscribe_utxos_changed(&self, addresses: &JsValue) -> Result<()> {
+    pub async fn subscribe_utxos_changed(&self, addresses: &JsValue, asset_type: Option<u32>, min_amount: Option<u64>) -> Result<()> {
         let addresses = Array::from(addresses)
             .to_vec()
             .into_iter()
             .map(|jsv| from_value(jsv).map_err(|err| JsError::new(&err.to_string())))
             .collect::<std::result::Result<Vec<Address>, JsError>>()?;
-        self.client.start_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+        let scope = UtxosChangedScope::with_filter(addresses, asset_type.map(AssetType::from), min_amount.unwrap_or_default());
+        self.client.start_notify(ListenerId::default(), Scope::UtxosChanged(scope)).await?;
         Ok(())
     }
 
@@ -230,18 +236,27 @@ impl RpcClient {
             .into_iter()
             .map(|jsv| from_value(jsv).map_err(|err| JsError::new(&err.to_string())))
             .collect::<std::result::Result<Vec<Address>, JsError>>()?;
-        self.client.stop_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+        self.client.stop_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope::new(addresses))).await?;
         Ok(())
     }
 
     // scope variant with field functions
 
+    /// Subscription to VirtualChainChanged notifications
+    ///
+    /// If `startHash` is provided, the changes of the virtual chain since this
+    /// chain block are replayed before live notifications are relayed.
     #[wasm_bindgen(js_name = subscribeVirtualChainChanged)]
-    pub async fn subscribe_virtual_chain_changed(&self, include_accepted_transaction_ids: bool) -> Result<()> {
+    pub async fn subscribe_virtual_chain_changed(
+        &self,
+        include_accepted_transaction_ids: bool,
+        start_hash: Option<String>,
+    ) -> Result<()> {
+        let start_hash = start_hash.map(|hash| RpcHash::from_str(&hash)).transpose().map_err(|err| Error::custom(err.to_string()))?;
         self.client
             .start_notify(
                 ListenerId::default(),
-                Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }),
+                Scope::VirtualChainChanged(VirtualChainChangedScope::with_start_hash(include_accepted_transaction_ids, start_hash)),
             )
             .await?;
         Ok(())
@@ -251,7 +266,7 @@ impl RpcClient {
         self.client
             .stop_notify(
                 ListenerId::default(),
-                Scope::VirtualChainChanged(VirtualChainChangedScope { include_accepted_transaction_ids }),
+                Scope::VirtualChainChanged(VirtualChainChangedScope::new(include_accepted_transaction_ids)),
             )
             .await?;
         Ok(())
@@ -259,12 +274,12 @@ impl RpcClient {
 
     // #[wasm_bindgen(js_name = subscribeUtxosChanged)]
     // pub async fn subscribe_utxos_changed(&self, addresses: Vec<Address>) -> JsResult<()> {
-    //     self.client.start_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+    //     self.client.start_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope::new(addresses))).await?;
     //     Ok(())
     // }
     // #[wasm_bindgen(js_name = unsubscribeUtxosChanged)]
     // pub async fn unsubscribe_utxos_changed(&self, addresses: Vec<Address>) -> JsResult<()> {
-    //     self.client.stop_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope { addresses })).await?;
+    //     self.client.stop_notify(ListenerId::default(), Scope::UtxosChanged(UtxosChangedScope::new(addresses))).await?;
     //     Ok(())
     // }
 }
@@ -280,6 +295,7 @@ build_wrpc_wasm_bindgen_subscriptions!([
     VirtualDaaScoreChanged,
     PruningPointUtxoSetOverride,
     NewBlockTemplate,
+    MempoolChanged,
 ]);
 
 // Build RPC method invocation functions. This macro
@@ -292,7 +308,6 @@ build_wrpc_wasm_bindgen_interface!(
         // functions with no arguments
         GetBlockCount,
         GetBlockDagInfo,
-        GetCoinSupply,
         GetConnectedPeerInfo,
         GetInfo,
         GetPeerAddresses,
@@ -314,12 +329,17 @@ build_wrpc_wasm_bindgen_interface!(
         GetBlock,
         GetBlocks,
         GetBlockTemplate,
+        GetCoinSupply,
         GetDaaScoreTimestampEstimate,
         GetCurrentNetwork,
         GetHeaders,
         GetMempoolEntries,
         GetMempoolEntriesByAddresses,
         GetMempoolEntry,
+        GetPrice,
+        GetTransaction,
+        GetTransactionsByAddress,
+        GetUtxoSet,
         GetSubnetwork,
         // GetUtxosByAddresses,
         GetVirtualChainFromBlock,
@@ -355,7 +375,7 @@ impl RpcClient {
     #[wasm_bindgen(js_name = getUtxosByAddresses)]
     pub async fn get_utxos_by_addresses(&self, request: JsValue) -> Result<JsValue> {
         let request = if let Ok(addresses) = AddressList::try_from(&request) {
-            GetUtxosByAddressesRequest { addresses: addresses.into() }
+            GetUtxosByAddressesRequest::new(addresses.into(), None, 0)
         } else {
             from_value::<GetUtxosByAddressesRequest>(request)?
         };
@@ -7,6 +7,7 @@ use kash_notify::{
     notifier::Notify,
 };
 use kash_rpc_core::{api::ops::RpcApiOps, notify::mode::NotificationMode, Notification};
+use kash_rpc_service::{auth::guard::Session, replay::VirtualChainReplay};
 use std::{
     fmt::Debug,
     sync::{Arc, Mutex},
@@ -50,6 +51,8 @@ struct ConnectionInner {
     pub grpc_client: Option<Arc<GrpcClient>>,
     // not using an atomic in case an Id will change type in the future...
     pub listener_id: Mutex<Option<ListenerId>>,
+    pub virtual_chain_replay: Mutex<Option<VirtualChainReplay>>,
+    pub session: Session,
 }
 
 impl ConnectionInner {
@@ -76,12 +79,28 @@ pub struct Connection {
 }
 
 impl Connection {
-    pub fn new(id: u64, peer: &SocketAddr, messenger: Arc<Messenger>, grpc_client: Option<Arc<GrpcClient>>) -> Connection {
+    pub fn new(
+        id: u64,
+        peer: &SocketAddr,
+        messenger: Arc<Messenger>,
+        grpc_client: Option<Arc<GrpcClient>>,
+        session: Session,
+    ) -> Connection {
         // If a GrpcClient is provided, it has to come configured in direct mode
         assert!(grpc_client.is_none() || grpc_client.as_ref().unwrap().notification_mode() == NotificationMode::Direct);
         // Should a gRPC client be provided, no listener_id is required for subscriptions so the listener id is set to default
         let listener_id = Mutex::new(grpc_client.clone().map(|_| ListenerId::default()));
-        Connection { inner: Arc::new(ConnectionInner { id, peer: *peer, messenger, grpc_client, listener_id }) }
+        Connection {
+            inner: Arc::new(ConnectionInner {
+                id,
+                peer: *peer,
+                messenger,
+                grpc_client,
+                listener_id,
+                virtual_chain_replay: Mutex::new(None),
+                session,
+            }),
+        }
     }
 
     /// Obtain the connection id
@@ -114,6 +133,16 @@ impl Connection {
         self.inner.listener_id.lock().unwrap().replace(listener_id);
     }
 
+    /// Sets the replay of the virtual chain changes relayed to this connection, stopping the previous one if any.
+    pub fn set_virtual_chain_replay(&self, replay: Option<VirtualChainReplay>) {
+        *self.inner.virtual_chain_replay.lock().unwrap() = replay;
+    }
+
+    /// The authorization state of the connection
+    pub fn session(&self) -> &Session {
+        &self.inner.session
+    }
+
     pub fn peer(&self) -> &SocketAddr {
         &self.inner.peer
     }
@@ -45,6 +45,10 @@ impl Router {
                 GetCoinSupply,
                 GetConnectedPeerInfo,
                 GetDaaScoreTimestampEstimate,
+                GetPrice,
+                GetTransaction,
+                GetTransactionsByAddress,
+                GetUtxoSet,
                 GetServerInfo,
                 GetCurrentNetwork,
                 GetHeaders,
@@ -74,6 +78,7 @@ impl Router {
             RpcApiOps::Subscribe,
             workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, scope: Scope| {
                 Box::pin(async move {
+                    manager.authorize(&connection, RpcApiOps::subscription((&scope).into())).map_err(|err| err.to_string())?;
                     manager.start_notify(&connection, scope).await.map_err(|err| err.to_string())?;
                     Ok(SubscribeResponse::new(connection.id()))
                 })
@@ -92,6 +97,16 @@ impl Router {
             }),
         );
 
+        interface.method(
+            RpcApiOps::Authenticate,
+            workflow_rpc::server::Method::new(move |manager: Server, connection: Connection, request: AuthenticateRequest| {
+                Box::pin(async move {
+                    let principal = manager.authenticate(&connection, &request.authorization).map_err(|err| err.to_string())?;
+                    Ok(AuthenticateResponse::new(principal))
+                })
+            }),
+        );
+
         Router { interface: Arc::new(interface), server_context }
     }
 }
@@ -5,13 +5,29 @@ use crate::{
     service::Options,
 };
 use kash_grpc_client::GrpcClient;
-use kash_notify::{connection::ChannelType, events::EVENT_TYPE_ARRAY, notifier::Notifier, scope::Scope, subscriber::Subscriber};
+use kash_notify::{
+    connection::ChannelType,
+    events::EVENT_TYPE_ARRAY,
+    notifier::Notifier,
+    scope::{Scope, VirtualChainChangedScope},
+    subscriber::Subscriber,
+};
 use kash_rpc_core::{
-    api::rpc::{DynRpcService, RpcApi},
+    api::{
+        ops::RpcApiOps,
+        rpc::{DynRpcService, RpcApi},
+    },
     notify::{channel::NotificationChannel, connection::ChannelConnection, mode::NotificationMode},
-    Notification, RpcResult,
+    Notification, RpcError, RpcResult,
+};
+use kash_rpc_service::{
+    auth::{
+        credentials::{Credentials, SignedRequest},
+        guard::RpcGuard,
+    },
+    replay::VirtualChainReplay,
+    service::RpcCoreService,
 };
-use kash_rpc_service::service::RpcCoreService;
 use std::{
     collections::HashMap,
     sync::{
@@ -35,6 +51,7 @@ struct ServerInner {
     pub sockets: Mutex<HashMap<u64, Connection>>,
     pub rpc_core: Option<RpcCore>,
     pub options: Arc<Options>,
+    pub guard: Arc<RpcGuard>,
 }
 
 #[derive(Clone)]
@@ -53,6 +70,9 @@ impl Server {
             "invalid setup: Server must exclusively get either a core service or a gRPC server address"
         );
 
+        // The proxy leaves the authorization to the gRPC server it relays the calls to
+        let guard = core_service.as_ref().map(|service| service.guard()).unwrap_or_default();
+
         let rpc_core = if let Some(service) = core_service {
             // Prepare rpc service objects
             let notification_channel = NotificationChannel::default();
@@ -77,6 +97,7 @@ impl Server {
                 sockets: Mutex::new(HashMap::new()),
                 rpc_core,
                 options,
+                guard,
             }),
         }
     }
@@ -114,7 +135,8 @@ impl Server {
 
             None
         };
-        let connection = Connection::new(id, peer, messenger, grpc_client);
+        let session = self.inner.guard.open_pending_session(peer.ip());
+        let connection = Connection::new(id, peer, messenger, grpc_client, session);
         if self.inner.options.grpc_proxy_address.is_some() {
             // log_trace!("starting gRPC");
             connection.grpc_client().start(Some(connection.grpc_client_notify_target())).await;
@@ -127,6 +149,7 @@ impl Server {
     pub async fn disconnect(&self, connection: Connection) {
         // log_info!("WebSocket disconnected: {}", connection.peer());
         if let Some(rpc_core) = &self.inner.rpc_core {
+            connection.set_virtual_chain_replay(None);
             if let Some(listener_id) = connection.listener_id() {
                 rpc_core.wrpc_notifier.unregister_listener(listener_id).unwrap_or_else(|err| {
                     log_error!("WebSocket {} (disconnected) error unregistering the notification listener: {err}", connection.peer());
@@ -156,6 +179,26 @@ impl Server {
         }
     }
 
+    /// Checks that `connection` may call `op` now
+    pub fn authorize(&self, connection: &Connection, op: RpcApiOps) -> RpcResult<()> {
+        connection.session().check(op)
+    }
+
+    /// Authenticates `connection` with the credentials of an `authorization` string, returning the principal name
+    pub fn authenticate(&self, connection: &Connection, authorization: &str) -> RpcResult<String> {
+        // The proxy only holds an open guard and its gRPC client connects with no credentials,
+        // so accepting these would give the client no more than the identity of the proxy
+        if self.inner.options.grpc_proxy_address.is_some() {
+            return Err(RpcError::General(
+                "the wRPC proxy does not relay credentials, authenticate against the node instead".to_string(),
+            ));
+        }
+        // Authentication attempts are charged to the rate limits like any other call
+        self.authorize(connection, RpcApiOps::Authenticate)?;
+        let credentials = authorization.parse::<Credentials>()?;
+        connection.session().authenticate(&credentials, &SignedRequest::WRPC)
+    }
+
     pub async fn start_notify(&self, connection: &Connection, scope: Scope) -> RpcResult<()> {
         let listener_id = if let Some(listener_id) = connection.listener_id() {
             listener_id
@@ -171,6 +214,22 @@ impl Server {
         };
         workflow_log::log_trace!("notification subscribe[0x{listener_id:x}] {scope:?}");
         if let Some(rpc_core) = &self.inner.rpc_core {
+            if let Scope::VirtualChainChanged(ref virtual_chain_changed_scope) = scope {
+                connection.set_virtual_chain_replay(None);
+                if let Some(start_hash) = virtual_chain_changed_scope.start_hash {
+                    // A replay is served by the connection itself in place of the regular subscription
+                    rpc_core.wrpc_notifier.clone().try_stop_notify(listener_id, VirtualChainChangedScope::default().into())?;
+                    let replay = VirtualChainReplay::start(
+                        rpc_core.service.clone(),
+                        connection.clone(),
+                        start_hash,
+                        virtual_chain_changed_scope.include_accepted_transaction_ids,
+                    )
+                    .await?;
+                    connection.set_virtual_chain_replay(Some(replay));
+                    return Ok(());
+                }
+            }
             rpc_core.wrpc_notifier.clone().try_start_notify(listener_id, scope)?;
         } else {
             connection.grpc_client().start_notify(listener_id, scope).await?;
@@ -182,6 +241,9 @@ impl Server {
         if let Some(listener_id) = connection.listener_id() {
             workflow_log::log_trace!("notification unsubscribe[0x{listener_id:x}] {scope:?}");
             if let Some(rpc_core) = &self.inner.rpc_core {
+                if let Scope::VirtualChainChanged(_) = scope {
+                    connection.set_virtual_chain_replay(None);
+                }
                 rpc_core.wrpc_notifier.clone().try_stop_notify(listener_id, scope)?;
             } else {
                 connection.grpc_client().stop_notify(listener_id, scope).await?;
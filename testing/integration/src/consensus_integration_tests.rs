@@ -35,6 +35,7 @@ use kash_consensus_core::tx::{
     ScriptPublicKey, Transaction, TransactionAction, TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry,
 };
 use kash_consensus_core::{blockhash, hashing, BlockHashMap, BlueWorkType};
+use kash_consensus_notify::notification::Notification;
 use kash_consensus_notify::root::ConsensusNotificationRoot;
 use kash_consensus_notify::service::NotifyService;
 use kash_consensusmanager::ConsensusManager;
@@ -56,6 +57,8 @@ use kash_database::prelude::{CachePolicy, ConnBuilder};
 use kash_index_processor::service::IndexService;
 use kash_math::Uint256;
 use kash_muhash::MuHash;
+use kash_notify::scope::{FinalityConflictResolvedScope, FinalityConflictScope};
+use kash_notify::subscriber::SubscriptionManager;
 use kash_txscript::caches::TxScriptCacheCounters;
 use kash_utxoindex::api::{UtxoIndexApi, UtxoIndexProxy};
 use kash_utxoindex::UtxoIndex;
@@ -834,9 +837,17 @@ impl KashdGoParams {
             max_block_mass: self.MaxBlockMass,
             storage_mass_parameter: STORAGE_MASS_PARAMETER,
             storage_mass_activation_daa_score: u64::MAX,
+            asset_commitment_activation_daa_score: u64::MAX,
+            price_oracle_activation_daa_score: u64::MAX,
+            price_oracle_public_keys: MAINNET_PARAMS.price_oracle_public_keys,
+            max_price_report_age: MAINNET_PARAMS.max_price_report_age,
+            conversion_rate_tolerance_bps: MAINNET_PARAMS.conversion_rate_tolerance_bps,
             deflationary_phase_daa_score: self.DeflationaryPhaseDaaScore,
             pre_deflationary_phase_base_subsidy: self.PreDeflationaryPhaseBaseSubsidy,
             coinbase_maturity: MAINNET_PARAMS.coinbase_maturity,
+            krv_unbonding_maturity: MAINNET_PARAMS.krv_unbonding_maturity,
+            krv_unbonding_activation_daa_score: u64::MAX,
+            introspection_activation_daa_score: u64::MAX,
             skip_proof_of_work: self.SkipProofOfWork,
             max_block_level: self.MaxBlockLevel,
             pruning_proof_m: self.PruningProofM,
@@ -952,7 +963,7 @@ async fn json_test(file_path: &str, concurrency: bool) {
     let (_utxoindex_db_lifetime, utxoindex_db) = create_temp_db!(ConnBuilder::default().with_files_limit(10));
     let consensus_manager = Arc::new(ConsensusManager::new(Arc::new(TestConsensusFactory::new(tc.clone()))));
     let utxoindex = UtxoIndex::new(consensus_manager.clone(), utxoindex_db).unwrap();
-    let index_service = Arc::new(IndexService::new(&notify_service.notifier(), Some(UtxoIndexProxy::new(utxoindex.clone()))));
+    let index_service = Arc::new(IndexService::new(&notify_service.notifier(), Some(UtxoIndexProxy::new(utxoindex.clone())), None));
 
     let async_runtime = Arc::new(AsyncRuntime::new(2));
     async_runtime.register(tick_service.clone());
@@ -1329,6 +1340,101 @@ async fn bounded_merge_depth_test() {
     consensus.shutdown(wait_handles);
 }
 
+#[tokio::test]
+async fn finality_conflict_test() {
+    init_allocator_with_default_settings();
+    let config = ConfigBuilder::new(MAINNET_PARAMS)
+        .skip_proof_of_work()
+        .edit_consensus_params(|p| {
+            p.min_difficulty_window_len = p.legacy_difficulty_window_size;
+            p.finality_depth = 10;
+        })
+        .build();
+
+    let (notification_send, notification_recv) = unbounded();
+    let consensus = TestConsensus::with_notifier(&config, notification_send);
+    let notification_root = consensus.notification_root();
+    notification_root.start_notify(Default::default(), FinalityConflictScope {}.into()).await.unwrap();
+    notification_root.start_notify(Default::default(), FinalityConflictResolvedScope {}.into()).await.unwrap();
+    let wait_handles = consensus.init();
+
+    // Build a selected chain long enough for the virtual finality point to be above genesis
+    let mut selected_chain = vec![config.genesis.hash];
+    for i in 1..=config.params.finality_depth + 5 {
+        let hash: Hash = i.into();
+        consensus.add_utxo_valid_block_with_parents(hash, vec![*selected_chain.last().unwrap()], vec![]).await.unwrap();
+        selected_chain.push(hash);
+    }
+    let finality_point = consensus.finality_point();
+    assert_ne!(finality_point, config.genesis.hash);
+
+    // Build a heavier chain forking from genesis. It is not in the future of the finality point, so it never becomes
+    // the sink, yet each of its tips which is heavier than the sink is reported as a finality conflict
+    let mut conflicting_chain = vec![config.genesis.hash];
+    for i in 1..=selected_chain.len() as u64 + 2 {
+        let hash: Hash = (i + 1000).into();
+        consensus.add_utxo_valid_block_with_parents(hash, vec![*conflicting_chain.last().unwrap()], vec![]).await.unwrap();
+        conflicting_chain.push(hash);
+    }
+    assert_eq!(consensus.get_sink(), *selected_chain.last().unwrap());
+    let reported = std::iter::from_fn(|| notification_recv.try_recv().ok())
+        .map(|notification| match notification {
+            Notification::FinalityConflict(notification) => notification.violating_block_hash,
+            notification => panic!("Unexpected notification: {notification:?}"),
+        })
+        .collect_vec();
+    assert!(reported.len() >= 3);
+    assert_eq!(reported.last(), conflicting_chain.last());
+    assert!(reported.iter().all(|hash| conflicting_chain.contains(hash)));
+    assert_eq!(reported.iter().collect::<HashSet<_>>().len(), reported.len(), "each tip should be reported once");
+
+    // Merging the selected chain into the conflicting chain creates a block which has the finality point
+    // in its past but not on its selected chain, hence a finality conflict
+    let violating_block: Hash = 2000.into();
+    consensus
+        .add_utxo_valid_block_with_parents(
+            violating_block,
+            vec![*conflicting_chain.last().unwrap(), *selected_chain.last().unwrap()],
+            vec![],
+        )
+        .await
+        .unwrap();
+    assert!(!consensus.is_chain_ancestor_of(finality_point, violating_block).unwrap());
+    assert_eq!(consensus.get_sink(), *selected_chain.last().unwrap());
+    match notification_recv.try_recv().unwrap() {
+        Notification::FinalityConflict(notification) => assert_eq!(notification.violating_block_hash, violating_block),
+        notification => panic!("Unexpected notification: {notification:?}"),
+    }
+
+    // Extending the selected chain does not report the same conflict again
+    let hash: Hash = 3000.into();
+    consensus.add_utxo_valid_block_with_parents(hash, vec![*selected_chain.last().unwrap()], vec![]).await.unwrap();
+    selected_chain.push(hash);
+    assert_eq!(consensus.get_sink(), hash);
+    assert!(notification_recv.is_empty());
+
+    // Unknown blocks cannot serve as the finality block
+    assert!(consensus.resolve_finality_conflict(4000.into()).is_err());
+    assert_eq!(consensus.get_sink(), hash);
+
+    // The operator picks the violating block as the finality block, which makes virtual reorg to the conflicting chain
+    consensus.resolve_finality_conflict(violating_block).unwrap();
+    assert_eq!(consensus.get_sink(), violating_block);
+    assert_eq!(consensus.finality_point(), violating_block);
+    match notification_recv.try_recv().unwrap() {
+        Notification::FinalityConflictResolved(notification) => assert_eq!(notification.finality_block_hash, violating_block),
+        notification => panic!("Unexpected notification: {notification:?}"),
+    }
+
+    // The selected chain now continues above the chosen finality block
+    let hash: Hash = 5000.into();
+    consensus.add_utxo_valid_block_with_parents(hash, vec![violating_block], vec![]).await.unwrap();
+    assert_eq!(consensus.get_sink(), hash);
+    assert!(notification_recv.is_empty());
+
+    consensus.shutdown(wait_handles);
+}
+
 #[tokio::test]
 async fn difficulty_test() {
     init_allocator_with_default_settings();
@@ -2,12 +2,14 @@ use kash_addresses::Address;
 use kash_alloc::init_allocator_with_default_settings;
 use kash_consensusmanager::ConsensusManager;
 use kash_core::task::runtime::AsyncRuntime;
-use kash_notify::scope::{Scope, VirtualDaaScoreChangedScope};
-use kash_rpc_core::{api::rpc::RpcApi, Notification};
+use kash_grpc_client::GrpcClient;
+use kash_hashes::Hash;
+use kash_notify::scope::{Scope, VirtualChainChangedScope, VirtualDaaScoreChangedScope};
+use kash_rpc_core::{api::rpc::RpcApi, GetHeadersRequest, GetUtxoSetRequest, Notification, RpcUtxoSetSource};
 use kashd_lib::args::Args;
 
 use crate::common::{client_notify::ChannelNotify, daemon::Daemon};
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn daemon_sanity_test() {
@@ -75,7 +77,7 @@ async fn daemon_mining_test() {
             Err(elapsed) => panic!("expected virtual event before {}", elapsed),
         } {
             match notification {
-                Notification::VirtualDaaScoreChanged(msg) if msg.virtual_daa_score == i + 1 => {
+                Notification::VirtualDaaScoreChanged(msg) if msg.virtual_daa_score == daa_score + i + 1 => {
                     break;
                 }
                 Notification::VirtualDaaScoreChanged(msg) if msg.virtual_daa_score > i + 1 => {
@@ -103,6 +105,219 @@ async fn daemon_mining_test() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_headers_paging_test() {
+    init_allocator_with_default_settings();
+    kash_core::log::try_init_logger("INFO");
+
+    let args = Args {
+        simnet: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut kashd = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client = kashd.start().await;
+
+    // Mine a chain of 10 blocks
+    let chain = mine_chain(&rpc_client, Address::new(kashd.network.into(), kash_addresses::Version::PubKey, &[0; 32]), 10).await;
+
+    // Ascending pages, each resuming from the last header of the previous one, cover the chain once and in order
+    let genesis_hash = kash_consensus::params::SIMNET_GENESIS.hash;
+    let mut ascending = vec![];
+    let mut start_hash = genesis_hash;
+    loop {
+        let headers = rpc_client.get_headers_call(GetHeadersRequest::new(start_hash, 3, true)).await.unwrap().headers;
+        let Some(last) = headers.last() else {
+            break;
+        };
+        assert!(headers.len() <= 3);
+        start_hash = last.hash;
+        ascending.extend(headers.into_iter().map(|header| header.hash));
+    }
+    assert_eq!(ascending, chain);
+
+    // A zero limit requests the server default page size
+    let headers = rpc_client.get_headers_call(GetHeadersRequest::new(genesis_hash, 0, true)).await.unwrap().headers;
+    assert_eq!(headers.into_iter().map(|header| header.hash).collect::<Vec<_>>(), chain);
+
+    // Descending pages walk the same blocks back down to genesis
+    let mut descending = vec![];
+    let mut start_hash = *chain.last().unwrap();
+    loop {
+        let headers = rpc_client.get_headers_call(GetHeadersRequest::new(start_hash, 4, false)).await.unwrap().headers;
+        let Some(last) = headers.last() else {
+            break;
+        };
+        assert!(headers.len() <= 4);
+        start_hash = last.hash;
+        descending.extend(headers.into_iter().map(|header| header.hash));
+    }
+    let expected = std::iter::once(genesis_hash).chain(chain.iter().copied()).rev().skip(1).collect::<Vec<_>>();
+    assert_eq!(descending, expected);
+
+    rpc_client.disconnect().await.unwrap();
+    drop(rpc_client);
+    kashd.shutdown();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_utxo_set_paging_test() {
+    init_allocator_with_default_settings();
+    kash_core::log::try_init_logger("INFO");
+
+    let args = Args {
+        simnet: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut kashd = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client = kashd.start().await;
+
+    // Fill the virtual UTXO set with the coinbase outputs of a chain of 10 blocks
+    mine_chain(&rpc_client, Address::new(kashd.network.into(), kash_addresses::Version::PubKey, &[0; 32]), 10).await;
+
+    let full = rpc_client
+        .get_utxo_set_call(GetUtxoSetRequest::new(RpcUtxoSetSource::Virtual, None, None, 1_000, None, None, None))
+        .await
+        .unwrap();
+    assert!(full.last_outpoint.is_none());
+    assert!(full.entries.len() > 3);
+    assert_eq!(Some(full.multiset_hash), full.utxo_commitment);
+
+    // Pages chained through the (last outpoint, multiset, UTXO commitment) cursor combine into the full set
+    let mut paged = vec![];
+    let (mut from_outpoint, mut multiset, mut utxo_commitment) = (None, None, None);
+    let multiset_hash = loop {
+        let request = GetUtxoSetRequest::new(RpcUtxoSetSource::Virtual, None, from_outpoint, 3, None, multiset, utxo_commitment);
+        let page = rpc_client.get_utxo_set_call(request).await.unwrap();
+        assert!(page.entries.len() <= 3);
+        paged.extend(page.entries.into_iter().map(|entry| entry.outpoint));
+        (from_outpoint, multiset, utxo_commitment) = (page.last_outpoint, Some(page.multiset), page.utxo_commitment);
+        if from_outpoint.is_none() {
+            assert_eq!(Some(page.multiset_hash), utxo_commitment);
+            break page.multiset_hash;
+        }
+    };
+    assert_eq!(paged, full.entries.iter().map(|entry| entry.outpoint).collect::<Vec<_>>());
+    assert_eq!(paged.iter().collect::<HashSet<_>>().len(), paged.len());
+    assert_eq!(multiset_hash, full.multiset_hash);
+
+    // Resuming requires the UTXO commitment, and fails once a block changed the virtual UTXO set
+    let first_page = rpc_client
+        .get_utxo_set_call(GetUtxoSetRequest::new(RpcUtxoSetSource::Virtual, None, None, 3, None, None, None))
+        .await
+        .unwrap();
+    let resume = |utxo_commitment| {
+        GetUtxoSetRequest::new(
+            RpcUtxoSetSource::Virtual,
+            None,
+            first_page.last_outpoint,
+            3,
+            None,
+            Some(first_page.multiset.clone()),
+            utxo_commitment,
+        )
+    };
+    assert!(rpc_client.get_utxo_set_call(resume(None)).await.is_err());
+    let mining_client = kashd.new_client().await;
+    mine_chain(&mining_client, Address::new(kashd.network.into(), kash_addresses::Version::PubKey, &[0; 32]), 1).await;
+    assert!(rpc_client.get_utxo_set_call(resume(first_page.utxo_commitment)).await.is_err());
+    mining_client.disconnect().await.unwrap();
+
+    rpc_client.disconnect().await.unwrap();
+    drop(rpc_client);
+    kashd.shutdown();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn daemon_virtual_chain_replay_test() {
+    init_allocator_with_default_settings();
+    kash_core::log::try_init_logger("INFO");
+
+    let args = Args {
+        simnet: true,
+        unsafe_rpc: true,
+        enable_unsynced_mining: true,
+        disable_upnp: true, // UPnP registration might take some time and is not needed for this test
+        ..Default::default()
+    };
+    let total_fd_limit = 10;
+    let mut kashd = Daemon::new_random_with_args(args, total_fd_limit);
+    let rpc_client = kashd.start().await;
+    let pay_address = Address::new(kashd.network.into(), kash_addresses::Version::PubKey, &[0; 32]);
+
+    // Mine a first chain of 5 blocks, then subscribe to a replay starting from its second block
+    let mut chain = mine_chain(&rpc_client, pay_address.clone(), 5).await;
+    let replay_client = kashd.new_client().await;
+    let (sender, event_receiver) = async_channel::unbounded();
+    replay_client.start(Some(Arc::new(ChannelNotify::new(sender)))).await;
+    let scope = Scope::VirtualChainChanged(VirtualChainChangedScope::with_start_hash(false, Some(chain[1])));
+    replay_client.start_notify(Default::default(), scope).await.unwrap();
+
+    // Live changes follow the replayed ones
+    let mining_client = kashd.new_client().await;
+    chain.extend(mine_chain(&mining_client, pay_address, 5).await);
+
+    // The replayed and live changes together add the chain above the start block exactly once
+    let mut added = vec![];
+    while added.len() < chain.len() - 2 {
+        let notification = tokio::time::timeout(Duration::from_secs(5), event_receiver.recv())
+            .await
+            .expect("the replay should relay all the virtual chain changes")
+            .unwrap();
+        let Notification::VirtualChainChanged(notification) = notification else {
+            panic!("expected only virtual chain changed notifications");
+        };
+        assert!(notification.removed_chain_block_hashes.is_empty());
+        added.extend(notification.added_chain_block_hashes.iter().copied());
+    }
+    assert_eq!(added, chain[2..]);
+
+    mining_client.disconnect().await.unwrap();
+    drop(mining_client);
+    replay_client.disconnect().await.unwrap();
+    drop(replay_client);
+    rpc_client.disconnect().await.unwrap();
+    drop(rpc_client);
+    kashd.shutdown();
+}
+
+/// Mines a chain of `count` blocks paying to `pay_address`, waiting for each one to be processed by the virtual.
+/// Returns the hashes of the mined blocks.
+async fn mine_chain(rpc_client: &GrpcClient, pay_address: Address, count: u64) -> Vec<Hash> {
+    let (sender, event_receiver) = async_channel::unbounded();
+    rpc_client.start(Some(Arc::new(ChannelNotify::new(sender)))).await;
+    rpc_client.start_notify(Default::default(), Scope::VirtualDaaScoreChanged(VirtualDaaScoreChangedScope {})).await.unwrap();
+
+    let daa_score = rpc_client.get_block_dag_info().await.unwrap().virtual_daa_score;
+    let mut chain = vec![];
+    for i in 0..count {
+        let template = rpc_client.get_block_template(pay_address.clone(), vec![]).await.unwrap();
+        chain.push(template.block.header.hash);
+        rpc_client.submit_block(template.block, false).await.unwrap();
+
+        while let Ok(notification) = match tokio::time::timeout(Duration::from_secs(1), event_receiver.recv()).await {
+            Ok(res) => res,
+            Err(elapsed) => panic!("expected virtual event before {}", elapsed),
+        } {
+            match notification {
+                Notification::VirtualDaaScoreChanged(msg) if msg.virtual_daa_score == daa_score + i + 1 => {
+                    break;
+                }
+                Notification::VirtualDaaScoreChanged(_) => {}
+                _ => panic!("expected only DAA score notifications"),
+            }
+        }
+    }
+    chain
+}
+
 // The following test runtime parameters are required for a graceful shutdown of the gRPC server
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn daemon_cleaning_test() {
@@ -5,14 +5,14 @@ use futures_util::future::try_join_all;
 use kash_addresses::{Address, Prefix, Version};
 use kash_consensus::params::SIMNET_GENESIS;
 use kash_consensus_core::tx::TransactionAction;
-use kash_consensus_core::{constants::MAX_SOMPI, subnets::SubnetworkId, tx::Transaction};
+use kash_consensus_core::{asset_type::AssetType, constants::MAX_SOMPI, subnets::SubnetworkId, tx::Transaction};
 use kash_core::info;
 use kash_grpc_core::ops::KashdPayloadOps;
 use kash_hashes::Hash;
 use kash_notify::{
     connection::{ChannelConnection, ChannelType},
     scope::{
-        BlockAddedScope, FinalityConflictScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope,
+        BlockAddedScope, FinalityConflictScope, MempoolChangedScope, NewBlockTemplateScope, PruningPointUtxoSetOverrideScope, Scope,
         SinkBlueScoreChangedScope, UtxosChangedScope, VirtualChainChangedScope, VirtualDaaScoreChangedScope,
     },
 };
@@ -49,6 +49,7 @@ async fn sanity_test() {
         enable_unsynced_mining: true,
         block_template_cache_lifetime: Some(0),
         utxoindex: true,
+        txindex: true,
         unsafe_rpc: true,
         ..Default::default()
     };
@@ -103,6 +104,7 @@ async fn sanity_test() {
                         .get_block_template_call(GetBlockTemplateRequest {
                             pay_address: Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]),
                             extra_data: Vec::new(),
+                            price_report: None,
                         })
                         .await
                         .unwrap();
@@ -198,6 +200,11 @@ async fn sanity_test() {
                     assert!(response.is_utxo_indexed);
                     assert!(response.has_message_id);
                     assert!(response.has_notify_command);
+                    assert_eq!(
+                        response.mempool_asset_policies.iter().map(|policy| policy.asset_type).collect::<Vec<_>>(),
+                        AssetType::ALL.to_vec(),
+                        "a mempool policy should be reported for every asset"
+                    );
                 })
             }
 
@@ -337,7 +344,7 @@ async fn sanity_test() {
                         })
                         .await;
 
-                    // Err because it's currently unimplemented
+                    // Err because the finality block is unknown
                     assert!(response_result.is_err());
                 })
             }
@@ -345,12 +352,20 @@ async fn sanity_test() {
             KashdPayloadOps::GetHeaders => {
                 let rpc_client = client.clone();
                 tst!(op, {
-                    let response_result = rpc_client
+                    // Blocks are submitted concurrently by other tests, so only the page bounds are checked here.
+                    // Paging over a known chain is covered by `daemon_headers_paging_test`.
+                    let response = rpc_client
                         .get_headers_call(GetHeadersRequest { start_hash: SIMNET_GENESIS.hash, limit: 1, is_ascending: true })
-                        .await;
+                        .await
+                        .unwrap();
+                    assert!(response.headers.iter().all(|header| header.hash != SIMNET_GENESIS.hash));
 
-                    // Err because it's currently unimplemented
-                    assert!(response_result.is_err());
+                    // Nothing precedes genesis
+                    let response = rpc_client
+                        .get_headers_call(GetHeadersRequest { start_hash: SIMNET_GENESIS.hash, limit: 1, is_ascending: false })
+                        .await
+                        .unwrap();
+                    assert!(response.headers.is_empty());
                 })
             }
 
@@ -358,7 +373,16 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 tst!(op, {
                     let addresses = vec![Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32])];
-                    let response = rpc_client.get_utxos_by_addresses_call(GetUtxosByAddressesRequest { addresses }).await.unwrap();
+                    let response = rpc_client
+                        .get_utxos_by_addresses_call(GetUtxosByAddressesRequest::new(addresses.clone(), None, 0))
+                        .await
+                        .unwrap();
+                    assert!(response.entries.is_empty());
+
+                    let response = rpc_client
+                        .get_utxos_by_addresses_call(GetUtxosByAddressesRequest::new(addresses, Some(AssetType::KUSD), 1))
+                        .await
+                        .unwrap();
                     assert!(response.entries.is_empty());
                 })
             }
@@ -373,6 +397,7 @@ async fn sanity_test() {
                         .await
                         .unwrap();
                     assert_eq!(response.balance, 0);
+                    assert_eq!(response.asset_balance, Some(RpcAssetBalance::default()));
                 })
             }
 
@@ -387,6 +412,7 @@ async fn sanity_test() {
                     assert_eq!(response.entries.len(), 1);
                     assert_eq!(response.entries[0].address, addresses[0]);
                     assert_eq!(response.entries[0].balance, Some(0));
+                    assert_eq!(response.entries[0].asset_balance, Some(RpcAssetBalance::default()));
 
                     let response =
                         rpc_client.get_balances_by_addresses_call(GetBalancesByAddressesRequest::new(vec![])).await.unwrap();
@@ -439,11 +465,19 @@ async fn sanity_test() {
             KashdPayloadOps::GetCoinSupply => {
                 let rpc_client = client.clone();
                 tst!(op, {
-                    let response = rpc_client.get_coin_supply_call(GetCoinSupplyRequest {}).await.unwrap();
+                    let response =
+                        rpc_client.get_coin_supply_call(GetCoinSupplyRequest { daa_score: None, audit: true }).await.unwrap();
                     assert_eq!(response.ksh_circulating_sompi, 0);
                     assert_eq!(response.kusd_circulating_sompi, 0);
                     assert_eq!(response.krv_circulating_sompi, 0);
                     assert_eq!(response.max_sompi, MAX_SOMPI);
+
+                    let historical_response = rpc_client
+                        .get_coin_supply_call(GetCoinSupplyRequest { daa_score: Some(response.daa_score), audit: false })
+                        .await
+                        .unwrap();
+                    assert_eq!(historical_response.daa_score, response.daa_score);
+                    assert_eq!(historical_response.ksh_circulating_sompi, 0);
                 })
             }
 
@@ -548,6 +582,55 @@ async fn sanity_test() {
                 })
             }
 
+            KashdPayloadOps::GetPrice => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    // No price report was ever mined in this test
+                    let response = rpc_client.get_price_call(GetPriceRequest { history_length: 10 }).await.unwrap();
+                    assert_eq!(response.price, None);
+                    assert!(response.history.is_empty());
+                })
+            }
+
+            KashdPayloadOps::GetTransaction => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response_result = rpc_client.get_transaction_call(GetTransactionRequest { transaction_id: 0.into() }).await;
+                    // No transaction with this id was ever accepted
+                    assert!(response_result.is_err());
+                })
+            }
+
+            KashdPayloadOps::GetTransactionsByAddress => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client
+                        .get_transactions_by_address_call(GetTransactionsByAddressRequest {
+                            address: Address::new(Prefix::Simnet, Version::PubKey, &[0u8; 32]),
+                            start_blue_score: 0,
+                            start_transaction_id: None,
+                            limit: 10,
+                        })
+                        .await
+                        .unwrap();
+                    assert!(response.entries.is_empty());
+                })
+            }
+
+            KashdPayloadOps::GetUtxoSet => {
+                let rpc_client = client.clone();
+                tst!(op, {
+                    let response = rpc_client
+                        .get_utxo_set_call(GetUtxoSetRequest::new(RpcUtxoSetSource::PruningPoint, None, None, 10, None, None, None))
+                        .await
+                        .unwrap();
+                    // The pruning point of a fresh node is genesis, whose UTXO set is empty
+                    assert!(response.entries.is_empty());
+                    assert!(response.last_outpoint.is_none());
+                    assert_eq!(response.utxo_commitment, Some(response.multiset_hash));
+                })
+            }
+
             KashdPayloadOps::NotifyBlockAdded => {
                 let rpc_client = client.clone();
                 let id = listener_id;
@@ -575,7 +658,7 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 let id = listener_id;
                 tst!(op, {
-                    rpc_client.start_notify(id, UtxosChangedScope { addresses: vec![] }.into()).await.unwrap();
+                    rpc_client.start_notify(id, UtxosChangedScope::new(vec![]).into()).await.unwrap();
                 })
             }
             KashdPayloadOps::NotifySinkBlueScoreChanged => {
@@ -603,17 +686,21 @@ async fn sanity_test() {
                 let rpc_client = client.clone();
                 let id = listener_id;
                 tst!(op, {
-                    rpc_client
-                        .start_notify(id, VirtualChainChangedScope { include_accepted_transaction_ids: false }.into())
-                        .await
-                        .unwrap();
+                    rpc_client.start_notify(id, VirtualChainChangedScope::new(false).into()).await.unwrap();
+                })
+            }
+            KashdPayloadOps::NotifyMempoolChanged => {
+                let rpc_client = client.clone();
+                let id = listener_id;
+                tst!(op, {
+                    rpc_client.start_notify(id, MempoolChangedScope {}.into()).await.unwrap();
                 })
             }
             KashdPayloadOps::StopNotifyingUtxosChanged => {
                 let rpc_client = client.clone();
                 let id = listener_id;
                 tst!(op, {
-                    rpc_client.stop_notify(id, UtxosChangedScope { addresses: vec![] }.into()).await.unwrap();
+                    rpc_client.stop_notify(id, UtxosChangedScope::new(vec![]).into()).await.unwrap();
                 })
             }
             KashdPayloadOps::StopNotifyingPruningPointUtxoSetOverride => {
@@ -18,7 +18,7 @@ use crate::storage::account::AccountSettings;
 use crate::storage::AccountMetadata;
 use crate::storage::{PrvKeyData, PrvKeyDataId};
 use crate::tx::PaymentOutput;
-use crate::tx::{Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Signer};
+use crate::tx::{Fees, Generator, GeneratorSettings, GeneratorSummary, PaymentDestination, PendingTransaction, Pskt, Signer};
 use crate::utxo::balance::{AtomicBalance, BalanceStrings};
 use crate::utxo::UtxoContextBinding;
 use kash_bip32::{ChildNumber, ExtendedPrivateKey, PrivateKey, PrivateKeyBytes};
@@ -196,9 +196,7 @@ pub trait Account: AnySync + Send + Sync + 'static {
         self.utxo_context().clear().await?;
 
         let current_daa_score = self.wallet().current_daa_score().ok_or(Error::NotConnected)?;
-        let ksh_balance = Arc::new(AtomicBalance::default());
-        let kusd_balance = Arc::new(AtomicBalance::default());
-        let krv_balance = Arc::new(AtomicBalance::default());
+        let balance = Arc::new(AtomicBalance::default());
 
         match self.clone().as_derivation_capable() {
             Ok(account) => {
@@ -212,18 +210,14 @@ pub trait Account: AnySync + Send + Sync + 'static {
                 let scans = vec![
                     Scan::new_with_address_manager(
                         derivation.receive_address_manager(),
-                        &ksh_balance,
-                        &kusd_balance,
-                        &krv_balance,
+                        &balance,
                         current_daa_score,
                         window_size,
                         Some(extent),
                     ),
                     Scan::new_with_address_manager(
                         derivation.change_address_manager(),
-                        &ksh_balance,
-                        &kusd_balance,
-                        &krv_balance,
+                        &balance,
                         current_daa_score,
                         window_size,
                         Some(extent),
@@ -239,7 +233,7 @@ pub trait Account: AnySync + Send + Sync + 'static {
                 address_set.insert(self.receive_address()?);
                 address_set.insert(self.change_address()?);
 
-                let scan = Scan::new_with_address_set(address_set, &ksh_balance, &kusd_balance, &krv_balance, current_daa_score);
+                let scan = Scan::new_with_address_set(address_set, &balance, current_daa_score);
                 scan.scan(self.utxo_context()).await?;
             }
         }
@@ -642,6 +636,64 @@ pub trait DerivationCapableAccount: Account {
         Ok(address)
     }
 
+    /// Creates an unsigned [`Pskt`] sending funds to `destination`, to be signed by the
+    /// holders of the account keys. The outgoing amount must fit in a single transaction.
+    async fn create_unsigned_transaction(
+        self: Arc<Self>,
+        asset_type: AssetType,
+        destination: PaymentDestination,
+        priority_fee_sompi: Fees,
+        payload: Option<Vec<u8>>,
+        abortable: &Abortable,
+    ) -> Result<Pskt> {
+        let tx_action = match asset_type {
+            AssetType::KSH => TransactionAction::TransferKSH,
+            AssetType::KUSD => TransactionAction::TransferKUSD,
+            AssetType::KRV => TransactionAction::TransferKRV,
+        };
+
+        let settings =
+            GeneratorSettings::try_new_with_account(self.clone().as_dyn_arc(), tx_action, destination, priority_fee_sompi, payload)?;
+
+        let generator = Generator::try_new(settings, None, Some(abortable))?;
+
+        let mut stream = generator.stream();
+        let transaction = stream.try_next().await?.ok_or(Error::PsktRequiresSingleTransaction)?;
+        if stream.try_next().await?.is_some() {
+            return Err(Error::PsktRequiresSingleTransaction);
+        }
+
+        Pskt::try_from_pending_transaction(&transaction, &self.as_derivation_capable()?)
+    }
+
+    /// Signs the inputs of `pskt` with the private keys of this account stored in the wallet,
+    /// returning the number of added signatures.
+    async fn sign_pskt(&self, pskt: &mut Pskt, wallet_secret: Secret, payment_secret: Option<Secret>) -> Result<usize> {
+        let receive = pskt
+            .inputs
+            .iter()
+            .filter(|input| !input.derivation.change)
+            .map(|input| (&input.address, input.derivation.index))
+            .collect::<Vec<_>>();
+        let change = pskt
+            .inputs
+            .iter()
+            .filter(|input| input.derivation.change)
+            .map(|input| (&input.address, input.derivation.index))
+            .collect::<Vec<_>>();
+
+        let store = self.wallet().store().as_prv_key_data_store()?;
+        let mut private_keys = vec![];
+        for prv_key_data_id in &self.descriptor()?.prv_key_data_ids {
+            let keydata =
+                store.load_key_data(&wallet_secret, &prv_key_data_id).await?.ok_or(Error::PrivateKeyNotFound(prv_key_data_id))?;
+            let keys = self.create_private_keys(&keydata, &payment_secret, &receive, &change)?;
+            private_keys.extend(keys.into_iter().map(|(_, key)| key));
+        }
+
+        pskt.sign(&private_keys)
+    }
+
     fn cosigner_index(&self) -> u32 {
         0
     }
@@ -7,9 +7,11 @@ pub mod keypair;
 pub mod legacy;
 pub mod multisig;
 pub mod resident;
+pub mod watchonly;
 
 pub use bip32::BIP32_ACCOUNT_KIND;
 pub use keypair::KEYPAIR_ACCOUNT_KIND;
 pub use legacy::LEGACY_ACCOUNT_KIND;
 pub use multisig::MULTISIG_ACCOUNT_KIND;
 pub use resident::RESIDENT_ACCOUNT_KIND;
+pub use watchonly::WATCH_ONLY_ACCOUNT_KIND;
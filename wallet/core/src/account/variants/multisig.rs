@@ -181,8 +181,8 @@ impl Account for MultiSig {
     }
 
     fn sig_op_count(&self) -> u8 {
-        // TODO @maxim
-        1
+        // OpCheckMultiSig accounts for one signature operation per public key
+        self.xpub_keys.len() as u8
     }
 
     fn minimum_signatures(&self) -> u16 {
@@ -246,6 +246,10 @@ impl DerivationCapableAccount for MultiSig {
     fn account_index(&self) -> u64 {
         0
     }
+
+    fn cosigner_index(&self) -> u32 {
+        self.cosigner_index.unwrap_or_default() as u32
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,355 @@
+//!
+//! Watch-only account implementation holding only extended public keys.
+//! A single key tracks a BIP32 account while multiple keys track a multisig account.
+//!
+
+use crate::account::Inner;
+use crate::derivation::{AddressDerivationManager, AddressDerivationManagerTrait, AddressDerivationMeta};
+use crate::imports::*;
+
+pub const WATCH_ONLY_ACCOUNT_KIND: &str = "kash-watch-only-standard";
+
+pub struct Ctor {}
+
+#[async_trait]
+impl Factory for Ctor {
+    fn name(&self) -> String {
+        "watch-only".to_string()
+    }
+
+    fn description(&self) -> String {
+        "Kash Core Watch-Only Account".to_string()
+    }
+
+    async fn try_load(
+        &self,
+        wallet: &Arc<Wallet>,
+        storage: &AccountStorage,
+        meta: Option<Arc<AccountMetadata>>,
+    ) -> Result<Arc<dyn Account>> {
+        Ok(Arc::new(WatchOnly::try_load(wallet, storage, meta).await?))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct Payload {
+    pub xpub_keys: ExtendedPublicKeys,
+    /// Index of the tracked cosigner among the sorted `xpub_keys` of a multisig account
+    pub cosigner_index: Option<u8>,
+    pub minimum_signatures: u16,
+    pub ecdsa: bool,
+}
+
+impl Payload {
+    pub fn new(xpub_keys: ExtendedPublicKeys, cosigner_index: Option<u8>, minimum_signatures: u16, ecdsa: bool) -> Self {
+        Self { xpub_keys, cosigner_index, minimum_signatures, ecdsa }
+    }
+
+    pub fn try_load(storage: &AccountStorage) -> Result<Self> {
+        Ok(Self::try_from_slice(storage.serialized.as_slice())?)
+    }
+}
+
+impl Storable for Payload {
+    const STORAGE_MAGIC: u32 = 0x4c4e4f57;
+    const STORAGE_VERSION: u32 = 0;
+}
+
+impl AccountStorable for Payload {}
+
+impl BorshSerialize for Payload {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+
+        BorshSerialize::serialize(&self.xpub_keys, writer)?;
+        BorshSerialize::serialize(&self.cosigner_index, writer)?;
+        BorshSerialize::serialize(&self.minimum_signatures, writer)?;
+        BorshSerialize::serialize(&self.ecdsa, writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Payload {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+
+        let xpub_keys = BorshDeserialize::deserialize(buf)?;
+        let cosigner_index = BorshDeserialize::deserialize(buf)?;
+        let minimum_signatures = BorshDeserialize::deserialize(buf)?;
+        let ecdsa = BorshDeserialize::deserialize(buf)?;
+
+        Ok(Self { xpub_keys, cosigner_index, minimum_signatures, ecdsa })
+    }
+}
+
+pub struct WatchOnly {
+    inner: Arc<Inner>,
+    xpub_keys: ExtendedPublicKeys,
+    cosigner_index: Option<u8>,
+    minimum_signatures: u16,
+    ecdsa: bool,
+    derivation: Arc<AddressDerivationManager>,
+}
+
+impl WatchOnly {
+    pub async fn try_new(
+        wallet: &Arc<Wallet>,
+        name: Option<String>,
+        xpub_keys: ExtendedPublicKeys,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+    ) -> Result<Self> {
+        // Multisig addresses are derived under the path of the tracked cosigner, which a BIP32 account has none of
+        let cosigner_index = match xpub_keys.len() {
+            1 => None,
+            len => match cosigner_index.unwrap_or(0) {
+                index if (index as usize) < len => Some(index),
+                index => return Err(Error::custom(format!("invalid cosigner index {index} for {len} extended public keys"))),
+            },
+        };
+        let storable = Payload::new(xpub_keys.clone(), cosigner_index, minimum_signatures, ecdsa);
+        let settings = AccountSettings { name, ..Default::default() };
+        let (id, storage_key) = make_account_hashes(from_watch_only(&storable));
+        let inner = Arc::new(Inner::new(wallet, id, storage_key, settings));
+
+        let derivation =
+            Self::create_derivation(wallet, &xpub_keys, cosigner_index, minimum_signatures, ecdsa, Default::default()).await?;
+
+        Ok(Self { inner, xpub_keys, cosigner_index, minimum_signatures, ecdsa, derivation })
+    }
+
+    pub async fn try_load(wallet: &Arc<Wallet>, storage: &AccountStorage, meta: Option<Arc<AccountMetadata>>) -> Result<Self> {
+        let storable = Payload::try_load(storage)?;
+        let inner = Arc::new(Inner::from_storage(wallet, storage));
+
+        let Payload { xpub_keys, cosigner_index, minimum_signatures, ecdsa } = storable;
+
+        let address_derivation_indexes = meta.and_then(|meta| meta.address_derivation_indexes()).unwrap_or_default();
+
+        let derivation =
+            Self::create_derivation(wallet, &xpub_keys, cosigner_index, minimum_signatures, ecdsa, address_derivation_indexes).await?;
+
+        Ok(Self { inner, xpub_keys, cosigner_index, minimum_signatures, ecdsa, derivation })
+    }
+
+    async fn create_derivation(
+        wallet: &Arc<Wallet>,
+        xpub_keys: &ExtendedPublicKeys,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+        address_derivation_indexes: AddressDerivationMeta,
+    ) -> Result<Arc<AddressDerivationManager>> {
+        if xpub_keys.len() > 1 {
+            AddressDerivationManager::new(
+                wallet,
+                MULTISIG_ACCOUNT_KIND.into(),
+                xpub_keys,
+                ecdsa,
+                0,
+                cosigner_index.map(|v| v as u32),
+                minimum_signatures,
+                address_derivation_indexes,
+            )
+            .await
+        } else {
+            AddressDerivationManager::new(wallet, BIP32_ACCOUNT_KIND.into(), xpub_keys, ecdsa, 0, None, 1, address_derivation_indexes)
+                .await
+        }
+    }
+
+    pub fn minimum_signatures(&self) -> u16 {
+        self.minimum_signatures
+    }
+
+    pub fn xpub_keys(&self) -> &ExtendedPublicKeys {
+        &self.xpub_keys
+    }
+
+    pub fn cosigner_index(&self) -> Option<u8> {
+        self.cosigner_index
+    }
+
+    pub fn is_multisig(&self) -> bool {
+        self.xpub_keys.len() > 1
+    }
+}
+
+#[async_trait]
+impl Account for WatchOnly {
+    fn inner(&self) -> &Arc<Inner> {
+        &self.inner
+    }
+
+    fn account_kind(&self) -> AccountKind {
+        WATCH_ONLY_ACCOUNT_KIND.into()
+    }
+
+    fn prv_key_data_id(&self) -> Result<&PrvKeyDataId> {
+        Err(Error::WatchOnlyAccount)
+    }
+
+    fn as_dyn_arc(self: Arc<Self>) -> Arc<dyn Account> {
+        self
+    }
+
+    fn sig_op_count(&self) -> u8 {
+        // OpCheckMultiSig accounts for one signature operation per public key
+        self.xpub_keys.len() as u8
+    }
+
+    fn minimum_signatures(&self) -> u16 {
+        if self.is_multisig() {
+            self.minimum_signatures
+        } else {
+            1
+        }
+    }
+
+    fn receive_address(&self) -> Result<Address> {
+        self.derivation.receive_address_manager().current_address()
+    }
+
+    fn change_address(&self) -> Result<Address> {
+        self.derivation.change_address_manager().current_address()
+    }
+
+    fn to_storage(&self) -> Result<AccountStorage> {
+        let settings = self.context().settings.clone();
+        let storable = Payload::new(self.xpub_keys.clone(), self.cosigner_index, self.minimum_signatures, self.ecdsa);
+        let account_storage = AccountStorage::try_new(
+            WATCH_ONLY_ACCOUNT_KIND.into(),
+            self.id(),
+            self.storage_key(),
+            AssocPrvKeyDataIds::None,
+            settings,
+            storable,
+        )?;
+
+        Ok(account_storage)
+    }
+
+    fn metadata(&self) -> Result<Option<AccountMetadata>> {
+        let metadata = AccountMetadata::new(self.inner.id, self.derivation.address_derivation_meta());
+        Ok(Some(metadata))
+    }
+
+    fn descriptor(&self) -> Result<AccountDescriptor> {
+        let descriptor = AccountDescriptor::new(
+            WATCH_ONLY_ACCOUNT_KIND.into(),
+            *self.id(),
+            self.name(),
+            AssocPrvKeyDataIds::None,
+            self.receive_address().ok(),
+            self.change_address().ok(),
+        )
+        .with_property(AccountDescriptorProperty::XpubKeys, self.xpub_keys.clone().into())
+        .with_property(AccountDescriptorProperty::Ecdsa, self.ecdsa.into())
+        .with_property(AccountDescriptorProperty::DerivationMeta, self.derivation.address_derivation_meta().into());
+
+        Ok(descriptor)
+    }
+
+    fn as_derivation_capable(self: Arc<Self>) -> Result<Arc<dyn DerivationCapableAccount>> {
+        Ok(self.clone())
+    }
+}
+
+impl DerivationCapableAccount for WatchOnly {
+    fn derivation(&self) -> Arc<dyn AddressDerivationManagerTrait> {
+        self.derivation.clone()
+    }
+
+    fn account_index(&self) -> u64 {
+        0
+    }
+
+    fn cosigner_index(&self) -> u32 {
+        self.cosigner_index.unwrap_or_default() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derivation::create_xpub_from_xprv;
+    use crate::tests::*;
+    use kash_bip32::{ExtendedPrivateKey, Prefix as KeyPrefix};
+
+    #[test]
+    fn test_storage_watch_only() -> Result<()> {
+        let storable_in = Payload::new(vec![make_xpub(), make_xpub()].into(), Some(1), 2, true);
+        let guard = StorageGuard::new(&storable_in);
+        let storable_out = guard.validate()?;
+
+        assert_eq!(storable_in.cosigner_index, storable_out.cosigner_index);
+        assert_eq!(storable_in.minimum_signatures, storable_out.minimum_signatures);
+        assert_eq!(storable_in.ecdsa, storable_out.ecdsa);
+        assert_eq!(storable_in.xpub_keys.len(), storable_out.xpub_keys.len());
+        for idx in 0..storable_in.xpub_keys.len() {
+            assert_eq!(storable_in.xpub_keys[idx], storable_out.xpub_keys[idx]);
+        }
+
+        Ok(())
+    }
+
+    async fn assert_same_addresses(account: &Arc<dyn DerivationCapableAccount>, watch_only: &WatchOnly) -> Result<()> {
+        for (manager, watch_only_manager) in [
+            (account.derivation().receive_address_manager(), watch_only.derivation.receive_address_manager()),
+            (account.derivation().change_address_manager(), watch_only.derivation.change_address_manager()),
+        ] {
+            assert_eq!(manager.get_range(0..10)?, watch_only_manager.get_range(0..10)?);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watch_only_addresses() -> Result<()> {
+        let wallet = Arc::new(Wallet::try_new(Wallet::resident_store()?, Some(NetworkId::with_suffix(NetworkType::Testnet, 11)))?);
+        let xprvs = (1..=3u8).map(|seed| ExtendedPrivateKey::<secp256k1::SecretKey>::new([seed; 64]).unwrap()).collect::<Vec<_>>();
+        let prv_key_data_id = PrvKeyDataId::new(0);
+
+        // A single key tracks the same addresses as the BIP32 account it was exported from
+        for ecdsa in [false, true] {
+            let xpub_key = create_xpub_from_xprv(xprvs[0].clone(), BIP32_ACCOUNT_KIND.into(), 0).await?;
+            let bip32 = bip32::Bip32::try_new(&wallet, None, prv_key_data_id, 0, Arc::new(vec![xpub_key.clone()]), ecdsa).await?;
+            let watch_only = WatchOnly::try_new(&wallet, None, Arc::new(vec![xpub_key]), None, 1, ecdsa).await?;
+            assert_same_addresses(&Arc::new(bip32).as_derivation_capable()?, &watch_only).await?;
+        }
+
+        // Multiple keys track the addresses of the multisig account of the cosigner at the same index
+        let mut xpub_keys = vec![];
+        for xprv in xprvs.iter() {
+            xpub_keys.push(create_xpub_from_xprv(xprv.clone(), MULTISIG_ACCOUNT_KIND.into(), 0).await?);
+        }
+        xpub_keys.sort_unstable_by_key(|xpub_key| xpub_key.to_string(Some(KeyPrefix::XPUB)));
+        let xpub_keys = Arc::new(xpub_keys);
+        for cosigner_index in 0..3u8 {
+            let multisig = multisig::MultiSig::try_new(
+                &wallet,
+                None,
+                xpub_keys.clone(),
+                Some(Arc::new(vec![prv_key_data_id])),
+                Some(cosigner_index),
+                2,
+                false,
+            )
+            .await?;
+            let watch_only = WatchOnly::try_new(&wallet, None, xpub_keys.clone(), Some(cosigner_index), 2, false).await?;
+            assert_same_addresses(&Arc::new(multisig).as_derivation_capable()?, &watch_only).await?;
+        }
+
+        // Different cosigners track different addresses, hence different accounts
+        let first = WatchOnly::try_new(&wallet, None, xpub_keys.clone(), Some(0), 2, false).await?;
+        let second = WatchOnly::try_new(&wallet, None, xpub_keys.clone(), Some(1), 2, false).await?;
+        assert_ne!(first.receive_address()?, second.receive_address()?);
+        assert_ne!(first.id(), second.id());
+        assert!(WatchOnly::try_new(&wallet, None, xpub_keys, Some(3), 2, false).await.is_err());
+
+        Ok(())
+    }
+}
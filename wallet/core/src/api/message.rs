@@ -280,11 +280,21 @@ pub struct AccountsCreateResponse {
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountsImportRequest {}
+pub struct AccountsImportRequest {
+    pub wallet_secret: Secret,
+    pub xpub_keys: Vec<String>,
+    pub account_name: Option<String>,
+    /// Index of the tracked cosigner among the sorted `xpub_keys` of a multisig account
+    pub cosigner_index: Option<u8>,
+    pub minimum_signatures: u16,
+    pub ecdsa: bool,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AccountsImportResponse {}
+pub struct AccountsImportResponse {
+    pub account_descriptor: AccountDescriptor,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
@@ -454,12 +464,122 @@ pub struct TransactionsReplaceMetadataResponse {}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AddressBookEnumerateRequest {}
+pub struct AddressBookEnumerateRequest {
+    pub search: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookEnumerateResponse {
+    pub entries: Vec<Arc<AddressBookEntry>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookCreateRequest {
+    pub wallet_secret: Secret,
+    pub entry: AddressBookEntry,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookCreateResponse {}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AddressBookEnumerateResponse {}
+pub struct AddressBookUpdateRequest {
+    pub wallet_secret: Secret,
+    pub alias: String,
+    pub entry: AddressBookEntry,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookUpdateResponse {}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookRemoveRequest {
+    pub wallet_secret: Secret,
+    pub aliases: Vec<String>,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct WalletNotification {}
+pub struct AddressBookRemoveResponse {}
+
+/// Filter applied to wallet events delivered to a notification channel.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationFilter {
+    /// Event kinds delivered to the channel (all kinds if `None`).
+    pub kinds: Option<Vec<EventKind>>,
+    /// Accounts whose events are delivered to the channel (all accounts if `None`).
+    /// Events that are not specific to an account are not affected by this filter.
+    pub account_ids: Option<Vec<AccountId>>,
+}
+
+impl NotificationFilter {
+    pub fn with_kinds(mut self, kinds: &[EventKind]) -> Self {
+        self.kinds = Some(kinds.to_vec());
+        self
+    }
+
+    pub fn with_account_ids(mut self, account_ids: &[AccountId]) -> Self {
+        self.account_ids = Some(account_ids.to_vec());
+        self
+    }
+
+    pub fn matches(&self, event: &Events) -> bool {
+        if let Some(kinds) = &self.kinds {
+            if !kinds.contains(&event.kind()) {
+                return false;
+            }
+        }
+
+        if let Some(account_ids) = &self.account_ids {
+            if event.is_bound_to(account_ids) == Some(false) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Wallet event delivered to a registered notification channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletNotification {
+    /// Id of the channel registration that produced the notification.
+    pub channel_id: u64,
+    /// Number of notifications dropped for this channel since the
+    /// previously delivered notification because the channel was full.
+    pub lagged: u64,
+    pub event: Arc<Events>,
+}
+
+// [`Events`] carry serde-only data (such as [`Balance`](crate::utxo::balance::Balance)),
+// so the event is embedded as JSON within the Borsh encoding.
+impl BorshSerialize for WalletNotification {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let event = serde_json::to_string(&self.event).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        BorshSerialize::serialize(&self.channel_id, writer)?;
+        BorshSerialize::serialize(&self.lagged, writer)?;
+        BorshSerialize::serialize(&event, writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for WalletNotification {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let channel_id = BorshDeserialize::deserialize(buf)?;
+        let lagged = BorshDeserialize::deserialize(buf)?;
+        let event: String = BorshDeserialize::deserialize(buf)?;
+        let event = serde_json::from_str(&event).map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+        Ok(Self { channel_id, lagged, event })
+    }
+}
@@ -5,6 +5,9 @@
 pub mod message;
 pub use message::*;
 
+pub mod notifications;
+pub use notifications::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -0,0 +1,124 @@
+//!
+//! Fan-out of wallet [`Events`] to notification channels registered
+//! via [`WalletApi::register_notifications()`](crate::api::WalletApi::register_notifications).
+//!
+//! Each registration supplies a [`Sender`] and a [`NotificationFilter`].
+//! Events are delivered using non-blocking sends, so a slow consumer can
+//! never stall the wallet event loop. If the channel is full, the event is
+//! dropped and counted; the count is reported in the [`WalletNotification::lagged`]
+//! field of the next delivered notification, signaling to the consumer that it
+//! should re-synchronize its state using the wallet API. Registrations whose
+//! receivers have been dropped are removed automatically.
+//!
+
+use crate::api::message::{NotificationFilter, WalletNotification};
+use crate::imports::*;
+use workflow_core::channel::{Sender, TrySendError};
+
+struct Registration {
+    sender: Sender<WalletNotification>,
+    filter: NotificationFilter,
+    lagged: AtomicU64,
+}
+
+/// Registry of notification channels observing a single wallet instance.
+#[derive(Default)]
+pub struct Notifications {
+    channels: Mutex<AHashMap<u64, Arc<Registration>>>,
+    next_channel_id: AtomicU64,
+}
+
+impl Notifications {
+    pub fn register(&self, sender: Sender<WalletNotification>, filter: NotificationFilter) -> u64 {
+        let channel_id = self.next_channel_id.fetch_add(1, Ordering::SeqCst);
+        let registration = Arc::new(Registration { sender, filter, lagged: AtomicU64::new(0) });
+        self.channels.lock().unwrap().insert(channel_id, registration);
+        channel_id
+    }
+
+    pub fn unregister(&self, channel_id: u64) -> Result<()> {
+        self.channels.lock().unwrap().remove(&channel_id).map(|_| ()).ok_or(Error::NotificationChannelNotFound(channel_id))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.channels.lock().unwrap().is_empty()
+    }
+
+    /// Delivers the event to all channels whose filter matches the event.
+    pub fn notify(&self, event: &Events) {
+        let mut channels = self.channels.lock().unwrap();
+        if channels.is_empty() {
+            return;
+        }
+
+        let event = Arc::new(event.clone());
+        channels.retain(|channel_id, registration| {
+            if !registration.filter.matches(&event) {
+                return true;
+            }
+
+            let lagged = registration.lagged.swap(0, Ordering::SeqCst);
+            let notification = WalletNotification { channel_id: *channel_id, lagged, event: event.clone() };
+            match registration.sender.try_send(notification) {
+                Ok(_) => true,
+                Err(TrySendError::Full(_)) => {
+                    registration.lagged.fetch_add(lagged + 1, Ordering::SeqCst);
+                    true
+                }
+                Err(TrySendError::Closed(_)) => false,
+            }
+        });
+    }
+
+    /// Accounts for `count` notifications dropped upstream (by a
+    /// remote wallet server) on every registered channel.
+    pub fn lag(&self, count: u64) {
+        self.channels.lock().unwrap().values().for_each(|registration| {
+            registration.lagged.fetch_add(count, Ordering::SeqCst);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use workflow_core::channel::bounded;
+
+    #[test]
+    fn test_notifications_fan_out() -> Result<()> {
+        let notifications = Notifications::default();
+
+        let (all_sender, all_receiver) = bounded(16);
+        let (slow_sender, slow_receiver) = bounded(1);
+        let (filtered_sender, filtered_receiver) = bounded(16);
+
+        let all = notifications.register(all_sender, NotificationFilter::default());
+        notifications.register(slow_sender, NotificationFilter::default());
+        notifications.register(filtered_sender, NotificationFilter::default().with_kinds(&[EventKind::DAAScoreChange]));
+
+        notifications.notify(&Events::UtxoProcStart);
+        notifications.notify(&Events::DAAScoreChange { current_daa_score: 1 });
+        notifications.notify(&Events::DAAScoreChange { current_daa_score: 2 });
+
+        assert_eq!(all_receiver.len(), 3);
+        assert_eq!(filtered_receiver.len(), 2);
+        assert_eq!(filtered_receiver.try_recv().unwrap().event.kind(), EventKind::DAAScoreChange);
+
+        // the slow channel holds the first event while the following two are dropped
+        assert_eq!(slow_receiver.try_recv().unwrap().event.kind(), EventKind::UtxoProcStart);
+        notifications.notify(&Events::UtxoProcStop);
+        let notification = slow_receiver.try_recv().unwrap();
+        assert_eq!(notification.lagged, 2);
+        assert_eq!(notification.event.kind(), EventKind::UtxoProcStop);
+
+        // closed channels are removed on the next delivery
+        drop(filtered_receiver);
+        notifications.notify(&Events::DAAScoreChange { current_daa_score: 3 });
+        assert_eq!(notifications.channels.lock().unwrap().len(), 2);
+
+        notifications.unregister(all)?;
+        assert!(notifications.unregister(all).is_err());
+
+        Ok(())
+    }
+}
@@ -13,16 +13,36 @@ use crate::result::Result;
 use crate::secret::Secret;
 use crate::storage::{PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, WalletDescriptor};
 use crate::tx::GeneratorSummary;
-use workflow_core::channel::Receiver;
+use workflow_core::channel::{bounded, Receiver, Sender};
 
 ///
 ///  API trait for interfacing with the Kash wallet subsystem.
 ///
 #[async_trait]
 pub trait WalletApi: Send + Sync + AnySync {
-    async fn register_notifications(self: Arc<Self>, channel: Receiver<WalletNotification>) -> Result<u64>;
+    /// Registers a channel that receives wallet events matching the supplied
+    /// `filter`, returning the channel id. Events are delivered without blocking:
+    /// if the channel is full, events are dropped and the number of dropped
+    /// events is reported in the next delivered [`WalletNotification`].
+    /// The channel is unregistered when its receiver is dropped.
+    async fn register_notifications(self: Arc<Self>, channel: Sender<WalletNotification>, filter: NotificationFilter) -> Result<u64>;
+
+    /// Unregisters a channel previously registered with
+    /// [`register_notifications()`](Self::register_notifications).
     async fn unregister_notifications(self: Arc<Self>, channel_id: u64) -> Result<()>;
 
+    /// Wrapper around [`register_notifications()`](Self::register_notifications)
+    /// creating a channel with the given `capacity`.
+    async fn notification_channel(
+        self: Arc<Self>,
+        capacity: usize,
+        filter: NotificationFilter,
+    ) -> Result<(u64, Receiver<WalletNotification>)> {
+        let (sender, receiver) = bounded(capacity);
+        let channel_id = self.register_notifications(sender, filter).await?;
+        Ok((channel_id, receiver))
+    }
+
     /// Wrapper around [`get_status_call()`](Self::get_status_call).
     async fn get_status(self: Arc<Self>) -> Result<GetStatusResponse> {
         Ok(self.get_status_call(GetStatusRequest {}).await?)
@@ -284,7 +304,30 @@ pub trait WalletApi: Send + Sync + AnySync {
     /// around this call.
     async fn accounts_create_call(self: Arc<Self>, request: AccountsCreateRequest) -> Result<AccountsCreateResponse>;
 
-    // TODO
+    /// Wrapper around [`accounts_import_call()`](Self::accounts_import_call)
+    async fn accounts_import(
+        self: Arc<Self>,
+        wallet_secret: Secret,
+        xpub_keys: Vec<String>,
+        account_name: Option<String>,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+    ) -> Result<AccountDescriptor> {
+        Ok(self
+            .accounts_import_call(AccountsImportRequest {
+                wallet_secret,
+                xpub_keys,
+                account_name,
+                cosigner_index,
+                minimum_signatures,
+                ecdsa,
+            })
+            .await?
+            .account_descriptor)
+    }
+    /// Import a watch-only account from one (BIP32) or multiple (multisig)
+    /// extended public keys. Returns an [`AccountDescriptor`] for the imported account.
     async fn accounts_import_call(self: Arc<Self>, request: AccountsImportRequest) -> Result<AccountsImportResponse>;
 
     /// Get an [`AccountDescriptor`] for a specific account id.
@@ -368,10 +411,29 @@ pub trait WalletApi: Send + Sync + AnySync {
         request: TransactionsReplaceMetadataRequest,
     ) -> Result<TransactionsReplaceMetadataResponse>;
 
+    /// Wrapper around [`address_book_enumerate_call()`](Self::address_book_enumerate_call)
+    async fn address_book_enumerate(self: Arc<Self>, search: Option<String>) -> Result<Vec<Arc<AddressBookEntry>>> {
+        Ok(self.address_book_enumerate_call(AddressBookEnumerateRequest { search }).await?.entries)
+    }
+
+    /// Returns address book entries (contacts) stored in the wallet.
+    /// If `search` is supplied, only entries whose alias, title
+    /// or address contain the search string are returned.
     async fn address_book_enumerate_call(
         self: Arc<Self>,
         request: AddressBookEnumerateRequest,
     ) -> Result<AddressBookEnumerateResponse>;
+
+    /// Creates a new address book entry. Fails if an entry with
+    /// the same alias already exists.
+    async fn address_book_create_call(self: Arc<Self>, request: AddressBookCreateRequest) -> Result<AddressBookCreateResponse>;
+
+    /// Replaces the address book entry identified by `alias` with
+    /// the supplied entry. The supplied entry may carry a new alias.
+    async fn address_book_update_call(self: Arc<Self>, request: AddressBookUpdateRequest) -> Result<AddressBookUpdateResponse>;
+
+    /// Removes address book entries identified by the supplied aliases.
+    async fn address_book_remove_call(self: Arc<Self>, request: AddressBookRemoveRequest) -> Result<AddressBookRemoveResponse>;
 }
 
 /// alias for `Arc<dyn WalletApi + Send + Sync + 'static>`
@@ -9,25 +9,54 @@
 //! (hash) for each API method based on the method name.
 //! This id is then use to identify the method.
 //!
+//! Wallet notifications are pushed from the server to the client
+//! out of band. When the first notification channel is registered
+//! with the [`WalletClient`], the client asks the server (via the
+//! transport) to start a relay. The server side of the transport
+//! handles this request by calling [`WalletServer::relay_notifications()`]
+//! with a [`NotificationSink`] bound to the connection, while the client
+//! side delivers received notifications to [`WalletClient::handle_notification()`].
+//! The relay is stopped once the last client channel is unregistered.
+//!
 
 use std::sync::Arc;
 
 use super::message::*;
+use super::notifications::Notifications;
 use super::traits::WalletApi;
 use crate::error::Error;
 use crate::result::Result;
+use async_std::sync::Mutex as AsyncMutex;
 use async_trait::async_trait;
 use borsh::{BorshDeserialize, BorshSerialize};
 use kash_wallet_macros::{build_wallet_client_transport_interface, build_wallet_server_transport_interface};
+use workflow_core::channel::Sender;
+use workflow_core::task::spawn;
+use workflow_log::log_error;
 
 #[async_trait]
 pub trait BorshTransport: Send + Sync {
     async fn call(&self, op: u64, request: Vec<u8>) -> Result<Vec<u8>>;
+    /// Asks the server to relay notifications matching `filter` to this
+    /// client (encoded with Borsh), returning the server-side relay id.
+    async fn register_notifications(&self, filter: NotificationFilter) -> Result<u64>;
+    async fn unregister_notifications(&self, relay_id: u64) -> Result<()>;
 }
 
 #[async_trait]
 pub trait SerdeTransport: Send + Sync {
     async fn call(&self, op: &str, request: &str) -> Result<String>;
+    /// Asks the server to relay notifications matching `filter` to this
+    /// client (encoded with Serde JSON), returning the server-side relay id.
+    async fn register_notifications(&self, filter: NotificationFilter) -> Result<u64>;
+    async fn unregister_notifications(&self, relay_id: u64) -> Result<()>;
+}
+
+/// Server-side sink through which a transport pushes
+/// wallet notifications to a connected client.
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    async fn notify(&self, notification: WalletNotification) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -36,6 +65,22 @@ pub enum Transport {
     Serde(Arc<dyn SerdeTransport>),
 }
 
+impl Transport {
+    async fn register_notifications(&self, filter: NotificationFilter) -> Result<u64> {
+        match self {
+            Transport::Borsh(transport) => transport.register_notifications(filter).await,
+            Transport::Serde(transport) => transport.register_notifications(filter).await,
+        }
+    }
+
+    async fn unregister_notifications(&self, relay_id: u64) -> Result<()> {
+        match self {
+            Transport::Borsh(transport) => transport.unregister_notifications(relay_id).await,
+            Transport::Serde(transport) => transport.unregister_notifications(relay_id).await,
+        }
+    }
+}
+
 pub struct WalletServer {
     pub wallet_api: Arc<dyn WalletApi>,
 }
@@ -48,6 +93,34 @@ impl WalletServer {
     pub fn wallet_api(&self) -> &Arc<dyn WalletApi> {
         &self.wallet_api
     }
+
+    /// Relays wallet notifications matching `filter` to the `sink` (typically
+    /// a connected client) until the relay is unregistered or the sink fails.
+    /// Notifications are buffered up to `capacity`, beyond which they are
+    /// dropped and reported to the client as lagged.
+    pub async fn relay_notifications(
+        &self,
+        sink: Arc<dyn NotificationSink>,
+        filter: NotificationFilter,
+        capacity: usize,
+    ) -> Result<u64> {
+        let (channel_id, receiver) = self.wallet_api.clone().notification_channel(capacity, filter).await?;
+
+        spawn(async move {
+            while let Ok(notification) = receiver.recv().await {
+                if let Err(err) = sink.notify(notification).await {
+                    log_error!("WalletServer: unable to relay notification: {err}");
+                    break;
+                }
+            }
+        });
+
+        Ok(channel_id)
+    }
+
+    pub async fn unregister_notifications(&self, channel_id: u64) -> Result<()> {
+        self.wallet_api.clone().unregister_notifications(channel_id).await
+    }
 }
 
 impl WalletServer {
@@ -86,27 +159,66 @@ impl WalletServer {
         TransactionsReplaceNote,
         TransactionsReplaceMetadata,
         AddressBookEnumerate,
+        AddressBookCreate,
+        AddressBookUpdate,
+        AddressBookRemove,
     ]}
 }
 
 pub struct WalletClient {
     pub transport: Transport,
+    notifications: Notifications,
+    // id of the server relay feeding `notifications`, registered
+    // with the first channel and unregistered with the last one
+    relay_id: AsyncMutex<Option<u64>>,
 }
 
 impl WalletClient {
     pub fn new(transport: Transport) -> Self {
-        Self { transport }
+        Self { transport, notifications: Notifications::default(), relay_id: AsyncMutex::new(None) }
+    }
+
+    /// Dispatches a notification pushed by the wallet server
+    /// (see [`WalletServer::relay_notifications()`]) to the
+    /// channels registered with this client.
+    pub fn handle_notification(&self, notification: WalletNotification) {
+        if notification.lagged > 0 {
+            self.notifications.lag(notification.lagged);
+        }
+        self.notifications.notify(&notification.event);
+    }
+
+    pub fn handle_borsh_notification(&self, data: &[u8]) -> Result<()> {
+        self.handle_notification(WalletNotification::try_from_slice(data)?);
+        Ok(())
+    }
+
+    pub fn handle_serde_notification(&self, data: &str) -> Result<()> {
+        self.handle_notification(serde_json::from_str(data)?);
+        Ok(())
     }
 }
 
-use workflow_core::channel::Receiver;
 #[async_trait]
 impl WalletApi for WalletClient {
-    async fn register_notifications(self: Arc<Self>, _channel: Receiver<WalletNotification>) -> Result<u64> {
-        todo!()
+    async fn register_notifications(self: Arc<Self>, channel: Sender<WalletNotification>, filter: NotificationFilter) -> Result<u64> {
+        // the server relays all events, client channels apply their own filters
+        let mut relay_id = self.relay_id.lock().await;
+        if relay_id.is_none() {
+            relay_id.replace(self.transport.register_notifications(NotificationFilter::default()).await?);
+        }
+        Ok(self.notifications.register(channel, filter))
     }
-    async fn unregister_notifications(self: Arc<Self>, _channel_id: u64) -> Result<()> {
-        todo!()
+
+    async fn unregister_notifications(self: Arc<Self>, channel_id: u64) -> Result<()> {
+        let mut relay_id = self.relay_id.lock().await;
+        self.notifications.unregister(channel_id)?;
+        if self.notifications.is_empty() {
+            if let Some(relay_id) = relay_id.take() {
+                self.transport.unregister_notifications(relay_id).await?;
+            }
+        }
+        Ok(())
     }
 
     build_wallet_client_transport_interface! {[
@@ -144,5 +256,85 @@ impl WalletApi for WalletClient {
         TransactionsReplaceNote,
         TransactionsReplaceMetadata,
         AddressBookEnumerate,
+        AddressBookCreate,
+        AddressBookUpdate,
+        AddressBookRemove,
     ]}
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{EventKind, Events};
+    use crate::wallet::Wallet;
+    use kash_consensus_core::network::{NetworkId, NetworkType};
+    use std::sync::{Mutex, Weak};
+
+    /// In-process transport connecting a [`WalletClient`] to a [`WalletServer`].
+    struct LoopbackTransport {
+        server: WalletServer,
+        client: Mutex<Weak<WalletClient>>,
+    }
+
+    /// Connection-bound sink delivering Borsh-encoded notifications to the client.
+    struct LoopbackSink {
+        client: Weak<WalletClient>,
+    }
+
+    #[async_trait]
+    impl NotificationSink for LoopbackSink {
+        async fn notify(&self, notification: WalletNotification) -> Result<()> {
+            let client = self.client.upgrade().ok_or(Error::Custom("client disconnected".to_string()))?;
+            client.handle_borsh_notification(&notification.try_to_vec()?)
+        }
+    }
+
+    #[async_trait]
+    impl BorshTransport for LoopbackTransport {
+        async fn call(&self, op: u64, request: Vec<u8>) -> Result<Vec<u8>> {
+            self.server.call_with_borsh(op, &request).await
+        }
+
+        async fn register_notifications(&self, filter: NotificationFilter) -> Result<u64> {
+            let sink = Arc::new(LoopbackSink { client: self.client.lock().unwrap().clone() });
+            self.server.relay_notifications(sink, filter, 16).await
+        }
+
+        async fn unregister_notifications(&self, relay_id: u64) -> Result<()> {
+            self.server.unregister_notifications(relay_id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notification_relay() -> Result<()> {
+        let wallet = Arc::new(Wallet::try_new(Wallet::resident_store()?, Some(NetworkId::with_suffix(NetworkType::Testnet, 11)))?);
+        let transport = Arc::new(LoopbackTransport { server: WalletServer::new(wallet.clone()), client: Mutex::new(Weak::new()) });
+        let client = Arc::new(WalletClient::new(Transport::Borsh(transport.clone())));
+        *transport.client.lock().unwrap() = Arc::downgrade(&client);
+
+        // registering client channels starts a single server relay
+        let (all, all_receiver) = client.clone().notification_channel(16, NotificationFilter::default()).await?;
+        let filter = NotificationFilter::default().with_kinds(&[EventKind::DAAScoreChange]);
+        let (filtered, filtered_receiver) = client.clone().notification_channel(16, filter).await?;
+        assert!(!wallet.notifications().is_empty());
+
+        wallet.notifications().notify(&Events::UtxoProcStart);
+        wallet.notifications().notify(&Events::DAAScoreChange { current_daa_score: 1 });
+
+        assert_eq!(all_receiver.recv().await.unwrap().event.kind(), EventKind::UtxoProcStart);
+        assert_eq!(all_receiver.recv().await.unwrap().event.kind(), EventKind::DAAScoreChange);
+        let notification = filtered_receiver.recv().await.unwrap();
+        assert_eq!(notification.channel_id, filtered);
+        assert!(matches!(*notification.event, Events::DAAScoreChange { current_daa_score: 1 }));
+        assert!(filtered_receiver.is_empty());
+
+        // the relay is stopped once the last client channel is unregistered
+        client.clone().unregister_notifications(filtered).await?;
+        assert!(!wallet.notifications().is_empty());
+        client.clone().unregister_notifications(all).await?;
+        assert!(wallet.notifications().is_empty());
+
+        Ok(())
+    }
+}
@@ -161,6 +161,14 @@ impl AddressManager {
         Ok(addresses)
     }
 
+    /// Public keys of the address at `index`, one per cosigner
+    pub fn get_public_keys(&self, index: u32) -> Result<Vec<secp256k1::PublicKey>> {
+        self.pubkey_managers
+            .iter()
+            .map(|m| m.get_range(index..index + 1)?.pop().ok_or_else(|| Error::custom(format!("unable to derive public key {index}"))))
+            .collect()
+    }
+
     fn update_address_to_index_map(&self, offset: u32, addresses: &[Address]) -> Result<()> {
         let address_to_index_map = &mut self.inner().address_to_index_map;
         for (index, address) in addresses.iter().enumerate() {
@@ -447,14 +455,20 @@ pub fn create_multisig_address(
     prefix: Prefix,
     ecdsa: bool,
 ) -> Result<Address> {
+    let script = create_multisig_redeem_script(minimum_signatures, &keys, ecdsa)?;
+    let script_pub_key = pay_to_script_hash_script(&script);
+    let address = extract_script_pub_key_address(&script_pub_key, prefix)?;
+    Ok(address)
+}
+
+/// Creates the redeem script of a multisig address, `keys` being ordered as in the account
+pub fn create_multisig_redeem_script(minimum_signatures: usize, keys: &[secp256k1::PublicKey], ecdsa: bool) -> Result<Vec<u8>> {
     let script = if !ecdsa {
         multisig_redeem_script(keys.iter().map(|pk| pk.x_only_public_key().0.serialize()), minimum_signatures)
     } else {
         multisig_redeem_script_ecdsa(keys.iter().map(|pk| pk.serialize()), minimum_signatures)
     }?;
-    let script_pub_key = pay_to_script_hash_script(&script);
-    let address = extract_script_pub_key_address(&script_pub_key, prefix)?;
-    Ok(address)
+    Ok(script)
 }
 
 #[wasm_bindgen]
@@ -2,7 +2,7 @@
 //! Deterministic byte sequence generation (used by Account ids).
 //!
 
-pub use crate::account::{bip32, keypair, legacy, multisig};
+pub use crate::account::{bip32, keypair, legacy, multisig, watchonly};
 use crate::encryption::sha256_hash;
 use crate::imports::*;
 use crate::storage::PrvKeyDataId;
@@ -122,6 +122,19 @@ pub fn from_multisig<const N: usize>(prv_key_data_ids: &Option<Arc<Vec<PrvKeyDat
     make_hashes(hashable)
 }
 
+pub fn from_watch_only<const N: usize>(data: &watchonly::Payload) -> [Hash; N] {
+    let hashable = DeterministicHashData {
+        account_kind: &watchonly::WATCH_ONLY_ACCOUNT_KIND.into(),
+        prv_key_data_ids: &None::<Vec<PrvKeyDataId>>,
+        ecdsa: Some(data.ecdsa),
+        account_index: None,
+        secp256k1_public_key: None,
+        // each cosigner of a multisig account tracks different addresses
+        data: Some([data.xpub_keys.try_to_vec().unwrap(), data.cosigner_index.try_to_vec().unwrap()].concat()),
+    };
+    make_hashes(hashable)
+}
+
 pub(crate) fn from_keypair<const N: usize>(prv_key_data_id: &PrvKeyDataId, data: &keypair::Payload) -> [Hash; N] {
     let hashable = DeterministicHashData {
         account_kind: &keypair::KEYPAIR_ACCOUNT_KIND.into(),
@@ -216,6 +216,27 @@ pub enum Error {
     #[error("This feature is not supported by this account type")]
     AccountKindFeature,
 
+    #[error("Watch-only accounts have no private keys, transactions must be signed externally")]
+    WatchOnlyAccount,
+
+    #[error("Notification channel {0} is not registered")]
+    NotificationChannelNotFound(u64),
+
+    #[error("Invalid address book alias '{0}'")]
+    AddressBookInvalidAlias(String),
+
+    #[error("Address book entry '{0}' already exists")]
+    AddressBookEntryExists(String),
+
+    #[error("Address book entry '{0}' not found")]
+    AddressBookEntryNotFound(String),
+
+    #[error("Address book entry '{0}' network does not match its address")]
+    AddressBookNetworkMismatch(String),
+
+    #[error("Address book entry '{0}' does not belong to the wallet network")]
+    AddressBookWrongNetwork(String),
+
     #[error("Address derivation processing is not supported by this account type")]
     AccountAddressDerivationCaps,
 
@@ -275,6 +296,24 @@ pub enum Error {
 
     #[error("Missing RPC listener id (this may be a node connection issue)")]
     ListenerId,
+
+    #[error("The payment requires more than one transaction, please compound the account UTXOs first")]
+    PsktRequiresSingleTransaction,
+
+    #[error("Input {0} of the partially signed transaction does not belong to the account")]
+    PsktUnknownInput(usize),
+
+    #[error("The keys or redeem script of input {0} of the partially signed transaction do not match the spent UTXO")]
+    PsktScriptMismatch(usize),
+
+    #[error("Partially signed transactions of different transactions can not be combined")]
+    PsktTransactionMismatch,
+
+    #[error("Input {input} of the partially signed transaction has {collected} of the {required} required signatures")]
+    PsktMissingSignatures { input: usize, collected: usize, required: usize },
+
+    #[error("The partially signed transaction targets network {found} while the wallet is on {expected}")]
+    PsktNetworkMismatch { expected: String, found: String },
 }
 
 impl From<Aborted> for Error {
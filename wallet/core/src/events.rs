@@ -9,7 +9,7 @@ use crate::storage::{Hint, PrvKeyDataInfo, StorageDescriptor, TransactionRecord,
 use crate::utxo::context::UtxoContextId;
 
 /// Sync state of the kashd node
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(tag = "sync", content = "state")]
 pub enum SyncState {
@@ -48,7 +48,7 @@ impl SyncState {
 }
 
 /// Events emitted by the wallet framework
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 #[serde(tag = "event", content = "data")]
 pub enum Events {
@@ -199,8 +199,9 @@ pub enum Events {
     Discovery {
         record: TransactionRecord,
     },
-    /// UtxoContext (Account) balance update. Emitted for each
-    /// balance change within the UtxoContext.
+    /// UtxoContext (Account) balance update, broken down by
+    /// asset type. Emitted for each balance change within
+    /// the UtxoContext.
     Balance {
         // #[serde(rename = "matureUtxoSize")]
         // mature_utxo_size: usize,
@@ -216,3 +217,105 @@ pub enum Events {
         message: String,
     },
 }
+
+/// Discriminant of [`Events`], used to filter
+/// wallet notifications by event type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventKind {
+    Connect,
+    Disconnect,
+    UtxoIndexNotEnabled,
+    SyncState,
+    WalletHint,
+    WalletOpen,
+    WalletCreate,
+    WalletReload,
+    WalletError,
+    WalletClose,
+    PrvKeyDataCreate,
+    AccountActivation,
+    AccountDeactivation,
+    AccountSelection,
+    AccountCreate,
+    AccountUpdate,
+    ServerStatus,
+    UtxoProcStart,
+    UtxoProcStop,
+    UtxoProcError,
+    DAAScoreChange,
+    Pending,
+    Reorg,
+    Stasis,
+    Maturity,
+    Discovery,
+    Balance,
+    Error,
+}
+
+impl From<&Events> for EventKind {
+    fn from(event: &Events) -> Self {
+        match event {
+            Events::Connect { .. } => EventKind::Connect,
+            Events::Disconnect { .. } => EventKind::Disconnect,
+            Events::UtxoIndexNotEnabled { .. } => EventKind::UtxoIndexNotEnabled,
+            Events::SyncState { .. } => EventKind::SyncState,
+            Events::WalletHint { .. } => EventKind::WalletHint,
+            Events::WalletOpen { .. } => EventKind::WalletOpen,
+            Events::WalletCreate { .. } => EventKind::WalletCreate,
+            Events::WalletReload { .. } => EventKind::WalletReload,
+            Events::WalletError { .. } => EventKind::WalletError,
+            Events::WalletClose => EventKind::WalletClose,
+            Events::PrvKeyDataCreate { .. } => EventKind::PrvKeyDataCreate,
+            Events::AccountActivation { .. } => EventKind::AccountActivation,
+            Events::AccountDeactivation { .. } => EventKind::AccountDeactivation,
+            Events::AccountSelection { .. } => EventKind::AccountSelection,
+            Events::AccountCreate { .. } => EventKind::AccountCreate,
+            Events::AccountUpdate { .. } => EventKind::AccountUpdate,
+            Events::ServerStatus { .. } => EventKind::ServerStatus,
+            Events::UtxoProcStart => EventKind::UtxoProcStart,
+            Events::UtxoProcStop => EventKind::UtxoProcStop,
+            Events::UtxoProcError { .. } => EventKind::UtxoProcError,
+            Events::DAAScoreChange { .. } => EventKind::DAAScoreChange,
+            Events::Pending { .. } => EventKind::Pending,
+            Events::Reorg { .. } => EventKind::Reorg,
+            Events::Stasis { .. } => EventKind::Stasis,
+            Events::Maturity { .. } => EventKind::Maturity,
+            Events::Discovery { .. } => EventKind::Discovery,
+            Events::Balance { .. } => EventKind::Balance,
+            Events::Error { .. } => EventKind::Error,
+        }
+    }
+}
+
+impl Events {
+    pub fn kind(&self) -> EventKind {
+        self.into()
+    }
+
+    /// Returns `Some(true)` if the event is bound to one of the supplied
+    /// accounts, `Some(false)` if it is bound to other accounts and `None`
+    /// if the event is not specific to an account.
+    pub fn is_bound_to(&self, account_ids: &[AccountId]) -> Option<bool> {
+        let is_account = |id: &AccountId| account_ids.contains(id);
+        let is_context = |id: &UtxoContextId| account_ids.iter().any(|account_id| UtxoContextId::from(account_id) == *id);
+
+        match self {
+            Events::AccountActivation { ids } | Events::AccountDeactivation { ids } => Some(ids.iter().any(is_account)),
+            Events::AccountSelection { id } => id.as_ref().map(is_account),
+            Events::AccountCreate { account_descriptor } | Events::AccountUpdate { account_descriptor } => {
+                Some(is_account(account_descriptor.account_id()))
+            }
+            Events::Pending { record }
+            | Events::Reorg { record }
+            | Events::Stasis { record }
+            | Events::Maturity { record }
+            | Events::Discovery { record } => match record.binding() {
+                Binding::Account(id) => Some(is_account(id)),
+                Binding::Custom(id) => Some(is_context(id)),
+            },
+            Events::Balance { id, .. } => Some(is_context(id)),
+            _ => None,
+        }
+    }
+}
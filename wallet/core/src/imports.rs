@@ -9,7 +9,7 @@ pub use crate::account::{Account, AccountKind, DerivationCapableAccount};
 pub use crate::deterministic::*;
 pub use crate::encryption::{Encryptable, EncryptionKind};
 pub use crate::error::Error;
-pub use crate::events::{Events, SyncState};
+pub use crate::events::{EventKind, Events, SyncState};
 pub use crate::factory::{factories, Factory};
 pub use crate::result::Result;
 pub use crate::rpc::Rpc;
@@ -18,7 +18,7 @@ pub use crate::secret::Secret;
 pub use crate::serializer::*;
 pub use crate::storage::*;
 pub use crate::types::*;
-pub use crate::utxo::balance::Balance;
+pub use crate::utxo::balance::{AssetBalance, Balance};
 pub use crate::utxo::scan::{Scan, ScanExtent};
 pub use crate::utxo::{Maturity, OutgoingTransaction, UtxoContext, UtxoEntryReference, UtxoProcessor};
 pub use crate::wallet::*;
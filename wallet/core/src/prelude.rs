@@ -8,13 +8,15 @@ pub use crate::account::{Account, AccountKind};
 pub use crate::api::*;
 pub use crate::deterministic::{AccountId, AccountStorageKey};
 pub use crate::encryption::EncryptionKind;
-pub use crate::events::{Events, SyncState};
+pub use crate::events::{EventKind, Events, SyncState};
 pub use crate::rpc::{ConnectOptions, ConnectStrategy, DynRpcApi};
 pub use crate::secret::Secret;
 pub use crate::settings::WalletSettings;
-pub use crate::storage::{IdT, Interface, PrvKeyDataId, PrvKeyDataInfo, TransactionId, TransactionRecord, WalletDescriptor};
-pub use crate::tx::{Fees, PaymentDestination, PaymentOutput, PaymentOutputs};
-pub use crate::utxo::balance::{Balance, BalanceStrings};
+pub use crate::storage::{
+    AddressBookEntry, IdT, Interface, PrvKeyDataId, PrvKeyDataInfo, TransactionId, TransactionRecord, WalletDescriptor,
+};
+pub use crate::tx::{Fees, PaymentDestination, PaymentOutput, PaymentOutputs, Pskt};
+pub use crate::utxo::balance::{AssetBalance, AssetBalanceStrings, Balance, BalanceStrings};
 pub use crate::wallet::args::*;
 pub use crate::wallet::Wallet;
 pub use kash_addresses::{Address, Prefix as AddressPrefix};
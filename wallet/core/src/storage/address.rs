@@ -3,11 +3,139 @@
 //!
 
 use crate::imports::*;
+use kash_consensus_core::asset_type::AssetType;
 
-// TODO
-#[derive(Debug, Clone, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+/// Labeled address (contact) stored in the wallet address book.
+/// Address book entries are stored in the encrypted wallet
+/// payload alongside private key data and accounts.
+/// Entries are identified by their alias, which is unique
+/// (case-insensitive) within the address book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AddressBookEntry {
     pub alias: String,
     pub title: String,
     pub address: Address,
+    pub network_type: NetworkType,
+    pub preferred_asset: Option<AssetType>,
+    pub notes: Option<String>,
+}
+
+impl AddressBookEntry {
+    pub fn try_new(alias: &str, address: Address) -> Result<Self> {
+        let alias = alias.trim();
+        if alias.is_empty() {
+            return Err(Error::AddressBookInvalidAlias(alias.to_string()));
+        }
+
+        let network_type = NetworkType::try_from(address.prefix)?;
+        Ok(Self { alias: alias.to_string(), title: String::new(), address, network_type, preferred_asset: None, notes: None })
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn with_preferred_asset(mut self, preferred_asset: Option<AssetType>) -> Self {
+        self.preferred_asset = preferred_asset;
+        self
+    }
+
+    pub fn with_notes(mut self, notes: Option<String>) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// Returns `true` if the entry alias matches the supplied alias (case-insensitive).
+    pub fn is_alias(&self, alias: &str) -> bool {
+        self.alias.eq_ignore_ascii_case(alias.trim())
+    }
+
+    /// Returns `true` if the supplied (case-insensitive) search string
+    /// is contained in the alias, title or address of the entry.
+    pub fn matches(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        self.alias.to_lowercase().contains(&search)
+            || self.title.to_lowercase().contains(&search)
+            || self.address.to_string().contains(&search)
+    }
+
+    /// Ensures that the entry alias is valid and that the network
+    /// tag matches the network of the stored address.
+    pub fn validate(&self) -> Result<()> {
+        if self.alias.trim().is_empty() {
+            return Err(Error::AddressBookInvalidAlias(self.alias.clone()));
+        }
+
+        if Prefix::from(self.network_type) != self.address.prefix {
+            return Err(Error::AddressBookNetworkMismatch(self.alias.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Storable for AddressBookEntry {
+    const STORAGE_MAGIC: u32 = 0x4b4f4f42;
+    const STORAGE_VERSION: u32 = 0;
+}
+
+impl BorshSerialize for AddressBookEntry {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+
+        BorshSerialize::serialize(&self.alias, writer)?;
+        BorshSerialize::serialize(&self.title, writer)?;
+        BorshSerialize::serialize(&self.address, writer)?;
+        BorshSerialize::serialize(&self.network_type, writer)?;
+        BorshSerialize::serialize(&self.preferred_asset, writer)?;
+        BorshSerialize::serialize(&self.notes, writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for AddressBookEntry {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+
+        let alias = BorshDeserialize::deserialize(buf)?;
+        let title = BorshDeserialize::deserialize(buf)?;
+        let address = BorshDeserialize::deserialize(buf)?;
+        let network_type = BorshDeserialize::deserialize(buf)?;
+        let preferred_asset = BorshDeserialize::deserialize(buf)?;
+        let notes = BorshDeserialize::deserialize(buf)?;
+
+        Ok(Self { alias, title, address, network_type, preferred_asset, notes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_storage_address_book_entry() -> Result<()> {
+        let address = Address::try_from("kash:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xj7xendmzj")?;
+        let storable_in = AddressBookEntry::try_new("alice", address)?
+            .with_title("Alice")
+            .with_preferred_asset(Some(AssetType::KUSD))
+            .with_notes(Some("rent".to_string()));
+        let guard = StorageGuard::new(&storable_in);
+        let storable_out = guard.validate()?;
+
+        assert_eq!(storable_in.alias, storable_out.alias);
+        assert_eq!(storable_in.title, storable_out.title);
+        assert_eq!(storable_in.address, storable_out.address);
+        assert_eq!(storable_in.network_type, storable_out.network_type);
+        assert_eq!(storable_in.preferred_asset, storable_out.preferred_asset);
+        assert_eq!(storable_in.notes, storable_out.notes);
+        assert!(storable_out.is_alias("Alice"));
+        storable_out.validate()?;
+
+        Ok(())
+    }
 }
@@ -81,6 +81,19 @@ pub trait AddressBookStore: Send + Sync {
     async fn search(&self, _search: &str) -> Result<Vec<Arc<AddressBookEntry>>> {
         Err(Error::NotImplemented)
     }
+    async fn load_single(&self, _alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        Err(Error::NotImplemented)
+    }
+    async fn store_single(&self, _entry: &AddressBookEntry) -> Result<()> {
+        Err(Error::NotImplemented)
+    }
+    async fn remove(&self, _aliases: &[&str]) -> Result<()> {
+        Err(Error::NotImplemented)
+    }
+    /// Restores a snapshot of the committed address book, discarding the changes of a failed commit
+    async fn restore(&self, _entries: &[Arc<AddressBookEntry>]) -> Result<()> {
+        Err(Error::NotImplemented)
+    }
 }
 
 pub struct TransactionRangeResult {
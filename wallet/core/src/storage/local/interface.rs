@@ -614,9 +614,40 @@ impl AddressBookStore for LocalStoreInner {
             .unwrap()
             .address_book
             .iter()
-            .filter_map(|entry| if entry.alias.contains(search) { Some(Arc::new(entry.clone())) } else { None })
+            .filter_map(|entry| if entry.matches(search) { Some(Arc::new(entry.clone())) } else { None })
             .collect();
 
         Ok(matches)
     }
+
+    async fn load_single(&self, alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        let cache = self.cache.read().unwrap();
+        Ok(cache.address_book.iter().find(|entry| entry.is_alias(alias)).cloned().map(Arc::new))
+    }
+
+    async fn store_single(&self, entry: &AddressBookEntry) -> Result<()> {
+        entry.validate()?;
+        let mut cache = self.cache.write().unwrap();
+        if let Some(existing) = cache.address_book.iter_mut().find(|existing| existing.is_alias(&entry.alias)) {
+            *existing = entry.clone();
+        } else {
+            cache.address_book.push(entry.clone());
+        }
+        self.set_modified(true);
+        Ok(())
+    }
+
+    async fn remove(&self, aliases: &[&str]) -> Result<()> {
+        let mut cache = self.cache.write().unwrap();
+        cache.address_book.retain(|entry| !aliases.iter().any(|alias| entry.is_alias(alias)));
+        self.set_modified(true);
+        Ok(())
+    }
+
+    async fn restore(&self, entries: &[Arc<AddressBookEntry>]) -> Result<()> {
+        let mut cache = self.cache.write().unwrap();
+        cache.address_book = entries.iter().map(|entry| (**entry).clone()).collect();
+        self.set_modified(false);
+        Ok(())
+    }
 }
@@ -22,7 +22,8 @@ pub use binding::Binding;
 pub use hint::Hint;
 pub use id::IdT;
 pub use interface::{
-    AccountStore, Interface, PrvKeyDataStore, StorageDescriptor, TransactionRecordStore, WalletDescriptor, WalletExportOptions,
+    AccountStore, AddressBookStore, Interface, PrvKeyDataStore, StorageDescriptor, TransactionRecordStore, WalletDescriptor,
+    WalletExportOptions,
 };
 pub use keydata::{AssocPrvKeyDataIds, PrvKeyData, PrvKeyDataId, PrvKeyDataInfo, PrvKeyDataMap, PrvKeyDataPayload};
 pub use local::interface::make_filename;
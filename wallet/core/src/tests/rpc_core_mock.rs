@@ -81,6 +81,9 @@ impl RpcApi for RpcCoreMock {
             is_synced: false,
             has_notify_command: false,
             has_message_id: false,
+            mempool_asset_policies: vec![],
+            maximum_mint_transactions_per_block: u64::MAX,
+            maximum_redeem_transactions_per_block: u64::MAX,
         })
     }
 
@@ -234,6 +237,25 @@ impl RpcApi for RpcCoreMock {
         Err(RpcError::NotImplemented)
     }
 
+    async fn get_price_call(&self, _request: GetPriceRequest) -> RpcResult<GetPriceResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transaction_call(&self, _request: GetTransactionRequest) -> RpcResult<GetTransactionResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_transactions_by_address_call(
+        &self,
+        _request: GetTransactionsByAddressRequest,
+    ) -> RpcResult<GetTransactionsByAddressResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
+    async fn get_utxo_set_call(&self, _request: GetUtxoSetRequest) -> RpcResult<GetUtxoSetResponse> {
+        Err(RpcError::NotImplemented)
+    }
+
     // ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
     // Notification API
 
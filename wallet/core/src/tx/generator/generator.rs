@@ -208,6 +208,8 @@ struct Inner {
     signer: Option<Arc<dyn SignerT>>,
     mass_calculator: MassCalculator,
     network_type: NetworkType,
+    // version of generated transactions
+    transaction_version: u16,
 
     // Source Utxo Context (Used for source UtxoEntry aggregation)
     source_utxo_context: Option<UtxoContext>,
@@ -257,6 +259,7 @@ impl Generator {
     pub fn try_new(settings: GeneratorSettings, signer: Option<Arc<dyn SignerT>>, abortable: Option<&Abortable>) -> Result<Self> {
         let GeneratorSettings {
             network_type,
+            transaction_version,
             multiplexer,
             utxo_iterator,
             source_utxo_context: utxo_context,
@@ -333,6 +336,7 @@ impl Generator {
 
         let inner = Inner {
             network_type,
+            transaction_version,
             multiplexer,
             context,
             signer,
@@ -685,7 +689,7 @@ impl Generator {
                 }
 
                 let tx = Transaction::new(
-                    0,
+                    self.inner.transaction_version,
                     inputs,
                     final_outputs,
                     transaction_action,
@@ -732,7 +736,16 @@ impl Generator {
                 let output_value = aggregate_input_value - transaction_fees;
                 let script_public_key = pay_to_address_script(&self.inner.change_address);
                 let output = TransactionOutput::new(output_value, script_public_key.clone(), output_asset_type);
-                let tx = Transaction::new(0, inputs, vec![output], transaction_action, 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+                let tx = Transaction::new(
+                    self.inner.transaction_version,
+                    inputs,
+                    vec![output],
+                    transaction_action,
+                    0,
+                    SUBNETWORK_ID_NATIVE,
+                    0,
+                    vec![],
+                );
                 context.number_of_transactions += 1;
 
                 let utxo_entry_reference = Self::create_batch_utxo_entry_reference(
@@ -834,7 +847,7 @@ fn script_engine_validator(generator : &Generator, tx: &Transaction, utxo_entry_
     let tx = PopulatedTransaction::new(signed_tx.as_ref(),entries);
     let mut reused_values = SigHashReusedValues::new();
     for (i, (input, entry)) in tx.populated_inputs().enumerate() {
-        match TxScriptEngine::from_transaction_input(&tx, input, i, entry, &mut reused_values, &sig_cache)
+        match TxScriptEngine::from_transaction_input(&tx, input, i, entry, &mut reused_values, &sig_cache, false)
             .map_err(TxRuleError::SignatureInvalid).map_err(|e|e.to_string()) {
                 Ok(mut engine) => {
                     match engine.execute().map_err(TxRuleError::SignatureInvalid).map_err(|e|e.to_string()) {
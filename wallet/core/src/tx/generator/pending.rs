@@ -8,6 +8,7 @@ use crate::result::Result;
 use crate::rpc::DynRpcApi;
 use crate::tx::{DataKind, Generator};
 use crate::utxo::{UtxoContext, UtxoEntryReference};
+use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::sign::sign_with_multiple_v2;
 use kash_consensus_core::tx::{SignableTransaction, Transaction, TransactionId};
 use kash_rpc_core::{RpcTransaction, RpcTransactionId};
@@ -150,6 +151,16 @@ impl PendingTransaction {
         self.inner.change_output_value
     }
 
+    /// Asset type of the UTXOs consumed by the transaction
+    pub fn asset_type(&self) -> AssetType {
+        self.inner.signable_tx.lock().unwrap().tx.action.asset_transfer_types().0
+    }
+
+    /// Asset type of the outputs created by the transaction
+    pub fn destination_asset_type(&self) -> AssetType {
+        self.inner.signable_tx.lock().unwrap().tx.action.asset_transfer_types().1
+    }
+
     pub fn is_final(&self) -> bool {
         self.inner.kind.is_final()
     }
@@ -7,8 +7,10 @@ use crate::events::Events;
 use crate::imports::*;
 use crate::result::Result;
 use crate::tx::{Fees, PaymentDestination};
-use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoIterator};
+use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoIterator, UtxoProcessor};
 use kash_addresses::Address;
+use kash_consensus_core::config::params::Params;
+use kash_consensus_core::constants::TX_VERSION;
 use kash_consensus_core::tx::TransactionAction;
 use std::sync::Arc;
 use workflow_core::channel::Multiplexer;
@@ -16,6 +18,8 @@ use workflow_core::channel::Multiplexer;
 pub struct GeneratorSettings {
     // Network type
     pub network_type: NetworkType,
+    // version of generated transactions
+    pub transaction_version: u16,
     // Event multiplexer
     pub multiplexer: Option<Multiplexer<Box<Events>>>,
     // Utxo iterator
@@ -49,6 +53,7 @@ impl GeneratorSettings {
         final_transaction_payload: Option<Vec<u8>>,
     ) -> Result<Self> {
         let network_type = account.utxo_context().processor().network_id()?.into();
+        let transaction_version = virtual_transaction_version(account.utxo_context().processor())?;
         let change_address = account.change_address()?;
         let multiplexer = account.wallet().multiplexer().clone();
         let sig_op_count = account.sig_op_count();
@@ -58,6 +63,7 @@ impl GeneratorSettings {
 
         let settings = GeneratorSettings {
             network_type,
+            transaction_version,
             multiplexer: Some(multiplexer),
             sig_op_count,
             minimum_signatures,
@@ -86,10 +92,12 @@ impl GeneratorSettings {
         multiplexer: Option<Multiplexer<Box<Events>>>,
     ) -> Result<Self> {
         let network_type = utxo_context.processor().network_id()?.into();
+        let transaction_version = virtual_transaction_version(utxo_context.processor())?;
         let utxo_iterator = UtxoIterator::new(&utxo_context);
 
         let settings = GeneratorSettings {
             network_type,
+            transaction_version,
             multiplexer,
             sig_op_count,
             minimum_signatures,
@@ -106,6 +114,9 @@ impl GeneratorSettings {
         Ok(settings)
     }
 
+    /// Creates settings for a generator consuming the supplied UTXO entries. Since the
+    /// virtual DAA score is not known here, transactions use the legacy version unless
+    /// overridden via [`GeneratorSettings::with_transaction_version()`].
     pub fn try_new_with_iterator(
         utxo_iterator: Box<dyn Iterator<Item = UtxoEntryReference> + Send + Sync + 'static>,
         change_address: Address,
@@ -121,6 +132,7 @@ impl GeneratorSettings {
 
         let settings = GeneratorSettings {
             network_type,
+            transaction_version: TX_VERSION,
             multiplexer,
             sig_op_count,
             minimum_signatures,
@@ -141,4 +153,17 @@ impl GeneratorSettings {
         self.destination_utxo_context = Some(destination_utxo_context.clone());
         self
     }
+
+    pub fn with_transaction_version(mut self, transaction_version: u16) -> Self {
+        self.transaction_version = transaction_version;
+        self
+    }
+}
+
+/// Selects the transaction version required by the network consensus
+/// params at the virtual DAA score known to the processor. The legacy
+/// version is used while the processor has not received a DAA score yet.
+fn virtual_transaction_version(processor: &UtxoProcessor) -> Result<u16> {
+    let params = Params::from(processor.network_id()?);
+    Ok(processor.current_daa_score().map(|daa_score| params.transaction_version(daa_score)).unwrap_or(TX_VERSION))
 }
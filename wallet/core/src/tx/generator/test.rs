@@ -7,6 +7,8 @@ use crate::utxo::UtxoEntryReference;
 use crate::{tx::PaymentOutputs, utils::kash_to_sompi};
 use kash_addresses::Address;
 use kash_consensus_core::asset_type::AssetType::KSH;
+use kash_consensus_core::config::params::Params;
+use kash_consensus_core::constants::{TX_VERSION, TX_VERSION_ASSET_COMMITMENT};
 use kash_consensus_core::network::NetworkType;
 use kash_consensus_core::tx::{Transaction, TransactionAction};
 use std::cell::RefCell;
@@ -308,6 +310,7 @@ where
 
     let settings = GeneratorSettings {
         network_type,
+        transaction_version: TX_VERSION,
         multiplexer,
         sig_op_count,
         minimum_signatures,
@@ -394,6 +397,34 @@ fn test_generator_sweep_two_utxos() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_generator_transaction_version() -> Result<()> {
+    let network_type = NetworkType::Testnet;
+    let mut params = Params::from(network_type);
+    params.asset_commitment_activation_daa_score = 1000;
+
+    for (daa_score, expected_version) in [(999, TX_VERSION), (1000, TX_VERSION_ASSET_COMMITMENT)] {
+        let utxo_entries = [10.0, 10.0].into_iter().map(kash_to_sompi).map(UtxoEntryReference::simulated).collect::<Vec<_>>();
+        let settings = GeneratorSettings::try_new_with_iterator(
+            Box::new(utxo_entries.into_iter()),
+            change_address(network_type),
+            0,
+            0,
+            TransactionAction::TransferKSH,
+            PaymentOutputs::from([(output_address(network_type), kash_to_sompi(5.0), KSH)].as_slice()).into(),
+            Fees::None,
+            None,
+            None,
+        )?
+        .with_transaction_version(params.transaction_version(daa_score));
+        let generator = Generator::try_new(settings, None, None)?;
+        let (_, tx) = generator.generate_transaction()?.unwrap().tuple();
+        assert_eq!(tx.version, expected_version);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_generator_sweep_two_utxos_with_priority_fees_rejection() -> Result<()> {
     let network_type = NetworkType::Testnet;
@@ -7,9 +7,11 @@ pub mod fees;
 pub mod generator;
 pub mod mass;
 pub mod payment;
+pub mod pskt;
 
 pub use consensus::*;
 pub use fees::*;
 pub use generator::*;
 pub use mass::*;
 pub use payment::*;
+pub use pskt::*;
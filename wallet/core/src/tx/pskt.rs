@@ -0,0 +1,517 @@
+//!
+//! Partially signed transactions (PSKT) carrying an unsigned
+//! transaction along with everything required by its signers,
+//! allowing (possibly air-gapped) cosigners to sign it independently.
+//!
+
+use crate::derivation::create_multisig_redeem_script;
+use crate::imports::*;
+use crate::tx::PendingTransaction;
+use kash_addresses::Version as AddressVersion;
+use kash_consensus_core::asset_type::AssetType;
+use kash_consensus_core::hashing::sighash::{calc_ecdsa_signature_hash, calc_schnorr_signature_hash, SigHashReusedValues};
+use kash_consensus_core::hashing::sighash_type::SIG_HASH_ALL;
+use kash_consensus_core::tx::{ScriptPublicKey, SignableTransaction, Transaction, TransactionOutpoint, UtxoEntry};
+use kash_txscript::{
+    extract_script_pub_key_address, pay_to_address_script, pay_to_script_hash_script, pay_to_script_hash_signature_script,
+};
+use secp256k1::{ecdsa, schnorr, KeyPair, Message, PublicKey, SecretKey, SECP256K1};
+use std::iter::once;
+
+/// Location of the address of a [`PsktInput`] within the derivation of its account
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PsktDerivation {
+    pub change: bool,
+    pub index: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PsktInput {
+    /// UTXO spent by the input, including its asset type
+    pub utxo_entry: UtxoEntry,
+    pub address: Address,
+    pub derivation: PsktDerivation,
+    /// Compressed public keys able to sign the input, ordered as in the redeem script
+    pub public_keys: Vec<Vec<u8>>,
+    /// Redeem script of inputs spending from a multisig address
+    pub redeem_script: Option<Vec<u8>>,
+    pub minimum_signatures: u16,
+    pub ecdsa: bool,
+    /// Signatures collected so far (followed by their sighash type), one slot per public key
+    pub signatures: Vec<Option<Vec<u8>>>,
+}
+
+impl PsktInput {
+    pub fn signature_count(&self) -> usize {
+        self.signatures.iter().flatten().count()
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.signature_count() >= self.minimum_signatures as usize
+    }
+
+    /// The script public key locking funds to the keys of the input, `None` if its keys do not match its redeem script
+    fn script_public_key(&self) -> Option<ScriptPublicKey> {
+        let public_keys = self.public_keys.iter().map(|key| PublicKey::from_slice(key).ok()).collect::<Option<Vec<_>>>()?;
+        match (&self.redeem_script, public_keys.as_slice()) {
+            (Some(redeem_script), _) => {
+                let expected = create_multisig_redeem_script(self.minimum_signatures as usize, &public_keys, self.ecdsa).ok()?;
+                (expected == *redeem_script).then(|| pay_to_script_hash_script(redeem_script))
+            }
+            (None, [public_key]) if self.minimum_signatures == 1 => {
+                let address = if self.ecdsa {
+                    Address::new(self.address.prefix, AddressVersion::PubKeyECDSA, &public_key.serialize())
+                } else {
+                    Address::new(self.address.prefix, AddressVersion::PubKey, &public_key.x_only_public_key().0.serialize())
+                };
+                Some(pay_to_address_script(&address))
+            }
+            _ => None,
+        }
+    }
+
+    fn signature_script(&self, index: usize) -> Result<Vec<u8>> {
+        let required = self.minimum_signatures as usize;
+        let signatures = self.signatures.iter().flatten().take(required).collect::<Vec<_>>();
+        if signatures.len() < required {
+            return Err(Error::PsktMissingSignatures { input: index, collected: signatures.len(), required });
+        }
+
+        // OpCheckMultiSig expects the signatures in the order of the redeem script public keys,
+        // each pushed as OP_DATA_65 <SIGNATURE+SIGHASH_TYPE>
+        let signatures = signatures.into_iter().flat_map(|signature| once(65u8).chain(signature.iter().copied())).collect::<Vec<_>>();
+        match &self.redeem_script {
+            Some(redeem_script) => Ok(pay_to_script_hash_signature_script(redeem_script.clone(), signatures)?),
+            None => Ok(signatures),
+        }
+    }
+}
+
+/// Partially signed transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pskt {
+    pub network_id: NetworkId,
+    /// Transaction without signature scripts
+    pub transaction: Transaction,
+    /// Signing data of each transaction input, in the order of the inputs
+    pub inputs: Vec<PsktInput>,
+}
+
+impl Pskt {
+    const STORAGE_MAGIC: u32 = 0x544b5350;
+    const STORAGE_VERSION: u32 = 0;
+
+    pub fn new(network_id: NetworkId, transaction: Transaction, inputs: Vec<PsktInput>) -> Self {
+        Self { network_id, transaction, inputs }
+    }
+
+    /// Creates a PSKT from a transaction produced by the [`Generator`](crate::tx::Generator) of `account`
+    pub fn try_from_pending_transaction(
+        pending_transaction: &PendingTransaction,
+        account: &Arc<dyn DerivationCapableAccount>,
+    ) -> Result<Self> {
+        let network_id = account.wallet().network_id()?;
+        let derivation = account.derivation();
+        let receive_address_manager = derivation.receive_address_manager();
+        let change_address_manager = derivation.change_address_manager();
+
+        let mut transaction = pending_transaction.transaction();
+        let mut inputs = Vec::with_capacity(transaction.inputs.len());
+        for (index, input) in transaction.inputs.iter_mut().enumerate() {
+            input.signature_script.clear();
+
+            let utxo = pending_transaction
+                .utxo_entries()
+                .iter()
+                .find(|utxo| TransactionOutpoint::from(utxo.utxo.outpoint.clone()) == input.previous_outpoint)
+                .ok_or(Error::PsktUnknownInput(index))?;
+            let address = utxo.utxo.address.clone().ok_or(Error::PsktUnknownInput(index))?;
+
+            let receive_index = receive_address_manager.inner().address_to_index_map.get(&address).copied();
+            let (address_manager, derivation) = match receive_index {
+                Some(index) => (&receive_address_manager, PsktDerivation { change: false, index }),
+                None => {
+                    let index = change_address_manager
+                        .inner()
+                        .address_to_index_map
+                        .get(&address)
+                        .copied()
+                        .ok_or(Error::PsktUnknownInput(index))?;
+                    (&change_address_manager, PsktDerivation { change: true, index })
+                }
+            };
+
+            let public_keys = address_manager.get_public_keys(derivation.index)?;
+            let (redeem_script, minimum_signatures) = if public_keys.len() > 1 {
+                let minimum_signatures = account.minimum_signatures();
+                (
+                    Some(create_multisig_redeem_script(minimum_signatures as usize, &public_keys, address_manager.ecdsa)?),
+                    minimum_signatures,
+                )
+            } else {
+                (None, 1)
+            };
+
+            inputs.push(PsktInput {
+                utxo_entry: utxo.utxo.entry.clone(),
+                address,
+                derivation,
+                signatures: vec![None; public_keys.len()],
+                public_keys: public_keys.iter().map(|public_key| public_key.serialize().to_vec()).collect(),
+                redeem_script,
+                minimum_signatures,
+                ecdsa: address_manager.ecdsa,
+            });
+        }
+        transaction.finalize();
+
+        Ok(Self::new(network_id, transaction, inputs))
+    }
+
+    pub fn id(&self) -> TransactionId {
+        self.transaction.id()
+    }
+
+    /// Asset type of the UTXOs spent by the transaction
+    pub fn asset_type(&self) -> AssetType {
+        self.transaction.action.asset_transfer_types().0
+    }
+
+    /// Total amount of the spent UTXOs
+    pub fn input_amount(&self) -> u64 {
+        self.inputs.iter().map(|input| input.utxo_entry.amount).sum()
+    }
+
+    /// Total amount of the transaction outputs
+    pub fn output_amount(&self) -> u64 {
+        self.transaction.outputs.iter().map(|output| output.value).sum()
+    }
+
+    /// Destination address of each transaction output along with its amount, `None` for non-standard scripts
+    pub fn output_destinations(&self) -> Vec<(Option<Address>, u64)> {
+        let prefix = Prefix::from(self.network_id);
+        self.transaction
+            .outputs
+            .iter()
+            .map(|output| (extract_script_pub_key_address(&output.script_public_key, prefix).ok(), output.value))
+            .collect()
+    }
+
+    /// Fees paid by the transaction, in its [`asset_type`](Self::asset_type)
+    pub fn fees(&self) -> u64 {
+        self.input_amount().saturating_sub(self.output_amount())
+    }
+
+    /// Checks that the keys and redeem script of every input, as well as its address, match the script
+    /// of the spent UTXO, so a signer is not led to sign for funds other than the ones displayed
+    pub fn validate(&self) -> Result<()> {
+        for (index, input) in self.inputs.iter().enumerate() {
+            let script_public_key = &input.utxo_entry.script_public_key;
+            if input.script_public_key().as_ref() != Some(script_public_key)
+                || pay_to_address_script(&input.address) != *script_public_key
+            {
+                return Err(Error::PsktScriptMismatch(index));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_fully_signed(&self) -> bool {
+        self.inputs.iter().all(|input| input.is_signed())
+    }
+
+    fn signable_transaction(&self) -> SignableTransaction {
+        let entries = self.inputs.iter().map(|input| input.utxo_entry.clone()).collect();
+        SignableTransaction::with_entries(self.transaction.clone(), entries)
+    }
+
+    fn signature_hash(
+        signable_tx: &SignableTransaction,
+        index: usize,
+        ecdsa: bool,
+        reused_values: &mut SigHashReusedValues,
+    ) -> Message {
+        let sig_hash = if ecdsa {
+            calc_ecdsa_signature_hash(&signable_tx.as_verifiable(), index, SIG_HASH_ALL, reused_values)
+        } else {
+            calc_schnorr_signature_hash(&signable_tx.as_verifiable(), index, SIG_HASH_ALL, reused_values)
+        };
+        Message::from_slice(sig_hash.as_bytes().as_slice()).expect("signature hashes are 32 bytes long")
+    }
+
+    /// Adds the signatures of `private_keys` to the inputs they are able to sign, returning the number of added signatures
+    pub fn sign(&mut self, private_keys: &[SecretKey]) -> Result<usize> {
+        self.validate()?;
+        let signable_tx = self.signable_transaction();
+        let mut reused_values = SigHashReusedValues::new();
+        let mut count = 0;
+        for (index, input) in self.inputs.iter_mut().enumerate() {
+            for private_key in private_keys.iter() {
+                let public_key = PublicKey::from_secret_key(SECP256K1, private_key).serialize();
+                let Some(slot) = input.public_keys.iter().position(|key| key.as_slice() == public_key.as_slice()) else {
+                    continue;
+                };
+                if input.signatures[slot].is_some() {
+                    continue;
+                }
+
+                let msg = Self::signature_hash(&signable_tx, index, input.ecdsa, &mut reused_values);
+                let signature = if input.ecdsa {
+                    private_key.sign_ecdsa(msg).serialize_compact().to_vec()
+                } else {
+                    KeyPair::from_secret_key(SECP256K1, private_key).sign_schnorr(msg).as_ref().to_vec()
+                };
+                input.signatures[slot] = Some(signature.into_iter().chain(once(SIG_HASH_ALL.to_u8())).collect());
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Merges the signatures collected by `other`, a PSKT of the same transaction,
+    /// returning the number of added signatures. Invalid signatures are ignored.
+    pub fn combine(&mut self, other: &Pskt) -> Result<usize> {
+        if self.network_id != other.network_id || self.id() != other.id() || self.inputs.len() != other.inputs.len() {
+            return Err(Error::PsktTransactionMismatch);
+        }
+
+        let signable_tx = self.signable_transaction();
+        let mut reused_values = SigHashReusedValues::new();
+        let mut count = 0;
+        for (index, (input, other)) in self.inputs.iter_mut().zip(other.inputs.iter()).enumerate() {
+            if input.public_keys != other.public_keys || input.signatures.len() != other.signatures.len() {
+                return Err(Error::PsktTransactionMismatch);
+            }
+            for (slot, signature) in other.signatures.iter().enumerate() {
+                let Some(signature) = signature else {
+                    continue;
+                };
+                if input.signatures[slot].is_some() {
+                    continue;
+                }
+                let msg = Self::signature_hash(&signable_tx, index, input.ecdsa, &mut reused_values);
+                if Self::verify_signature(&input.public_keys[slot], signature, &msg, input.ecdsa) {
+                    input.signatures[slot] = Some(signature.clone());
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn verify_signature(public_key: &[u8], signature: &[u8], msg: &Message, ecdsa: bool) -> bool {
+        let (Ok(public_key), Some(signature)) = (PublicKey::from_slice(public_key), signature.get(..64)) else {
+            return false;
+        };
+        if ecdsa {
+            ecdsa::Signature::from_compact(signature)
+                .is_ok_and(|signature| SECP256K1.verify_ecdsa(msg, &signature, &public_key).is_ok())
+        } else {
+            let public_key = public_key.x_only_public_key().0;
+            schnorr::Signature::from_slice(signature)
+                .is_ok_and(|signature| SECP256K1.verify_schnorr(&signature, msg, &public_key).is_ok())
+        }
+    }
+
+    /// Builds the signature scripts of the inputs, returning the transaction ready to be submitted
+    pub fn finalize(&self) -> Result<Transaction> {
+        let mut transaction = self.transaction.clone();
+        for (index, (input, pskt_input)) in transaction.inputs.iter_mut().zip(self.inputs.iter()).enumerate() {
+            input.signature_script = pskt_input.signature_script(index)?;
+        }
+        transaction.finalize();
+        Ok(transaction)
+    }
+
+    pub fn to_hex(&self) -> Result<String> {
+        Ok(self.try_to_vec()?.to_hex())
+    }
+
+    pub fn try_from_hex(hex: &str) -> Result<Self> {
+        let bytes = Vec::<u8>::from_hex(hex.trim())?;
+        Ok(Self::try_from_slice(bytes.as_slice())?)
+    }
+}
+
+impl BorshSerialize for Pskt {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        StorageHeader::new(Self::STORAGE_MAGIC, Self::STORAGE_VERSION).serialize(writer)?;
+
+        BorshSerialize::serialize(&self.network_id, writer)?;
+        BorshSerialize::serialize(&self.transaction, writer)?;
+        BorshSerialize::serialize(&self.inputs, writer)?;
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for Pskt {
+    fn deserialize(buf: &mut &[u8]) -> IoResult<Self> {
+        let StorageHeader { version: _, .. } =
+            StorageHeader::deserialize(buf)?.try_magic(Self::STORAGE_MAGIC)?.try_version(Self::STORAGE_VERSION)?;
+
+        let network_id = BorshDeserialize::deserialize(buf)?;
+        let mut transaction: Transaction = BorshDeserialize::deserialize(buf)?;
+        transaction.finalize();
+        let inputs: Vec<PsktInput> = BorshDeserialize::deserialize(buf)?;
+
+        if inputs.len() != transaction.inputs.len() || inputs.iter().any(|input| input.signatures.len() != input.public_keys.len()) {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "PSKT inputs do not match its transaction"));
+        }
+
+        Ok(Self { network_id, transaction, inputs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kash_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+    use kash_consensus_core::tx::{TransactionAction, TransactionInput, TransactionOutput, VerifiableTransaction};
+    use kash_txscript::{caches::Cache, TxScriptEngine};
+    use std::str::FromStr;
+
+    /// A PSKT spending a UTXO locked to `keys`, with a redeem script unless a single signature of a single key is required
+    fn create_pskt(keys: &[SecretKey], minimum_signatures: u16, ecdsa: bool) -> Pskt {
+        let public_keys = keys.iter().map(|key| PublicKey::from_secret_key(SECP256K1, key)).collect::<Vec<_>>();
+        let redeem_script =
+            (public_keys.len() > 1).then(|| create_multisig_redeem_script(minimum_signatures as usize, &public_keys, ecdsa).unwrap());
+        let script_public_key = match &redeem_script {
+            Some(redeem_script) => pay_to_script_hash_script(redeem_script),
+            None if ecdsa => {
+                pay_to_address_script(&Address::new(Prefix::Mainnet, AddressVersion::PubKeyECDSA, &public_keys[0].serialize()))
+            }
+            None => pay_to_address_script(&Address::new(
+                Prefix::Mainnet,
+                AddressVersion::PubKey,
+                &public_keys[0].x_only_public_key().0.serialize(),
+            )),
+        };
+        let utxo_entry = UtxoEntry {
+            amount: 12793000000000,
+            script_public_key,
+            block_daa_score: 36151168,
+            is_coinbase: false,
+            asset_type: AssetType::KSH,
+        };
+        let address = extract_script_pub_key_address(&utxo_entry.script_public_key, Prefix::Mainnet).unwrap();
+        let transaction = Transaction::new(
+            0,
+            vec![TransactionInput {
+                previous_outpoint: TransactionOutpoint {
+                    transaction_id: TransactionId::from_str("63020db736215f8b1105a9281f7bcbb6473d965ecc45bb2fb5da59bd35e6ff84")
+                        .unwrap(),
+                    index: 0,
+                },
+                signature_script: vec![],
+                sequence: 0,
+                sig_op_count: public_keys.len() as u8,
+            }],
+            vec![TransactionOutput::new(12792999990000, utxo_entry.script_public_key.clone(), AssetType::KSH)],
+            TransactionAction::TransferKSH,
+            0,
+            SUBNETWORK_ID_NATIVE,
+            0,
+            vec![],
+        );
+        let input = PsktInput {
+            utxo_entry,
+            address,
+            derivation: PsktDerivation { change: false, index: 0 },
+            public_keys: public_keys.iter().map(|key| key.serialize().to_vec()).collect(),
+            redeem_script,
+            minimum_signatures,
+            ecdsa,
+            signatures: vec![None; public_keys.len()],
+        };
+        Pskt::new(NetworkId::new(NetworkType::Mainnet), transaction, vec![input])
+    }
+
+    /// Runs the script engine over the single input of a finalized PSKT
+    fn execute_script(pskt: &Pskt) {
+        let transaction = pskt.finalize().unwrap();
+        assert_eq!(transaction.id(), pskt.id());
+        let signable_tx = SignableTransaction::with_entries(transaction, vec![pskt.inputs[0].utxo_entry.clone()]);
+        let tx = signable_tx.as_verifiable();
+        let (input, entry) = tx.populated_inputs().next().unwrap();
+        let cache = Cache::new(10_000);
+        let mut reused_values = SigHashReusedValues::new();
+        let mut engine = TxScriptEngine::from_transaction_input(&tx, input, 0, entry, &mut reused_values, &cache, false).unwrap();
+        assert!(engine.execute().is_ok());
+    }
+
+    #[test]
+    fn test_pskt_multisig_flow() {
+        for ecdsa in [false, true] {
+            let keys = (0..3).map(|_| SecretKey::new(&mut rand::thread_rng())).collect::<Vec<_>>();
+            let pskt = create_pskt(&keys, 2, ecdsa);
+            assert_eq!(pskt.fees(), 10000);
+            assert_eq!(pskt.output_destinations(), vec![(Some(pskt.inputs[0].address.clone()), 12792999990000)]);
+
+            // Each cosigner signs a copy of the exported PSKT on its own
+            let mut first = Pskt::try_from_hex(&pskt.to_hex().unwrap()).unwrap();
+            assert_eq!(first.sign(&keys[2..3]).unwrap(), 1);
+            assert!(!first.is_fully_signed());
+            assert!(matches!(first.finalize(), Err(Error::PsktMissingSignatures { input: 0, collected: 1, required: 2 })));
+
+            let mut second = Pskt::try_from_hex(&pskt.to_hex().unwrap()).unwrap();
+            assert_eq!(second.sign(&keys[0..1]).unwrap(), 1);
+            // A key foreign to the input does not sign it
+            assert_eq!(second.sign(&[SecretKey::new(&mut rand::thread_rng())]).unwrap(), 0);
+
+            // Tampered signatures are not combined
+            let mut tampered = second.clone();
+            tampered.inputs[0].signatures[0].as_mut().unwrap()[0] ^= 1;
+            assert_eq!(first.clone().combine(&tampered).unwrap(), 0);
+
+            assert_eq!(first.combine(&second).unwrap(), 1);
+            assert!(first.is_fully_signed());
+            assert!(first.combine(&create_pskt(&keys, 1, ecdsa)).is_err());
+
+            execute_script(&first);
+        }
+    }
+
+    #[test]
+    fn test_pskt_single_signature() {
+        for ecdsa in [false, true] {
+            let key = SecretKey::new(&mut rand::thread_rng());
+            let mut pskt = create_pskt(&[key], 1, ecdsa);
+            assert!(matches!(pskt.finalize(), Err(Error::PsktMissingSignatures { input: 0, collected: 0, required: 1 })));
+            assert_eq!(pskt.sign(&[key]).unwrap(), 1);
+            assert!(pskt.is_fully_signed());
+            execute_script(&pskt);
+        }
+    }
+
+    #[test]
+    fn test_pskt_script_mismatch() {
+        let keys = (0..3).map(|_| SecretKey::new(&mut rand::thread_rng())).collect::<Vec<_>>();
+        let foreign_key = PublicKey::from_secret_key(SECP256K1, &SecretKey::new(&mut rand::thread_rng()));
+        let pskt = create_pskt(&keys, 2, false);
+        assert!(pskt.validate().is_ok());
+
+        // A redeem script other than the one locking the UTXO
+        let mut tampered = pskt.clone();
+        tampered.inputs[0].redeem_script = Some(create_multisig_redeem_script(1, &[foreign_key], false).unwrap());
+        assert!(matches!(tampered.sign(&keys), Err(Error::PsktScriptMismatch(0))));
+
+        // Keys not matching the redeem script
+        let mut tampered = pskt.clone();
+        tampered.inputs[0].public_keys[1] = foreign_key.serialize().to_vec();
+        assert!(matches!(tampered.validate(), Err(Error::PsktScriptMismatch(0))));
+
+        // An address other than the one of the UTXO
+        let mut tampered = pskt.clone();
+        tampered.inputs[0].address = create_pskt(&keys[..1], 1, false).inputs[0].address.clone();
+        assert!(matches!(tampered.validate(), Err(Error::PsktScriptMismatch(0))));
+
+        // A single key other than the one locking the UTXO
+        let mut tampered = create_pskt(&keys[..1], 1, false);
+        tampered.inputs[0].public_keys[0] = foreign_key.serialize().to_vec();
+        assert!(matches!(tampered.sign(&keys), Err(Error::PsktScriptMismatch(0))));
+    }
+}
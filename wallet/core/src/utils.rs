@@ -4,6 +4,7 @@
 
 use crate::result::Result;
 use kash_addresses::Address;
+use kash_consensus_core::asset_type::AssetType;
 use kash_consensus_core::constants::*;
 use kash_consensus_core::network::NetworkType;
 use separator::{separated_float, separated_int, separated_uint_with_output, Separatable};
@@ -59,6 +60,21 @@ pub fn kash_suffix(network_type: &NetworkType) -> &'static str {
     }
 }
 
+/// Display suffix of an asset amount, the network-specific [`kash_suffix`] for KSH
+/// and the asset name prefixed by the network initial for the other assets.
+pub fn asset_suffix(asset_type: AssetType, network_type: &NetworkType) -> String {
+    if asset_type == AssetType::KSH {
+        return kash_suffix(network_type).to_string();
+    }
+    let prefix = match network_type {
+        NetworkType::Mainnet => "",
+        NetworkType::Testnet => "T",
+        NetworkType::Simnet => "S",
+        NetworkType::Devnet => "D",
+    };
+    format!("{prefix}{asset_type}")
+}
+
 #[inline]
 pub fn sompi_to_kash_string_with_suffix(sompi: u64, network_type: &NetworkType) -> String {
     let kas = sompi_to_kash_string(sompi);
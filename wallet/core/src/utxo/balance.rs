@@ -3,6 +3,7 @@
 //!
 
 use crate::imports::*;
+use kash_consensus_core::asset_type::AssetType;
 
 pub enum DeltaStyle {
     Mature,
@@ -43,11 +44,14 @@ impl From<std::cmp::Ordering> for Delta {
     }
 }
 
+/// Balance of a single asset type
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Balance {
+pub struct AssetBalance {
     pub mature: u64,
     pub pending: u64,
+    /// Part of the mature balance consisting of KRV which is still bonding, i.e. can not be redeemed for KSH yet
+    pub bonding: u64,
     pub outgoing: u64,
     pub mature_utxo_count: usize,
     pub pending_utxo_count: usize,
@@ -58,10 +62,11 @@ pub struct Balance {
     pending_delta: Delta,
 }
 
-impl Balance {
+impl AssetBalance {
     pub fn new(
         mature: u64,
         pending: u64,
+        bonding: u64,
         outgoing: u64,
         mature_utxo_count: usize,
         pending_utxo_count: usize,
@@ -70,6 +75,7 @@ impl Balance {
         Self {
             mature,
             pending,
+            bonding,
             outgoing,
             mature_delta: Delta::default(),
             pending_delta: Delta::default(),
@@ -83,7 +89,18 @@ impl Balance {
         self.mature == 0 && self.pending == 0
     }
 
-    pub fn delta(&mut self, previous: &Option<Balance>) {
+    /// Accumulates the amounts and UTXO counts of `other` into this balance
+    pub fn add(&mut self, other: &AssetBalance) {
+        self.mature += other.mature;
+        self.pending += other.pending;
+        self.bonding += other.bonding;
+        self.outgoing += other.outgoing;
+        self.mature_utxo_count += other.mature_utxo_count;
+        self.pending_utxo_count += other.pending_utxo_count;
+        self.stasis_utxo_count += other.stasis_utxo_count;
+    }
+
+    pub fn delta(&mut self, previous: Option<&AssetBalance>) {
         if let Some(previous) = previous {
             self.mature_delta = self.mature.cmp(&previous.mature).into();
             self.pending_delta = self.pending.cmp(&previous.pending).into();
@@ -94,30 +111,93 @@ impl Balance {
     }
 }
 
+/// Balance of a [`UtxoContext`](super::UtxoContext) broken down by asset type
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub ksh: AssetBalance,
+    pub kusd: AssetBalance,
+    pub krv: AssetBalance,
+}
+
+impl Balance {
+    pub fn new(ksh: AssetBalance, kusd: AssetBalance, krv: AssetBalance) -> Self {
+        Self { ksh, kusd, krv }
+    }
+
+    /// Returns the balance of `asset_type`.
+    pub fn get(&self, asset_type: AssetType) -> &AssetBalance {
+        match asset_type {
+            AssetType::KSH => &self.ksh,
+            AssetType::KUSD => &self.kusd,
+            AssetType::KRV => &self.krv,
+        }
+    }
+
+    pub fn get_mut(&mut self, asset_type: AssetType) -> &mut AssetBalance {
+        match asset_type {
+            AssetType::KSH => &mut self.ksh,
+            AssetType::KUSD => &mut self.kusd,
+            AssetType::KRV => &mut self.krv,
+        }
+    }
+
+    /// Iterates the balances of all asset types, in the order of [`AssetType::ALL`]
+    pub fn iter(&self) -> impl Iterator<Item = (AssetType, &AssetBalance)> {
+        AssetType::ALL.into_iter().map(move |asset_type| (asset_type, self.get(asset_type)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(|(_, balance)| balance.is_empty())
+    }
+
+    pub fn mature_utxo_count(&self) -> usize {
+        self.iter().map(|(_, balance)| balance.mature_utxo_count).sum()
+    }
+
+    pub fn pending_utxo_count(&self) -> usize {
+        self.iter().map(|(_, balance)| balance.pending_utxo_count).sum()
+    }
+
+    pub fn stasis_utxo_count(&self) -> usize {
+        self.iter().map(|(_, balance)| balance.stasis_utxo_count).sum()
+    }
+
+    pub fn delta(&mut self, previous: &Option<Balance>) {
+        for asset_type in AssetType::ALL {
+            let previous = previous.as_ref().map(|previous| previous.get(asset_type));
+            self.get_mut(asset_type).delta(previous);
+        }
+    }
+}
+
 #[derive(Default, Debug)]
-pub struct AtomicBalance {
+pub struct AtomicAssetBalance {
     pub mature: AtomicU64,
     pub pending: AtomicU64,
+    pub bonding: AtomicU64,
     pub mature_utxos: AtomicUsize,
     pub pending_utxos: AtomicUsize,
     pub stasis_utxos: AtomicUsize,
 }
 
-impl AtomicBalance {
-    pub fn add(&self, balance: Balance) {
+impl AtomicAssetBalance {
+    pub fn add(&self, balance: &AssetBalance) {
         self.mature.fetch_add(balance.mature, Ordering::SeqCst);
         self.pending.fetch_add(balance.pending, Ordering::SeqCst);
+        self.bonding.fetch_add(balance.bonding, Ordering::SeqCst);
         self.mature_utxos.fetch_add(balance.mature_utxo_count, Ordering::SeqCst);
         self.pending_utxos.fetch_add(balance.pending_utxo_count, Ordering::SeqCst);
         self.stasis_utxos.fetch_add(balance.stasis_utxo_count, Ordering::SeqCst);
     }
 }
 
-impl From<AtomicBalance> for Balance {
-    fn from(atomic_balance: AtomicBalance) -> Self {
+impl From<&AtomicAssetBalance> for AssetBalance {
+    fn from(atomic_balance: &AtomicAssetBalance) -> Self {
         Self {
             mature: atomic_balance.mature.load(Ordering::SeqCst),
             pending: atomic_balance.pending.load(Ordering::SeqCst),
+            bonding: atomic_balance.bonding.load(Ordering::SeqCst),
             outgoing: 0,
             mature_utxo_count: atomic_balance.mature_utxos.load(Ordering::SeqCst),
             pending_utxo_count: atomic_balance.pending_utxos.load(Ordering::SeqCst),
@@ -128,37 +208,153 @@ impl From<AtomicBalance> for Balance {
     }
 }
 
-pub struct BalanceStrings {
+/// Balance accumulated concurrently by the address scanners, broken down by asset type
+#[derive(Default, Debug)]
+pub struct AtomicBalance {
+    pub ksh: AtomicAssetBalance,
+    pub kusd: AtomicAssetBalance,
+    pub krv: AtomicAssetBalance,
+}
+
+impl AtomicBalance {
+    pub fn get(&self, asset_type: AssetType) -> &AtomicAssetBalance {
+        match asset_type {
+            AssetType::KSH => &self.ksh,
+            AssetType::KUSD => &self.kusd,
+            AssetType::KRV => &self.krv,
+        }
+    }
+
+    pub fn add(&self, balance: &Balance) {
+        for (asset_type, balance) in balance.iter().filter(|(_, balance)| !balance.is_empty()) {
+            self.get(asset_type).add(balance);
+        }
+    }
+}
+
+impl From<AtomicBalance> for Balance {
+    fn from(atomic_balance: AtomicBalance) -> Self {
+        Self { ksh: (&atomic_balance.ksh).into(), kusd: (&atomic_balance.kusd).into(), krv: (&atomic_balance.krv).into() }
+    }
+}
+
+#[derive(Clone)]
+pub struct AssetBalanceStrings {
+    pub asset_type: AssetType,
     pub mature: String,
     pub pending: Option<String>,
+    pub bonding: Option<String>,
+}
+
+impl AssetBalanceStrings {
+    fn new(asset_type: AssetType, balance: &AssetBalance, network_type: &NetworkType, padding: Option<usize>) -> Self {
+        let suffix = utils::asset_suffix(asset_type, network_type);
+        let mut mature = utils::sompi_to_kash_string(balance.mature);
+        let mut pending = if balance.pending > 0 { Some(utils::sompi_to_kash_string(balance.pending)) } else { None };
+        let mut bonding = if balance.bonding > 0 { Some(utils::sompi_to_kash_string(balance.bonding)) } else { None };
+        if let Some(padding) = padding {
+            mature = mature.pad_to_width(padding);
+            pending = pending.map(|pending| pending.pad_to_width(padding));
+            bonding = bonding.map(|bonding| bonding.pad_to_width(padding));
+        }
+        Self {
+            asset_type,
+            mature: format!("{} {}", balance.mature_delta.style(&mature, DeltaStyle::Mature), suffix),
+            pending: pending.map(|pending| format!("{} {}", balance.pending_delta.style(&pending, DeltaStyle::Pending), suffix)),
+            bonding: bonding.map(|bonding| format!("{} {}", bonding, suffix)),
+        }
+    }
+}
+
+impl std::fmt::Display for AssetBalanceStrings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.pending, &self.bonding) {
+            (Some(pending), Some(bonding)) => write!(f, "{} ({} pending, {} bonding)", self.mature, pending, bonding),
+            (Some(pending), None) => write!(f, "{} ({} pending)", self.mature, pending),
+            (None, Some(bonding)) => write!(f, "{} ({} bonding)", self.mature, bonding),
+            (None, None) => write!(f, "{}", self.mature),
+        }
+    }
+}
+
+/// Display strings of a [`Balance`]: KSH is always present while the other
+/// assets are only listed when the balance holds some of them.
+pub struct BalanceStrings {
+    pub assets: Vec<AssetBalanceStrings>,
+}
+
+impl BalanceStrings {
+    pub fn get(&self, asset_type: AssetType) -> Option<&AssetBalanceStrings> {
+        self.assets.iter().find(|strings| strings.asset_type == asset_type)
+    }
 }
 
 impl From<(&Option<Balance>, &NetworkType, Option<usize>)> for BalanceStrings {
     fn from((balance, network_type, padding): (&Option<Balance>, &NetworkType, Option<usize>)) -> Self {
-        let suffix = utils::kash_suffix(network_type);
         if let Some(balance) = balance {
-            let mut mature = utils::sompi_to_kash_string(balance.mature);
-            let mut pending = if balance.pending > 0 { Some(utils::sompi_to_kash_string(balance.pending)) } else { None };
-            if let Some(padding) = padding {
-                mature = mature.pad_to_width(padding);
-                pending = pending.map(|pending| pending.pad_to_width(padding));
-            }
+            let assets = balance
+                .iter()
+                .filter(|(asset_type, balance)| *asset_type == AssetType::KSH || !balance.is_empty() || balance.outgoing > 0)
+                .map(|(asset_type, balance)| AssetBalanceStrings::new(asset_type, balance, network_type, padding))
+                .collect();
+            Self { assets }
+        } else {
+            let suffix = utils::asset_suffix(AssetType::KSH, network_type);
             Self {
-                mature: format!("{} {}", balance.mature_delta.style(&mature, DeltaStyle::Mature), suffix),
-                pending: pending.map(|pending| format!("{} {}", balance.pending_delta.style(&pending, DeltaStyle::Pending), suffix)),
+                assets: vec![AssetBalanceStrings {
+                    asset_type: AssetType::KSH,
+                    mature: format!("N/A {suffix}"),
+                    pending: None,
+                    bonding: None,
+                }],
             }
-        } else {
-            Self { mature: format!("N/A {suffix}"), pending: None }
         }
     }
 }
 
 impl std::fmt::Display for BalanceStrings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(pending) = &self.pending {
-            write!(f, "{} ({} pending)", self.mature, pending)
-        } else {
-            write!(f, "{}", self.mature)
-        }
+        let assets = self.assets.iter().map(|strings| strings.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", assets.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balance_by_asset_type() {
+        let mut balance = Balance::default();
+        balance.get_mut(AssetType::KSH).add(&AssetBalance::new(5, 0, 0, 0, 1, 0, 0));
+        balance.get_mut(AssetType::KUSD).add(&AssetBalance::new(5, 2, 0, 0, 1, 1, 0));
+        assert_eq!(balance.ksh.mature, 5);
+        assert_eq!(balance.kusd.mature, 5);
+        assert_eq!(balance.kusd.pending, 2);
+        assert!(balance.krv.is_empty());
+        assert_eq!(balance.mature_utxo_count(), 2);
+        assert_eq!(balance.pending_utxo_count(), 1);
+
+        let strings = BalanceStrings::from((&Some(balance), &NetworkType::Mainnet, None));
+        assert_eq!(strings.assets.iter().map(|x| x.asset_type).collect::<Vec<_>>(), vec![AssetType::KSH, AssetType::KUSD]);
+        assert!(strings.get(AssetType::KRV).is_none());
+        assert!(strings.get(AssetType::KUSD).unwrap().pending.as_ref().is_some_and(|pending| pending.ends_with(" KUSD")));
+        assert!(strings.get(AssetType::KSH).unwrap().pending.is_none());
+
+        // bonding KRV is displayed with the KRV suffix rather than the KSH one
+        let mut balance = Balance::default();
+        balance.get_mut(AssetType::KRV).add(&AssetBalance::new(5, 0, 3, 0, 1, 0, 0));
+        let strings = BalanceStrings::from((&Some(balance), &NetworkType::Testnet, None));
+        let krv = strings.get(AssetType::KRV).unwrap();
+        assert!(krv.bonding.as_ref().is_some_and(|bonding| bonding.ends_with(" TKRV")));
+        assert!(krv.to_string().ends_with(" TKRV bonding)"));
+
+        let atomic = AtomicBalance::default();
+        atomic.add(&Balance::new(AssetBalance::default(), AssetBalance::new(3, 0, 0, 0, 1, 0, 0), AssetBalance::default()));
+        atomic.add(&Balance::new(AssetBalance::default(), AssetBalance::new(4, 0, 0, 0, 1, 0, 0), AssetBalance::default()));
+        let balance = Balance::from(atomic);
+        assert_eq!(balance.ksh.mature, 0);
+        assert_eq!(balance.kusd.mature, 7);
+        assert_eq!(balance.kusd.mature_utxo_count, 2);
     }
 }
@@ -478,40 +478,69 @@ impl UtxoContext {
 
     pub async fn calculate_balance(&self) -> Balance {
         let context = self.context();
-        let mature: u64 = context.mature.iter().map(|e| e.as_ref().entry.amount).sum();
-        let pending: u64 = context.pending.values().map(|e| e.as_ref().entry.amount).sum();
+        // KRV bonding status can only be determined once the current DAA score is known
+        let current_daa_score = self.processor().current_daa_score();
+
+        let mut balance = Balance::default();
+        for entry in context.mature.iter() {
+            let asset_balance = balance.get_mut(entry.utxo.entry.asset_type);
+            asset_balance.mature += entry.amount();
+            asset_balance.mature_utxo_count += 1;
+            if current_daa_score.is_some_and(|current_daa_score| entry.is_bonding(current_daa_score)) {
+                asset_balance.bonding += entry.amount();
+            }
+        }
+        for entry in context.pending.values() {
+            let asset_balance = balance.get_mut(entry.utxo.entry.asset_type);
+            asset_balance.pending += entry.amount();
+            asset_balance.pending_utxo_count += 1;
+        }
+        for entry in context.stasis.values() {
+            balance.get_mut(entry.utxo.entry.asset_type).stasis_utxo_count += 1;
+        }
 
         // this will aggregate only transactions containing
         // the final payments (not compound transactions)
         // and outgoing transactions that have not yet
-        // been accepted
-        let mut outgoing: u64 = 0;
-        let mut consumed: u64 = 0;
+        // been accepted; fees and change are accounted
+        // against the asset type of the consumed UTXOs,
+        // while the payment is denominated in the asset
+        // type of the transaction outputs
         for tx in context.outgoing.values() {
             if !tx.is_accepted() {
-                if let Some(payment_value) = tx.payment_value() {
+                let source_asset_type = tx.asset_type();
+                let destination_asset_type = tx.destination_asset_type();
+                let charge = if let Some(payment_value) = tx.payment_value() {
                     // final tx
-                    outgoing += tx.fees() + payment_value;
-                    consumed += tx.aggregate_input_value();
+                    balance.get_mut(source_asset_type).mature += tx.aggregate_input_value();
+                    if source_asset_type == destination_asset_type {
+                        tx.fees() + payment_value
+                    } else {
+                        // a conversion consumes everything but the
+                        // change from the source asset and pays out
+                        // in the destination asset, which is not
+                        // held by the consumed UTXOs
+                        balance.get_mut(destination_asset_type).outgoing += payment_value;
+                        tx.aggregate_input_value().saturating_sub(tx.change_value())
+                    }
                 } else {
                     // compound tx has no payment value
                     // we skip them, accumulating only fees
                     // as fees are the only component that will
                     // reduce the final balance after the
                     // compound process
-                    outgoing += tx.fees();
-                }
+                    tx.fees()
+                };
+                let asset_balance = balance.get_mut(source_asset_type);
+                asset_balance.outgoing += charge;
+                asset_balance.mature = asset_balance.mature.checked_sub(charge).unwrap_or_else(|| {
+                    log_error!("Error: outgoing {source_asset_type} transaction {} exceeds the mature balance", tx.id());
+                    0
+                });
             }
         }
 
-        Balance::new(
-            (mature + consumed) - outgoing,
-            pending,
-            outgoing,
-            context.mature.len(),
-            context.pending.len(),
-            context.stasis.len(),
-        )
+        balance
     }
 
     pub(crate) async fn handle_utxo_added(&self, utxos: Vec<UtxoEntryReference>, current_daa_score: u64) -> Result<()> {
@@ -15,7 +15,7 @@ pub mod settings;
 pub mod stream;
 pub mod sync;
 
-pub use balance::Balance;
+pub use balance::{AssetBalance, Balance};
 pub use binding::UtxoContextBinding;
 pub use context::{UtxoContext, UtxoContextId};
 pub use iterator::UtxoIterator;
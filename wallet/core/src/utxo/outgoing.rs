@@ -7,6 +7,7 @@
 use crate::imports::*;
 use crate::tx::PendingTransaction;
 use crate::utxo::{UtxoContext, UtxoEntryReference};
+use kash_consensus_core::asset_type::AssetType;
 
 struct Inner {
     pub id: TransactionId,
@@ -57,6 +58,18 @@ impl OutgoingTransaction {
         self.inner.pending_transaction.aggregate_input_value()
     }
 
+    pub fn change_value(&self) -> u64 {
+        self.inner.pending_transaction.change_value()
+    }
+
+    pub fn asset_type(&self) -> AssetType {
+        self.inner.pending_transaction.asset_type()
+    }
+
+    pub fn destination_asset_type(&self) -> AssetType {
+        self.inner.pending_transaction.destination_asset_type()
+    }
+
     pub fn pending_transaction(&self) -> &PendingTransaction {
         &self.inner.pending_transaction
     }
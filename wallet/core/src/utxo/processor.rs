@@ -30,7 +30,7 @@ use kash_rpc_core::{
     Notification,
 };
 
-use super::UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA;
+use super::{UtxoProcessingSettings, UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA};
 
 pub struct Inner {
     /// Coinbase UTXOs in stasis
@@ -100,6 +100,9 @@ impl UtxoProcessor {
         wallet_bus: Option<Channel<WalletBusMessage>>,
     ) -> Self {
         let multiplexer = multiplexer.unwrap_or_default();
+        if let Some(network_id) = &network_id {
+            UtxoProcessingSettings::init_network_defaults(network_id);
+        }
         UtxoProcessor { inner: Arc::new(Inner::new(rpc, network_id, multiplexer, wallet_bus)) }
     }
 
@@ -150,6 +153,7 @@ impl UtxoProcessor {
     }
 
     pub fn set_network_id(&self, network_id: NetworkId) {
+        UtxoProcessingSettings::init_network_defaults(&network_id);
         self.inner.network_id.lock().unwrap().replace(network_id);
     }
 
@@ -194,7 +198,7 @@ impl UtxoProcessor {
         if self.is_connected() {
             if !addresses.is_empty() {
                 let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
-                let utxos_changed_scope = UtxosChangedScope { addresses };
+                let utxos_changed_scope = UtxosChangedScope::new(addresses);
                 self.rpc_api().start_notify(self.listener_id()?, Scope::UtxosChanged(utxos_changed_scope)).await?;
             } else {
                 log_error!("registering empty address list!");
@@ -211,7 +215,7 @@ impl UtxoProcessor {
         if self.is_connected() {
             if !addresses.is_empty() {
                 let addresses = addresses.into_iter().map(|address| (*address).clone()).collect::<Vec<_>>();
-                let utxos_changed_scope = UtxosChangedScope { addresses };
+                let utxos_changed_scope = UtxosChangedScope::new(addresses);
                 self.rpc_api().stop_notify(self.listener_id()?, Scope::UtxosChanged(utxos_changed_scope)).await?;
             } else {
                 log_error!("unregistering empty address list!");
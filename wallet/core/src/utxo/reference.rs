@@ -4,9 +4,10 @@
 
 use crate::imports::*;
 use crate::utxo::{
-    UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA, UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA,
+    UTXO_BONDING_PERIOD_KRV_DAA, UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA, UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA,
     UTXO_STASIS_PERIOD_COINBASE_TRANSACTION_DAA,
 };
+use kash_consensus_core::asset_type::AssetType;
 pub use kash_consensus_wasm::{TryIntoUtxoEntryReferences, UtxoEntryReference};
 
 pub enum Maturity {
@@ -33,7 +34,9 @@ impl std::fmt::Display for Maturity {
 
 pub trait UtxoEntryReferenceExtension {
     fn maturity(&self, current_daa_score: u64) -> Maturity;
-    fn balance(&self, current_daa_score: u64) -> Balance;
+    /// KRV UTXO that has not reached [`UTXO_BONDING_PERIOD_KRV_DAA`], i.e. that can not be redeemed for KSH yet
+    fn is_bonding(&self, current_daa_score: u64) -> bool;
+    fn balance(&self, current_daa_score: u64) -> AssetBalance;
 }
 
 impl UtxoEntryReferenceExtension for UtxoEntryReference {
@@ -54,11 +57,19 @@ impl UtxoEntryReferenceExtension for UtxoEntryReference {
         }
     }
 
-    fn balance(&self, current_daa_score: u64) -> Balance {
+    fn is_bonding(&self, current_daa_score: u64) -> bool {
+        self.utxo.entry.asset_type == AssetType::KRV
+            && self.block_daa_score() + UTXO_BONDING_PERIOD_KRV_DAA.load(Ordering::SeqCst) > current_daa_score
+    }
+
+    fn balance(&self, current_daa_score: u64) -> AssetBalance {
         match self.maturity(current_daa_score) {
-            Maturity::Pending => Balance::new(0, self.amount(), self.amount(), 0, 1, 0),
-            Maturity::Stasis => Balance::new(0, 0, 0, 0, 0, 1),
-            Maturity::Confirmed => Balance::new(self.amount(), 0, 0, 1, 0, 0),
+            Maturity::Pending => AssetBalance::new(0, self.amount(), 0, self.amount(), 0, 1, 0),
+            Maturity::Stasis => AssetBalance::new(0, 0, 0, 0, 0, 0, 1),
+            Maturity::Confirmed => {
+                let bonding = if self.is_bonding(current_daa_score) { self.amount() } else { 0 };
+                AssetBalance::new(self.amount(), 0, bonding, 0, 1, 0, 0)
+            }
         }
     }
 }
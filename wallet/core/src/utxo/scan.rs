@@ -8,7 +8,6 @@ use crate::derivation::AddressManager;
 use crate::imports::*;
 use crate::utxo::balance::AtomicBalance;
 use crate::utxo::{UtxoContext, UtxoEntryReference, UtxoEntryReferenceExtension};
-use kash_consensus_core::asset_type::AssetType;
 use std::cmp::max;
 
 pub const DEFAULT_WINDOW_SIZE: usize = 8;
@@ -31,17 +30,13 @@ pub struct Scan {
     provider: Provider,
     window_size: Option<usize>,
     extent: Option<ScanExtent>,
-    ksh_balance: Arc<AtomicBalance>,
-    kusd_balance: Arc<AtomicBalance>,
-    krv_balance: Arc<AtomicBalance>,
+    balance: Arc<AtomicBalance>,
     current_daa_score: u64,
 }
 impl Scan {
     pub fn new_with_address_manager(
         address_manager: Arc<AddressManager>,
-        ksh_balance: &Arc<AtomicBalance>,
-        kusd_balance: &Arc<AtomicBalance>,
-        krv_balance: &Arc<AtomicBalance>,
+        balance: &Arc<AtomicBalance>,
         current_daa_score: u64,
         window_size: Option<usize>,
         extent: Option<ScanExtent>,
@@ -50,26 +45,16 @@ impl Scan {
             provider: Provider::AddressManager(address_manager),
             window_size, //: Some(DEFAULT_WINDOW_SIZE),
             extent,      //: Some(ScanExtent::EmptyWindow),
-            ksh_balance: ksh_balance.clone(),
-            kusd_balance: kusd_balance.clone(),
-            krv_balance: krv_balance.clone(),
+            balance: balance.clone(),
             current_daa_score,
         }
     }
-    pub fn new_with_address_set(
-        addresses: HashSet<Address>,
-        ksh_balance: &Arc<AtomicBalance>,
-        kusd_balance: &Arc<AtomicBalance>,
-        krv_balance: &Arc<AtomicBalance>,
-        current_daa_score: u64,
-    ) -> Scan {
+    pub fn new_with_address_set(addresses: HashSet<Address>, balance: &Arc<AtomicBalance>, current_daa_score: u64) -> Scan {
         Scan {
             provider: Provider::AddressSet(addresses),
             window_size: None,
             extent: None,
-            ksh_balance: ksh_balance.clone(),
-            kusd_balance: kusd_balance.clone(),
-            krv_balance: krv_balance.clone(),
+            balance: balance.clone(),
             current_daa_score,
         }
     }
@@ -117,32 +102,15 @@ impl Scan {
                         }
                     }
                 }
-                // Initialize separate balances for each currency
-                let mut ksh_balance = Balance::default();
-                let mut kusd_balance = Balance::default();
-                let mut krv_balance = Balance::default();
-
-                // Process each UTXO reference and accumulate balances for each asset type
+                // Accumulate the balance of each asset type separately
+                let mut balance = Balance::default();
                 refs.iter().for_each(|utxo_ref| {
-                    let entry_balance = utxo_ref.balance(self.current_daa_score);
-                    match utxo_ref.utxo.entry.asset_type {
-                        AssetType::KSH => update_balance(&mut ksh_balance, entry_balance),
-                        AssetType::KUSD => update_balance(&mut kusd_balance, entry_balance),
-                        AssetType::KRV => update_balance(&mut krv_balance, entry_balance),
-                    }
+                    balance.get_mut(utxo_ref.utxo.entry.asset_type).add(&utxo_ref.balance(self.current_daa_score));
                 });
 
                 utxo_context.extend_from_scan(refs, self.current_daa_score).await?;
 
-                if !ksh_balance.is_empty() {
-                    self.ksh_balance.add(ksh_balance);
-                }
-                if !kusd_balance.is_empty() {
-                    self.kusd_balance.add(kusd_balance);
-                }
-                if !krv_balance.is_empty() {
-                    self.krv_balance.add(krv_balance);
-                }
+                self.balance.add(&balance);
             } else {
                 match &extent {
                     ScanExtent::EmptyWindow => {
@@ -172,44 +140,17 @@ impl Scan {
         let resp = utxo_context.processor().rpc_api().get_utxos_by_addresses(address_vec).await?;
         let refs: Vec<UtxoEntryReference> = resp.into_iter().map(UtxoEntryReference::from).collect();
 
-        // Structs to hold the balances for each asset type
-        let mut ksh_total_balance = Balance::default();
-        let mut kusd_total_balance = Balance::default();
-        let mut krv_total_balance = Balance::default();
-
-        // Process each UTXO reference and accumulate balances for each asset type
+        // Accumulate the balance of each asset type separately
+        let mut balance = Balance::default();
         for r in refs.iter() {
-            let entry_balance = r.balance(self.current_daa_score);
-            match r.utxo.entry.asset_type {
-                AssetType::KSH => update_balance(&mut ksh_total_balance, entry_balance),
-                AssetType::KUSD => update_balance(&mut kusd_total_balance, entry_balance),
-                AssetType::KRV => update_balance(&mut krv_total_balance, entry_balance),
-            }
+            balance.get_mut(r.utxo.entry.asset_type).add(&r.balance(self.current_daa_score));
         }
         yield_executor().await;
 
         utxo_context.extend_from_scan(refs, self.current_daa_score).await?;
 
-        // Update the Scan struct balances
-        if !ksh_total_balance.is_empty() {
-            self.ksh_balance.add(ksh_total_balance);
-        }
-        if !kusd_total_balance.is_empty() {
-            self.kusd_balance.add(kusd_total_balance);
-        }
-        if !krv_total_balance.is_empty() {
-            self.krv_balance.add(krv_total_balance);
-        }
+        self.balance.add(&balance);
 
         Ok(())
     }
 }
-
-// Function to update balance
-fn update_balance(total_balance: &mut Balance, entry_balance: Balance) {
-    total_balance.mature += entry_balance.mature;
-    total_balance.pending += entry_balance.pending;
-    total_balance.mature_utxo_count += entry_balance.mature_utxo_count;
-    total_balance.pending_utxo_count += entry_balance.pending_utxo_count;
-    total_balance.stasis_utxo_count += entry_balance.stasis_utxo_count;
-}
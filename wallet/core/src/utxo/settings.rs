@@ -5,6 +5,7 @@
 
 use crate::imports::*;
 use crate::result::Result;
+use kash_consensus_core::config::{constants::consensus::LEGACY_KRV_UNBONDING_MATURITY, params::Params};
 
 /// Maturity period for coinbase transactions.
 pub static UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA: AtomicU64 = AtomicU64::new(100);
@@ -13,6 +14,13 @@ pub static UTXO_MATURITY_PERIOD_COINBASE_TRANSACTION_DAA: AtomicU64 = AtomicU64:
 pub static UTXO_STASIS_PERIOD_COINBASE_TRANSACTION_DAA: AtomicU64 = AtomicU64::new(50);
 /// Maturity period for user transactions.
 pub static UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA: AtomicU64 = AtomicU64::new(10);
+/// Bonding period for KRV UTXOs. Defaults to the consensus unbonding maturity of the wallet
+/// network (see `Params::krv_unbonding_maturity`) unless configured explicitly.
+/// KRV UTXOs within this period are mature but can not be redeemed for KSH yet.
+pub static UTXO_BONDING_PERIOD_KRV_DAA: AtomicU64 = AtomicU64::new(LEGACY_KRV_UNBONDING_MATURITY);
+/// Set once the KRV bonding period is configured via [`UtxoProcessingSettings`],
+/// in which case it is no longer reset to the network default.
+static UTXO_BONDING_PERIOD_KRV_DAA_CONFIGURED: AtomicBool = AtomicBool::new(false);
 /// Enables wallet events containing context UTXO updates.
 /// Useful if the client wants to keep track of UTXO sets or
 /// supply them during creation of transactions.
@@ -22,6 +30,7 @@ pub static ENABLE_UTXO_SELECTION_EVENTS: AtomicBool = AtomicBool::new(false);
 pub struct UtxoProcessingSettings {
     pub coinbase_transaction_maturity_daa: Option<u64>,
     pub user_transaction_maturity_daa: Option<u64>,
+    pub krv_bonding_period_daa: Option<u64>,
     pub enable_utxo_selection_events: Option<bool>,
 }
 
@@ -29,9 +38,10 @@ impl UtxoProcessingSettings {
     pub fn new(
         coinbase_transaction_maturity_daa: Option<u64>,
         user_transaction_maturity_daa: Option<u64>,
+        krv_bonding_period_daa: Option<u64>,
         enable_utxo_selection_events: Option<bool>,
     ) -> Self {
-        Self { coinbase_transaction_maturity_daa, user_transaction_maturity_daa, enable_utxo_selection_events }
+        Self { coinbase_transaction_maturity_daa, user_transaction_maturity_daa, krv_bonding_period_daa, enable_utxo_selection_events }
     }
 
     pub fn init(settings: UtxoProcessingSettings) {
@@ -41,10 +51,21 @@ impl UtxoProcessingSettings {
         if let Some(v) = settings.user_transaction_maturity_daa {
             UTXO_MATURITY_PERIOD_USER_TRANSACTION_DAA.store(v, Ordering::Relaxed)
         }
+        if let Some(v) = settings.krv_bonding_period_daa {
+            UTXO_BONDING_PERIOD_KRV_DAA.store(v, Ordering::Relaxed);
+            UTXO_BONDING_PERIOD_KRV_DAA_CONFIGURED.store(true, Ordering::Relaxed);
+        }
         if let Some(v) = settings.enable_utxo_selection_events {
             ENABLE_UTXO_SELECTION_EVENTS.store(v, Ordering::Relaxed)
         }
     }
+
+    /// Applies the defaults of the given network to the settings which were not configured explicitly.
+    pub fn init_network_defaults(network_id: &NetworkId) {
+        if !UTXO_BONDING_PERIOD_KRV_DAA_CONFIGURED.load(Ordering::Relaxed) {
+            UTXO_BONDING_PERIOD_KRV_DAA.store(Params::from(*network_id).krv_unbonding_maturity, Ordering::Relaxed)
+        }
+    }
 }
 
 #[wasm_bindgen(js_name = configureUtxoProcessing)]
@@ -52,10 +73,15 @@ pub fn configure_utxo_processing(thresholds: &JsValue) -> Result<()> {
     let object = Object::try_from(thresholds).ok_or(Error::custom("Supplied value must be an object"))?;
     let coinbase_transaction_maturity_daa = object.get_u64("coinbaseTransactionMaturityInDAA").ok();
     let user_transaction_maturity_daa = object.get_u64("userTransactionMaturityInDAA").ok();
+    let krv_bonding_period_daa = object.get_u64("krvBondingPeriodInDAA").ok();
     let enable_utxo_selection_events = object.get_bool("enableUtxoSelectionEvents").ok();
 
-    let thresholds =
-        UtxoProcessingSettings { coinbase_transaction_maturity_daa, user_transaction_maturity_daa, enable_utxo_selection_events };
+    let thresholds = UtxoProcessingSettings {
+        coinbase_transaction_maturity_daa,
+        user_transaction_maturity_daa,
+        krv_bonding_period_daa,
+        enable_utxo_selection_events,
+    };
 
     UtxoProcessingSettings::init(thresholds);
 
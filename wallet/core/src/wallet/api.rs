@@ -8,15 +8,16 @@ use crate::result::Result;
 use crate::storage::interface::TransactionRangeResult;
 use crate::storage::Binding;
 use crate::tx::Fees;
-use workflow_core::channel::Receiver;
+use workflow_core::channel::Sender;
 
 #[async_trait]
 impl WalletApi for super::Wallet {
-    async fn register_notifications(self: Arc<Self>, _channel: Receiver<WalletNotification>) -> Result<u64> {
-        todo!()
+    async fn register_notifications(self: Arc<Self>, channel: Sender<WalletNotification>, filter: NotificationFilter) -> Result<u64> {
+        Ok(self.notifications().register(channel, filter))
     }
-    async fn unregister_notifications(self: Arc<Self>, _channel_id: u64) -> Result<()> {
-        todo!()
+
+    async fn unregister_notifications(self: Arc<Self>, channel_id: u64) -> Result<()> {
+        self.notifications().unregister(channel_id)
     }
 
     async fn get_status_call(self: Arc<Self>, _request: GetStatusRequest) -> Result<GetStatusResponse> {
@@ -212,9 +213,15 @@ impl WalletApi for super::Wallet {
         Ok(AccountsCreateResponse { account_descriptor })
     }
 
-    async fn accounts_import_call(self: Arc<Self>, _request: AccountsImportRequest) -> Result<AccountsImportResponse> {
-        // TODO handle account imports
-        return Err(Error::NotImplemented);
+    async fn accounts_import_call(self: Arc<Self>, request: AccountsImportRequest) -> Result<AccountsImportResponse> {
+        let AccountsImportRequest { wallet_secret, xpub_keys, account_name, cosigner_index, minimum_signatures, ecdsa } = request;
+
+        let account_create_args =
+            AccountCreateArgs::new_watch_only(xpub_keys, account_name, cosigner_index, minimum_signatures, ecdsa);
+        let account = self.create_account(&wallet_secret, account_create_args, true).await?;
+        let account_descriptor = account.descriptor()?;
+
+        Ok(AccountsImportResponse { account_descriptor })
     }
 
     async fn accounts_get_call(self: Arc<Self>, request: AccountsGetRequest) -> Result<AccountsGetResponse> {
@@ -354,8 +361,178 @@ impl WalletApi for super::Wallet {
 
     async fn address_book_enumerate_call(
         self: Arc<Self>,
-        _request: AddressBookEnumerateRequest,
+        request: AddressBookEnumerateRequest,
     ) -> Result<AddressBookEnumerateResponse> {
-        return Err(Error::NotImplemented);
+        let AddressBookEnumerateRequest { search } = request;
+
+        let store = self.store().as_address_book_store()?;
+        let entries =
+            if let Some(search) = search { store.search(&search).await? } else { store.iter().await?.try_collect::<Vec<_>>().await? };
+
+        Ok(AddressBookEnumerateResponse { entries })
+    }
+
+    async fn address_book_create_call(self: Arc<Self>, request: AddressBookCreateRequest) -> Result<AddressBookCreateResponse> {
+        let AddressBookCreateRequest { wallet_secret, entry } = request;
+        entry.validate()?;
+
+        let store = self.store().as_address_book_store()?;
+        if store.load_single(&entry.alias).await?.is_some() {
+            return Err(Error::AddressBookEntryExists(entry.alias));
+        }
+        let snapshot = store.iter().await?.try_collect::<Vec<_>>().await?;
+        store.store_single(&entry).await?;
+        self.commit_address_book(&wallet_secret, &snapshot).await?;
+
+        Ok(AddressBookCreateResponse {})
+    }
+
+    async fn address_book_update_call(self: Arc<Self>, request: AddressBookUpdateRequest) -> Result<AddressBookUpdateResponse> {
+        let AddressBookUpdateRequest { wallet_secret, alias, entry } = request;
+        entry.validate()?;
+
+        let store = self.store().as_address_book_store()?;
+        if store.load_single(&alias).await?.is_none() {
+            return Err(Error::AddressBookEntryNotFound(alias));
+        }
+        if !entry.is_alias(&alias) && store.load_single(&entry.alias).await?.is_some() {
+            return Err(Error::AddressBookEntryExists(entry.alias));
+        }
+        let snapshot = store.iter().await?.try_collect::<Vec<_>>().await?;
+        if !entry.is_alias(&alias) {
+            store.remove(&[alias.as_str()]).await?;
+        }
+        store.store_single(&entry).await?;
+        self.commit_address_book(&wallet_secret, &snapshot).await?;
+
+        Ok(AddressBookUpdateResponse {})
+    }
+
+    async fn address_book_remove_call(self: Arc<Self>, request: AddressBookRemoveRequest) -> Result<AddressBookRemoveResponse> {
+        let AddressBookRemoveRequest { wallet_secret, aliases } = request;
+
+        let store = self.store().as_address_book_store()?;
+        for alias in aliases.iter() {
+            if store.load_single(alias).await?.is_none() {
+                return Err(Error::AddressBookEntryNotFound(alias.clone()));
+            }
+        }
+        let snapshot = store.iter().await?.try_collect::<Vec<_>>().await?;
+        let aliases = aliases.iter().map(String::as_str).collect::<Vec<_>>();
+        store.remove(&aliases).await?;
+        self.commit_address_book(&wallet_secret, &snapshot).await?;
+
+        Ok(AddressBookRemoveResponse {})
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::{Wallet, WalletCreateArgs};
+
+    const TESTNET_ADDRESS: &str = "kashtest:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xjceef60sd";
+    const MAINNET_ADDRESS: &str = "kash:qz7ulu4c25dh7fzec9zjyrmlhnkzrg4wmf89q7gzr3gfrsj3uz6xj7xendmzj";
+
+    async fn create_wallet() -> Result<(Arc<Wallet>, Secret)> {
+        let wallet = Arc::new(Wallet::try_new(Wallet::resident_store()?, Some(NetworkId::with_suffix(NetworkType::Testnet, 11)))?);
+        let wallet_secret = Secret::new(b"secret".to_vec());
+        wallet
+            .create_wallet(&wallet_secret, WalletCreateArgs::new(None, None, EncryptionKind::XChaCha20Poly1305, None, false))
+            .await?;
+        Ok((wallet, wallet_secret))
+    }
+
+    fn create_entry(alias: &str, address: &str) -> Result<AddressBookEntry> {
+        AddressBookEntry::try_new(alias, Address::try_from(address)?)
+    }
+
+    async fn create(wallet: &Arc<Wallet>, wallet_secret: &Secret, entry: AddressBookEntry) -> Result<()> {
+        wallet.clone().address_book_create_call(AddressBookCreateRequest { wallet_secret: wallet_secret.clone(), entry }).await?;
+        Ok(())
+    }
+
+    async fn update(wallet: &Arc<Wallet>, wallet_secret: &Secret, alias: &str, entry: AddressBookEntry) -> Result<()> {
+        let request = AddressBookUpdateRequest { wallet_secret: wallet_secret.clone(), alias: alias.to_string(), entry };
+        wallet.clone().address_book_update_call(request).await?;
+        Ok(())
+    }
+
+    async fn aliases(wallet: &Arc<Wallet>) -> Result<Vec<String>> {
+        let request = AddressBookEnumerateRequest { search: None };
+        let AddressBookEnumerateResponse { entries } = wallet.clone().address_book_enumerate_call(request).await?;
+        Ok(entries.iter().map(|entry| entry.alias.clone()).collect())
+    }
+
+    #[tokio::test]
+    async fn test_address_book() -> Result<()> {
+        let (wallet, wallet_secret) = create_wallet().await?;
+
+        create(&wallet, &wallet_secret, create_entry("alice", TESTNET_ADDRESS)?).await?;
+        create(&wallet, &wallet_secret, create_entry("bob", MAINNET_ADDRESS)?.with_title("Bob")).await?;
+        assert_eq!(aliases(&wallet).await?, vec!["alice", "bob"]);
+
+        // Aliases are unique regardless of their case
+        let result = create(&wallet, &wallet_secret, create_entry("Alice", MAINNET_ADDRESS)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryExists(_))));
+
+        // Entries whose network does not match their address are refused
+        let mut entry = create_entry("carol", TESTNET_ADDRESS)?;
+        entry.network_type = NetworkType::Mainnet;
+        let result = create(&wallet, &wallet_secret, entry).await;
+        assert!(matches!(result, Err(Error::AddressBookNetworkMismatch(_))));
+
+        // Update in place, looking the entry up by a case-insensitive alias
+        update(&wallet, &wallet_secret, "ALICE", create_entry("alice", TESTNET_ADDRESS)?.with_title("Alice")).await?;
+        let entry = wallet.store().as_address_book_store()?.load_single("alice").await?.unwrap();
+        assert_eq!(entry.title, "Alice");
+
+        // Rename, refusing aliases that are already taken
+        let result = update(&wallet, &wallet_secret, "alice", create_entry("BOB", TESTNET_ADDRESS)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryExists(_))));
+        update(&wallet, &wallet_secret, "alice", create_entry("carol", TESTNET_ADDRESS)?).await?;
+        assert_eq!(aliases(&wallet).await?, vec!["bob", "carol"]);
+
+        // An invalid update leaves the original entry untouched
+        let mut entry = create_entry("dave", TESTNET_ADDRESS)?;
+        entry.network_type = NetworkType::Mainnet;
+        let result = update(&wallet, &wallet_secret, "carol", entry).await;
+        assert!(matches!(result, Err(Error::AddressBookNetworkMismatch(_))));
+        let mut entry = create_entry("carol", TESTNET_ADDRESS)?;
+        entry.alias = " ".to_string();
+        let result = update(&wallet, &wallet_secret, "carol", entry).await;
+        assert!(matches!(result, Err(Error::AddressBookInvalidAlias(_))));
+        assert_eq!(aliases(&wallet).await?, vec!["bob", "carol"]);
+
+        let result = update(&wallet, &wallet_secret, "dave", create_entry("dave", TESTNET_ADDRESS)?).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryNotFound(_))));
+
+        // Contacts resolve only on the network of the wallet
+        let entry = wallet.find_address_book_entry("Carol").await?.unwrap();
+        assert_eq!(entry.address, Address::try_from(TESTNET_ADDRESS)?);
+        assert!(wallet.find_address_book_entry("dave").await?.is_none());
+        let result = wallet.find_address_book_entry("bob").await;
+        assert!(matches!(result, Err(Error::AddressBookWrongNetwork(_))));
+
+        // Remove, refusing the whole request if any alias is unknown
+        let request =
+            AddressBookRemoveRequest { wallet_secret: wallet_secret.clone(), aliases: vec!["bob".to_string(), "dave".to_string()] };
+        let result = wallet.clone().address_book_remove_call(request).await;
+        assert!(matches!(result, Err(Error::AddressBookEntryNotFound(_))));
+        assert_eq!(aliases(&wallet).await?, vec!["bob", "carol"]);
+        let request = AddressBookRemoveRequest { wallet_secret: wallet_secret.clone(), aliases: vec!["BOB".to_string()] };
+        wallet.clone().address_book_remove_call(request).await?;
+        assert_eq!(aliases(&wallet).await?, vec!["carol"]);
+
+        // Restoring a snapshot discards the changes made since it was taken, as done when a commit fails
+        let store = wallet.store().as_address_book_store()?;
+        let snapshot = store.iter().await?.try_collect::<Vec<_>>().await?;
+        store.store_single(&create_entry("dave", TESTNET_ADDRESS)?).await?;
+        store.remove(&["carol"]).await?;
+        store.restore(&snapshot).await?;
+        assert_eq!(aliases(&wallet).await?, vec!["carol"]);
+
+        Ok(())
     }
 }
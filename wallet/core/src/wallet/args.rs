@@ -118,6 +118,13 @@ pub enum AccountCreateArgs {
         name: Option<String>,
         minimum_signatures: u16,
     },
+    WatchOnly {
+        xpub_keys: Vec<String>,
+        name: Option<String>,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+    },
 }
 
 impl AccountCreateArgs {
@@ -144,4 +151,15 @@ impl AccountCreateArgs {
     ) -> Self {
         AccountCreateArgs::Multisig { prv_key_data_args, additional_xpub_keys, name, minimum_signatures }
     }
+
+    /// Watch-only account tracking a BIP32 account (single key) or a multisig account (multiple keys)
+    pub fn new_watch_only(
+        xpub_keys: Vec<String>,
+        name: Option<String>,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+    ) -> Self {
+        AccountCreateArgs::WatchOnly { xpub_keys, name, cosigner_index, minimum_signatures, ecdsa }
+    }
 }
@@ -3,12 +3,14 @@
 //!
 
 use crate::account::ScanNotifier;
+use crate::api::Notifications;
 use crate::factory::try_load_account;
 use crate::imports::*;
 use crate::settings::{SettingsStore, WalletSettings};
 use crate::storage::interface::{OpenArgs, StorageDescriptor};
 use crate::storage::local::interface::LocalStore;
 use crate::storage::local::Storage;
+use crate::tx::Pskt;
 use crate::wallet::maps::ActiveAccountMap;
 use kash_bip32::Prefix as KeyPrefix;
 use kash_bip32::{Language, Mnemonic, WordCount};
@@ -40,6 +42,7 @@ pub struct Inner {
     settings: SettingsStore<WalletSettings>,
     utxo_processor: Arc<UtxoProcessor>,
     multiplexer: Multiplexer<Box<Events>>,
+    notifications: Notifications,
     wallet_bus: Channel<WalletBusMessage>,
     estimation_abortables: Mutex<HashMap<AccountId, Abortable>>,
 }
@@ -81,6 +84,7 @@ impl Wallet {
         let wallet = Wallet {
             inner: Arc::new(Inner {
                 multiplexer,
+                notifications: Notifications::default(),
                 store,
                 active_accounts: ActiveAccountMap::default(),
                 legacy_accounts: ActiveAccountMap::default(),
@@ -387,6 +391,11 @@ impl Wallet {
         &self.inner.multiplexer
     }
 
+    /// Notification channels registered via [`WalletApi::register_notifications()`](crate::api::WalletApi::register_notifications)
+    pub fn notifications(&self) -> &Notifications {
+        &self.inner.notifications
+    }
+
     pub(crate) fn wallet_bus(&self) -> &Channel<WalletBusMessage> {
         &self.inner.wallet_bus
     }
@@ -458,8 +467,15 @@ impl Wallet {
         Ok(())
     }
 
-    pub async fn broadcast(&self) -> Result<()> {
-        Ok(())
+    /// Finalizes a fully signed [`Pskt`] and submits the resulting transaction to the network
+    pub async fn broadcast(&self, pskt: &Pskt) -> Result<TransactionId> {
+        let network_id = self.network_id()?;
+        if pskt.network_id != network_id {
+            return Err(Error::PsktNetworkMismatch { expected: network_id.to_string(), found: pskt.network_id.to_string() });
+        }
+
+        let transaction = pskt.finalize()?;
+        Ok(self.rpc_api().submit_transaction((&transaction).into(), false).await?)
     }
 
     pub fn set_network_id(&self, network_id: NetworkId) -> Result<()> {
@@ -508,6 +524,9 @@ impl Wallet {
             AccountCreateArgs::Multisig { prv_key_data_args, additional_xpub_keys, name, minimum_signatures } => {
                 self.create_account_multisig(wallet_secret, prv_key_data_args, additional_xpub_keys, name, minimum_signatures).await?
             }
+            AccountCreateArgs::WatchOnly { xpub_keys, name, cosigner_index, minimum_signatures, ecdsa } => {
+                self.create_account_watch_only(wallet_secret, xpub_keys, name, cosigner_index, minimum_signatures, ecdsa).await?
+            }
         };
 
         if notify {
@@ -594,6 +613,40 @@ impl Wallet {
         Ok(account)
     }
 
+    pub async fn create_account_watch_only(
+        self: &Arc<Wallet>,
+        wallet_secret: &Secret,
+        xpub_keys: Vec<String>,
+        account_name: Option<String>,
+        cosigner_index: Option<u8>,
+        minimum_signatures: u16,
+        ecdsa: bool,
+    ) -> Result<Arc<dyn Account>> {
+        let account_store = self.inner.store.clone().as_account_store()?;
+
+        let mut xpub_keys = xpub_keys
+            .into_iter()
+            .map(|xpub_key| {
+                ExtendedPublicKeySecp256k1::from_str(&xpub_key).map_err(|err| Error::InvalidExtendedPublicKey(xpub_key, err))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // multisig cosigners are ordered the same way as in `create_account_multisig()`
+        xpub_keys.sort_unstable_by_key(|xpub_key| xpub_key.to_string(Some(KeyPrefix::XPUB)));
+
+        let account: Arc<dyn Account> = Arc::new(
+            watchonly::WatchOnly::try_new(self, account_name, Arc::new(xpub_keys), cosigner_index, minimum_signatures, ecdsa).await?,
+        );
+
+        if account_store.load_single(account.id()).await?.is_some() {
+            return Err(Error::AccountAlreadyExists(*account.id()));
+        }
+
+        self.inner.store.clone().as_account_store()?.store_single(&account.to_storage()?, None).await?;
+        self.inner.store.commit(wallet_secret).await?;
+
+        Ok(account)
+    }
+
     pub async fn create_account_bip32(
         self: &Arc<Wallet>,
         wallet_secret: &Secret,
@@ -846,6 +899,7 @@ impl Wallet {
                     msg = events.receiver.recv().fuse() => {
                         match msg {
                             Ok(event) => {
+                                this.notifications().notify(&event);
                                 this.handle_event(event).await.unwrap_or_else(|e| log_error!("Wallet::handle_event() error: {}", e));
                             },
                             Err(err) => {
@@ -910,6 +964,28 @@ impl Wallet {
         Ok(matches)
     }
 
+    /// Looks up an address book entry (contact) by its alias, ensuring
+    /// that the contact address belongs to the currently selected network.
+    pub async fn find_address_book_entry(&self, alias: &str) -> Result<Option<Arc<AddressBookEntry>>> {
+        let entry = self.inner.store.as_address_book_store()?.load_single(alias).await?;
+        if let Some(entry) = entry.as_ref() {
+            if entry.network_type != self.network_id()?.network_type() {
+                return Err(Error::AddressBookWrongNetwork(entry.alias.clone()));
+            }
+        }
+        Ok(entry)
+    }
+
+    /// Commits pending address book changes, restoring the address book
+    /// to `snapshot` (taken prior to the changes) if the commit fails.
+    pub(crate) async fn commit_address_book(&self, wallet_secret: &Secret, snapshot: &[Arc<AddressBookEntry>]) -> Result<()> {
+        if let Err(err) = self.inner.store.commit(wallet_secret).await {
+            self.inner.store.as_address_book_store()?.restore(snapshot).await?;
+            return Err(err);
+        }
+        Ok(())
+    }
+
     pub async fn accounts(self: &Arc<Self>, filter: Option<PrvKeyDataId>) -> Result<impl Stream<Item = Result<Arc<dyn Account>>>> {
         let iter = self.inner.store.as_account_store().unwrap().iter(filter).await.unwrap();
         let wallet = self.clone();
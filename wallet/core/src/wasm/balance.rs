@@ -1,14 +1,15 @@
 use crate::imports::*;
 use crate::result::Result;
 use crate::utxo::balance as native;
+use kash_consensus_core::asset_type::AssetType;
 
 #[wasm_bindgen]
-pub struct Balance {
-    inner: native::Balance,
+pub struct AssetBalance {
+    inner: native::AssetBalance,
 }
 
 #[wasm_bindgen]
-impl Balance {
+impl AssetBalance {
     #[wasm_bindgen(getter)]
     pub fn mature(&self) -> BigInt {
         self.inner.mature.into()
@@ -19,6 +20,51 @@ impl Balance {
         self.inner.pending.into()
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn bonding(&self) -> BigInt {
+        self.inner.bonding.into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn outgoing(&self) -> BigInt {
+        self.inner.outgoing.into()
+    }
+}
+
+impl From<native::AssetBalance> for AssetBalance {
+    fn from(inner: native::AssetBalance) -> Self {
+        Self { inner }
+    }
+}
+
+#[wasm_bindgen]
+pub struct Balance {
+    inner: native::Balance,
+}
+
+#[wasm_bindgen]
+impl Balance {
+    #[wasm_bindgen(getter)]
+    pub fn ksh(&self) -> AssetBalance {
+        self.inner.ksh.clone().into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kusd(&self) -> AssetBalance {
+        self.inner.kusd.clone().into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn krv(&self) -> AssetBalance {
+        self.inner.krv.clone().into()
+    }
+
+    /// Returns the balance of the given asset type
+    pub fn asset(&self, asset_type: JsValue) -> Result<AssetBalance> {
+        let asset_type = AssetType::try_from(asset_type).map_err(|_| Error::InvalidAssetType)?;
+        Ok(self.inner.get(asset_type).clone().into())
+    }
+
     pub fn as_strings(&self, network_type: JsValue) -> Result<BalanceStrings> {
         let network_type = NetworkType::try_from(network_type)?;
         Ok(native::BalanceStrings::from((&Some(self.inner.clone()), &network_type, None)).into())
@@ -32,12 +78,17 @@ impl From<native::Balance> for Balance {
 }
 
 #[wasm_bindgen]
-pub struct BalanceStrings {
-    inner: native::BalanceStrings,
+pub struct AssetBalanceStrings {
+    inner: native::AssetBalanceStrings,
 }
 
 #[wasm_bindgen]
-impl BalanceStrings {
+impl AssetBalanceStrings {
+    #[wasm_bindgen(getter, js_name = assetType)]
+    pub fn asset_type(&self) -> AssetType {
+        self.inner.asset_type
+    }
+
     #[wasm_bindgen(getter)]
     pub fn mature(&self) -> JsValue {
         self.inner.mature.clone().into()
@@ -47,6 +98,46 @@ impl BalanceStrings {
     pub fn pending(&self) -> JsValue {
         self.inner.pending.clone().into()
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn bonding(&self) -> JsValue {
+        self.inner.bonding.clone().into()
+    }
+}
+
+impl From<native::AssetBalanceStrings> for AssetBalanceStrings {
+    fn from(inner: native::AssetBalanceStrings) -> Self {
+        Self { inner }
+    }
+}
+
+#[wasm_bindgen]
+pub struct BalanceStrings {
+    inner: native::BalanceStrings,
+}
+
+#[wasm_bindgen]
+impl BalanceStrings {
+    #[wasm_bindgen(getter)]
+    pub fn ksh(&self) -> Option<AssetBalanceStrings> {
+        self.inner.get(AssetType::KSH).cloned().map(Into::into)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kusd(&self) -> Option<AssetBalanceStrings> {
+        self.inner.get(AssetType::KUSD).cloned().map(Into::into)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn krv(&self) -> Option<AssetBalanceStrings> {
+        self.inner.get(AssetType::KRV).cloned().map(Into::into)
+    }
+
+    /// Returns the strings of the given asset type, `undefined` if the balance holds none of it
+    pub fn asset(&self, asset_type: JsValue) -> Result<Option<AssetBalanceStrings>> {
+        let asset_type = AssetType::try_from(asset_type).map_err(|_| Error::InvalidAssetType)?;
+        Ok(self.inner.get(asset_type).cloned().map(Into::into))
+    }
 }
 
 impl From<native::BalanceStrings> for BalanceStrings {
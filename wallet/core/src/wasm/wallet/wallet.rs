@@ -153,6 +153,19 @@ impl Wallet {
     //     // }
     // }
 
+    /// Imports a watch-only account from one (BIP32) or multiple (multisig) extended public keys.
+    /// Accepts an object `{ walletSecret, xpubKeys, name?, cosignerIndex?, minimumSignatures?, ecdsa? }`,
+    /// `cosignerIndex` being the index of the tracked cosigner among the sorted `xpubKeys` of a multisig account.
+    #[wasm_bindgen(js_name = "importWatchOnlyAccount")]
+    pub async fn import_watch_only_account(&self, args: JsValue) -> Result<Account> {
+        let WatchOnlyImportArgs { wallet_secret, xpub_keys, name, cosigner_index, minimum_signatures, ecdsa } =
+            WatchOnlyImportArgs::try_from(args)?;
+
+        let account_create_args = AccountCreateArgs::new_watch_only(xpub_keys, name, cosigner_index, minimum_signatures, ecdsa);
+        let account = self.wallet.create_account(&wallet_secret, account_create_args, true).await?;
+        Account::try_new(account).await
+    }
+
     // pub async fn ping(&self) -> bool {
     //     self.wallet.ping().await.is_ok()
     // }
@@ -180,6 +193,36 @@ impl Wallet {
     }
 }
 
+struct WatchOnlyImportArgs {
+    wallet_secret: Secret,
+    xpub_keys: Vec<String>,
+    name: Option<String>,
+    cosigner_index: Option<u8>,
+    minimum_signatures: u16,
+    ecdsa: bool,
+}
+
+impl TryFrom<JsValue> for WatchOnlyImportArgs {
+    type Error = Error;
+    fn try_from(js_value: JsValue) -> Result<Self> {
+        if let Some(object) = Object::try_from(&js_value) {
+            let wallet_secret = object.get_string("walletSecret")?.into();
+            let xpub_keys = Array::from(&object.get_value("xpubKeys")?)
+                .iter()
+                .map(|xpub_key| xpub_key.as_string().ok_or(Error::custom("xpubKeys must be an array of strings")))
+                .collect::<Result<Vec<_>>>()?;
+            let name = object.get_value("name")?.as_string();
+            let cosigner_index = object.get_u8("cosignerIndex").ok();
+            let minimum_signatures = object.get_u16("minimumSignatures").unwrap_or(1);
+            let ecdsa = object.get_bool("ecdsa").unwrap_or(false);
+
+            Ok(WatchOnlyImportArgs { wallet_secret, xpub_keys, name, cosigner_index, minimum_signatures, ecdsa })
+        } else {
+            Err(Error::custom("Wallet::importWatchOnlyAccount(): argument must be an object"))
+        }
+    }
+}
+
 #[derive(Default)]
 struct WalletCtorArgs {
     resident: bool,